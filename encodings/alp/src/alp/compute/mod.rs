@@ -1,16 +1,21 @@
+use vortex_array::array::{ConstantArray, SparseArray};
 use vortex_array::compute::{
-    filter, scalar_at, slice, take, ComputeVTable, FilterFn, FilterMask, ScalarAtFn, SliceFn,
-    TakeFn, TakeOptions,
+    binary_numeric, filter, scalar_at, slice, take, BinaryNumericFn, BinaryNumericOperator,
+    ComputeVTable, FilterFn, FilterMask, ScalarAtFn, SliceFn, TakeFn, TakeOptions,
 };
 use vortex_array::validity::ArrayValidity;
 use vortex_array::variants::PrimitiveArrayTrait;
-use vortex_array::{ArrayDType, ArrayData, IntoArrayData};
+use vortex_array::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
-use crate::{match_each_alp_float_ptype, ALPArray, ALPEncoding, ALPFloat};
+use crate::{match_each_alp_float_ptype, ALPArray, ALPEncoding, ALPFloat, Exponents};
 
 impl ComputeVTable for ALPEncoding {
+    fn binary_numeric_fn(&self) -> Option<&dyn BinaryNumericFn<ArrayData>> {
+        Some(self)
+    }
+
     fn filter_fn(&self) -> Option<&dyn FilterFn<ArrayData>> {
         Some(self)
     }
@@ -93,3 +98,120 @@ impl FilterFn<ALPArray> for ALPEncoding {
         )
     }
 }
+
+impl BinaryNumericFn<ALPArray> for ALPEncoding {
+    fn binary_numeric(
+        &self,
+        array: &ALPArray,
+        other: &ArrayData,
+        op: BinaryNumericOperator,
+    ) -> VortexResult<Option<ArrayData>> {
+        let Some(scalar) = other.as_constant() else {
+            return Ok(None);
+        };
+        if scalar.dtype().is_nullable() && !array.dtype().is_nullable() {
+            return Ok(None);
+        }
+
+        // Multiplying or dividing by a power of ten only changes the exponent the encoded integers
+        // are scaled by, as long as that's exact for all the values.
+        let exponents = match_each_alp_float_ptype!(array.ptype(), |$T| {
+            let Some(value) = scalar.as_primitive().typed_value::<$T>() else {
+                return Ok(None);
+            };
+            let Some(exponents) = scaled_exponents::<$T>(array.exponents(), value, op) else {
+                return Ok(None);
+            };
+            let encoded = array.encoded().into_primitive()?;
+            if !is_exact_scaling::<$T>(
+                encoded.maybe_null_slice(),
+                array.exponents(),
+                exponents,
+                value,
+                op,
+            ) {
+                return Ok(None);
+            }
+            exponents
+        });
+
+        let patches = array
+            .patches()
+            .map(|p| binary_numeric(&p, &ConstantArray::new(scalar, p.len()).into_array(), op))
+            .transpose()?;
+        if patches
+            .as_ref()
+            .is_some_and(|p| SparseArray::try_from(p.clone()).is_err())
+        {
+            return Ok(None);
+        }
+
+        ALPArray::try_new(array.encoded(), exponents, patches).map(|a| Some(a.into_array()))
+    }
+}
+
+/// The exponents that scale values encoded with `exponents` by `value` under `op`, if `value` is a
+/// power of ten that the exponents can absorb. The scaling may not be exact for all values.
+fn scaled_exponents<T: ALPFloat>(
+    exponents: Exponents,
+    value: T,
+    op: BinaryNumericOperator,
+) -> Option<Exponents> {
+    let power = u8::try_from(T::F10.iter().position(|f| *f == value)?).ok()?;
+    let e = match op {
+        BinaryNumericOperator::Mul => exponents.e.checked_sub(power)?,
+        BinaryNumericOperator::Div => exponents
+            .e
+            .checked_add(power)
+            .filter(|e| *e <= T::MAX_EXPONENT)?,
+        _ => return None,
+    };
+    Some(Exponents { e, f: exponents.f })
+}
+
+/// Whether decoding with the scaled exponents gives the same values as applying `op` to the
+/// decoded values.
+fn is_exact_scaling<T: ALPFloat>(
+    encoded: &[T::ALPInt],
+    exponents: Exponents,
+    scaled: Exponents,
+    value: T,
+    op: BinaryNumericOperator,
+) -> bool {
+    encoded.iter().all(|e| {
+        T::decode_single(*e, scaled) == op.apply_float(T::decode_single(*e, exponents), value)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::{ConstantArray, PrimitiveArray};
+    use vortex_array::compute::{div, mul};
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+
+    use crate::{alp_encode, ALPArray};
+
+    #[test]
+    fn alp_scale() {
+        let values = vec![1.1f64, 2.2, 1e20, 3.3];
+        let alp = alp_encode(&PrimitiveArray::from(values.clone()))
+            .unwrap()
+            .into_array();
+        assert!(ALPArray::try_from(alp.clone()).unwrap().patches().is_some());
+
+        let scaled = mul(&alp, ConstantArray::new(10.0f64, 4).into_array()).unwrap();
+        assert!(ALPArray::try_from(scaled.clone()).is_ok());
+        assert_eq!(
+            scaled.into_primitive().unwrap().maybe_null_slice::<f64>(),
+            values.iter().map(|v| v * 10.0).collect::<Vec<_>>()
+        );
+
+        // Scaling the exponents isn't exact for these values, so they're decoded instead.
+        let scaled = div(&alp, ConstantArray::new(10.0f64, 4).into_array()).unwrap();
+        assert!(ALPArray::try_from(scaled.clone()).is_err());
+        assert_eq!(
+            scaled.into_primitive().unwrap().maybe_null_slice::<f64>(),
+            values.iter().map(|v| v / 10.0).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use num_traits::AsPrimitive;
+use vortex_array::array::PrimitiveArray;
+use vortex_array::compute::{
+    min_max, scalar_at, take, MinMaxFn, MinMaxResult, SumAccumulator, SumFn, TakeOptions,
+};
+use vortex_array::variants::PrimitiveArrayTrait;
+use vortex_array::IntoArrayVariant;
+use vortex_dtype::match_each_unsigned_integer_ptype;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::{DictArray, DictEncoding};
+
+impl SumFn<DictArray> for DictEncoding {
+    fn sum(&self, array: &DictArray) -> VortexResult<Scalar> {
+        let values = array.values().into_primitive()?;
+        let mut result = SumAccumulator::new(values.ptype());
+        for (code, count) in code_counts(array)?
+            .into_iter()
+            .enumerate()
+            .filter(|(_, count)| *count > 0)
+        {
+            result.add(&scalar_at(&values, code)?, count)?;
+        }
+        result.finish()
+    }
+}
+
+impl MinMaxFn<DictArray> for DictEncoding {
+    fn min_max(&self, array: &DictArray) -> VortexResult<Option<MinMaxResult>> {
+        // Values that no code refers to, e.g. after filtering the codes, must be left out.
+        let counts = code_counts(array)?;
+        if counts.iter().all(|count| *count > 0) {
+            return min_max(&array.values());
+        }
+
+        let used_codes = PrimitiveArray::from(
+            counts
+                .iter()
+                .enumerate()
+                .filter(|(_, count)| **count > 0)
+                .map(|(code, _)| code as u64)
+                .collect::<Vec<_>>(),
+        );
+        min_max(&take(
+            array.values(),
+            used_codes.as_ref(),
+            TakeOptions::default(),
+        )?)
+    }
+}
+
+/// The number of occurrences of each code.
+pub(super) fn code_counts(array: &DictArray) -> VortexResult<Vec<u64>> {
+    let codes = array.codes().into_primitive()?;
+    let mut counts = vec![0u64; array.values().len()];
+    match_each_unsigned_integer_ptype!(codes.ptype(), |$C| {
+        for code in codes.maybe_null_slice::<$C>() {
+            let code: usize = code.as_();
+            counts[code] += 1;
+        }
+    });
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::compute::{filter, min_max, sum, FilterMask};
+    use vortex_array::IntoArrayData;
+    use vortex_scalar::Scalar;
+
+    use crate::DictArray;
+
+    #[test]
+    fn dict_sum() {
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(vec![0u8, 2, 2, 1, 2]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(-10i32), None, Some(7)]).into_array(),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(sum(&dict).unwrap(), Scalar::from(Some(11i64)));
+        assert_eq!(
+            min_max(&dict).unwrap().unwrap().min,
+            Scalar::from(Some(-10i32))
+        );
+
+        let filtered = filter(
+            &dict,
+            FilterMask::from_iter([false, true, true, true, false]),
+        )
+        .unwrap();
+        assert_eq!(sum(&filtered).unwrap(), Scalar::from(Some(14i64)));
+        let min_max = min_max(&filtered).unwrap().unwrap();
+        assert_eq!(min_max.min, Scalar::from(Some(7i32)));
+        assert_eq!(min_max.max, Scalar::from(Some(7i32)));
+    }
+}
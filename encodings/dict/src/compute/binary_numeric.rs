@@ -0,0 +1,74 @@
+use vortex_array::array::ConstantArray;
+use vortex_array::compute::{binary_numeric, BinaryNumericFn, BinaryNumericOperator};
+use vortex_array::{ArrayData, IntoArrayData};
+use vortex_error::VortexResult;
+
+use crate::compute::aggregate::code_counts;
+use crate::{DictArray, DictEncoding};
+
+impl BinaryNumericFn<DictArray> for DictEncoding {
+    fn binary_numeric(
+        &self,
+        array: &DictArray,
+        other: &ArrayData,
+        op: BinaryNumericOperator,
+    ) -> VortexResult<Option<ArrayData>> {
+        let Some(scalar) = other.as_constant() else {
+            return Ok(None);
+        };
+        // Values that no code refers to, e.g. after filtering the codes, must not fail the op.
+        if code_counts(array)?.contains(&0) {
+            return Ok(None);
+        }
+
+        let values = array.values();
+        let values = binary_numeric(
+            &values,
+            &ConstantArray::new(scalar, values.len()).into_array(),
+            op,
+        )?;
+        DictArray::try_new(array.codes(), values).map(|a| Some(a.into_array()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::{ConstantArray, PrimitiveArray};
+    use vortex_array::compute::{div, sub};
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+
+    use crate::DictArray;
+
+    #[test]
+    fn dict_sub() {
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(vec![0u8, 1, 1, 0]).into_array(),
+            PrimitiveArray::from(vec![100u64, 7]).into_array(),
+        )
+        .unwrap()
+        .into_array();
+
+        let result = sub(ConstantArray::new(200u64, 4).into_array(), &dict).unwrap();
+        assert!(DictArray::try_from(result.clone()).is_ok());
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<u64>(),
+            &[100, 193, 193, 100]
+        );
+    }
+
+    #[test]
+    fn dict_div_unreferenced_zero() {
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(vec![0u8, 2, 0]).into_array(),
+            PrimitiveArray::from(vec![10i32, 0, 4]).into_array(),
+        )
+        .unwrap()
+        .into_array();
+
+        let result = div(ConstantArray::new(20i32, 3).into_array(), &dict).unwrap();
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[2, 5, 2]
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use vortex_array::compute::{hash, take, HashFn, TakeOptions};
+use vortex_array::ArrayData;
+use vortex_error::VortexResult;
+
+use crate::{DictArray, DictEncoding};
+
+impl HashFn<DictArray> for DictEncoding {
+    fn hash(&self, array: &DictArray) -> VortexResult<ArrayData> {
+        // Each distinct value is hashed once.
+        take(
+            hash(&array.values())?,
+            array.codes(),
+            TakeOptions::default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::{PrimitiveArray, VarBinViewArray};
+    use vortex_array::compute::hash;
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+
+    use crate::DictArray;
+
+    #[test]
+    fn dict_hash() {
+        let values = ["a", "b", "a", "c", "b"];
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(vec![0u8, 1, 0, 2, 1]).into_array(),
+            VarBinViewArray::from_iter_str(["a", "b", "c"]).into_array(),
+        )
+        .unwrap()
+        .into_array();
+
+        assert_eq!(
+            hash(&dict)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            hash(&VarBinViewArray::from_iter_str(values).into_array())
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>()
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use vortex_array::compute::{is_in, take, IsInFn, TakeOptions};
+use vortex_array::ArrayData;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::{DictArray, DictEncoding};
+
+impl IsInFn<DictArray> for DictEncoding {
+    fn is_in(&self, array: &DictArray, values: &[Scalar]) -> VortexResult<ArrayData> {
+        // Each distinct value is looked up once.
+        take(
+            is_in(&array.values(), values)?,
+            array.codes(),
+            TakeOptions::default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::{PrimitiveArray, VarBinViewArray};
+    use vortex_array::compute::is_in;
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+
+    use crate::DictArray;
+
+    #[test]
+    fn dict_is_in() {
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(vec![0u8, 1, 0, 2, 1]).into_array(),
+            VarBinViewArray::from_iter_str(["a", "b", "c"]).into_array(),
+        )
+        .unwrap()
+        .into_array();
+
+        assert_eq!(
+            is_in(&dict, &["b".into(), "c".into()])
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![false, true, false, true, true]
+        );
+    }
+}
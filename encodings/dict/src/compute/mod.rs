@@ -1,9 +1,15 @@
+mod aggregate;
+mod binary_numeric;
 mod compare;
+mod hash;
+mod is_in;
 mod like;
+mod sort;
 
 use vortex_array::compute::{
-    filter, scalar_at, slice, take, CompareFn, ComputeVTable, FilterFn, FilterMask, LikeFn,
-    ScalarAtFn, SliceFn, TakeFn, TakeOptions,
+    filter, scalar_at, slice, take, BinaryNumericFn, CompareFn, ComputeVTable, FilterFn,
+    FilterMask, HashFn, IsInFn, LikeFn, MinMaxFn, ScalarAtFn, SliceFn, SortFn, SumFn, TakeFn,
+    TakeOptions,
 };
 use vortex_array::{ArrayData, IntoArrayData};
 use vortex_error::VortexResult;
@@ -12,6 +18,10 @@ use vortex_scalar::Scalar;
 use crate::{DictArray, DictEncoding};
 
 impl ComputeVTable for DictEncoding {
+    fn binary_numeric_fn(&self) -> Option<&dyn BinaryNumericFn<ArrayData>> {
+        Some(self)
+    }
+
     fn compare_fn(&self) -> Option<&dyn CompareFn<ArrayData>> {
         Some(self)
     }
@@ -20,10 +30,22 @@ impl ComputeVTable for DictEncoding {
         Some(self)
     }
 
+    fn hash_fn(&self) -> Option<&dyn HashFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn is_in_fn(&self) -> Option<&dyn IsInFn<ArrayData>> {
+        Some(self)
+    }
+
     fn like_fn(&self) -> Option<&dyn LikeFn<ArrayData>> {
         Some(self)
     }
 
+    fn min_max_fn(&self) -> Option<&dyn MinMaxFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -32,6 +54,14 @@ impl ComputeVTable for DictEncoding {
         Some(self)
     }
 
+    fn sort_fn(&self) -> Option<&dyn SortFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        Some(self)
+    }
+
     fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
         Some(self)
     }
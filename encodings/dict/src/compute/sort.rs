@@ -0,0 +1,87 @@
+use std::iter;
+
+use num_traits::AsPrimitive;
+use vortex_array::array::PrimitiveArray;
+use vortex_array::compute::{argsort, take, try_cast, SortFn, SortOptions, TakeOptions};
+use vortex_array::variants::PrimitiveArrayTrait;
+use vortex_array::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
+use vortex_dtype::match_each_unsigned_integer_ptype;
+use vortex_error::VortexResult;
+
+use crate::{DictArray, DictEncoding};
+
+impl SortFn<DictArray> for DictEncoding {
+    fn sort(&self, array: &DictArray, options: SortOptions) -> VortexResult<ArrayData> {
+        // Sort the values, and then the codes by the rank of their value.
+        let values = array.values();
+        let order = argsort(&values, options)?.into_primitive()?;
+        let mut ranks = vec![0usize; values.len()];
+        for (rank, code) in order.maybe_null_slice::<u64>().iter().enumerate() {
+            ranks[AsPrimitive::<usize>::as_(*code)] = rank;
+        }
+
+        let codes = array.codes().into_primitive()?;
+        let mut rank_counts = vec![0usize; values.len()];
+        match_each_unsigned_integer_ptype!(codes.ptype(), |$C| {
+            for code in codes.maybe_null_slice::<$C>() {
+                rank_counts[ranks[AsPrimitive::<usize>::as_(*code)]] += 1;
+            }
+        });
+        let sorted_codes = PrimitiveArray::from(
+            rank_counts
+                .into_iter()
+                .enumerate()
+                .flat_map(|(rank, count)| iter::repeat(rank as u64).take(count))
+                .collect::<Vec<_>>(),
+        );
+
+        DictArray::try_new(
+            try_cast(sorted_codes, codes.dtype())?,
+            take(values, order, TakeOptions::default())?,
+        )
+        .map(IntoArrayData::into_array)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::{PrimitiveArray, VarBinViewArray};
+    use vortex_array::compute::{scalar_at, sort, SortOptions};
+    use vortex_array::IntoArrayData;
+    use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::DictArray;
+
+    #[test]
+    fn dict_sort() {
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(vec![1u8, 0, 2, 1, 0]).into_array(),
+            VarBinViewArray::from_iter_nullable_str([Some("b"), None, Some("a")]).into_array(),
+        )
+        .unwrap()
+        .into_array();
+
+        let sorted = sort(
+            &dict,
+            SortOptions {
+                descending: false,
+                nulls_first: true,
+            },
+        )
+        .unwrap();
+        assert!(DictArray::try_from(sorted.clone()).is_ok());
+        assert_eq!(
+            (0..5)
+                .map(|i| scalar_at(&sorted, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Scalar::null(DType::Utf8(Nullability::Nullable)),
+                Scalar::null(DType::Utf8(Nullability::Nullable)),
+                Scalar::utf8("a", Nullability::Nullable),
+                Scalar::utf8("b", Nullability::Nullable),
+                Scalar::utf8("b", Nullability::Nullable),
+            ]
+        );
+    }
+}
@@ -90,7 +90,8 @@ fn compress_primitive<T: NativePType + WrappingSub + PrimInt>(
             .maybe_null_slice::<T>()
             .iter()
             .map(|&v| v.wrapping_sub(&min))
-            .map(|v| v >> shift as usize)
+            // The offsets are unsigned, so they mustn't be sign extended.
+            .map(|v| v.unsigned_shr(u32::from(shift)))
             .collect_vec()
     } else {
         parray
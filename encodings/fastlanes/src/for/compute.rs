@@ -2,11 +2,13 @@ use std::ops::AddAssign;
 
 use num_traits::{CheckedShl, CheckedShr, WrappingAdd, WrappingSub};
 use vortex_array::compute::{
-    filter, scalar_at, search_sorted, slice, take, ComputeVTable, FilterFn, FilterMask, ScalarAtFn,
-    SearchResult, SearchSortedFn, SearchSortedSide, SliceFn, TakeFn, TakeOptions,
+    count, filter, min_max, scalar_at, scalar_binary_numeric, search_sorted, slice, take,
+    BinaryNumericFn, BinaryNumericOperator, ComputeVTable, FilterFn, FilterMask, MinMaxFn,
+    MinMaxResult, ScalarAtFn, SearchResult, SearchSortedFn, SearchSortedSide, SliceFn,
+    SumAccumulator, SumFn, TakeFn, TakeOptions,
 };
 use vortex_array::variants::PrimitiveArrayTrait;
-use vortex_array::{ArrayDType, ArrayData, IntoArrayData};
+use vortex_array::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
 use vortex_dtype::{match_each_integer_ptype, NativePType};
 use vortex_error::{VortexError, VortexExpect as _, VortexResult};
 use vortex_scalar::{PValue, Scalar};
@@ -14,10 +16,18 @@ use vortex_scalar::{PValue, Scalar};
 use crate::{FoRArray, FoREncoding};
 
 impl ComputeVTable for FoREncoding {
+    fn binary_numeric_fn(&self) -> Option<&dyn BinaryNumericFn<ArrayData>> {
+        Some(self)
+    }
+
     fn filter_fn(&self) -> Option<&dyn FilterFn<ArrayData>> {
         Some(self)
     }
 
+    fn min_max_fn(&self) -> Option<&dyn MinMaxFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -30,6 +40,10 @@ impl ComputeVTable for FoREncoding {
         Some(self)
     }
 
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        Some(self)
+    }
+
     fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
         Some(self)
     }
@@ -64,27 +78,97 @@ impl FilterFn<FoRArray> for FoREncoding {
 
 impl ScalarAtFn<FoRArray> for FoREncoding {
     fn scalar_at(&self, array: &FoRArray, index: usize) -> VortexResult<Scalar> {
-        let encoded_pvalue = scalar_at(array.encoded(), index)?.reinterpret_cast(array.ptype());
-        let encoded_pvalue = encoded_pvalue.as_primitive();
-        let reference = array.reference_scalar();
-        let reference = reference.as_primitive();
-
-        Ok(match_each_integer_ptype!(array.ptype(), |$P| {
-            encoded_pvalue
-                .typed_value::<$P>()
-                .map(|v|
-                     v.checked_shl(array.shift() as u32)
-                     .unwrap_or_default()
-                     .wrapping_add(
-                         reference
-                             .typed_value::<$P>()
-                             .vortex_expect("FoRArray Reference value cannot be null")))
-                .map(|v| Scalar::primitive::<$P>(v, array.dtype().nullability()))
-                .unwrap_or_else(|| Scalar::null(array.dtype().clone()))
-        }))
+        decode_scalar(array, scalar_at(array.encoded(), index)?)
+    }
+}
+
+impl SumFn<FoRArray> for FoREncoding {
+    fn sum(&self, array: &FoRArray) -> VortexResult<Scalar> {
+        // Every valid value is the reference plus its encoded offset shifted left.
+        let encoded = array.encoded();
+        let mut result = SumAccumulator::new(array.ptype());
+        result.add(&array.reference_scalar(), count(&encoded)? as u64)?;
+        // The offsets are summed into the same accumulator, since their own sum may not fit into
+        // their ptype even if the decoded sum does.
+        result.add_primitive_times(
+            &encoded.into_primitive()?,
+            1u64.checked_shl(u32::from(array.shift()))
+                .unwrap_or_default(),
+        )?;
+        result.finish()
     }
 }
 
+impl MinMaxFn<FoRArray> for FoREncoding {
+    fn min_max(&self, array: &FoRArray) -> VortexResult<Option<MinMaxResult>> {
+        // Decoding preserves the order of the encoded offsets.
+        min_max(&array.encoded())?
+            .map(|MinMaxResult { min, max }| {
+                Ok(MinMaxResult {
+                    min: decode_scalar(array, min)?,
+                    max: decode_scalar(array, max)?,
+                })
+            })
+            .transpose()
+    }
+}
+
+impl BinaryNumericFn<FoRArray> for FoREncoding {
+    fn binary_numeric(
+        &self,
+        array: &FoRArray,
+        other: &ArrayData,
+        op: BinaryNumericOperator,
+    ) -> VortexResult<Option<ArrayData>> {
+        // Adding or subtracting a constant only shifts the reference.
+        if !matches!(op, BinaryNumericOperator::Add | BinaryNumericOperator::Sub) {
+            return Ok(None);
+        }
+        let Some(scalar) = other.as_constant() else {
+            return Ok(None);
+        };
+        if scalar.is_null() || (scalar.dtype().is_nullable() && !array.dtype().is_nullable()) {
+            return Ok(None);
+        }
+
+        // The decoded values wrap around, so we leave it to the fallback to report overflows.
+        let Ok(reference) = scalar_binary_numeric(&array.reference_scalar(), &scalar, op) else {
+            return Ok(None);
+        };
+        if let Some(MinMaxResult { min, max }) = min_max(array.as_ref())? {
+            if scalar_binary_numeric(&min, &scalar, op).is_err()
+                || scalar_binary_numeric(&max, &scalar, op).is_err()
+            {
+                return Ok(None);
+            }
+        }
+
+        FoRArray::try_new(array.encoded(), reference, array.shift()).map(|a| Some(a.into_array()))
+    }
+}
+
+/// Decode a scalar of the encoded child of the array.
+fn decode_scalar(array: &FoRArray, encoded: Scalar) -> VortexResult<Scalar> {
+    let encoded_pvalue = encoded.reinterpret_cast(array.ptype());
+    let encoded_pvalue = encoded_pvalue.as_primitive();
+    let reference = array.reference_scalar();
+    let reference = reference.as_primitive();
+
+    Ok(match_each_integer_ptype!(array.ptype(), |$P| {
+        encoded_pvalue
+            .typed_value::<$P>()
+            .map(|v|
+                 v.checked_shl(array.shift() as u32)
+                 .unwrap_or_default()
+                 .wrapping_add(
+                     reference
+                         .typed_value::<$P>()
+                         .vortex_expect("FoRArray Reference value cannot be null")))
+            .map(|v| Scalar::primitive::<$P>(v, array.dtype().nullability()))
+            .unwrap_or_else(|| Scalar::null(array.dtype().clone()))
+    }))
+}
+
 impl SliceFn<FoRArray> for FoREncoding {
     fn slice(&self, array: &FoRArray, start: usize, stop: usize) -> VortexResult<ArrayData> {
         FoRArray::try_new(
@@ -176,9 +260,12 @@ where
 
 #[cfg(test)]
 mod test {
-    use vortex_array::array::PrimitiveArray;
-    use vortex_array::compute::{scalar_at, search_sorted, SearchResult, SearchSortedSide};
-    use vortex_array::IntoArrayData;
+    use vortex_array::array::{ConstantArray, PrimitiveArray};
+    use vortex_array::compute::{
+        add, min_max, scalar_at, search_sorted, sub, sum, SearchResult, SearchSortedSide,
+    };
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+    use vortex_scalar::Scalar;
 
     use crate::{for_compress, FoRArray};
 
@@ -191,6 +278,68 @@ mod test {
         assert_eq!(scalar_at(&for_arr, 3).unwrap(), 1900.into());
     }
 
+    #[test]
+    fn for_sum() {
+        let for_arr = for_compress(&PrimitiveArray::from_nullable_vec(vec![
+            Some(-100i32),
+            None,
+            Some(1100),
+            Some(1500),
+            Some(1900),
+        ]))
+        .unwrap()
+        .into_array();
+        assert_eq!(sum(&for_arr).unwrap(), Scalar::from(Some(4400i64)));
+
+        let min_max = min_max(&for_arr).unwrap().unwrap();
+        assert_eq!(min_max.min, Scalar::from(Some(-100i32)));
+        assert_eq!(min_max.max, Scalar::from(Some(1900i32)));
+    }
+
+    #[test]
+    fn for_sum_offsets_overflow() {
+        // The offsets from the reference sum to 2^64, which overflows u64 but not the i64 sum.
+        let values = vec![-(1i64 << 62), 1 << 62, 1 << 62];
+        let for_arr = FoRArray::try_new(
+            PrimitiveArray::from(vec![0u64, 1 << 63, 1 << 63]).into_array(),
+            Scalar::from(-(1i64 << 62)),
+            0,
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(
+            for_arr
+                .clone()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i64>(),
+            values
+        );
+        assert_eq!(sum(&for_arr).unwrap(), Scalar::from(Some(1i64 << 62)));
+
+        let for_arr = for_compress(&PrimitiveArray::from(values))
+            .unwrap()
+            .into_array();
+        assert_eq!(sum(&for_arr).unwrap(), Scalar::from(Some(1i64 << 62)));
+    }
+
+    #[test]
+    fn for_add() {
+        let for_arr = for_compress(&PrimitiveArray::from(vec![-100i32, 1100, 1500, 1900]))
+            .unwrap()
+            .into_array();
+
+        let result = add(&for_arr, ConstantArray::new(100i32, 4).into_array()).unwrap();
+        assert!(FoRArray::try_from(result.clone()).is_ok());
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[0, 1200, 1600, 2000]
+        );
+
+        // Overflows are reported by the fallback.
+        assert!(sub(&for_arr, ConstantArray::new(i32::MAX, 4).into_array()).is_err());
+    }
+
     #[test]
     fn for_search() {
         let for_arr = for_compress(&PrimitiveArray::from(vec![1100, 1500, 1900]))
@@ -0,0 +1,9 @@
+//! A terminal encoding that compresses each buffer of an array into an LZ4 frame.
+//!
+//! LZ4 compresses less than zstd, but decompresses considerably faster. Like zstd, the values can't be
+//! accessed without decompressing the whole array.
+
+pub use array::*;
+
+mod array;
+mod compute;
@@ -0,0 +1,114 @@
+use std::cmp::min;
+
+use num_traits::AsPrimitive;
+use vortex_array::compute::{
+    min_max, scalar_at, sum, MinMaxFn, MinMaxResult, SumAccumulator, SumFn,
+};
+use vortex_array::validity::Validity;
+use vortex_array::variants::PrimitiveArrayTrait;
+use vortex_array::{ArrayLen, IntoArrayData, IntoArrayVariant, IntoCanonical};
+use vortex_dtype::match_each_unsigned_integer_ptype;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::{RunEndArray, RunEndEncoding};
+
+impl SumFn<RunEndArray> for RunEndEncoding {
+    fn sum(&self, array: &RunEndArray) -> VortexResult<Scalar> {
+        if let Validity::Array(_) = array.validity() {
+            // Nulls may split runs, so we sum the decoded values instead.
+            return sum(&array.clone().into_primitive()?.into_array());
+        }
+
+        let values = array.values().into_primitive()?;
+        let mut result = SumAccumulator::new(values.ptype());
+        if array.validity() != Validity::AllInvalid {
+            for (idx, run_length) in run_lengths(array)?.into_iter().enumerate() {
+                result.add(&scalar_at(&values, idx)?, run_length)?;
+            }
+        }
+        result.finish()
+    }
+}
+
+impl MinMaxFn<RunEndArray> for RunEndEncoding {
+    fn min_max(&self, array: &RunEndArray) -> VortexResult<Option<MinMaxResult>> {
+        match array.validity() {
+            Validity::NonNullable | Validity::AllValid => min_max(&array.values()),
+            Validity::AllInvalid => Ok(None),
+            Validity::Array(_) => min_max(&array.clone().into_canonical()?.into_array()),
+        }
+    }
+}
+
+/// The number of values of each run that fall within the array.
+pub(super) fn run_lengths(array: &RunEndArray) -> VortexResult<Vec<u64>> {
+    let ends = array.ends().into_primitive()?;
+    let offset = array.offset() as u64;
+    let length = array.len() as u64;
+    Ok(match_each_unsigned_integer_ptype!(ends.ptype(), |$E| {
+        ends.maybe_null_slice::<$E>()
+            .iter()
+            .scan(0u64, |start, end| {
+                let end = min(AsPrimitive::<u64>::as_(*end).saturating_sub(offset), length);
+                let run_length = end.saturating_sub(*start);
+                *start = end;
+                Some(run_length)
+            })
+            .collect()
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::{BoolArray, PrimitiveArray};
+    use vortex_array::compute::{min_max, slice, sum};
+    use vortex_array::validity::Validity;
+    use vortex_array::IntoArrayData;
+    use vortex_scalar::Scalar;
+
+    use crate::RunEndArray;
+
+    fn ree_array() -> RunEndArray {
+        RunEndArray::encode(
+            PrimitiveArray::from(vec![1i32, 1, 1, 4, 4, -2, -2, -2, -2, 5]).into_array(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn ree_sum() {
+        let array = ree_array().into_array();
+        assert_eq!(sum(&array).unwrap(), Scalar::from(Some(8i64)));
+        assert_eq!(
+            sum(&slice(&array, 2, 7).unwrap()).unwrap(),
+            Scalar::from(Some(5i64))
+        );
+
+        let min_max = min_max(&array).unwrap().unwrap();
+        assert_eq!(min_max.min, Scalar::from(-2i32));
+        assert_eq!(min_max.max, Scalar::from(5i32));
+    }
+
+    #[test]
+    fn ree_sum_nullable() {
+        let array = RunEndArray::try_new(
+            PrimitiveArray::from(vec![3u32, 5, 9, 10]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), Some(4), Some(-2), Some(5)])
+                .into_array(),
+            Validity::Array(
+                BoolArray::from_iter([
+                    true, true, false, true, true, true, true, true, true, false,
+                ])
+                .into_array(),
+            ),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(sum(&array).unwrap(), Scalar::from(Some(2i64)));
+        assert_eq!(
+            min_max(&array).unwrap().unwrap().max,
+            Scalar::from(Some(4i32))
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use vortex_array::array::ConstantArray;
+use vortex_array::compute::{binary_numeric, BinaryNumericFn, BinaryNumericOperator};
+use vortex_array::validity::Validity;
+use vortex_array::{ArrayDType, ArrayData, ArrayLen, IntoArrayData};
+use vortex_error::VortexResult;
+
+use crate::{RunEndArray, RunEndEncoding};
+
+impl BinaryNumericFn<RunEndArray> for RunEndEncoding {
+    fn binary_numeric(
+        &self,
+        array: &RunEndArray,
+        other: &ArrayData,
+        op: BinaryNumericOperator,
+    ) -> VortexResult<Option<ArrayData>> {
+        let Some(scalar) = other.as_constant() else {
+            return Ok(None);
+        };
+        if let Validity::Array(_) | Validity::AllInvalid = array.validity() {
+            // The values of null runs are undefined and must not be computed.
+            return Ok(None);
+        }
+
+        let values = array.values();
+        let values = binary_numeric(
+            &values,
+            &ConstantArray::new(scalar, values.len()).into_array(),
+            op,
+        )?;
+        let validity = if values.dtype().is_nullable() {
+            array.validity().into_nullable()
+        } else {
+            array.validity()
+        };
+        RunEndArray::with_offset_and_length(
+            array.ends(),
+            values,
+            validity,
+            array.offset(),
+            array.len(),
+        )
+        .map(|a| Some(a.into_array()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::{BoolArray, ConstantArray, PrimitiveArray};
+    use vortex_array::compute::{add, mul, scalar_at, slice};
+    use vortex_array::validity::Validity;
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+    use vortex_scalar::Scalar;
+
+    use crate::RunEndArray;
+
+    #[test]
+    fn ree_mul() {
+        let array = RunEndArray::encode(
+            PrimitiveArray::from(vec![1i32, 1, 1, 4, 4, -2, -2, -2, -2, 5]).into_array(),
+        )
+        .unwrap()
+        .into_array();
+        let array = slice(&array, 2, 8).unwrap();
+
+        let result = mul(&array, ConstantArray::new(3i32, 6).into_array()).unwrap();
+        assert!(RunEndArray::try_from(result.clone()).is_ok());
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[3, 12, 12, -6, -6, -6]
+        );
+    }
+
+    #[test]
+    fn ree_add_skips_null_runs() {
+        let array = RunEndArray::try_new(
+            PrimitiveArray::from(vec![2u32, 4]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), Some(i32::MAX)]).into_array(),
+            Validity::Array(BoolArray::from_iter([true, true, false, false]).into_array()),
+        )
+        .unwrap()
+        .into_array();
+
+        let result = add(&array, ConstantArray::new(1i32, 4).into_array()).unwrap();
+        assert_eq!(scalar_at(&result, 1).unwrap(), Scalar::from(Some(2i32)));
+        assert!(scalar_at(&result, 2).unwrap().is_null());
+    }
+}
@@ -0,0 +1,54 @@
+use vortex_array::array::ConstantArray;
+use vortex_array::compute::{hash, HashFn, NULL_HASH};
+use vortex_array::validity::Validity;
+use vortex_array::{ArrayData, ArrayLen, IntoArrayData, IntoCanonical};
+use vortex_error::VortexResult;
+
+use crate::{RunEndArray, RunEndEncoding};
+
+impl HashFn<RunEndArray> for RunEndEncoding {
+    fn hash(&self, array: &RunEndArray) -> VortexResult<ArrayData> {
+        match array.validity() {
+            // Each run is hashed once.
+            Validity::NonNullable | Validity::AllValid => RunEndArray::with_offset_and_length(
+                array.ends(),
+                hash(&array.values())?,
+                Validity::NonNullable,
+                array.offset(),
+                array.len(),
+            )
+            .map(IntoArrayData::into_array),
+            Validity::AllInvalid => Ok(ConstantArray::new(NULL_HASH, array.len()).into_array()),
+            Validity::Array(_) => hash(&array.clone().into_canonical()?.into_array()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::compute::{hash, slice};
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+
+    use crate::RunEndArray;
+
+    #[test]
+    fn ree_hash() {
+        let ree =
+            RunEndArray::encode(PrimitiveArray::from(vec![1i32, 1, 1, 4, 4, 2, 2, 2]).into_array())
+                .unwrap()
+                .into_array();
+        let ree = slice(&ree, 2, 7).unwrap();
+
+        let hashes = hash(&ree).unwrap();
+        assert!(RunEndArray::try_from(hashes.clone()).is_ok());
+        assert_eq!(
+            hashes.into_primitive().unwrap().maybe_null_slice::<u64>(),
+            hash(&PrimitiveArray::from(vec![1i32, 4, 4, 2, 2]).into_array())
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>()
+        );
+    }
+}
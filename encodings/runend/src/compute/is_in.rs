@@ -0,0 +1,50 @@
+use vortex_array::compute::{is_in, IsInFn};
+use vortex_array::{ArrayData, ArrayLen, IntoArrayData};
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::{RunEndArray, RunEndEncoding};
+
+impl IsInFn<RunEndArray> for RunEndEncoding {
+    fn is_in(&self, array: &RunEndArray, values: &[Scalar]) -> VortexResult<ArrayData> {
+        // Each run is looked up once, and the result keeps the runs.
+        RunEndArray::with_offset_and_length(
+            array.ends(),
+            is_in(&array.values(), values)?,
+            array.validity(),
+            array.offset(),
+            array.len(),
+        )
+        .map(IntoArrayData::into_array)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::compute::{is_in, slice};
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+
+    use crate::RunEndArray;
+
+    #[test]
+    fn ree_is_in() {
+        let ree =
+            RunEndArray::encode(PrimitiveArray::from(vec![1i32, 1, 1, 4, 4, 2, 2, 2]).into_array())
+                .unwrap()
+                .into_array();
+        let ree = slice(&ree, 2, 7).unwrap();
+
+        let found = is_in(&ree, &[4i32.into(), 2i32.into()]).unwrap();
+        assert!(RunEndArray::try_from(found.clone()).is_ok());
+        assert_eq!(
+            found
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![false, true, true, true, true]
+        );
+    }
+}
@@ -1,5 +1,10 @@
+mod aggregate;
+mod binary_numeric;
 mod compare;
+mod hash;
 mod invert;
+mod is_in;
+mod sort;
 
 use std::cmp::min;
 use std::ops::AddAssign;
@@ -7,8 +12,9 @@ use std::ops::AddAssign;
 use num_traits::AsPrimitive;
 use vortex_array::array::{BooleanBuffer, ConstantArray, PrimitiveArray, SparseArray};
 use vortex_array::compute::{
-    filter, scalar_at, slice, take, CompareFn, ComputeVTable, FilterFn, FilterMask, InvertFn,
-    ScalarAtFn, SliceFn, TakeFn, TakeOptions,
+    filter, scalar_at, slice, take, BinaryNumericFn, CompareFn, ComputeVTable, FilterFn,
+    FilterMask, HashFn, InvertFn, IsInFn, MinMaxFn, ScalarAtFn, SliceFn, SortFn, SumFn, TakeFn,
+    TakeOptions,
 };
 use vortex_array::validity::Validity;
 use vortex_array::variants::PrimitiveArrayTrait;
@@ -20,6 +26,10 @@ use vortex_scalar::Scalar;
 use crate::{RunEndArray, RunEndEncoding};
 
 impl ComputeVTable for RunEndEncoding {
+    fn binary_numeric_fn(&self) -> Option<&dyn BinaryNumericFn<ArrayData>> {
+        Some(self)
+    }
+
     fn compare_fn(&self) -> Option<&dyn CompareFn<ArrayData>> {
         Some(self)
     }
@@ -28,10 +38,22 @@ impl ComputeVTable for RunEndEncoding {
         Some(self)
     }
 
+    fn hash_fn(&self) -> Option<&dyn HashFn<ArrayData>> {
+        Some(self)
+    }
+
     fn invert_fn(&self) -> Option<&dyn InvertFn<ArrayData>> {
         Some(self)
     }
 
+    fn is_in_fn(&self) -> Option<&dyn IsInFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn min_max_fn(&self) -> Option<&dyn MinMaxFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -40,6 +62,14 @@ impl ComputeVTable for RunEndEncoding {
         Some(self)
     }
 
+    fn sort_fn(&self) -> Option<&dyn SortFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        Some(self)
+    }
+
     fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
         Some(self)
     }
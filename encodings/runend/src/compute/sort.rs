@@ -0,0 +1,81 @@
+use num_traits::AsPrimitive;
+use vortex_array::array::PrimitiveArray;
+use vortex_array::compute::{argsort, sort, take, SortFn, SortOptions, TakeOptions};
+use vortex_array::validity::Validity;
+use vortex_array::{ArrayData, IntoArrayData, IntoArrayVariant, IntoCanonical};
+use vortex_error::VortexResult;
+
+use crate::compute::aggregate::run_lengths;
+use crate::{RunEndArray, RunEndEncoding};
+
+impl SortFn<RunEndArray> for RunEndEncoding {
+    fn sort(&self, array: &RunEndArray, options: SortOptions) -> VortexResult<ArrayData> {
+        match array.validity() {
+            Validity::NonNullable | Validity::AllValid => {}
+            Validity::AllInvalid => return Ok(array.clone().into_array()),
+            // Nulls may split runs, so we sort the decoded values instead.
+            Validity::Array(_) => {
+                return sort(&array.clone().into_canonical()?.into_array(), options)
+            }
+        }
+
+        // Sort the runs by their values, skipping those sliced away.
+        let run_lengths = run_lengths(array)?;
+        let order = argsort(&array.values(), options)?.into_primitive()?;
+        let mut ends = Vec::with_capacity(run_lengths.len());
+        let mut indices = Vec::with_capacity(run_lengths.len());
+        let mut end = 0u64;
+        for idx in order.maybe_null_slice::<u64>() {
+            let run_length = run_lengths[AsPrimitive::<usize>::as_(*idx)];
+            if run_length > 0 {
+                end += run_length;
+                ends.push(end);
+                indices.push(*idx);
+            }
+        }
+
+        RunEndArray::try_new(
+            PrimitiveArray::from(ends).into_array(),
+            take(
+                array.values(),
+                PrimitiveArray::from(indices),
+                TakeOptions::default(),
+            )?,
+            array.validity(),
+        )
+        .map(IntoArrayData::into_array)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::compute::{slice, sort, SortOptions};
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+
+    use crate::RunEndArray;
+
+    #[test]
+    fn ree_sort() {
+        let ree = RunEndArray::encode(
+            PrimitiveArray::from(vec![3i32, 3, 3, 1, 1, 5, 5, 2, 2, 2]).into_array(),
+        )
+        .unwrap()
+        .into_array();
+        let ree = slice(&ree, 1, 9).unwrap();
+
+        let sorted = sort(
+            &ree,
+            SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+        )
+        .unwrap();
+        assert!(RunEndArray::try_from(sorted.clone()).is_ok());
+        assert_eq!(
+            sorted.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[5, 5, 3, 3, 2, 2, 1, 1]
+        );
+    }
+}
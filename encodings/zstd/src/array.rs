@@ -0,0 +1,288 @@
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+use arrow_buffer::MutableBuffer;
+use serde::{Deserialize, Serialize};
+use vortex_array::encoding::ids;
+use vortex_array::stats::{StatisticsVTable, StatsSet};
+use vortex_array::validity::{ArrayValidity, LogicalValidity, ValidityVTable};
+use vortex_array::variants::{
+    BinaryArrayTrait, BoolArrayTrait, ExtensionArrayTrait, ListArrayTrait, NullArrayTrait,
+    PrimitiveArrayTrait, StructArrayTrait, Utf8ArrayTrait, VariantsVTable,
+};
+use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
+use vortex_array::{
+    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoCanonical,
+};
+use vortex_buffer::Buffer;
+use vortex_dtype::field::Field;
+use vortex_error::{vortex_bail, vortex_err, VortexExpect as _, VortexResult};
+
+impl_encoding!("vortex.zstd", ids::ZSTD, Zstd);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ZstdMetadata {
+    /// The decompressed length of each buffer of the child, in depth-first order.
+    buffer_lens: Vec<usize>,
+}
+
+impl Display for ZstdMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl ZstdArray {
+    /// Create a new array from a child whose buffers are zstd frames, which decompress to
+    /// `buffer_lens` bytes each.
+    pub fn try_new(child: ArrayData, buffer_lens: Vec<usize>) -> VortexResult<Self> {
+        let nbuffers = child.cumulative_nbuffers();
+        if nbuffers != buffer_lens.len() {
+            vortex_bail!(
+                "Expected a buffer length for each of the {nbuffers} child buffers, found {}",
+                buffer_lens.len()
+            );
+        }
+
+        ArrayData::try_new_owned(
+            &ZstdEncoding,
+            child.dtype().clone(),
+            child.len(),
+            Arc::new(ZstdMetadata { buffer_lens }),
+            None,
+            [child].into(),
+            StatsSet::default(),
+        )?
+        .try_into()
+    }
+
+    /// Compress each buffer of the array into a zstd frame at the given compression level.
+    pub fn encode(array: &ArrayData, level: i32) -> VortexResult<Self> {
+        let mut buffer_lens = Vec::new();
+        let child = array.try_map_buffers(&mut |buffer| {
+            buffer_lens.push(buffer.len());
+            zstd::bulk::compress(buffer.as_slice(), level)
+                .map(Buffer::from)
+                .map_err(|e| vortex_err!("Failed to compress array with zstd: {e}"))
+        })?;
+        Self::try_new(child, buffer_lens)
+    }
+
+    /// Decompress the zstd frames back into the original array.
+    pub fn decompress(&self) -> VortexResult<ArrayData> {
+        let mut buffer_lens = self.metadata().buffer_lens.iter();
+        self.child().try_map_buffers(&mut |compressed| {
+            let nbytes = *buffer_lens
+                .next()
+                .ok_or_else(|| vortex_err!("ZstdArray is missing the length of a buffer"))?;
+            // Decompress into an aligned buffer, so that it can be reinterpreted as the values.
+            let mut values = MutableBuffer::from_len_zeroed(nbytes);
+            let decompressed =
+                zstd::bulk::decompress_to_buffer(compressed.as_slice(), values.as_slice_mut())
+                    .map_err(|e| vortex_err!("Failed to decompress zstd frame: {e}"))?;
+            if decompressed != nbytes {
+                vortex_bail!(
+                    "Decompressed zstd frame has {decompressed} bytes, expected {nbytes} bytes"
+                );
+            }
+            Ok(Buffer::from(values))
+        })
+    }
+
+    /// The compressed child, whose buffers are zstd frames.
+    pub fn child(&self) -> ArrayData {
+        self.as_ref()
+            .child(0, self.dtype(), self.len())
+            .vortex_expect("ZstdArray is missing its child")
+    }
+
+    fn decompressed(&self) -> ArrayData {
+        self.decompress()
+            .vortex_expect("ZstdArray: failed to decompress child")
+    }
+}
+
+impl ArrayTrait for ZstdArray {}
+
+/// Zstd arrays support all DTypes
+impl VariantsVTable<ZstdArray> for ZstdEncoding {
+    fn as_null_array<'a>(&self, array: &'a ZstdArray) -> Option<&'a dyn NullArrayTrait> {
+        Some(array)
+    }
+
+    fn as_bool_array<'a>(&self, array: &'a ZstdArray) -> Option<&'a dyn BoolArrayTrait> {
+        Some(array)
+    }
+
+    fn as_primitive_array<'a>(&self, array: &'a ZstdArray) -> Option<&'a dyn PrimitiveArrayTrait> {
+        Some(array)
+    }
+
+    fn as_utf8_array<'a>(&self, array: &'a ZstdArray) -> Option<&'a dyn Utf8ArrayTrait> {
+        Some(array)
+    }
+
+    fn as_binary_array<'a>(&self, array: &'a ZstdArray) -> Option<&'a dyn BinaryArrayTrait> {
+        Some(array)
+    }
+
+    fn as_struct_array<'a>(&self, array: &'a ZstdArray) -> Option<&'a dyn StructArrayTrait> {
+        Some(array)
+    }
+
+    fn as_list_array<'a>(&self, array: &'a ZstdArray) -> Option<&'a dyn ListArrayTrait> {
+        Some(array)
+    }
+
+    fn as_extension_array<'a>(&self, array: &'a ZstdArray) -> Option<&'a dyn ExtensionArrayTrait> {
+        Some(array)
+    }
+}
+
+impl NullArrayTrait for ZstdArray {}
+
+impl BoolArrayTrait for ZstdArray {}
+
+impl PrimitiveArrayTrait for ZstdArray {}
+
+impl Utf8ArrayTrait for ZstdArray {}
+
+impl BinaryArrayTrait for ZstdArray {}
+
+impl StructArrayTrait for ZstdArray {
+    fn field(&self, idx: usize) -> Option<ArrayData> {
+        self.decompressed()
+            .as_struct_array()
+            .and_then(|st| st.field(idx))
+    }
+
+    fn project(&self, projection: &[Field]) -> VortexResult<ArrayData> {
+        self.decompress()?
+            .as_struct_array()
+            .ok_or_else(|| vortex_err!("ZstdArray child is not a struct array"))?
+            .project(projection)
+    }
+}
+
+impl ListArrayTrait for ZstdArray {}
+
+impl ExtensionArrayTrait for ZstdArray {
+    fn storage_data(&self) -> ArrayData {
+        self.decompressed()
+            .as_extension_array()
+            .vortex_expect("ZstdArray child is not an extension array")
+            .storage_data()
+    }
+}
+
+impl ValidityVTable<ZstdArray> for ZstdEncoding {
+    fn is_valid(&self, array: &ZstdArray, index: usize) -> bool {
+        array.decompressed().is_valid(index)
+    }
+
+    fn logical_validity(&self, array: &ZstdArray) -> LogicalValidity {
+        array.decompressed().logical_validity()
+    }
+}
+
+impl VisitorVTable<ZstdArray> for ZstdEncoding {
+    fn accept(&self, array: &ZstdArray, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("child", &array.child())
+    }
+}
+
+impl StatisticsVTable<ZstdArray> for ZstdEncoding {}
+
+impl IntoCanonical for ZstdArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        self.decompress()?.into_canonical()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_array::array::{PrimitiveArray, StructArray, VarBinViewArray};
+    use vortex_array::compute::{scalar_at, slice};
+    use vortex_array::validity::Validity;
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+    use vortex_scalar::Scalar;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let values = (0..1024u32).map(|i| i % 7).collect::<Vec<_>>();
+        let array = PrimitiveArray::from_nullable_vec(
+            values
+                .iter()
+                .map(|&v| (v != 0).then_some(v))
+                .collect::<Vec<_>>(),
+        );
+        let zstd = ZstdArray::encode(array.as_ref(), 3).unwrap();
+        assert!(zstd.child().nbytes() < array.as_ref().nbytes());
+
+        let decoded = zstd.clone().into_array().into_primitive().unwrap();
+        assert_eq!(
+            decoded.maybe_null_slice::<u32>(),
+            array.maybe_null_slice::<u32>()
+        );
+        assert_eq!(
+            decoded
+                .validity()
+                .to_logical(decoded.len())
+                .into_array()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer(),
+            array
+                .validity()
+                .to_logical(array.len())
+                .into_array()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+        );
+
+        assert_eq!(
+            scalar_at(&zstd, 0).unwrap(),
+            Scalar::null(zstd.dtype().clone())
+        );
+        assert_eq!(scalar_at(&zstd, 1).unwrap(), Scalar::from(Some(1u32)));
+        let sliced = slice(&zstd, 10, 20).unwrap().into_primitive().unwrap();
+        assert_eq!(sliced.maybe_null_slice::<u32>(), &values[10..20]);
+    }
+
+    #[test]
+    fn round_trip_struct() {
+        let strings = (0..100)
+            .map(|i| format!("value-{}", i % 3))
+            .collect::<Vec<_>>();
+        let array = StructArray::try_new(
+            ["numbers".into(), "strings".into()].into(),
+            vec![
+                PrimitiveArray::from((0..100i64).collect::<Vec<_>>()).into_array(),
+                VarBinViewArray::from_iter_str(strings.iter().map(String::as_str)).into_array(),
+            ],
+            100,
+            Validity::NonNullable,
+        )
+        .unwrap();
+        let zstd = ZstdArray::encode(array.as_ref(), 3).unwrap();
+        assert_eq!(zstd.dtype(), array.dtype());
+
+        let decoded = zstd.clone().into_array().into_struct().unwrap();
+        assert_eq!(
+            decoded
+                .field(0)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i64>(),
+            (0..100i64).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            scalar_at(zstd.field(1).unwrap(), 4).unwrap(),
+            Scalar::from("value-1")
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use vortex_array::compute::ComputeVTable;
+
+use crate::ZstdEncoding;
+
+// The values can only be accessed by decompressing the child, which the canonical fallbacks do.
+impl ComputeVTable for ZstdEncoding {}
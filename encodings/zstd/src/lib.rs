@@ -0,0 +1,9 @@
+//! A terminal encoding that compresses each buffer of an array into a zstd frame.
+//!
+//! Unlike the lightweight encodings, the values can't be accessed without decompressing the whole
+//! array, which makes this encoding suited to cold data where size matters more than decode speed.
+
+pub use array::*;
+
+mod array;
+mod compute;
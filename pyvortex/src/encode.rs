@@ -6,7 +6,7 @@ use arrow::record_batch::RecordBatchReader;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use vortex::array::ChunkedArray;
-use vortex::arrow::{FromArrowArray, FromArrowType};
+use vortex::arrow::{FromArrowType, TryFromArrowArray};
 use vortex::dtype::DType;
 use vortex::error::{VortexError, VortexResult};
 use vortex::{ArrayData, IntoArrayData};
@@ -24,16 +24,15 @@ pub fn _encode<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyArray>> {
     if obj.is_instance(&pa_array)? {
         let arrow_array = ArrowArrayData::from_pyarrow_bound(obj).map(make_array)?;
         let is_nullable = arrow_array.is_nullable();
-        let enc_array = ArrayData::from_arrow(arrow_array, is_nullable);
+        let enc_array = ArrayData::try_from_arrow(arrow_array, is_nullable)?;
         Bound::new(obj.py(), PyArray::new(enc_array))
     } else if obj.is_instance(&chunked_array)? {
         let chunks: Vec<Bound<PyAny>> = obj.getattr("chunks")?.extract()?;
         let encoded_chunks = chunks
             .iter()
             .map(|a| {
-                ArrowArrayData::from_pyarrow_bound(a)
-                    .map(make_array)
-                    .map(|a| ArrayData::from_arrow(a, false))
+                let a = ArrowArrayData::from_pyarrow_bound(a).map(make_array)?;
+                Ok(ArrayData::try_from_arrow(a, false)?)
             })
             .collect::<PyResult<Vec<_>>>()?;
         let dtype: DType = obj
@@ -306,6 +306,14 @@ pub fn scalar_helper(dtype: DType, value: &Bound<'_, PyAny>) -> PyResult<Scalar>
                 .collect::<PyResult<Vec<_>>>()?;
             Ok(Scalar::list(element_type, values))
         }
+        DType::FixedSizeList(element_type, _, n) => {
+            let list = value.downcast::<PyList>();
+            let values = list
+                .iter()
+                .map(|element| scalar_helper(element_type.as_ref().clone(), element))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(Scalar::fixed_size_list(element_type, values, n))
+        }
         DType::Extension(..) => todo!(),
     }
 }
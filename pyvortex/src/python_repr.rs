@@ -46,6 +46,13 @@ impl Display for DTypePythonRepr<'_> {
                 n.python_repr()
             ),
             DType::List(edt, n) => write!(f, "list({}, {})", edt.python_repr(), n.python_repr()),
+            DType::FixedSizeList(edt, size, n) => write!(
+                f,
+                "fixed_size_list({}, {}, {})",
+                edt.python_repr(),
+                size,
+                n.python_repr()
+            ),
             DType::Extension(ext) => {
                 write!(
                     f,
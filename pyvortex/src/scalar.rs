@@ -67,7 +67,7 @@ pub fn scalar_into_py(py: Python, x: Scalar, copy_into_python: bool) -> PyResult
                 PyVortexStruct::new_pyobject(py, x)?
             }
         }
-        DType::List(..) => {
+        DType::List(..) | DType::FixedSizeList(..) => {
             let list_scalar = x.as_list();
             if list_scalar.is_null() {
                 py.None()
@@ -85,6 +85,9 @@ fn random_array(u: &mut Unstructured, dtype: &DType, len: Option<usize>) -> Resu
                 DType::List(..) => {
                     todo!("List arrays are not implemented")
                 }
+                DType::FixedSizeList(..) => {
+                    todo!("FixedSizeList arrays are not implemented")
+                }
                 DType::Extension(..) => {
                     todo!("Extension arrays are not implemented")
                 }
@@ -7,7 +7,7 @@ use crate::array::extension::ExtensionArray;
 use crate::array::null::NullArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::struct_::StructArray;
-use crate::array::{BinaryView, BoolArray, ListArray, VarBinViewArray};
+use crate::array::{BinaryView, BoolArray, FixedSizeListArray, ListArray, VarBinViewArray};
 use crate::compute::{scalar_at, slice, try_cast};
 use crate::validity::Validity;
 use crate::{
@@ -97,6 +97,20 @@ pub(crate) fn try_canonicalize_chunks(
             Ok(Canonical::List(list))
         }
 
+        // Fixed-size lists push the chunking down into their elements.
+        DType::FixedSizeList(element_dtype, list_size, _) => {
+            let len = chunks.iter().map(|chunk| chunk.len()).sum();
+            let element_chunks = chunks
+                .iter()
+                .map(|chunk| chunk.clone().into_fixed_size_list().map(|l| l.elements()))
+                .collect::<VortexResult<Vec<ArrayData>>>()?;
+            let elements =
+                ChunkedArray::try_new(element_chunks, element_dtype.as_ref().clone())?.into_array();
+            Ok(Canonical::FixedSizeList(FixedSizeListArray::try_new(
+                elements, *list_size, len, validity,
+            )?))
+        }
+
         DType::Bool(_) => {
             let bool_array = pack_bools(chunks.as_slice(), validity)?;
             Ok(Canonical::Bool(bool_array))
@@ -0,0 +1,59 @@
+use vortex_dtype::PType;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::chunked::ChunkedArray;
+use crate::array::ChunkedEncoding;
+use crate::compute::{min_max, sum, MinMaxFn, MinMaxResult, SumAccumulator, SumFn};
+use crate::ArrayDType;
+
+impl SumFn<ChunkedArray> for ChunkedEncoding {
+    fn sum(&self, array: &ChunkedArray) -> VortexResult<Scalar> {
+        let mut result = SumAccumulator::new(PType::try_from(array.dtype())?);
+        for chunk in array.chunks() {
+            result.add(&sum(&chunk)?, 1)?;
+        }
+        result.finish()
+    }
+}
+
+impl MinMaxFn<ChunkedArray> for ChunkedEncoding {
+    fn min_max(&self, array: &ChunkedArray) -> VortexResult<Option<MinMaxResult>> {
+        Ok(MinMaxResult::merge_all(
+            array
+                .chunks()
+                .map(|chunk| min_max(&chunk))
+                .collect::<VortexResult<Vec<_>>>()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ChunkedArray, PrimitiveArray};
+    use crate::compute::{mean, min_max, sum};
+    use crate::IntoArrayData;
+
+    #[test]
+    fn test_sum_chunked() {
+        let array = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1i64), None]).into_array(),
+                PrimitiveArray::from_nullable_vec::<i64>(vec![None]).into_array(),
+                PrimitiveArray::from_nullable_vec(vec![Some(-4i64), Some(9)]).into_array(),
+            ],
+            DType::Primitive(PType::I64, Nullability::Nullable),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(sum(&array).unwrap(), Scalar::from(Some(6i64)));
+        assert_eq!(mean(&array).unwrap(), Scalar::from(Some(2.0f64)));
+
+        let min_max = min_max(&array).unwrap().unwrap();
+        assert_eq!(min_max.min, Scalar::from(Some(-4i64)));
+        assert_eq!(min_max.max, Scalar::from(Some(9i64)));
+    }
+}
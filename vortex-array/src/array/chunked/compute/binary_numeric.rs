@@ -0,0 +1,74 @@
+use vortex_dtype::Nullability;
+use vortex_error::VortexResult;
+
+use crate::array::{ChunkedArray, ChunkedEncoding};
+use crate::compute::{binary_numeric, slice, BinaryNumericFn, BinaryNumericOperator};
+use crate::{ArrayDType, ArrayData, IntoArrayData};
+
+impl BinaryNumericFn<ChunkedArray> for ChunkedEncoding {
+    fn binary_numeric(
+        &self,
+        array: &ChunkedArray,
+        other: &ArrayData,
+        op: BinaryNumericOperator,
+    ) -> VortexResult<Option<ArrayData>> {
+        let mut idx = 0;
+        let mut result_chunks = Vec::with_capacity(array.nchunks());
+
+        for chunk in array.chunks() {
+            let sliced = slice(other, idx, idx + chunk.len())?;
+            result_chunks.push(binary_numeric(&chunk, &sliced, op)?);
+
+            idx += chunk.len();
+        }
+
+        let nullability =
+            Nullability::from(array.dtype().is_nullable() || other.dtype().is_nullable());
+        Ok(Some(
+            ChunkedArray::try_new(result_chunks, array.dtype().with_nullability(nullability))?
+                .into_array(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::{ChunkedArray, ConstantArray, PrimitiveArray};
+    use crate::compute::{mul, sub};
+    use crate::validity::ArrayValidity;
+    use crate::{ArrayDType, IntoArrayData, IntoArrayVariant};
+
+    #[test]
+    fn chunked_mul() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+                PrimitiveArray::from(vec![4i32, 5]).into_array(),
+            ],
+            DType::Primitive(PType::I32, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array();
+
+        let result = mul(&chunked, ConstantArray::new(3i32, 5).into_array()).unwrap();
+        assert!(ChunkedArray::try_from(result.clone()).is_ok());
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[3, 6, 9, 12, 15]
+        );
+
+        let result = sub(
+            &chunked,
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(1), Some(1), None])
+                .into_array(),
+        )
+        .unwrap();
+        assert_eq!(
+            result.dtype(),
+            &DType::Primitive(PType::I32, Nullability::Nullable)
+        );
+        assert_eq!(result.logical_validity().null_count().unwrap(), 2);
+    }
+}
@@ -0,0 +1,20 @@
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::VortexResult;
+
+use crate::array::{ChunkedArray, ChunkedEncoding};
+use crate::compute::{hash, HashFn};
+use crate::{ArrayData, IntoArrayData};
+
+impl HashFn<ChunkedArray> for ChunkedEncoding {
+    fn hash(&self, array: &ChunkedArray) -> VortexResult<ArrayData> {
+        let chunks = array
+            .chunks()
+            .map(|chunk| hash(&chunk))
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(ChunkedArray::try_new(
+            chunks,
+            DType::Primitive(PType::U64, Nullability::NonNullable),
+        )?
+        .into_array())
+    }
+}
@@ -4,15 +4,18 @@ use vortex_error::VortexResult;
 use crate::array::chunked::ChunkedArray;
 use crate::array::ChunkedEncoding;
 use crate::compute::{
-    try_cast, BinaryBooleanFn, CastFn, CompareFn, ComputeVTable, FillNullFn, FilterFn, InvertFn,
-    ScalarAtFn, SliceFn, SubtractScalarFn, TakeFn,
+    try_cast, BinaryBooleanFn, BinaryNumericFn, CastFn, CompareFn, ComputeVTable, FillNullFn,
+    FilterFn, HashFn, InvertFn, MinMaxFn, ScalarAtFn, SliceFn, SubtractScalarFn, SumFn, TakeFn,
 };
 use crate::{ArrayData, IntoArrayData};
 
+mod aggregate;
+mod binary_numeric;
 mod boolean;
 mod compare;
 mod fill_null;
 mod filter;
+mod hash;
 mod invert;
 mod scalar_at;
 mod slice;
@@ -23,6 +26,10 @@ impl ComputeVTable for ChunkedEncoding {
         Some(self)
     }
 
+    fn binary_numeric_fn(&self) -> Option<&dyn BinaryNumericFn<ArrayData>> {
+        Some(self)
+    }
+
     fn cast_fn(&self) -> Option<&dyn CastFn<ArrayData>> {
         Some(self)
     }
@@ -35,10 +42,18 @@ impl ComputeVTable for ChunkedEncoding {
         Some(self)
     }
 
+    fn hash_fn(&self) -> Option<&dyn HashFn<ArrayData>> {
+        Some(self)
+    }
+
     fn invert_fn(&self) -> Option<&dyn InvertFn<ArrayData>> {
         Some(self)
     }
 
+    fn min_max_fn(&self) -> Option<&dyn MinMaxFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -51,6 +66,10 @@ impl ComputeVTable for ChunkedEncoding {
         Some(self)
     }
 
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        Some(self)
+    }
+
     fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
         Some(self)
     }
@@ -1,15 +1,17 @@
 use arrow_array::builder::make_view;
 use arrow_buffer::{BooleanBuffer, BufferBuilder};
 use vortex_buffer::Buffer;
-use vortex_dtype::{match_each_native_ptype, DType, Nullability, PType};
-use vortex_error::{vortex_bail, VortexResult};
-use vortex_scalar::{BinaryScalar, BoolScalar, ExtScalar, Utf8Scalar};
+use vortex_dtype::{is_decimal_ext_type, match_each_native_ptype, DType, Nullability, PType};
+use vortex_error::VortexResult;
+use vortex_scalar::{BinaryScalar, BoolScalar, DecimalScalar, ExtScalar, Utf8Scalar};
 
 use crate::array::constant::ConstantArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::{
-    BinaryView, BoolArray, ExtensionArray, NullArray, VarBinViewArray, VIEW_SIZE_BYTES,
+    BinaryView, BoolArray, DecimalArray, ExtensionArray, NullArray, VarBinViewArray,
+    VIEW_SIZE_BYTES,
 };
+use crate::builders::{builder_with_capacity, ArrayBuilderExt};
 use crate::validity::Validity;
 use crate::{ArrayDType, ArrayLen, Canonical, IntoArrayData, IntoCanonical};
 
@@ -53,8 +55,25 @@ impl IntoCanonical for ConstantArray {
                 let const_value = value.as_ref().map(|v| v.as_slice());
                 Canonical::VarBinView(canonical_byte_view(const_value, self.dtype(), self.len())?)
             }
-            DType::Struct(..) => vortex_bail!("Unsupported scalar type {}", self.dtype()),
-            DType::List(..) => vortex_bail!("Unsupported scalar type {}", self.dtype()),
+            DType::Struct(..) | DType::List(..) | DType::FixedSizeList(..) => {
+                let mut builder = builder_with_capacity(self.dtype(), self.len());
+                for _ in 0..self.len() {
+                    builder.append_scalar(scalar)?;
+                }
+                builder.finish()?.into_canonical()?
+            }
+            // Wide decimals are stored as structs, which can't be constant-canonicalized.
+            DType::Extension(ext_dtype) if is_decimal_ext_type(ext_dtype.id()) => {
+                let decimal = DecimalScalar::try_from(scalar)?;
+                Canonical::Extension(
+                    DecimalArray::from_i256(
+                        vec![decimal.unscaled().unwrap_or_default(); self.len()],
+                        decimal.decimal_dtype(),
+                        validity,
+                    )?
+                    .into(),
+                )
+            }
             DType::Extension(ext_dtype) => {
                 let s = ExtScalar::try_from(scalar)?;
 
@@ -0,0 +1,53 @@
+use vortex_dtype::PType;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::constant::ConstantArray;
+use crate::array::ConstantEncoding;
+use crate::compute::{MinMaxFn, MinMaxResult, SumAccumulator, SumFn};
+use crate::{ArrayDType, ArrayLen};
+
+impl SumFn<ConstantArray> for ConstantEncoding {
+    fn sum(&self, array: &ConstantArray) -> VortexResult<Scalar> {
+        let mut sum = SumAccumulator::new(PType::try_from(array.dtype())?);
+        sum.add(&array.scalar(), array.len() as u64)?;
+        sum.finish()
+    }
+}
+
+impl MinMaxFn<ConstantArray> for ConstantEncoding {
+    fn min_max(&self, array: &ConstantArray) -> VortexResult<Option<MinMaxResult>> {
+        let scalar = array.scalar();
+        Ok(
+            (scalar.is_valid() && !array.is_empty()).then(|| MinMaxResult {
+                min: scalar.clone(),
+                max: scalar,
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::ConstantArray;
+    use crate::compute::{min_max, sum};
+    use crate::IntoArrayData;
+
+    #[test]
+    fn test_sum_constant() {
+        let array = ConstantArray::new(-3i16, 1000).into_array();
+        assert_eq!(sum(&array).unwrap(), Scalar::from(Some(-3000i64)));
+        assert_eq!(min_max(&array).unwrap().unwrap().max, Scalar::from(-3i16));
+
+        let nulls = ConstantArray::new(
+            Scalar::null(DType::Primitive(PType::F32, Nullability::Nullable)),
+            10,
+        )
+        .into_array();
+        assert!(sum(&nulls).unwrap().is_null());
+        assert_eq!(min_max(&nulls).unwrap(), None);
+    }
+}
@@ -0,0 +1,38 @@
+use vortex_error::VortexResult;
+
+use crate::array::{ConstantArray, ConstantEncoding};
+use crate::compute::{hash, scalar_at, HashFn};
+use crate::{ArrayData, ArrayLen, IntoArrayData, IntoCanonical};
+
+impl HashFn<ConstantArray> for ConstantEncoding {
+    fn hash(&self, array: &ConstantArray) -> VortexResult<ArrayData> {
+        // Hash the value once, by hashing a canonical array of the single value.
+        let hashes = hash(
+            &ConstantArray::new(array.scalar(), 1)
+                .into_canonical()?
+                .into_array(),
+        )?;
+        Ok(ConstantArray::new(scalar_at(&hashes, 0)?, array.len()).into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{ConstantArray, PrimitiveArray};
+    use crate::compute::hash;
+    use crate::{IntoArrayData, IntoArrayVariant};
+
+    #[test]
+    fn hash_constant() {
+        let hashes = hash(&ConstantArray::new(7u16, 3).into_array()).unwrap();
+        assert!(ConstantArray::try_from(hashes.clone()).is_ok());
+        assert_eq!(
+            hashes.into_primitive().unwrap().maybe_null_slice::<u64>(),
+            hash(&PrimitiveArray::from(vec![7u16; 3]).into_array())
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>()
+        );
+    }
+}
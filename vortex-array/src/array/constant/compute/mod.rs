@@ -1,5 +1,7 @@
+mod aggregate;
 mod boolean;
 mod compare;
+mod hash;
 mod invert;
 mod search_sorted;
 
@@ -9,8 +11,8 @@ use vortex_scalar::Scalar;
 use crate::array::constant::ConstantArray;
 use crate::array::ConstantEncoding;
 use crate::compute::{
-    BinaryBooleanFn, CompareFn, ComputeVTable, FilterFn, FilterMask, InvertFn, ScalarAtFn,
-    SearchSortedFn, SliceFn, TakeFn, TakeOptions,
+    BinaryBooleanFn, CompareFn, ComputeVTable, FilterFn, FilterMask, HashFn, InvertFn, MinMaxFn,
+    ScalarAtFn, SearchSortedFn, SliceFn, SumFn, TakeFn, TakeOptions,
 };
 use crate::{ArrayData, IntoArrayData};
 
@@ -27,10 +29,18 @@ impl ComputeVTable for ConstantEncoding {
         Some(self)
     }
 
+    fn hash_fn(&self) -> Option<&dyn HashFn<ArrayData>> {
+        Some(self)
+    }
+
     fn invert_fn(&self) -> Option<&dyn InvertFn<ArrayData>> {
         Some(self)
     }
 
+    fn min_max_fn(&self) -> Option<&dyn MinMaxFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -43,6 +53,10 @@ impl ComputeVTable for ConstantEncoding {
         Some(self)
     }
 
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        Some(self)
+    }
+
     fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
         Some(self)
     }
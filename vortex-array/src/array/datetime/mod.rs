@@ -3,7 +3,9 @@ mod test;
 
 use std::sync::Arc;
 
-use vortex_datetime_dtype::{TemporalMetadata, TimeUnit, DATE_ID, TIMESTAMP_ID, TIME_ID};
+use vortex_datetime_dtype::{
+    TemporalMetadata, TimeUnit, DATE_ID, DURATION_ID, TIMESTAMP_ID, TIME_ID,
+};
 use vortex_dtype::{DType, ExtDType};
 use vortex_error::{vortex_panic, VortexError};
 
@@ -14,7 +16,8 @@ use crate::{ArrayDType, ArrayData, IntoArrayData};
 /// An array wrapper for primitive values that have an associated temporal meaning.
 ///
 /// This is a wrapper around ExtensionArrays containing numeric types, each of which corresponds to
-/// either a timestamp or julian date (both referenced to UNIX epoch), OR a time since midnight.
+/// either a timestamp or julian date (both referenced to UNIX epoch), a time since midnight, OR
+/// a duration.
 ///
 /// ## Arrow compatibility
 ///
@@ -24,6 +27,7 @@ use crate::{ArrayDType, ArrayData, IntoArrayData};
 /// * `Timestamp`
 /// * `Date32`
 /// * `Date64`
+/// * `Duration`
 ///
 /// Anything that can be constructed and held in a `TemporalArray` can also be zero-copy converted
 /// back to the relevant Arrow datatype.
@@ -157,6 +161,33 @@ impl TemporalArray {
             temporal_metadata,
         }
     }
+
+    /// Create a new `TemporalArray` holding Arrow spec compliant Duration data.
+    ///
+    /// # Panics
+    ///
+    /// If `array` does not hold Primitive i64 data, the function will panic.
+    ///
+    /// If the time_unit is days, the function will panic.
+    pub fn new_duration(array: ArrayData, time_unit: TimeUnit) -> Self {
+        assert_width!(i64, array);
+        if time_unit == TimeUnit::D {
+            vortex_panic!("invalid unit D for vortex.duration data");
+        }
+
+        let temporal_metadata = TemporalMetadata::Duration(time_unit);
+        Self {
+            ext: ExtensionArray::new(
+                Arc::new(ExtDType::new(
+                    DURATION_ID.clone(),
+                    Arc::new(array.dtype().clone()),
+                    Some(temporal_metadata.clone().into()),
+                )),
+                array,
+            ),
+            temporal_metadata,
+        }
+    }
 }
 
 impl TemporalArray {
@@ -1,8 +1,13 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, DurationMillisecondArray};
 use vortex_datetime_dtype::{TemporalMetadata, TimeUnit};
 
 use crate::array::{PrimitiveArray, TemporalArray};
+use crate::arrow::FromArrowArray;
+use crate::compute::scalar_at;
 use crate::validity::Validity;
-use crate::{IntoArrayData, IntoArrayVariant};
+use crate::{ArrayData, IntoArrayData, IntoArrayVariant, IntoCanonical};
 
 macro_rules! test_temporal_roundtrip {
     ($prim:ty, $constructor:expr, $unit:expr) => {{
@@ -114,6 +119,32 @@ test_success_case!(
 );
 test_fail_case!(test_fail_date64, i32, TemporalArray::new_date, TimeUnit::Ms);
 
+// Duration conformance tests
+test_success_case!(
+    test_roundtrip_duration_s,
+    i64,
+    TemporalArray::new_duration,
+    TimeUnit::S
+);
+test_success_case!(
+    test_roundtrip_duration_ns,
+    i64,
+    TemporalArray::new_duration,
+    TimeUnit::Ns
+);
+test_fail_case!(
+    test_fail_duration_i32,
+    i32,
+    TemporalArray::new_duration,
+    TimeUnit::Ms
+);
+test_fail_case!(
+    test_fail_duration_days,
+    i64,
+    TemporalArray::new_duration,
+    TimeUnit::D
+);
+
 // We test Timestamp explicitly to avoid the macro getting too complex.
 #[test]
 fn test_timestamp() {
@@ -142,3 +173,23 @@ fn test_timestamp_fails_i32() {
 
     let _ = TemporalArray::new_timestamp(ts_array, TimeUnit::S, None);
 }
+
+#[test]
+fn test_duration_arrow_round_trip() {
+    let arrow: ArrayRef = Arc::new(DurationMillisecondArray::from(vec![
+        Some(1_500),
+        None,
+        Some(-86_400_000),
+    ]));
+    let array = ArrayData::from_arrow(arrow.clone(), true);
+
+    let temporal_array = TemporalArray::try_from(array.clone()).unwrap();
+    assert_eq!(
+        temporal_array.temporal_metadata(),
+        &TemporalMetadata::Duration(TimeUnit::Ms)
+    );
+    assert_eq!(scalar_at(&array, 0).unwrap().to_string(), "PT1.5s");
+
+    let exported = array.into_canonical().unwrap().into_arrow().unwrap();
+    assert_eq!(exported.as_ref(), arrow.as_ref());
+}
@@ -0,0 +1,263 @@
+#[cfg(test)]
+mod test;
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Decimal128Type, Decimal256Type};
+use arrow_buffer::i256;
+use arrow_cast::{cast_with_options, CastOptions};
+use arrow_schema::DataType;
+use num_traits::ToPrimitive;
+use vortex_dtype::{DType, DecimalDType, ExtDType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_scalar::{join_decimal_words, split_decimal_words};
+
+use crate::array::{ExtensionArray, PrimitiveArray, StructArray};
+use crate::arrow::{infer_data_type, FromArrowArray};
+use crate::validity::{ArrayValidity, LogicalValidity, Validity};
+use crate::variants::{ExtensionArrayTrait, PrimitiveArrayTrait};
+use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant, IntoCanonical};
+
+/// An array wrapper for fixed-point decimals, stored as their unscaled integer values.
+///
+/// This is a wrapper around ExtensionArrays of the well-known `vortex.decimal` type, whose storage
+/// is described by [`DecimalDType`]. Since the unscaled values are plain integers, the storage can
+/// be compressed with the usual integer encodings, e.g. frame-of-reference and bit-packing.
+///
+/// ## Arrow compatibility
+///
+/// DecimalArray can be created from Arrow arrays of the `Decimal128` and `Decimal256` datatypes,
+/// and is converted back into `Decimal128` if its precision allows, or `Decimal256` otherwise.
+#[derive(Clone, Debug)]
+pub struct DecimalArray {
+    /// The underlying Vortex extension array holding the unscaled values.
+    ext: ExtensionArray,
+
+    /// In-memory representation of the ExtMetadata of the underlying extension array.
+    decimal: DecimalDType,
+}
+
+impl DecimalArray {
+    /// Create a new `DecimalArray` from its unscaled values, whose dtype must match the
+    /// [storage dtype][DecimalDType::storage_dtype] of the decimal type.
+    pub fn try_new(unscaled: ArrayData, decimal: DecimalDType) -> VortexResult<Self> {
+        let storage_dtype = decimal.storage_dtype(unscaled.dtype().nullability());
+        if unscaled.dtype() != &storage_dtype {
+            vortex_bail!(
+                "Expected unscaled values of type {storage_dtype} for {decimal}, found {}",
+                unscaled.dtype()
+            );
+        }
+
+        Ok(Self {
+            ext: ExtensionArray::new(
+                Arc::new(decimal.ext_dtype(unscaled.dtype().nullability())),
+                unscaled,
+            ),
+            decimal,
+        })
+    }
+
+    /// Create a new `DecimalArray` from unscaled values, which must fit into its storage.
+    ///
+    /// The values of null elements are ignored.
+    pub fn from_i256(
+        values: Vec<i256>,
+        decimal: DecimalDType,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        let len = values.len();
+        let values = match validity.to_logical(len).to_null_buffer()? {
+            Some(nulls) => values
+                .into_iter()
+                .zip(nulls.iter())
+                .map(|(v, valid)| if valid { v } else { i256::ZERO })
+                .collect(),
+            None => values,
+        };
+        let unscaled = match decimal.words() {
+            1 => PrimitiveArray::from_vec(
+                values
+                    .into_iter()
+                    .map(|v| {
+                        v.to_i64()
+                            .ok_or_else(|| vortex_err!("Value {v} doesn't fit into {decimal}"))
+                    })
+                    .collect::<VortexResult<Vec<_>>>()?,
+                validity,
+            )
+            .into_array(),
+            n => {
+                let DType::Struct(storage, _) = decimal.storage_dtype(validity.nullability())
+                else {
+                    vortex_bail!("Expected struct storage for {decimal}");
+                };
+                let mut words = vec![Vec::with_capacity(len); n];
+                for value in values {
+                    if n == 2 && value.to_i128().is_none() {
+                        vortex_bail!("Value {value} doesn't fit into {decimal}");
+                    }
+                    for (word, w) in words.iter_mut().zip(split_decimal_words(value, n)) {
+                        word.push(w);
+                    }
+                }
+                let fields = words
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, word)| {
+                        if i == 0 {
+                            PrimitiveArray::from_vec(
+                                word.into_iter().map(|w| w as i64).collect(),
+                                Validity::NonNullable,
+                            )
+                            .into_array()
+                        } else {
+                            PrimitiveArray::from_vec(word, Validity::NonNullable).into_array()
+                        }
+                    })
+                    .collect();
+                StructArray::try_new(storage.names().clone(), fields, len, validity)?.into_array()
+            }
+        };
+        Self::try_new(unscaled, decimal)
+    }
+
+    /// Create a new `DecimalArray` from unscaled `i128` values, see [`DecimalArray::from_i256`].
+    pub fn from_i128(
+        values: Vec<i128>,
+        decimal: DecimalDType,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        Self::from_i256(
+            values.into_iter().map(i256::from_i128).collect(),
+            decimal,
+            validity,
+        )
+    }
+}
+
+impl DecimalArray {
+    /// Access the unscaled values in the underlying ExtensionArray storage.
+    pub fn unscaled_values(&self) -> ArrayData {
+        self.ext.storage()
+    }
+
+    /// Decode all unscaled values, including the undefined values of null elements.
+    pub fn unscaled_i256(&self) -> VortexResult<Vec<i256>> {
+        let storage = self.unscaled_values();
+        if self.decimal.words() == 1 {
+            return Ok(storage
+                .into_primitive()?
+                .maybe_null_slice::<i64>()
+                .iter()
+                .map(|&v| i256::from_i128(v.into()))
+                .collect());
+        }
+
+        let storage = storage.into_struct()?;
+        let words = storage
+            .children()
+            .map(|word| {
+                let word = word.into_primitive()?;
+                Ok(if word.ptype().is_signed_int() {
+                    word.maybe_null_slice::<i64>()
+                        .iter()
+                        .map(|&w| w as u64)
+                        .collect()
+                } else {
+                    word.maybe_null_slice::<u64>().to_vec()
+                })
+            })
+            .collect::<VortexResult<Vec<Vec<u64>>>>()?;
+        let mut scratch = vec![0; words.len()];
+        Ok((0..storage.len())
+            .map(|idx| {
+                for (w, word) in scratch.iter_mut().zip(&words) {
+                    *w = word[idx];
+                }
+                join_decimal_words(&scratch)
+            })
+            .collect())
+    }
+
+    /// The logical validity of the decimals.
+    pub fn logical_validity(&self) -> LogicalValidity {
+        self.unscaled_values().logical_validity()
+    }
+
+    /// Retrieve the precision and scale of the decimals.
+    pub fn decimal_dtype(&self) -> DecimalDType {
+        self.decimal
+    }
+
+    /// Retrieve the extension DType associated with the underlying array.
+    pub fn ext_dtype(&self) -> Arc<ExtDType> {
+        self.ext.ext_dtype().clone()
+    }
+}
+
+/// Cast from or to decimals using Arrow, which rescales the unscaled values and fails on overflow.
+pub(crate) fn cast_decimal(array: &ArrayData, dtype: &DType) -> VortexResult<ArrayData> {
+    if !dtype.is_nullable() && !array.logical_validity().all_valid() {
+        vortex_bail!(
+            "invalid cast from nullable to non-nullable, since source array actually contains nulls"
+        );
+    }
+
+    let cast = cast_with_options(
+        &array.clone().into_canonical()?.into_arrow()?,
+        &infer_data_type(dtype)?,
+        &CastOptions {
+            safe: false,
+            ..Default::default()
+        },
+    )?;
+    // Arrow doesn't check the precision of decimals that keep their scale.
+    match cast.data_type() {
+        DataType::Decimal128(precision, _) => cast
+            .as_primitive::<Decimal128Type>()
+            .validate_decimal_precision(*precision)?,
+        DataType::Decimal256(precision, _) => cast
+            .as_primitive::<Decimal256Type>()
+            .validate_decimal_precision(*precision)?,
+        _ => {}
+    }
+    Ok(ArrayData::from_arrow(cast, dtype.is_nullable()))
+}
+
+impl From<DecimalArray> for ArrayData {
+    fn from(value: DecimalArray) -> Self {
+        value.ext.into_array()
+    }
+}
+
+impl TryFrom<ArrayData> for DecimalArray {
+    type Error = VortexError;
+
+    /// Try to specialize a generic Vortex array as a DecimalArray.
+    ///
+    /// # Errors
+    ///
+    /// If the provided Array does not have `vortex.ext` encoding, or isn't of the decimal extension
+    /// type, an error will be returned.
+    fn try_from(value: ArrayData) -> Result<Self, Self::Error> {
+        Self::try_from(ExtensionArray::try_from(value)?)
+    }
+}
+
+// Conversions to/from ExtensionArray
+impl From<DecimalArray> for ExtensionArray {
+    fn from(value: DecimalArray) -> Self {
+        value.ext
+    }
+}
+
+impl TryFrom<ExtensionArray> for DecimalArray {
+    type Error = VortexError;
+
+    fn try_from(ext: ExtensionArray) -> Result<Self, Self::Error> {
+        let decimal = DecimalDType::try_from(ext.ext_dtype().as_ref())?;
+        Ok(Self { ext, decimal })
+    }
+}
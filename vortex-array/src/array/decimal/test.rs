@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, Decimal128Array, Decimal256Array};
+use arrow_buffer::{i256, NullBuffer};
+use vortex_dtype::{DType, DecimalDType, Nullability, PType};
+use vortex_scalar::Scalar;
+
+use crate::array::{ConstantArray, DecimalArray, PrimitiveArray};
+use crate::arrow::{FromArrowArray, TryFromArrowArray};
+use crate::compute::{compare, scalar_at, slice, try_cast, Operator};
+use crate::validity::Validity;
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant, IntoCanonical};
+
+fn decimal_dtype(precision: u8, scale: i8) -> DecimalDType {
+    DecimalDType::try_new(precision, scale).unwrap()
+}
+
+#[test]
+fn narrow_decimals_are_stored_as_i64() {
+    let array = DecimalArray::from_i128(
+        vec![12345, -50, 0],
+        decimal_dtype(10, 2),
+        Validity::NonNullable,
+    )
+    .unwrap();
+    assert_eq!(
+        array.unscaled_values().dtype(),
+        &DType::Primitive(PType::I64, Nullability::NonNullable)
+    );
+    assert_eq!(
+        array.unscaled_i256().unwrap(),
+        vec![
+            i256::from_i128(12345),
+            i256::from_i128(-50),
+            i256::from_i128(0)
+        ]
+    );
+
+    let array = ArrayData::from(array);
+    assert_eq!(scalar_at(&array, 0).unwrap().to_string(), "123.45");
+    assert_eq!(scalar_at(&array, 1).unwrap().to_string(), "-0.50");
+}
+
+#[test]
+fn wide_decimals_round_trip() {
+    let values = vec![i128::MAX / 10, -1, i128::MIN / 10, 0];
+    let array = DecimalArray::from_i128(
+        values.clone(),
+        decimal_dtype(38, 4),
+        Validity::from_iter([true, true, true, false]),
+    )
+    .unwrap();
+    assert!(array.unscaled_values().dtype().is_struct());
+    assert_eq!(
+        array.unscaled_i256().unwrap()[..3],
+        values[..3]
+            .iter()
+            .map(|&v| i256::from_i128(v))
+            .collect::<Vec<_>>()
+    );
+
+    let array = ArrayData::from(array);
+    assert_eq!(
+        scalar_at(&array, 1).unwrap().as_decimal().unscaled(),
+        Some(i256::from_i128(-1))
+    );
+    assert!(scalar_at(&array, 3).unwrap().is_null());
+    let sliced = DecimalArray::try_from(slice(&array, 1, 3).unwrap()).unwrap();
+    assert_eq!(
+        sliced.unscaled_i256().unwrap(),
+        vec![i256::from_i128(-1), i256::from_i128(i128::MIN / 10)]
+    );
+}
+
+#[test]
+fn arrow_round_trip() {
+    let decimal128 = Decimal128Array::from(vec![Some(1234), None, Some(-99)])
+        .with_precision_and_scale(20, 3)
+        .unwrap();
+    let array = ArrayData::from_arrow(Arc::new(decimal128.clone()) as ArrayRef, true);
+    assert_eq!(
+        array.dtype(),
+        &DType::Extension(Arc::new(
+            decimal_dtype(20, 3).ext_dtype(Nullability::Nullable)
+        ))
+    );
+    let arrow = array.into_canonical().unwrap().into_arrow().unwrap();
+    assert_eq!(arrow.as_ref(), &decimal128 as &dyn Array);
+
+    let decimal256 = Decimal256Array::from(vec![
+        i256::MAX.checked_div(i256::from_i128(100)).unwrap(),
+        i256::from_i128(-7),
+    ])
+    .with_precision_and_scale(76, 10)
+    .unwrap();
+    let array = ArrayData::from_arrow(Arc::new(decimal256.clone()) as ArrayRef, false);
+    let arrow = array.into_canonical().unwrap().into_arrow().unwrap();
+    assert_eq!(arrow.as_ref(), &decimal256 as &dyn Array);
+}
+
+#[test]
+fn compare_decimals() {
+    for precision in [10, 30] {
+        let decimal = decimal_dtype(precision, 2);
+        let array = ArrayData::from(
+            DecimalArray::from_i128(vec![100, -250, 300], decimal, Validity::NonNullable).unwrap(),
+        );
+        let threshold =
+            Scalar::decimal(i256::from_i128(100), decimal, Nullability::NonNullable).unwrap();
+
+        let result = compare(
+            &array,
+            ConstantArray::new(threshold, array.len()),
+            Operator::Gt,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, false, true]
+        );
+
+        let result = compare(&array, &array, Operator::Eq)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(result.boolean_buffer().count_set_bits(), 3);
+    }
+}
+
+#[test]
+fn cast_decimals() {
+    let array = ArrayData::from(
+        DecimalArray::from_i128(
+            vec![12345, -12355],
+            decimal_dtype(10, 2),
+            Validity::NonNullable,
+        )
+        .unwrap(),
+    );
+
+    let rescaled = try_cast(
+        &array,
+        &DType::Extension(Arc::new(
+            decimal_dtype(30, 1).ext_dtype(Nullability::NonNullable),
+        )),
+    )
+    .unwrap();
+    assert_eq!(
+        DecimalArray::try_from(rescaled)
+            .unwrap()
+            .unscaled_i256()
+            .unwrap(),
+        vec![i256::from_i128(1235), i256::from_i128(-1236)]
+    );
+
+    let floats = try_cast(
+        &array,
+        &DType::Primitive(PType::F64, Nullability::NonNullable),
+    )
+    .unwrap()
+    .into_primitive()
+    .unwrap();
+    assert_eq!(floats.maybe_null_slice::<f64>(), &[123.45, -123.55]);
+
+    let ints = PrimitiveArray::from(vec![1i32, -2]).into_array();
+    let decimals = try_cast(
+        &ints,
+        &DType::Extension(Arc::new(
+            decimal_dtype(5, 2).ext_dtype(Nullability::NonNullable),
+        )),
+    )
+    .unwrap();
+    assert_eq!(
+        DecimalArray::try_from(decimals)
+            .unwrap()
+            .unscaled_i256()
+            .unwrap(),
+        vec![i256::from_i128(100), i256::from_i128(-200)]
+    );
+
+    // Overflowing the precision fails instead of producing invalid decimals.
+    try_cast(
+        &array,
+        &DType::Extension(Arc::new(
+            decimal_dtype(3, 2).ext_dtype(Nullability::NonNullable),
+        )),
+    )
+    .unwrap_err();
+}
+
+#[test]
+fn arrow_decimals_beyond_precision() {
+    // Arrow validates neither the values of null elements nor the precision of the others.
+    let decimal128 = Decimal128Array::new(
+        vec![12, i128::MAX].into(),
+        Some(NullBuffer::from(vec![true, false])),
+    )
+    .with_precision_and_scale(10, 2)
+    .unwrap();
+    let array = ArrayData::try_from_arrow(Arc::new(decimal128) as ArrayRef, true).unwrap();
+    assert_eq!(scalar_at(&array, 0).unwrap().to_string(), "0.12");
+    assert!(scalar_at(&array, 1).unwrap().is_null());
+
+    let out_of_range = Decimal128Array::from(vec![i128::MAX])
+        .with_precision_and_scale(10, 2)
+        .unwrap();
+    assert!(ArrayData::try_from_arrow(Arc::new(out_of_range) as ArrayRef, false).is_err());
+}
@@ -2,6 +2,7 @@ use vortex_error::VortexResult;
 
 use crate::array::{ConstantArray, ExtensionArray, ExtensionEncoding};
 use crate::compute::{compare, CompareFn, Operator};
+use crate::variants::ExtensionArrayTrait;
 use crate::{ArrayData, ArrayLen};
 
 impl CompareFn<ExtensionArray> for ExtensionEncoding {
@@ -11,6 +12,12 @@ impl CompareFn<ExtensionArray> for ExtensionEncoding {
         rhs: &ArrayData,
         operator: Operator,
     ) -> VortexResult<Option<ArrayData>> {
+        // Wide decimals are stored as structs of words, which can't be compared field by field.
+        // Fall back to comparing them in Arrow.
+        if lhs.ext_dtype().storage_dtype().is_struct() {
+            return Ok(None);
+        }
+
         // If the RHS is a constant, we can extract the storage scalar.
         if let Some(const_ext) = rhs.as_constant() {
             let storage_scalar = const_ext.as_extension().storage();
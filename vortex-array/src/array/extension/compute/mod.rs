@@ -1,23 +1,21 @@
 mod compare;
 
-use vortex_error::VortexResult;
+use vortex_dtype::{is_decimal_ext_type, DType};
+use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::array::extension::ExtensionArray;
-use crate::array::ExtensionEncoding;
+use crate::array::{cast_decimal, ExtensionEncoding};
 use crate::compute::{
     scalar_at, slice, take, CastFn, CompareFn, ComputeVTable, ScalarAtFn, SliceFn, TakeFn,
     TakeOptions,
 };
 use crate::variants::ExtensionArrayTrait;
-use crate::{ArrayData, IntoArrayData};
+use crate::{ArrayDType, ArrayData, IntoArrayData};
 
 impl ComputeVTable for ExtensionEncoding {
     fn cast_fn(&self) -> Option<&dyn CastFn<ArrayData>> {
-        // It's not possible to cast an extension array to another type.
-        // TODO(ngates): we should allow some extension arrays to implement a callback
-        //  to support this
-        None
+        Some(self)
     }
 
     fn compare_fn(&self) -> Option<&dyn CompareFn<ArrayData>> {
@@ -37,6 +35,22 @@ impl ComputeVTable for ExtensionEncoding {
     }
 }
 
+impl CastFn<ExtensionArray> for ExtensionEncoding {
+    fn cast(&self, array: &ExtensionArray, dtype: &DType) -> VortexResult<ArrayData> {
+        // Only decimals can be cast to other types.
+        // TODO(ngates): we should allow other extension arrays to implement a callback
+        //  to support this
+        if !is_decimal_ext_type(array.id()) {
+            vortex_bail!(
+                "No compute kernel to cast array from {} to {}",
+                array.dtype(),
+                dtype
+            );
+        }
+        cast_decimal(array.as_ref(), dtype)
+    }
+}
+
 impl ScalarAtFn<ExtensionArray> for ExtensionEncoding {
     fn scalar_at(&self, array: &ExtensionArray, index: usize) -> VortexResult<Scalar> {
         Ok(Scalar::extension(
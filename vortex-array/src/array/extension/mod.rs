@@ -50,7 +50,6 @@ impl ExtensionArray {
             .vortex_expect("Missing storage array for ExtensionArray")
     }
 
-    #[allow(dead_code)]
     #[inline]
     pub fn id(&self) -> &ExtID {
         self.ext_dtype().id()
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use itertools::Itertools;
+use num_traits::AsPrimitive;
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::{FixedSizeListArray, FixedSizeListEncoding, PrimitiveArray};
+use crate::compute::{
+    filter, scalar_at, slice, take, ComputeVTable, FilterFn, FilterMask, ScalarAtFn, SliceFn,
+    TakeFn, TakeOptions,
+};
+use crate::validity::{ArrayValidity, Validity};
+use crate::variants::PrimitiveArrayTrait;
+use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
+
+impl ComputeVTable for FixedSizeListEncoding {
+    fn filter_fn(&self) -> Option<&dyn FilterFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn slice_fn(&self) -> Option<&dyn SliceFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn<FixedSizeListArray> for FixedSizeListEncoding {
+    fn scalar_at(&self, array: &FixedSizeListArray, index: usize) -> VortexResult<Scalar> {
+        if !array.validity().is_valid(index) {
+            return Ok(Scalar::null(array.dtype().clone()));
+        }
+        let elem = array.elements_at(index)?;
+        let scalars: Vec<Scalar> = (0..elem.len()).map(|i| scalar_at(&elem, i)).try_collect()?;
+
+        Ok(Scalar::fixed_size_list(
+            Arc::new(elem.dtype().clone()),
+            scalars,
+            array.dtype().nullability(),
+        ))
+    }
+}
+
+impl SliceFn<FixedSizeListArray> for FixedSizeListEncoding {
+    fn slice(
+        &self,
+        array: &FixedSizeListArray,
+        start: usize,
+        stop: usize,
+    ) -> VortexResult<ArrayData> {
+        let size = array.list_size() as usize;
+        FixedSizeListArray::try_new(
+            slice(array.elements(), start * size, stop * size)?,
+            array.list_size(),
+            stop - start,
+            array.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl TakeFn<FixedSizeListArray> for FixedSizeListEncoding {
+    fn take(
+        &self,
+        array: &FixedSizeListArray,
+        indices: &ArrayData,
+        options: TakeOptions,
+    ) -> VortexResult<ArrayData> {
+        let size = array.list_size() as u64;
+        let indices = indices.clone().into_primitive()?;
+        let nulls = indices.logical_validity().to_null_buffer()?;
+        // Null indices take the elements of the first list, which are masked by the validity.
+        let element_indices: Vec<u64> = match_each_integer_ptype!(indices.ptype(), |$I| {
+            indices
+                .maybe_null_slice::<$I>()
+                .iter()
+                .enumerate()
+                .flat_map(|(i, &idx)| {
+                    let start = if nulls.as_ref().map_or(true, |n| n.is_valid(i)) {
+                        AsPrimitive::<u64>::as_(idx) * size
+                    } else {
+                        0
+                    };
+                    start..start + size
+                })
+                .collect()
+        });
+
+        FixedSizeListArray::try_new(
+            take(
+                array.elements(),
+                PrimitiveArray::from_vec(element_indices, Validity::NonNullable),
+                options,
+            )?,
+            array.list_size(),
+            indices.len(),
+            array.validity().take(indices.as_ref(), options)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl FilterFn<FixedSizeListArray> for FixedSizeListEncoding {
+    fn filter(&self, array: &FixedSizeListArray, mask: FilterMask) -> VortexResult<ArrayData> {
+        let size = array.list_size() as usize;
+        let element_mask = FilterMask::from_indices(
+            array.len() * size,
+            mask.to_boolean_buffer()?
+                .set_slices()
+                .flat_map(|(start, end)| start * size..end * size),
+        );
+
+        FixedSizeListArray::try_new(
+            filter(&array.elements(), element_mask)?,
+            array.list_size(),
+            mask.true_count(),
+            array.validity().filter(&mask)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
@@ -0,0 +1,298 @@
+mod compute;
+
+use std::fmt::Display;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexExpect, VortexResult};
+
+use crate::compute::slice;
+use crate::encoding::ids;
+use crate::stats::{Stat, StatisticsVTable, StatsSet};
+use crate::validity::{LogicalValidity, Validity, ValidityMetadata, ValidityVTable};
+use crate::variants::{ListArrayTrait, VariantsVTable};
+use crate::visitor::{ArrayVisitor, VisitorVTable};
+use crate::{impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoCanonical};
+
+impl_encoding!(
+    "vortex.fixed_size_list",
+    ids::FIXED_SIZE_LIST,
+    FixedSizeList
+);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixedSizeListMetadata {
+    validity: ValidityMetadata,
+}
+
+impl Display for FixedSizeListMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FixedSizeListMetadata")
+    }
+}
+
+impl FixedSizeListArray {
+    /// Create a new array of `len` lists, each holding `list_size` elements.
+    ///
+    /// The elements of the list at `index` are stored at `index * list_size..(index + 1) *
+    /// list_size`, so unlike [`ListArray`][crate::array::ListArray] no offsets are needed. Null
+    /// lists still occupy their elements, whose values are undefined.
+    pub fn try_new(
+        elements: ArrayData,
+        list_size: u32,
+        len: usize,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        let elements_len = len
+            .checked_mul(list_size as usize)
+            .ok_or_else(|| vortex_err!("Fixed-size list of {len} x {list_size} overflows"))?;
+        if elements.len() != elements_len {
+            vortex_bail!(
+                "Expected {elements_len} elements for {len} lists of size {list_size}, found {}",
+                elements.len()
+            );
+        }
+
+        let dtype = DType::FixedSizeList(
+            Arc::new(elements.dtype().clone()),
+            list_size,
+            validity.nullability(),
+        );
+        let validity_metadata = validity.to_metadata(len)?;
+
+        let mut children = vec![elements];
+        if let Some(val) = validity.into_array() {
+            children.push(val);
+        }
+
+        Self::try_from_parts(
+            dtype,
+            len,
+            FixedSizeListMetadata {
+                validity: validity_metadata,
+            },
+            children.into(),
+            StatsSet::default(),
+        )
+    }
+
+    /// The number of elements in every list.
+    pub fn list_size(&self) -> u32 {
+        self.dtype()
+            .as_fixed_size_list()
+            .map(|(_, size)| size)
+            .unwrap_or_else(|| vortex_panic!("FixedSizeListArray must have fixed-size list dtype"))
+    }
+
+    /// The elements of all lists, including those of null lists.
+    pub fn elements(&self) -> ArrayData {
+        let (dtype, size) = self
+            .dtype()
+            .as_fixed_size_list()
+            .vortex_expect("FixedSizeListArray must have fixed-size list dtype");
+        self.as_ref()
+            .child(0, dtype, self.len() * size as usize)
+            .vortex_expect("FixedSizeListArray: elements child")
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata().validity.to_validity(|| {
+            self.as_ref()
+                .child(1, &Validity::DTYPE, self.len())
+                .vortex_expect("FixedSizeListArray: validity child")
+        })
+    }
+
+    /// The elements of the list at `index`, ignoring validity.
+    pub fn elements_at(&self, index: usize) -> VortexResult<ArrayData> {
+        if index >= self.len() {
+            vortex_bail!("Index out of bounds: index={} len={}", index, self.len());
+        }
+        let size = self.list_size() as usize;
+        slice(self.elements(), index * size, (index + 1) * size)
+    }
+}
+
+impl VariantsVTable<FixedSizeListArray> for FixedSizeListEncoding {
+    fn as_list_array<'a>(&self, array: &'a FixedSizeListArray) -> Option<&'a dyn ListArrayTrait> {
+        Some(array)
+    }
+}
+
+impl ArrayTrait for FixedSizeListArray {}
+
+impl ListArrayTrait for FixedSizeListArray {}
+
+impl VisitorVTable<FixedSizeListArray> for FixedSizeListEncoding {
+    fn accept(
+        &self,
+        array: &FixedSizeListArray,
+        visitor: &mut dyn ArrayVisitor,
+    ) -> VortexResult<()> {
+        visitor.visit_child("elements", &array.elements())?;
+        visitor.visit_validity(&array.validity())
+    }
+}
+
+impl IntoCanonical for FixedSizeListArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        Ok(Canonical::FixedSizeList(self))
+    }
+}
+
+impl StatisticsVTable<FixedSizeListArray> for FixedSizeListEncoding {
+    fn compute_statistics(
+        &self,
+        _array: &FixedSizeListArray,
+        _stat: Stat,
+    ) -> VortexResult<StatsSet> {
+        Ok(StatsSet::default())
+    }
+}
+
+impl ValidityVTable<FixedSizeListArray> for FixedSizeListEncoding {
+    fn is_valid(&self, array: &FixedSizeListArray, index: usize) -> bool {
+        array.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self, array: &FixedSizeListArray) -> LogicalValidity {
+        array.validity().to_logical(array.len())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{Array, FixedSizeBinaryArray};
+    use arrow_schema::{DataType, Field};
+    use vortex_dtype::{fixed_size_binary_ext_dtype, DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{FixedSizeListArray, PrimitiveArray};
+    use crate::arrow::FromArrowArray;
+    use crate::compute::{filter, scalar_at, slice, take, FilterMask, TakeOptions};
+    use crate::validity::Validity;
+    use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant, IntoCanonical};
+
+    fn vectors() -> FixedSizeListArray {
+        FixedSizeListArray::try_new(
+            PrimitiveArray::from(vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]).into_array(),
+            2,
+            4,
+            Validity::from_iter([true, true, false, true]),
+        )
+        .unwrap()
+    }
+
+    fn elements(array: &FixedSizeListArray) -> Vec<f32> {
+        array
+            .elements()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<f32>()
+            .to_vec()
+    }
+
+    #[test]
+    fn scalar_at_fixed_size_list() {
+        let array = vectors();
+        assert_eq!(
+            scalar_at(&array, 1).unwrap(),
+            Scalar::fixed_size_list(
+                Arc::new(PType::F32.into()),
+                vec![3.0f32.into(), 4.0f32.into()],
+                Nullability::Nullable
+            )
+        );
+        assert!(scalar_at(&array, 2).unwrap().is_null());
+    }
+
+    #[test]
+    fn slice_take_filter() {
+        let array = vectors();
+
+        let sliced = FixedSizeListArray::try_from(slice(&array, 1, 3).unwrap()).unwrap();
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(elements(&sliced), vec![3.0, 4.0, 5.0, 6.0]);
+
+        let taken = FixedSizeListArray::try_from(
+            take(
+                &array,
+                PrimitiveArray::from(vec![3u32, 0, 2]),
+                TakeOptions::default(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(elements(&taken), vec![7.0, 8.0, 1.0, 2.0, 5.0, 6.0]);
+        assert!(!taken.validity().is_valid(2));
+
+        let filtered = FixedSizeListArray::try_from(
+            filter(
+                array.as_ref(),
+                FilterMask::from_iter([false, true, true, true]),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(elements(&filtered), vec![3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        assert!(!filtered.validity().is_valid(1));
+    }
+
+    #[test]
+    fn empty_lists() {
+        let array = FixedSizeListArray::try_new(
+            PrimitiveArray::from(Vec::<i32>::new()).into_array(),
+            0,
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(
+            array.dtype(),
+            &DType::FixedSizeList(Arc::new(PType::I32.into()), 0, Nullability::NonNullable)
+        );
+        assert_eq!(scalar_at(&array, 2).unwrap().as_list().len(), 0);
+    }
+
+    #[test]
+    fn arrow_round_trip() {
+        let arrow = vectors().into_canonical().unwrap().into_arrow().unwrap();
+        assert_eq!(
+            arrow.data_type(),
+            &DataType::FixedSizeList(Arc::new(Field::new_list_field(DataType::Float32, false)), 2)
+        );
+        let array = ArrayData::from_arrow(arrow.clone(), true);
+        assert_eq!(array.dtype(), vectors().dtype());
+        assert_eq!(
+            array
+                .into_canonical()
+                .unwrap()
+                .into_arrow()
+                .unwrap()
+                .as_ref(),
+            arrow.as_ref()
+        );
+
+        let binary = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            [Some([1u8, 2, 3]), None, Some([4, 5, 6])].into_iter(),
+            3,
+        )
+        .unwrap();
+        let array = ArrayData::from_arrow(&binary, true);
+        assert_eq!(
+            array.dtype(),
+            &DType::Extension(Arc::new(fixed_size_binary_ext_dtype(
+                3,
+                Nullability::Nullable
+            )))
+        );
+        assert_eq!(scalar_at(&array, 2).unwrap().to_string(), "4,5,6");
+        assert!(scalar_at(&array, 1).unwrap().is_null());
+        let arrow = array.into_canonical().unwrap().into_arrow().unwrap();
+        assert_eq!(arrow.as_ref(), &binary as &dyn Array);
+    }
+}
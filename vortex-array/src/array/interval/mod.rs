@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use vortex_datetime_dtype::IntervalUnit;
+use vortex_dtype::ExtDType;
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+
+use crate::array::ExtensionArray;
+use crate::variants::ExtensionArrayTrait;
+use crate::{ArrayDType, ArrayData, IntoArrayData};
+
+/// An array wrapper for calendar intervals.
+///
+/// This is a wrapper around ExtensionArrays of the well-known `vortex.interval` type. Year-month
+/// intervals are stored as `i32` months, day-time and month-day-nano intervals as a struct of
+/// their components, see [`IntervalUnit::storage_dtype`].
+///
+/// ## Arrow compatibility
+///
+/// IntervalArray can be created from Arrow `Interval` arrays of every unit, and is converted
+/// back into them.
+#[derive(Clone, Debug)]
+pub struct IntervalArray {
+    /// The underlying Vortex extension array holding the interval components.
+    ext: ExtensionArray,
+
+    /// In-memory representation of the ExtMetadata of the underlying extension array.
+    unit: IntervalUnit,
+}
+
+impl IntervalArray {
+    /// Create a new `IntervalArray` from the storage of intervals of the given unit.
+    pub fn try_new(storage: ArrayData, unit: IntervalUnit) -> VortexResult<Self> {
+        let storage_dtype = unit.storage_dtype(storage.dtype().nullability());
+        if storage.dtype() != &storage_dtype {
+            vortex_bail!(
+                "Expected storage of type {storage_dtype} for {unit} intervals, found {}",
+                storage.dtype()
+            );
+        }
+
+        Ok(Self {
+            ext: ExtensionArray::new(
+                Arc::new(unit.ext_dtype(storage.dtype().nullability())),
+                storage,
+            ),
+            unit,
+        })
+    }
+
+    /// Access the interval components in the underlying ExtensionArray storage.
+    pub fn storage(&self) -> ArrayData {
+        self.ext.storage()
+    }
+
+    /// Retrieve the unit of the intervals.
+    pub fn interval_unit(&self) -> IntervalUnit {
+        self.unit
+    }
+
+    /// Retrieve the extension DType associated with the underlying array.
+    pub fn ext_dtype(&self) -> Arc<ExtDType> {
+        self.ext.ext_dtype().clone()
+    }
+}
+
+impl From<IntervalArray> for ArrayData {
+    fn from(value: IntervalArray) -> Self {
+        value.ext.into_array()
+    }
+}
+
+impl TryFrom<ArrayData> for IntervalArray {
+    type Error = VortexError;
+
+    /// Try to specialize a generic Vortex array as an IntervalArray.
+    ///
+    /// # Errors
+    ///
+    /// If the provided Array does not have `vortex.ext` encoding, or isn't of the interval
+    /// extension type, an error will be returned.
+    fn try_from(value: ArrayData) -> Result<Self, Self::Error> {
+        Self::try_from(ExtensionArray::try_from(value)?)
+    }
+}
+
+// Conversions to/from ExtensionArray
+impl From<IntervalArray> for ExtensionArray {
+    fn from(value: IntervalArray) -> Self {
+        value.ext
+    }
+}
+
+impl TryFrom<ExtensionArray> for IntervalArray {
+    type Error = VortexError;
+
+    fn try_from(ext: ExtensionArray) -> Result<Self, Self::Error> {
+        let unit = IntervalUnit::try_from(ext.ext_dtype().as_ref())?;
+        Ok(Self { ext, unit })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::types::{IntervalDayTime, IntervalMonthDayNano};
+    use arrow_array::{
+        Array, ArrayRef, IntervalDayTimeArray, IntervalMonthDayNanoArray, IntervalYearMonthArray,
+    };
+    use vortex_datetime_dtype::IntervalUnit;
+
+    use crate::array::IntervalArray;
+    use crate::arrow::{infer_data_type, FromArrowArray};
+    use crate::compute::scalar_at;
+    use crate::{ArrayDType, ArrayData, IntoCanonical};
+
+    fn round_trip(arrow: ArrayRef, unit: IntervalUnit) -> ArrayData {
+        let array = ArrayData::from_arrow(arrow.clone(), true);
+        assert_eq!(
+            IntervalArray::try_from(array.clone())
+                .unwrap()
+                .interval_unit(),
+            unit
+        );
+        assert_eq!(&infer_data_type(array.dtype()).unwrap(), arrow.data_type());
+
+        let exported = array
+            .clone()
+            .into_canonical()
+            .unwrap()
+            .into_arrow()
+            .unwrap();
+        assert_eq!(exported.as_ref(), arrow.as_ref());
+        array
+    }
+
+    #[test]
+    fn year_month_round_trip() {
+        let array = round_trip(
+            Arc::new(IntervalYearMonthArray::from(vec![Some(14), None, Some(-3)])),
+            IntervalUnit::YearMonth,
+        );
+        assert!(scalar_at(&array, 1).unwrap().is_null());
+    }
+
+    #[test]
+    fn day_time_round_trip() {
+        let array = round_trip(
+            Arc::new(IntervalDayTimeArray::from(vec![
+                Some(IntervalDayTime::new(1, 500)),
+                None,
+                Some(IntervalDayTime::new(-2, 0)),
+            ])),
+            IntervalUnit::DayTime,
+        );
+        assert!(scalar_at(&array, 1).unwrap().is_null());
+        assert!(!scalar_at(&array, 2).unwrap().is_null());
+    }
+
+    #[test]
+    fn month_day_nano_round_trip() {
+        round_trip(
+            Arc::new(IntervalMonthDayNanoArray::from(vec![
+                Some(IntervalMonthDayNano::new(1, 2, 3_000_000_000)),
+                Some(IntervalMonthDayNano::new(0, -1, 0)),
+                None,
+            ])),
+            IntervalUnit::MonthDayNano,
+        );
+    }
+}
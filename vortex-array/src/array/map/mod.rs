@@ -0,0 +1,191 @@
+use std::sync::Arc;
+
+use vortex_dtype::{ExtDType, MapDType, MAP_ID};
+use vortex_error::{VortexError, VortexResult};
+
+use crate::array::ExtensionArray;
+use crate::variants::ExtensionArrayTrait;
+use crate::{ArrayDType, ArrayData, IntoArrayData};
+
+/// An array wrapper for maps, stored as lists of key-value entries.
+///
+/// This is a wrapper around ExtensionArrays of the well-known `vortex.map` type, whose storage is
+/// described by [`MapDType`].
+///
+/// ## Arrow compatibility
+///
+/// MapArray can be created from Arrow `Map` arrays, and is converted back into them. Arrow's
+/// `keys_sorted` flag is not preserved.
+#[derive(Clone, Debug)]
+pub struct MapArray {
+    /// The underlying Vortex extension array holding the entries.
+    ext: ExtensionArray,
+
+    /// In-memory representation of the key and value types of the underlying extension array.
+    map: MapDType,
+}
+
+impl MapArray {
+    /// Create a new `MapArray` from a list of `Struct<key, value>` entries.
+    pub fn try_new(entries: ArrayData) -> VortexResult<Self> {
+        let ext_dtype = ExtDType::new(MAP_ID.clone(), Arc::new(entries.dtype().clone()), None);
+        let map = MapDType::try_from(&ext_dtype)?;
+        Ok(Self {
+            ext: ExtensionArray::new(Arc::new(ext_dtype), entries),
+            map,
+        })
+    }
+
+    /// Access the list of entries in the underlying ExtensionArray storage.
+    pub fn entries(&self) -> ArrayData {
+        self.ext.storage()
+    }
+
+    /// Retrieve the key and value types of the maps.
+    pub fn map_dtype(&self) -> &MapDType {
+        &self.map
+    }
+
+    /// Retrieve the extension DType associated with the underlying array.
+    pub fn ext_dtype(&self) -> Arc<ExtDType> {
+        self.ext.ext_dtype().clone()
+    }
+}
+
+impl From<MapArray> for ArrayData {
+    fn from(value: MapArray) -> Self {
+        value.ext.into_array()
+    }
+}
+
+impl TryFrom<ArrayData> for MapArray {
+    type Error = VortexError;
+
+    /// Try to specialize a generic Vortex array as a MapArray.
+    ///
+    /// # Errors
+    ///
+    /// If the provided Array does not have `vortex.ext` encoding, or isn't of the map extension
+    /// type, an error will be returned.
+    fn try_from(value: ArrayData) -> Result<Self, Self::Error> {
+        Self::try_from(ExtensionArray::try_from(value)?)
+    }
+}
+
+// Conversions to/from ExtensionArray
+impl From<MapArray> for ExtensionArray {
+    fn from(value: MapArray) -> Self {
+        value.ext
+    }
+}
+
+impl TryFrom<ExtensionArray> for MapArray {
+    type Error = VortexError;
+
+    fn try_from(ext: ExtensionArray) -> Result<Self, Self::Error> {
+        let map = MapDType::try_from(ext.ext_dtype().as_ref())?;
+        Ok(Self { ext, map })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Array, ArrayRef};
+    use vortex_dtype::{DType, MapDType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ConstantArray, MapArray};
+    use crate::arrow::{infer_data_type, FromArrowArray};
+    use crate::builders::{builder_with_capacity, ArrayBuilderExt};
+    use crate::compute::scalar_at;
+    use crate::{ArrayDType, ArrayData, IntoArrayData, IntoCanonical};
+
+    fn map_dtype() -> MapDType {
+        MapDType::try_new(
+            DType::Utf8(Nullability::NonNullable),
+            DType::Primitive(PType::I32, Nullability::Nullable),
+        )
+        .unwrap()
+    }
+
+    fn scalars() -> Vec<Scalar> {
+        let dtype = DType::Extension(Arc::new(map_dtype().ext_dtype(Nullability::Nullable)));
+        vec![
+            Scalar::map(
+                map_dtype(),
+                vec![
+                    ("a".into(), Scalar::from(Some(1i32))),
+                    ("b".into(), Scalar::null(map_dtype().value_dtype().clone())),
+                ],
+                Nullability::Nullable,
+            )
+            .unwrap(),
+            Scalar::null(dtype),
+            Scalar::map(map_dtype(), vec![], Nullability::Nullable).unwrap(),
+        ]
+    }
+
+    fn assert_scalars(array: &ArrayData) {
+        assert_eq!(array.len(), 3);
+        for (i, expected) in scalars().iter().enumerate() {
+            assert_eq!(&scalar_at(array, i).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn arrow_round_trip() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.append(true).unwrap();
+        let arrow = builder.finish();
+
+        let array = ArrayData::from_arrow(Arc::new(arrow) as ArrayRef, true);
+        assert_eq!(
+            MapArray::try_from(array.clone()).unwrap().map_dtype(),
+            &map_dtype()
+        );
+        assert_scalars(&array);
+
+        let exported = array
+            .clone()
+            .into_canonical()
+            .unwrap()
+            .into_arrow()
+            .unwrap();
+        assert_eq!(
+            exported.data_type(),
+            &infer_data_type(array.dtype()).unwrap()
+        );
+        assert_eq!(exported.as_map().value_offsets(), &[0, 2, 2, 2]);
+        assert!(exported.is_null(1));
+        assert_scalars(&ArrayData::from_arrow(exported, true));
+    }
+
+    #[test]
+    fn build_and_canonicalize() {
+        let dtype = scalars()[0].dtype().clone();
+        let mut builder = builder_with_capacity(&dtype, 3);
+        for scalar in scalars() {
+            builder.append_scalar(&scalar).unwrap();
+        }
+        let array = builder.finish().unwrap();
+        assert_eq!(array.dtype(), &dtype);
+        assert_scalars(&array);
+
+        let constant = ConstantArray::new(scalars()[0].clone(), 2)
+            .into_array()
+            .into_canonical()
+            .unwrap()
+            .into_array();
+        assert_eq!(scalar_at(&constant, 1).unwrap(), scalars()[0]);
+    }
+}
@@ -7,14 +7,20 @@ mod bool;
 mod chunked;
 mod constant;
 mod datetime;
+mod decimal;
 mod extension;
+mod fixed_size_list;
+mod interval;
 mod list;
+mod map;
 mod null;
 mod primitive;
 mod sparse;
 mod struct_;
+mod union;
 mod varbin;
 mod varbinview;
+mod variant;
 
 pub mod from;
 
@@ -25,11 +31,17 @@ pub use self::bool::*;
 pub use self::chunked::*;
 pub use self::constant::*;
 pub use self::datetime::*;
+pub use self::decimal::*;
 pub use self::extension::*;
+pub use self::fixed_size_list::*;
+pub use self::interval::*;
 pub use self::list::*;
+pub use self::map::*;
 pub use self::null::*;
 pub use self::primitive::*;
 pub use self::sparse::*;
 pub use self::struct_::*;
+pub use self::union::*;
 pub use self::varbin::*;
 pub use self::varbinview::*;
+pub use self::variant::*;
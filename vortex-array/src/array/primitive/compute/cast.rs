@@ -1,8 +1,8 @@
-use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability};
+use vortex_dtype::{is_decimal_ext_type, match_each_native_ptype, DType, NativePType, Nullability};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
 use crate::array::primitive::PrimitiveArray;
-use crate::array::PrimitiveEncoding;
+use crate::array::{cast_decimal, PrimitiveEncoding};
 use crate::compute::CastFn;
 use crate::validity::Validity;
 use crate::variants::PrimitiveArrayTrait;
@@ -10,6 +10,12 @@ use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData};
 
 impl CastFn<PrimitiveArray> for PrimitiveEncoding {
     fn cast(&self, array: &PrimitiveArray, dtype: &DType) -> VortexResult<ArrayData> {
+        if let DType::Extension(ext_dtype) = dtype {
+            if is_decimal_ext_type(ext_dtype.id()) {
+                return cast_decimal(array.as_ref(), dtype);
+            }
+        }
+
         let DType::Primitive(new_ptype, new_nullability) = dtype else {
             vortex_bail!(MismatchedTypes: "primitive type", dtype);
         };
@@ -1,7 +1,7 @@
 use crate::array::PrimitiveEncoding;
 use crate::compute::{
     CastFn, ComputeVTable, FillForwardFn, FilterFn, ScalarAtFn, SearchSortedFn,
-    SearchSortedUsizeFn, SliceFn, SubtractScalarFn, TakeFn,
+    SearchSortedUsizeFn, SliceFn, SubtractScalarFn, SumFn, TakeFn,
 };
 use crate::ArrayData;
 
@@ -12,6 +12,7 @@ mod scalar_at;
 mod search_sorted;
 mod slice;
 mod subtract_scalar;
+mod sum;
 mod take;
 
 impl ComputeVTable for PrimitiveEncoding {
@@ -47,6 +48,10 @@ impl ComputeVTable for PrimitiveEncoding {
         Some(self)
     }
 
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        Some(self)
+    }
+
     fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
         Some(self)
     }
@@ -0,0 +1,61 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::array::PrimitiveEncoding;
+use crate::compute::{SumAccumulator, SumFn};
+use crate::variants::PrimitiveArrayTrait;
+
+impl SumFn<PrimitiveArray> for PrimitiveEncoding {
+    fn sum(&self, array: &PrimitiveArray) -> VortexResult<Scalar> {
+        let mut sum = SumAccumulator::new(array.ptype());
+        sum.add_primitive(array)?;
+        sum.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::{count, mean, min_max, sum};
+    use crate::IntoArrayData;
+
+    #[test]
+    fn test_sum_nullable() {
+        let values = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(-5), Some(10)])
+            .into_array();
+        assert_eq!(sum(&values).unwrap(), Scalar::from(Some(6i64)));
+        assert_eq!(count(&values).unwrap(), 3);
+        assert_eq!(mean(&values).unwrap(), Scalar::from(Some(2.0f64)));
+
+        let min_max = min_max(&values).unwrap().unwrap();
+        assert_eq!(min_max.min, Scalar::from(Some(-5i32)));
+        assert_eq!(min_max.max, Scalar::from(Some(10i32)));
+    }
+
+    #[test]
+    fn test_sum_all_null() {
+        let values = PrimitiveArray::from_nullable_vec::<u8>(vec![None, None]).into_array();
+        assert_eq!(
+            sum(&values).unwrap(),
+            Scalar::null(DType::Primitive(PType::U64, Nullability::Nullable))
+        );
+        assert!(mean(&values).unwrap().is_null());
+        assert_eq!(min_max(&values).unwrap(), None);
+    }
+
+    #[test]
+    fn test_sum_floats() {
+        let values = PrimitiveArray::from(vec![0.5f32, 1.25, 2.0]).into_array();
+        assert_eq!(sum(&values).unwrap(), Scalar::from(Some(3.75f64)));
+    }
+
+    #[test]
+    fn test_sum_overflow() {
+        let values = PrimitiveArray::from(vec![u64::MAX, 1]).into_array();
+        assert!(sum(&values).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use vortex_dtype::PType;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::sparse::SparseArray;
+use crate::array::SparseEncoding;
+use crate::compute::{min_max, sum, MinMaxFn, MinMaxResult, SumAccumulator, SumFn};
+use crate::{ArrayDType, ArrayLen};
+
+impl SumFn<SparseArray> for SparseEncoding {
+    fn sum(&self, array: &SparseArray) -> VortexResult<Scalar> {
+        let mut result = SumAccumulator::new(PType::try_from(array.dtype())?);
+        result.add(
+            &array.fill_scalar(),
+            (array.len() - array.values().len()) as u64,
+        )?;
+        result.add(&sum(&array.values())?, 1)?;
+        result.finish()
+    }
+}
+
+impl MinMaxFn<SparseArray> for SparseEncoding {
+    fn min_max(&self, array: &SparseArray) -> VortexResult<Option<MinMaxResult>> {
+        let fill = array.fill_scalar();
+        let fill = (fill.is_valid() && array.len() > array.values().len()).then(|| MinMaxResult {
+            min: fill.clone(),
+            max: fill,
+        });
+        Ok(MinMaxResult::merge_all([fill, min_max(&array.values())?]))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{PrimitiveArray, SparseArray};
+    use crate::compute::{min_max, slice, sum};
+    use crate::IntoArrayData;
+
+    #[test]
+    fn test_sum_sparse() {
+        let array = SparseArray::try_new(
+            PrimitiveArray::from(vec![2u64, 5, 8]).into_array(),
+            PrimitiveArray::from(vec![100u32, 200, 300]).into_array(),
+            10,
+            Scalar::from(1u32),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(sum(&array).unwrap(), Scalar::from(Some(607u64)));
+        assert_eq!(
+            sum(&slice(&array, 3, 10).unwrap()).unwrap(),
+            Scalar::from(Some(505u64))
+        );
+
+        let min_max = min_max(&array).unwrap().unwrap();
+        assert_eq!(min_max.min, Scalar::from(1u32));
+        assert_eq!(min_max.max, Scalar::from(300u32));
+    }
+
+    #[test]
+    fn test_sum_sparse_null_fill() {
+        let array = SparseArray::try_new(
+            PrimitiveArray::from(vec![1u64]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(-7i8)]).into_array(),
+            4,
+            Scalar::null(DType::Primitive(PType::I8, Nullability::Nullable)),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(sum(&array).unwrap(), Scalar::from(Some(-7i64)));
+        assert_eq!(
+            min_max(&array).unwrap().unwrap().min,
+            Scalar::from(Some(-7i8))
+        );
+    }
+}
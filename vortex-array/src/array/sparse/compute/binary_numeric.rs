@@ -0,0 +1,99 @@
+use vortex_error::VortexResult;
+
+use crate::array::sparse::SparseArray;
+use crate::array::{ConstantArray, SparseEncoding};
+use crate::compute::{
+    binary_numeric, scalar_binary_numeric, BinaryNumericFn, BinaryNumericOperator,
+};
+use crate::{ArrayData, ArrayLen, IntoArrayData};
+
+impl BinaryNumericFn<SparseArray> for SparseEncoding {
+    fn binary_numeric(
+        &self,
+        array: &SparseArray,
+        other: &ArrayData,
+        op: BinaryNumericOperator,
+    ) -> VortexResult<Option<ArrayData>> {
+        // Only a constant operand can be applied to the patches and the fill value separately.
+        let Some(scalar) = other.as_constant() else {
+            return Ok(None);
+        };
+        // If every position is patched, the fill value is never observed and must not fail the op.
+        if array.indices().len() == array.len() {
+            return Ok(None);
+        }
+
+        let values = array.values();
+        let values = binary_numeric(
+            &values,
+            &ConstantArray::new(scalar.clone(), values.len()).into_array(),
+            op,
+        )?;
+        Ok(Some(
+            SparseArray::try_new_with_offset(
+                array.indices(),
+                values,
+                array.len(),
+                array.indices_offset(),
+                scalar_binary_numeric(&array.fill_scalar(), &scalar, op)?,
+            )?
+            .into_array(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ConstantArray, PrimitiveArray, SparseArray};
+    use crate::compute::{add, scalar_at};
+    use crate::IntoArrayData;
+
+    #[test]
+    fn test_add_sparse() {
+        let array = SparseArray::try_new(
+            PrimitiveArray::from(vec![1u64, 3]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(10i32), Some(20)]).into_array(),
+            4,
+            Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable)),
+        )
+        .unwrap()
+        .into_array();
+
+        let result = add(&array, ConstantArray::new(5i32, 4).into_array()).unwrap();
+        assert!(SparseArray::try_from(result.clone()).is_ok());
+        assert_eq!(
+            (0..4)
+                .map(|i| scalar_at(&result, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable)),
+                Scalar::from(Some(15i32)),
+                Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable)),
+                Scalar::from(Some(25i32)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_add_fully_patched_sparse() {
+        let array = SparseArray::try_new(
+            PrimitiveArray::from(vec![0u64, 1]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2]).into_array(),
+            2,
+            Scalar::from(i32::MAX),
+        )
+        .unwrap()
+        .into_array();
+
+        let result = add(&array, ConstantArray::new(1i32, 2).into_array()).unwrap();
+        assert_eq!(
+            (0..2)
+                .map(|i| scalar_at(&result, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![Scalar::from(2i32), Scalar::from(3i32)]
+        );
+    }
+}
@@ -5,18 +5,24 @@ use vortex_scalar::Scalar;
 use crate::array::sparse::SparseArray;
 use crate::array::{PrimitiveArray, SparseEncoding};
 use crate::compute::{
-    scalar_at, search_sorted, take, ComputeVTable, FilterFn, FilterMask, InvertFn, ScalarAtFn,
-    SearchResult, SearchSortedFn, SearchSortedSide, SearchSortedUsizeFn, SliceFn, TakeFn,
-    TakeOptions,
+    scalar_at, search_sorted, take, BinaryNumericFn, ComputeVTable, FilterFn, FilterMask, InvertFn,
+    MinMaxFn, ScalarAtFn, SearchResult, SearchSortedFn, SearchSortedSide, SearchSortedUsizeFn,
+    SliceFn, SumFn, TakeFn, TakeOptions,
 };
 use crate::variants::PrimitiveArrayTrait;
 use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
 
+mod aggregate;
+mod binary_numeric;
 mod invert;
 mod slice;
 mod take;
 
 impl ComputeVTable for SparseEncoding {
+    fn binary_numeric_fn(&self) -> Option<&dyn BinaryNumericFn<ArrayData>> {
+        Some(self)
+    }
+
     fn filter_fn(&self) -> Option<&dyn FilterFn<ArrayData>> {
         Some(self)
     }
@@ -25,6 +31,10 @@ impl ComputeVTable for SparseEncoding {
         Some(self)
     }
 
+    fn min_max_fn(&self) -> Option<&dyn MinMaxFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -41,6 +51,10 @@ impl ComputeVTable for SparseEncoding {
         Some(self)
     }
 
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        Some(self)
+    }
+
     fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
         Some(self)
     }
@@ -2,7 +2,7 @@ use std::fmt::{Debug, Display};
 
 use serde::{Deserialize, Serialize};
 use vortex_dtype::field::Field;
-use vortex_dtype::{DType, FieldName, FieldNames, StructDType};
+use vortex_dtype::{DType, FieldId, FieldName, FieldNames, StructDType};
 use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexExpect as _, VortexResult};
 
 use crate::encoding::ids;
@@ -103,6 +103,33 @@ impl StructArray {
         Self::try_new(FieldNames::from(names), fields, len, Validity::NonNullable)
     }
 
+    /// Assign stable IDs to the fields of the array, see [`StructDType::with_field_ids`].
+    ///
+    /// Field IDs are kept when the array is written to a file, but not by most compute functions.
+    pub fn with_field_ids(&self, field_ids: Vec<FieldId>) -> VortexResult<Self> {
+        if field_ids.len() != self.nfields() {
+            vortex_bail!(
+                "Got {} field ids for {} fields",
+                field_ids.len(),
+                self.nfields()
+            );
+        }
+
+        let mut children = self.children().collect::<Vec<_>>();
+        children.extend(self.validity().into_array());
+        Self::try_from_parts(
+            DType::Struct(
+                StructDType::new(self.names().clone(), self.dtypes().to_vec())
+                    .with_field_ids(field_ids),
+                self.dtype().nullability(),
+            ),
+            self.len(),
+            self.metadata().clone(),
+            children.into(),
+            StatsSet::default(),
+        )
+    }
+
     // TODO(aduffy): Add equivalent function to support field masks for nested column access.
     /// Return a new StructArray with the given projection applied.
     ///
@@ -123,6 +150,11 @@ impl StructArray {
                     .position(|name| name.as_ref() == n)
                     .ok_or_else(|| vortex_err!("Unknown field {n}"))?,
                 Field::Index(i) => *i,
+                Field::Id(id) => self
+                    .dtype()
+                    .as_struct()
+                    .and_then(|st| st.find_field_id(*id))
+                    .ok_or_else(|| vortex_err!("Unknown field id {id}"))?,
             };
 
             names.push(self.names()[idx].clone());
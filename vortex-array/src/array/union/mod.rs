@@ -0,0 +1,313 @@
+use std::sync::Arc;
+
+use vortex_dtype::{
+    DType, ExtDType, FieldNames, Nullability, PType, UnionDType, UNION_TYPE_IDS_FIELD,
+    UNION_VARIANTS_FIELD,
+};
+use vortex_error::{vortex_bail, VortexError, VortexExpect, VortexResult};
+
+use crate::array::{ExtensionArray, StructArray};
+use crate::validity::Validity;
+use crate::variants::{ExtensionArrayTrait, StructArrayTrait};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
+
+/// An array wrapper for tagged unions, stored as the type id of each value and one array per
+/// variant.
+///
+/// This is a wrapper around ExtensionArrays of the well-known `vortex.union` type, whose storage is
+/// described by [`UnionDType`]. Every variant has the same length as the union, and only holds
+/// meaningful values where its type id is selected.
+///
+/// ## Arrow compatibility
+///
+/// UnionArray can be created from sparse and dense Arrow `Union` arrays, and is converted back into
+/// the [mode][vortex_dtype::UnionMode] it was created with. Since Arrow unions can't be null,
+/// null unions are exported as nulls of their selected variant.
+#[derive(Clone, Debug)]
+pub struct UnionArray {
+    /// The underlying Vortex extension array holding the type ids and variants.
+    ext: ExtensionArray,
+
+    /// In-memory representation of the ExtMetadata of the underlying extension array.
+    union: UnionDType,
+}
+
+impl UnionArray {
+    /// Create a new `UnionArray` from the non-nullable `i8` type id of each value and the variants
+    /// of the union, which must all have the same length.
+    pub fn try_new(
+        type_ids: ArrayData,
+        variants: Vec<ArrayData>,
+        union: UnionDType,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        if type_ids.dtype() != &DType::Primitive(PType::I8, Nullability::NonNullable) {
+            vortex_bail!(
+                "Expected non-nullable i8 type ids, found {}",
+                type_ids.dtype()
+            );
+        }
+        if variants.len() != union.variants().names().len() {
+            vortex_bail!(
+                "Expected {} variants for {union}, found {}",
+                union.variants().names().len(),
+                variants.len()
+            );
+        }
+        for (variant, dtype) in variants.iter().zip(union.variants().dtypes().iter()) {
+            if variant.dtype() != dtype {
+                vortex_bail!(
+                    "Expected variant of type {dtype}, found {}",
+                    variant.dtype()
+                );
+            }
+        }
+
+        let len = type_ids.len();
+        let variants = StructArray::try_new(
+            union.variants().names().clone(),
+            variants,
+            len,
+            Validity::NonNullable,
+        )?;
+        let storage = StructArray::try_new(
+            FieldNames::from([UNION_TYPE_IDS_FIELD.into(), UNION_VARIANTS_FIELD.into()]),
+            vec![type_ids, variants.into_array()],
+            len,
+            validity,
+        )?;
+
+        Ok(Self {
+            ext: ExtensionArray::new(
+                Arc::new(union.ext_dtype(storage.dtype().nullability())),
+                storage.into_array(),
+            ),
+            union,
+        })
+    }
+
+    /// The `i8` type id of each value.
+    pub fn type_ids(&self) -> VortexResult<ArrayData> {
+        Ok(self
+            .storage()?
+            .field(0)
+            .vortex_expect("Union storage must have type ids"))
+    }
+
+    /// The values of the variant at `idx`, in the order of [`UnionDType::variants`].
+    pub fn variant(&self, idx: usize) -> VortexResult<ArrayData> {
+        let variants = self
+            .storage()?
+            .field(1)
+            .vortex_expect("Union storage must have variants")
+            .into_struct()?;
+        match variants.field(idx) {
+            Some(variant) => Ok(variant),
+            None => vortex_bail!("Variant index {idx} out of bounds for {}", self.union),
+        }
+    }
+
+    /// The validity of the unions themselves, independent of the validity of their variants.
+    pub fn validity(&self) -> VortexResult<Validity> {
+        Ok(self.storage()?.validity())
+    }
+
+    /// Retrieve the variants and type ids of the union.
+    pub fn union_dtype(&self) -> &UnionDType {
+        &self.union
+    }
+
+    /// Retrieve the extension DType associated with the underlying array.
+    pub fn ext_dtype(&self) -> Arc<ExtDType> {
+        self.ext.ext_dtype().clone()
+    }
+
+    fn storage(&self) -> VortexResult<StructArray> {
+        self.ext.storage().into_struct()
+    }
+}
+
+impl From<UnionArray> for ArrayData {
+    fn from(value: UnionArray) -> Self {
+        value.ext.into_array()
+    }
+}
+
+impl TryFrom<ArrayData> for UnionArray {
+    type Error = VortexError;
+
+    /// Try to specialize a generic Vortex array as a UnionArray.
+    ///
+    /// # Errors
+    ///
+    /// If the provided Array does not have `vortex.ext` encoding, or isn't of the union extension
+    /// type, an error will be returned.
+    fn try_from(value: ArrayData) -> Result<Self, Self::Error> {
+        Self::try_from(ExtensionArray::try_from(value)?)
+    }
+}
+
+// Conversions to/from ExtensionArray
+impl From<UnionArray> for ExtensionArray {
+    fn from(value: UnionArray) -> Self {
+        value.ext
+    }
+}
+
+impl TryFrom<ExtensionArray> for UnionArray {
+    type Error = VortexError;
+
+    fn try_from(ext: ExtensionArray) -> Result<Self, Self::Error> {
+        let union = UnionDType::try_from(ext.ext_dtype().as_ref())?;
+        Ok(Self { ext, union })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Array, ArrayRef, Float64Array, Int32Array};
+    use arrow_buffer::ScalarBuffer;
+    use arrow_schema::{DataType, Field, UnionFields};
+    use vortex_dtype::{DType, FieldNames, Nullability, PType, StructDType, UnionDType, UnionMode};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{PrimitiveArray, UnionArray};
+    use crate::arrow::FromArrowArray;
+    use crate::builders::{builder_with_capacity, ArrayBuilderExt};
+    use crate::compute::scalar_at;
+    use crate::validity::Validity;
+    use crate::{ArrayData, IntoArrayData, IntoCanonical};
+
+    fn union_fields() -> UnionFields {
+        UnionFields::new(
+            [2, 5],
+            [
+                Field::new("int", DataType::Int32, true),
+                Field::new("float", DataType::Float64, true),
+            ],
+        )
+    }
+
+    fn union_dtype(mode: UnionMode) -> UnionDType {
+        UnionDType::try_new(
+            StructDType::new(
+                FieldNames::from(["int".into(), "float".into()]),
+                vec![
+                    DType::Primitive(PType::I32, Nullability::Nullable),
+                    DType::Primitive(PType::F64, Nullability::Nullable),
+                ],
+            ),
+            vec![2, 5],
+            mode,
+        )
+        .unwrap()
+    }
+
+    fn assert_values(array: &ArrayData) {
+        let expected = [
+            (2, Scalar::from(Some(1i32))),
+            (5, Scalar::from(Some(2.5f64))),
+            (2, Scalar::from(Some(3i32))),
+        ];
+        for (i, (type_id, value)) in expected.into_iter().enumerate() {
+            let scalar = scalar_at(array, i).unwrap();
+            let union = scalar.as_union();
+            assert_eq!(union.type_id(), Some(type_id));
+            assert_eq!(union.value(), Some(&value));
+        }
+    }
+
+    #[test]
+    fn sparse_arrow_round_trip() {
+        let arrow = arrow_array::UnionArray::try_new(
+            union_fields(),
+            ScalarBuffer::from(vec![2i8, 5, 2]),
+            None,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 0, 3])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![0.0, 2.5, 0.0])),
+            ],
+        )
+        .unwrap();
+
+        let array = ArrayData::from_arrow(Arc::new(arrow.clone()) as ArrayRef, false);
+        assert_eq!(
+            UnionArray::try_from(array.clone()).unwrap().union_dtype(),
+            &union_dtype(UnionMode::Sparse)
+        );
+        assert_values(&array);
+
+        let exported = array.into_canonical().unwrap().into_arrow().unwrap();
+        assert_eq!(exported.as_ref(), &arrow as &dyn Array);
+    }
+
+    #[test]
+    fn dense_arrow_round_trip() {
+        let arrow = arrow_array::UnionArray::try_new(
+            union_fields(),
+            ScalarBuffer::from(vec![2i8, 5, 2]),
+            Some(ScalarBuffer::from(vec![0, 0, 1])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 3])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![2.5])),
+            ],
+        )
+        .unwrap();
+
+        let array = ArrayData::from_arrow(Arc::new(arrow.clone()) as ArrayRef, false);
+        let union = UnionArray::try_from(array.clone()).unwrap();
+        assert_eq!(union.union_dtype(), &union_dtype(UnionMode::Dense));
+        // Variants are expanded to the length of the union.
+        assert!(scalar_at(union.variant(1).unwrap(), 0).unwrap().is_null());
+        assert_values(&array);
+
+        let exported = array.into_canonical().unwrap().into_arrow().unwrap();
+        assert_eq!(exported.as_ref(), &arrow as &dyn Array);
+    }
+
+    #[test]
+    fn null_unions_export_as_null_variants() {
+        let array = ArrayData::from(
+            UnionArray::try_new(
+                PrimitiveArray::from(vec![2i8, 5]).into_array(),
+                vec![
+                    PrimitiveArray::from_nullable_vec(vec![Some(1i32), None]).into_array(),
+                    PrimitiveArray::from_nullable_vec(vec![None, Some(2.5f64)]).into_array(),
+                ],
+                union_dtype(UnionMode::Sparse),
+                Validity::from_iter([true, false]),
+            )
+            .unwrap(),
+        );
+        assert!(scalar_at(&array, 1).unwrap().is_null());
+
+        let exported = array.into_canonical().unwrap().into_arrow().unwrap();
+        let exported = exported.as_union();
+        assert!(exported.child(2).is_valid(0));
+        assert!(exported.child(5).is_null(1));
+    }
+
+    #[test]
+    fn build_unions() {
+        let union = union_dtype(UnionMode::Sparse);
+        let dtype = DType::Extension(Arc::new(union.ext_dtype(Nullability::NonNullable)));
+        let mut builder = builder_with_capacity(&dtype, 3);
+        for (type_id, value) in [
+            (2, Scalar::from(1i32)),
+            (5, Scalar::from(2.5f64)),
+            (2, Scalar::from(3i32)),
+        ] {
+            builder
+                .append_scalar(
+                    &Scalar::union(union.clone(), type_id, value, Nullability::NonNullable)
+                        .unwrap(),
+                )
+                .unwrap();
+        }
+        let array = builder.finish().unwrap();
+        assert_values(&array);
+    }
+}
@@ -0,0 +1,568 @@
+use std::sync::Arc;
+
+use itertools::Itertools;
+use serde_json::{Map, Value};
+use vortex_dtype::{
+    DType, ExtDType, FieldName, FieldNames, Nullability, PType, VariantDType,
+    VARIANT_RESIDUAL_FIELD, VARIANT_SHREDDED_FIELD,
+};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexExpect, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::accessor::ArrayAccessor;
+use crate::aliases::hash_map::HashMap;
+use crate::array::{BoolArray, ExtensionArray, PrimitiveArray, StructArray, VarBinViewArray};
+use crate::compute::scalar_at;
+use crate::validity::{ArrayValidity, Validity};
+use crate::variants::{ExtensionArrayTrait, StructArrayTrait};
+use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
+
+/// An array wrapper for semi-structured values, e.g. JSON documents.
+///
+/// This is a wrapper around ExtensionArrays of the well-known `vortex.variant` type, whose storage
+/// is described by [`VariantDType`]. Frequently occurring paths are shredded into typed columns,
+/// everything else is kept in a residual column of JSON-encoded binary values.
+///
+/// Only booleans, strings, and integer (`i64`) and floating point (`f64`) numbers are shredded.
+/// The value at a path is never coerced: the JSON number `1` is an integer, `1.0` a float.
+#[derive(Clone, Debug)]
+pub struct VariantArray {
+    /// The underlying Vortex extension array holding the residual and shredded columns.
+    ext: ExtensionArray,
+
+    /// In-memory representation of the shredded paths of the underlying extension array.
+    variant: VariantDType,
+}
+
+impl VariantArray {
+    /// Create a new `VariantArray` from its residual and shredded columns.
+    ///
+    /// The residual must be a nullable binary array of JSON values, and the shredded columns must
+    /// all be nullable. Each shredded column must only hold values that aren't also in the residual.
+    pub fn try_new(
+        residual: ArrayData,
+        shredded: StructArray,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        if residual.dtype() != &DType::Binary(Nullability::Nullable) {
+            vortex_bail!(
+                "Expected variant residual of type binary?, found {}",
+                residual.dtype()
+            );
+        }
+        let shredded_dtype = shredded
+            .dtype()
+            .as_struct()
+            .vortex_expect("StructArray must have struct dtype");
+        if let Some(dtype) = shredded_dtype
+            .dtypes()
+            .iter()
+            .find(|dtype| !dtype.is_nullable())
+        {
+            vortex_bail!("Shredded variant columns must be nullable, found {dtype}");
+        }
+        let variant = VariantDType::new(shredded_dtype.clone());
+
+        let nullability = validity.nullability();
+        let len = shredded.len();
+        let storage = StructArray::try_new(
+            FieldNames::from([VARIANT_RESIDUAL_FIELD.into(), VARIANT_SHREDDED_FIELD.into()]),
+            vec![residual, shredded.into_array()],
+            len,
+            validity,
+        )?;
+        Ok(Self {
+            ext: ExtensionArray::new(
+                Arc::new(variant.ext_dtype(nullability)),
+                storage.into_array(),
+            ),
+            variant,
+        })
+    }
+
+    /// Create a new `VariantArray` from JSON documents, shredding paths that occur frequently.
+    ///
+    /// A path is shredded if at least `min_frequency` of all rows hold a value of the same type at
+    /// it, e.g. `0.5` shreds paths that hold the same type of value in half of the rows. Object keys
+    /// containing `.` are never shredded, as they can't be addressed by a path.
+    pub fn from_json<'a>(
+        values: impl IntoIterator<Item = Option<&'a str>>,
+        nullability: Nullability,
+        min_frequency: f64,
+    ) -> VortexResult<Self> {
+        let mut values = values
+            .into_iter()
+            .map(|value| value.map(serde_json::from_str::<Value>).transpose())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| vortex_err!("Invalid JSON value: {e}"))?;
+        if nullability == Nullability::NonNullable && values.iter().any(Option::is_none) {
+            vortex_bail!("Non-nullable variant array can't hold null values");
+        }
+
+        // Count the values of each type at each path, and shred the most common type per path.
+        let mut counts = HashMap::<(String, DType), usize>::new();
+        for value in values.iter().flatten() {
+            visit_leaves(value, &mut Vec::new(), &mut |path, dtype| {
+                *counts.entry((path.join("."), dtype)).or_default() += 1;
+            });
+        }
+        let min_count = min_frequency * values.len() as f64;
+        let shredded_paths = counts
+            .into_iter()
+            .filter(|(_, count)| *count as f64 >= min_count)
+            .into_group_map_by(|((path, _), _)| path.clone())
+            .into_iter()
+            .filter_map(|(path, candidates)| {
+                candidates
+                    .into_iter()
+                    // Break ties deterministically, as the counts are in arbitrary order.
+                    .max_by_key(|((_, dtype), count)| (*count, dtype.to_string()))
+                    .map(|((_, dtype), _)| (path, dtype))
+            })
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .collect::<Vec<_>>();
+
+        let mut shredded_columns = Vec::with_capacity(shredded_paths.len());
+        let mut shredded_any = vec![false; values.len()];
+        for (path, dtype) in shredded_paths.iter() {
+            let keys = path.split('.').collect::<Vec<_>>();
+            let column = values
+                .iter_mut()
+                .zip(shredded_any.iter_mut())
+                .map(|(value, shredded)| {
+                    let taken = value
+                        .as_mut()
+                        .and_then(|value| take_path(value, &keys, dtype));
+                    *shredded |= taken.is_some();
+                    taken
+                })
+                .collect::<Vec<_>>();
+            shredded_columns.push(json_array(column.iter().map(Option::as_ref), dtype)?);
+        }
+
+        let residual = values
+            .iter()
+            .zip(shredded_any)
+            .map(|(value, shredded)| {
+                value
+                    .as_ref()
+                    .filter(|value| !(shredded && value.as_object().is_some_and(Map::is_empty)))
+                    .map(serde_json::to_vec)
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| vortex_err!("Failed to encode JSON value: {e}"))?;
+
+        let validity = match nullability {
+            Nullability::NonNullable => Validity::NonNullable,
+            Nullability::Nullable => values.iter().map(Option::is_some).collect(),
+        };
+        let shredded = StructArray::try_new(
+            shredded_paths
+                .into_iter()
+                .map(|(path, _)| FieldName::from(path))
+                .collect(),
+            shredded_columns,
+            values.len(),
+            Validity::NonNullable,
+        )?;
+        Self::try_new(
+            VarBinViewArray::from_iter_nullable_bin(residual).into_array(),
+            shredded,
+            validity,
+        )
+    }
+
+    /// Access the residual and shredded columns in the underlying ExtensionArray storage.
+    pub fn storage(&self) -> ArrayData {
+        self.ext.storage()
+    }
+
+    /// The JSON-encoded values that weren't shredded, null where nothing remains of a value.
+    pub fn residual(&self) -> VortexResult<ArrayData> {
+        self.storage_field(VARIANT_RESIDUAL_FIELD)
+    }
+
+    /// The struct of the shredded columns, with a column per shredded path.
+    pub fn shredded(&self) -> VortexResult<StructArray> {
+        self.storage_field(VARIANT_SHREDDED_FIELD)?.into_struct()
+    }
+
+    /// Retrieve the shredded paths of the variants.
+    pub fn variant_dtype(&self) -> &VariantDType {
+        &self.variant
+    }
+
+    /// Retrieve the extension DType associated with the underlying array.
+    pub fn ext_dtype(&self) -> Arc<ExtDType> {
+        self.ext.ext_dtype().clone()
+    }
+
+    /// Extract the values of the given dtype at a path, null where the value is missing or of
+    /// another type.
+    ///
+    /// Shredded paths are returned without decoding any JSON. The dtype must be one of `bool`,
+    /// `i64`, `f64` or `utf8`, and the returned array is always nullable.
+    pub fn get_path(&self, path: &str, dtype: &DType) -> VortexResult<ArrayData> {
+        let dtype = dtype.as_nullable();
+        if !is_shreddable(&dtype) {
+            vortex_bail!("Can't extract values of type {dtype} from a variant");
+        }
+        if let Some((idx, shredded_dtype)) = self.variant.shredded_path(path) {
+            if shredded_dtype == &dtype {
+                return self
+                    .shredded()?
+                    .field(idx)
+                    .ok_or_else(|| vortex_err!("Missing shredded column {path}"));
+            }
+        }
+
+        // Every value of the requested type that isn't shredded is in the residual.
+        let keys = path.split('.').collect::<Vec<_>>();
+        let residual = self.residual_values()?;
+        json_array(
+            residual.iter().map(|value| {
+                value
+                    .as_ref()
+                    .and_then(|value| lookup_path(value, &keys))
+                    .filter(|value| json_dtype(value).as_ref() == Some(&dtype))
+            }),
+            &dtype,
+        )
+    }
+
+    /// Reassemble the JSON document of each row from its residual and shredded values.
+    pub fn to_json(&self) -> VortexResult<Vec<Option<String>>> {
+        let storage = self.storage();
+        let mut values = self
+            .residual_values()?
+            .into_iter()
+            .enumerate()
+            .map(|(idx, value)| {
+                storage
+                    .is_valid(idx)
+                    .then(|| value.unwrap_or_else(|| Value::Object(Map::new())))
+            })
+            .collect::<Vec<_>>();
+
+        let shredded = self.shredded()?;
+        for (path, column) in shredded.names().iter().zip(shredded.children()) {
+            let keys = path.split('.').collect::<Vec<_>>();
+            for (idx, value) in values.iter_mut().enumerate() {
+                let Some(value) = value.as_mut() else {
+                    continue;
+                };
+                if let Some(shredded_value) = scalar_to_json(&scalar_at(&column, idx)?) {
+                    insert_path(value, &keys, shredded_value)?;
+                }
+            }
+        }
+
+        values
+            .into_iter()
+            .map(|value| value.map(|value| value.to_string()))
+            .map(Ok)
+            .collect()
+    }
+
+    fn storage_field(&self, name: &str) -> VortexResult<ArrayData> {
+        self.storage()
+            .into_struct()?
+            .field_by_name(name)
+            .ok_or_else(|| vortex_err!("Variant storage is missing field {name}"))
+    }
+
+    fn residual_values(&self) -> VortexResult<Vec<Option<Value>>> {
+        self.residual()?
+            .into_varbinview()?
+            .with_iterator(|iter| {
+                iter.map(|bytes| bytes.map(serde_json::from_slice::<Value>).transpose())
+                    .collect::<Result<Vec<_>, _>>()
+            })?
+            .map_err(|e| vortex_err!("Invalid JSON in variant residual: {e}"))
+    }
+}
+
+/// Whether values of the dtype can be shredded and extracted.
+fn is_shreddable(dtype: &DType) -> bool {
+    matches!(
+        dtype,
+        DType::Bool(_) | DType::Primitive(PType::I64 | PType::F64, _) | DType::Utf8(_)
+    )
+}
+
+/// The nullable dtype that a JSON value is shredded into, if any.
+fn json_dtype(value: &Value) -> Option<DType> {
+    let dtype = match value {
+        Value::Bool(_) => DType::Bool(Nullability::Nullable),
+        Value::Number(n) if n.is_i64() => DType::Primitive(PType::I64, Nullability::Nullable),
+        Value::Number(_) => DType::Primitive(PType::F64, Nullability::Nullable),
+        Value::String(_) => DType::Utf8(Nullability::Nullable),
+        Value::Null | Value::Array(_) | Value::Object(_) => return None,
+    };
+    Some(dtype)
+}
+
+/// Call `f` with the path and dtype of every shreddable value nested in objects.
+fn visit_leaves<'a>(value: &'a Value, path: &mut Vec<&'a str>, f: &mut impl FnMut(&[&str], DType)) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                if key.contains('.') {
+                    continue;
+                }
+                path.push(key);
+                visit_leaves(field, path, f);
+                path.pop();
+            }
+        }
+        _ => {
+            if let Some(dtype) = json_dtype(value).filter(|_| !path.is_empty()) {
+                f(path, dtype)
+            }
+        }
+    }
+}
+
+fn lookup_path<'a>(value: &'a Value, keys: &[&str]) -> Option<&'a Value> {
+    keys.iter()
+        .try_fold(value, |value, key| value.as_object()?.get(*key))
+}
+
+/// Remove the value at the path if it has the given dtype, along with any objects left empty.
+fn take_path(value: &mut Value, keys: &[&str], dtype: &DType) -> Option<Value> {
+    let (key, rest) = keys.split_first()?;
+    let fields = value.as_object_mut()?;
+    if rest.is_empty() {
+        fields
+            .get(*key)
+            .filter(|field| json_dtype(field).as_ref() == Some(dtype))?;
+        fields.remove(*key)
+    } else {
+        let field = fields.get_mut(*key)?;
+        let taken = take_path(field, rest, dtype)?;
+        if field.as_object().is_some_and(Map::is_empty) {
+            fields.remove(*key);
+        }
+        Some(taken)
+    }
+}
+
+fn insert_path(value: &mut Value, keys: &[&str], field: Value) -> VortexResult<()> {
+    let Some((key, rest)) = keys.split_first() else {
+        vortex_bail!("Variant paths must not be empty");
+    };
+    let fields = value
+        .as_object_mut()
+        .ok_or_else(|| vortex_err!("Can't insert {key} into non-object variant value"))?;
+    if rest.is_empty() {
+        fields.insert((*key).to_string(), field);
+        Ok(())
+    } else {
+        insert_path(
+            fields
+                .entry(*key)
+                .or_insert_with(|| Value::Object(Map::new())),
+            rest,
+            field,
+        )
+    }
+}
+
+fn scalar_to_json(scalar: &Scalar) -> Option<Value> {
+    match scalar.dtype() {
+        DType::Bool(_) => scalar.as_bool().value().map(Value::from),
+        DType::Primitive(PType::I64, _) => {
+            scalar.as_primitive().typed_value::<i64>().map(Value::from)
+        }
+        DType::Primitive(PType::F64, _) => {
+            scalar.as_primitive().typed_value::<f64>().map(Value::from)
+        }
+        DType::Utf8(_) => scalar
+            .as_utf8()
+            .value()
+            .map(|value| Value::from(value.as_str())),
+        _ => None,
+    }
+}
+
+/// Build an array of the given shreddable dtype from JSON values of that type.
+fn json_array<'a>(
+    values: impl Iterator<Item = Option<&'a Value>>,
+    dtype: &DType,
+) -> VortexResult<ArrayData> {
+    Ok(match dtype {
+        DType::Bool(_) => values
+            .map(|value| value.and_then(Value::as_bool))
+            .collect::<BoolArray>()
+            .into_array(),
+        DType::Primitive(PType::I64, _) => {
+            PrimitiveArray::from_nullable_vec(values.map(|v| v.and_then(Value::as_i64)).collect())
+                .into_array()
+        }
+        DType::Primitive(PType::F64, _) => {
+            PrimitiveArray::from_nullable_vec(values.map(|v| v.and_then(Value::as_f64)).collect())
+                .into_array()
+        }
+        DType::Utf8(_) => {
+            VarBinViewArray::from_iter_nullable_str(values.map(|v| v.and_then(Value::as_str)))
+                .into_array()
+        }
+        _ => vortex_bail!("Can't shred values of type {dtype}"),
+    })
+}
+
+impl From<VariantArray> for ArrayData {
+    fn from(value: VariantArray) -> Self {
+        value.ext.into_array()
+    }
+}
+
+impl TryFrom<ArrayData> for VariantArray {
+    type Error = VortexError;
+
+    /// Try to specialize a generic Vortex array as a VariantArray.
+    ///
+    /// # Errors
+    ///
+    /// If the provided Array does not have `vortex.ext` encoding, or isn't of the variant extension
+    /// type, an error will be returned.
+    fn try_from(value: ArrayData) -> Result<Self, Self::Error> {
+        Self::try_from(ExtensionArray::try_from(value)?)
+    }
+}
+
+// Conversions to/from ExtensionArray
+impl From<VariantArray> for ExtensionArray {
+    fn from(value: VariantArray) -> Self {
+        value.ext
+    }
+}
+
+impl TryFrom<ExtensionArray> for VariantArray {
+    type Error = VortexError;
+
+    fn try_from(ext: ExtensionArray) -> Result<Self, Self::Error> {
+        let variant = VariantDType::try_from(ext.ext_dtype().as_ref())?;
+        Ok(Self { ext, variant })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::VariantArray;
+    use crate::validity::ArrayValidity;
+    use crate::variants::StructArrayTrait;
+    use crate::IntoArrayVariant;
+
+    const LOGS: [Option<&str>; 4] = [
+        Some(r#"{"level":"info","user":{"id":1,"name":"a"}}"#),
+        Some(r#"{"level":"warn","user":{"id":"x"},"tags":["t"]}"#),
+        None,
+        Some(r#"{"level":"info","user":{"id":3},"latency":1.5}"#),
+    ];
+
+    #[test]
+    fn shred_frequent_paths() {
+        let array = VariantArray::from_json(LOGS, Nullability::Nullable, 0.5).unwrap();
+        let shredded = array.shredded().unwrap();
+        assert_eq!(
+            shredded.names().as_ref(),
+            &["level".into(), "user.id".into()]
+        );
+        assert_eq!(
+            shredded.dtypes(),
+            vec![
+                DType::Utf8(Nullability::Nullable),
+                DType::Primitive(PType::I64, Nullability::Nullable)
+            ]
+        );
+        assert!(array.ext_dtype().storage_dtype().is_nullable());
+
+        let residual = array.residual().unwrap().into_varbinview().unwrap();
+        assert_eq!(
+            residual.bytes_at(0).unwrap().as_slice(),
+            br#"{"user":{"name":"a"}}"#
+        );
+        assert!(!residual.is_valid(2));
+    }
+
+    #[test]
+    fn get_paths() {
+        let array = VariantArray::from_json(LOGS, Nullability::Nullable, 0.5).unwrap();
+        let ids = array
+            .get_path(
+                "user.id",
+                &DType::Primitive(PType::I64, Nullability::NonNullable),
+            )
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(ids.maybe_null_slice::<i64>()[0], 1);
+        assert_eq!(ids.maybe_null_slice::<i64>()[3], 3);
+        assert!(!ids.is_valid(1));
+
+        let string_ids = array
+            .get_path("user.id", &DType::Utf8(Nullability::Nullable))
+            .unwrap()
+            .into_varbinview()
+            .unwrap();
+        assert_eq!(string_ids.bytes_at(1).unwrap().as_slice(), b"x");
+        assert!(!string_ids.is_valid(0));
+
+        let latency = array
+            .get_path(
+                "latency",
+                &DType::Primitive(PType::F64, Nullability::Nullable),
+            )
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(latency.maybe_null_slice::<f64>()[3], 1.5);
+        assert!(!latency.is_valid(0));
+
+        array
+            .get_path(
+                "tags",
+                &DType::List(
+                    DType::Utf8(Nullability::Nullable).into(),
+                    Nullability::Nullable,
+                ),
+            )
+            .unwrap_err();
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let array = VariantArray::from_json(LOGS, Nullability::Nullable, 0.5).unwrap();
+        let expected = LOGS
+            .iter()
+            .map(|value| {
+                value.map(|value| serde_json::from_str::<serde_json::Value>(value).unwrap())
+            })
+            .collect::<Vec<_>>();
+        let actual = array
+            .to_json()
+            .unwrap()
+            .into_iter()
+            .map(|value| {
+                value.map(|value| serde_json::from_str::<serde_json::Value>(&value).unwrap())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn non_nullable_rejects_nulls() {
+        VariantArray::from_json(LOGS, Nullability::NonNullable, 0.5).unwrap_err();
+        let array = VariantArray::from_json([Some("1"), Some("{}")], Nullability::NonNullable, 0.0)
+            .unwrap();
+        assert!(array.shredded().unwrap().names().is_empty());
+        assert_eq!(
+            array.to_json().unwrap(),
+            vec![Some("1".to_string()), Some("{}".to_string())]
+        );
+    }
+}
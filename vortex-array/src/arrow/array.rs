@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use arrow_array::array::{
     Array as ArrowArray, ArrayRef as ArrowArrayRef, ArrowPrimitiveType,
     BooleanArray as ArrowBooleanArray, GenericByteArray, NullArray as ArrowNullArray,
@@ -5,30 +7,45 @@ use arrow_array::array::{
 };
 use arrow_array::cast::{as_null_array, AsArray};
 use arrow_array::types::{
-    ByteArrayType, ByteViewType, Date32Type, Date64Type, DurationMicrosecondType,
-    DurationMillisecondType, DurationNanosecondType, DurationSecondType, Float16Type, Float32Type,
-    Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, Time32MillisecondType,
+    ByteArrayType, ByteViewType, Date32Type, Date64Type, Decimal128Type, Decimal256Type,
+    DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType, DurationSecondType,
+    Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+    IntervalDayTimeType, IntervalMonthDayNanoType, IntervalYearMonthType, Time32MillisecondType,
     Time32SecondType, Time64MicrosecondType, Time64NanosecondType, TimestampMicrosecondType,
     TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type,
     UInt64Type, UInt8Type,
 };
-use arrow_array::{BinaryViewArray, GenericByteViewArray, GenericListArray, StringViewArray};
+use arrow_array::{
+    BinaryViewArray, Decimal128Array, Decimal256Array, FixedSizeBinaryArray,
+    FixedSizeListArray as ArrowFixedSizeListArray, GenericByteViewArray, GenericListArray,
+    Int32Array, IntervalDayTimeArray, IntervalMonthDayNanoArray, MapArray as ArrowMapArray,
+    StringViewArray, UnionArray as ArrowUnionArray,
+};
 use arrow_buffer::buffer::{NullBuffer, OffsetBuffer};
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, Buffer, ScalarBuffer};
-use arrow_schema::{DataType, TimeUnit as ArrowTimeUnit};
+use arrow_cast::cast;
+use arrow_schema::{
+    DataType, IntervalUnit as ArrowIntervalUnit, TimeUnit as ArrowTimeUnit,
+    UnionMode as ArrowUnionMode,
+};
+use arrow_select::take::take;
 use itertools::Itertools;
-use vortex_datetime_dtype::TimeUnit;
-use vortex_dtype::{DType, NativePType, Nullability, PType};
-use vortex_error::{vortex_panic, VortexExpect as _};
+use vortex_datetime_dtype::{IntervalUnit, TimeUnit};
+use vortex_dtype::{
+    fixed_size_binary_ext_dtype, DType, DecimalDType, FieldNames, NativePType, Nullability, PType,
+    StructDType, UnionDType, UnionMode, MAP_KEY_FIELD, MAP_VALUE_FIELD,
+};
+use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexExpect as _, VortexResult};
 
 use crate::array::{
-    BoolArray, ListArray, NullArray, PrimitiveArray, StructArray, TemporalArray, VarBinArray,
+    BoolArray, DecimalArray, ExtensionArray, FixedSizeListArray, IntervalArray, ListArray,
+    MapArray, NullArray, PrimitiveArray, StructArray, TemporalArray, UnionArray, VarBinArray,
     VarBinViewArray,
 };
-use crate::arrow::FromArrowArray;
+use crate::arrow::{FromArrowArray, TryFromArrowArray};
 use crate::stats::{ArrayStatistics, Stat};
 use crate::validity::Validity;
-use crate::{ArrayData, IntoArrayData};
+use crate::{ArrayDType, ArrayData, IntoArrayData};
 
 impl From<Buffer> for ArrayData {
     fn from(value: Buffer) -> Self {
@@ -97,13 +114,98 @@ where
             }
             DataType::Date32 => TemporalArray::new_date(arr.into_array(), TimeUnit::D).into(),
             DataType::Date64 => TemporalArray::new_date(arr.into_array(), TimeUnit::Ms).into(),
-            DataType::Duration(_) => unimplemented!(),
-            DataType::Interval(_) => unimplemented!(),
+            DataType::Duration(time_unit) => {
+                TemporalArray::new_duration(arr.into_array(), time_unit.into()).into()
+            }
+            DataType::Interval(interval_unit) => {
+                IntervalArray::try_new(arr.into_array(), interval_unit.into())
+                    .vortex_expect("Arrow intervals must match their storage type")
+                    .into()
+            }
             _ => vortex_panic!("Invalid data type for PrimitiveArray: {}", T::DATA_TYPE),
         }
     }
 }
 
+// Arrow decimals are primitive arrays, but their native types aren't Vortex primitive types.
+//
+// Arrow doesn't check the values against the declared precision, so they may not fit into the
+// storage of the decimal type.
+fn decimal128_from_arrow(value: &Decimal128Array, nullable: bool) -> VortexResult<ArrayData> {
+    Ok(DecimalArray::from_i128(
+        value.values().to_vec(),
+        DecimalDType::try_new(value.precision(), value.scale())?,
+        nulls(value.nulls(), nullable),
+    )?
+    .into())
+}
+
+fn decimal256_from_arrow(value: &Decimal256Array, nullable: bool) -> VortexResult<ArrayData> {
+    Ok(DecimalArray::from_i256(
+        value.values().to_vec(),
+        DecimalDType::try_new(value.precision(), value.scale())?,
+        nulls(value.nulls(), nullable),
+    )?
+    .into())
+}
+
+// Day-time and month-day-nano intervals are primitive arrays in Arrow, but are stored as a struct
+// of their components in Vortex.
+fn interval_day_time_from_arrow(value: &IntervalDayTimeArray, nullable: bool) -> ArrayData {
+    let (days, milliseconds) = value
+        .values()
+        .iter()
+        .map(|v| (v.days, v.milliseconds))
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+    interval_from_components(
+        vec![
+            PrimitiveArray::from(days).into_array(),
+            PrimitiveArray::from(milliseconds).into_array(),
+        ],
+        IntervalUnit::DayTime,
+        nulls(value.nulls(), nullable),
+    )
+}
+
+fn interval_month_day_nano_from_arrow(
+    value: &IntervalMonthDayNanoArray,
+    nullable: bool,
+) -> ArrayData {
+    let mut months = Vec::with_capacity(value.len());
+    let mut days = Vec::with_capacity(value.len());
+    let mut nanoseconds = Vec::with_capacity(value.len());
+    for v in value.values().iter() {
+        months.push(v.months);
+        days.push(v.days);
+        nanoseconds.push(v.nanoseconds);
+    }
+    interval_from_components(
+        vec![
+            PrimitiveArray::from(months).into_array(),
+            PrimitiveArray::from(days).into_array(),
+            PrimitiveArray::from(nanoseconds).into_array(),
+        ],
+        IntervalUnit::MonthDayNano,
+        nulls(value.nulls(), nullable),
+    )
+}
+
+fn interval_from_components(
+    components: Vec<ArrayData>,
+    unit: IntervalUnit,
+    validity: Validity,
+) -> ArrayData {
+    let DType::Struct(st, _) = unit.storage_dtype(validity.nullability()) else {
+        vortex_panic!("Storage of {unit} intervals must be a struct");
+    };
+    let len = components[0].len();
+    let storage = StructArray::try_new(st.names().clone(), components, len, validity)
+        .vortex_expect("Interval components must have the same length");
+    IntervalArray::try_new(storage.into_array(), unit)
+        .vortex_expect("Interval components must match their storage type")
+        .into()
+}
+
 impl<T: ByteArrayType> FromArrowArray<&GenericByteArray<T>> for ArrayData
 where
     <T as ByteArrayType>::Offset: NativePType,
@@ -157,7 +259,14 @@ impl FromArrowArray<&ArrowBooleanArray> for ArrayData {
 
 impl FromArrowArray<&ArrowStructArray> for ArrayData {
     fn from_arrow(value: &ArrowStructArray, nullable: bool) -> Self {
-        StructArray::try_new(
+        Self::try_from_arrow(value, nullable)
+            .vortex_expect("Failed to convert Arrow StructArray to Vortex StructArray")
+    }
+}
+
+impl TryFromArrowArray<&ArrowStructArray> for ArrayData {
+    fn try_from_arrow(value: &ArrowStructArray, nullable: bool) -> VortexResult<Self> {
+        Ok(StructArray::try_new(
             value
                 .column_names()
                 .iter()
@@ -168,29 +277,169 @@ impl FromArrowArray<&ArrowStructArray> for ArrayData {
                 .columns()
                 .iter()
                 .zip(value.fields())
-                .map(|(c, field)| Self::from_arrow(c.clone(), field.is_nullable()))
-                .collect(),
+                .map(|(c, field)| Self::try_from_arrow(c.clone(), field.is_nullable()))
+                .collect::<VortexResult<Vec<_>>>()?,
             value.len(),
             nulls(value.nulls(), nullable),
-        )
-        .vortex_expect("Failed to convert Arrow StructArray to Vortex StructArray")
-        .into_array()
+        )?
+        .into_array())
     }
 }
 
 impl<O: OffsetSizeTrait + NativePType> FromArrowArray<&GenericListArray<O>> for ArrayData {
     fn from_arrow(value: &GenericListArray<O>, nullable: bool) -> Self {
-        ListArray::try_new(
-            Self::from_arrow(value.values().clone(), value.values().is_nullable()),
+        Self::try_from_arrow(value, nullable)
+            .vortex_expect("Failed to convert Arrow ListArray to Vortex ListArray")
+    }
+}
+
+impl<O: OffsetSizeTrait + NativePType> TryFromArrowArray<&GenericListArray<O>> for ArrayData {
+    fn try_from_arrow(value: &GenericListArray<O>, nullable: bool) -> VortexResult<Self> {
+        Ok(ListArray::try_new(
+            Self::try_from_arrow(value.values().clone(), value.values().is_nullable())?,
             // offsets are always non-nullable
             ArrayData::from(value.offsets().clone()),
             nulls(value.nulls(), nullable),
+        )?
+        .into_array())
+    }
+}
+
+impl FromArrowArray<&ArrowFixedSizeListArray> for ArrayData {
+    fn from_arrow(value: &ArrowFixedSizeListArray, nullable: bool) -> Self {
+        Self::try_from_arrow(value, nullable).vortex_expect(
+            "Failed to convert Arrow FixedSizeListArray to Vortex FixedSizeListArray",
+        )
+    }
+}
+
+impl TryFromArrowArray<&ArrowFixedSizeListArray> for ArrayData {
+    fn try_from_arrow(value: &ArrowFixedSizeListArray, nullable: bool) -> VortexResult<Self> {
+        Ok(FixedSizeListArray::try_new(
+            Self::try_from_arrow(value.values().clone(), value.values().is_nullable())?,
+            value
+                .value_length()
+                .try_into()
+                .map_err(|_| vortex_err!("Arrow fixed-size list size must not be negative"))?,
+            value.len(),
+            nulls(value.nulls(), nullable),
+        )?
+        .into_array())
+    }
+}
+
+impl FromArrowArray<&FixedSizeBinaryArray> for ArrayData {
+    fn from_arrow(value: &FixedSizeBinaryArray, nullable: bool) -> Self {
+        let validity = nulls(value.nulls(), nullable);
+        let size = value
+            .value_length()
+            .try_into()
+            .vortex_expect("Arrow fixed-size binary size must not be negative");
+        let storage = FixedSizeListArray::try_new(
+            Self::from(value.values().clone()),
+            size,
+            value.len(),
+            validity,
+        )
+        .vortex_expect("Failed to convert Arrow FixedSizeBinaryArray to Vortex FixedSizeListArray")
+        .into_array();
+        ExtensionArray::new(
+            Arc::new(fixed_size_binary_ext_dtype(size, nullable.into())),
+            storage,
         )
-        .vortex_expect("Failed to convert Arrow StructArray to Vortex StructArray")
         .into_array()
     }
 }
 
+impl FromArrowArray<&ArrowMapArray> for ArrayData {
+    fn from_arrow(value: &ArrowMapArray, nullable: bool) -> Self {
+        Self::try_from_arrow(value, nullable)
+            .vortex_expect("Failed to convert Arrow MapArray to Vortex MapArray")
+    }
+}
+
+impl TryFromArrowArray<&ArrowMapArray> for ArrayData {
+    fn try_from_arrow(value: &ArrowMapArray, nullable: bool) -> VortexResult<Self> {
+        let entries = value.entries();
+        // Keys are never null, and the entries are renamed to the canonical key and value fields.
+        let entries = StructArray::try_new(
+            FieldNames::from([MAP_KEY_FIELD.into(), MAP_VALUE_FIELD.into()]),
+            vec![
+                Self::try_from_arrow(value.keys().clone(), false)?,
+                Self::try_from_arrow(value.values().clone(), entries.fields()[1].is_nullable())?,
+            ],
+            entries.len(),
+            Validity::NonNullable,
+        )?;
+        let list = ListArray::try_new(
+            entries.into_array(),
+            ArrayData::from(value.offsets().clone()),
+            nulls(value.nulls(), nullable),
+        )?;
+        Ok(MapArray::try_new(list.into_array())?.into())
+    }
+}
+
+impl FromArrowArray<&ArrowUnionArray> for ArrayData {
+    fn from_arrow(value: &ArrowUnionArray, nullable: bool) -> Self {
+        Self::try_from_arrow(value, nullable)
+            .vortex_expect("Failed to convert Arrow UnionArray to Vortex UnionArray")
+    }
+}
+
+impl TryFromArrowArray<&ArrowUnionArray> for ArrayData {
+    fn try_from_arrow(value: &ArrowUnionArray, nullable: bool) -> VortexResult<Self> {
+        let DataType::Union(fields, mode) = value.data_type() else {
+            vortex_bail!("Expected Arrow union type, found {}", value.data_type());
+        };
+        let variants = fields
+            .iter()
+            .map(|(type_id, _)| {
+                let child = match value.offsets() {
+                    None => value.child(type_id).clone(),
+                    // Expand dense variants to the length of the union, with nulls wherever
+                    // another variant is selected.
+                    Some(offsets) => take(
+                        value.child(type_id).as_ref(),
+                        &Int32Array::from_iter(
+                            value
+                                .type_ids()
+                                .iter()
+                                .zip(offsets.iter())
+                                .map(|(t, offset)| (*t == type_id).then_some(*offset)),
+                        ),
+                        None,
+                    )?,
+                };
+                Self::try_from_arrow(child, true)
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        let union = UnionDType::try_new(
+            StructDType::new(
+                fields
+                    .iter()
+                    .map(|(_, f)| f.name().as_str().into())
+                    .collect_vec()
+                    .into(),
+                variants.iter().map(|v| v.dtype().clone()).collect(),
+            ),
+            fields.iter().map(|(type_id, _)| type_id).collect(),
+            match mode {
+                ArrowUnionMode::Sparse => UnionMode::Sparse,
+                ArrowUnionMode::Dense => UnionMode::Dense,
+            },
+        )?;
+        Ok(UnionArray::try_new(
+            ArrayData::from(value.type_ids().clone()),
+            variants,
+            union,
+            nulls(None, nullable),
+        )?
+        .into())
+    }
+}
+
 impl FromArrowArray<&ArrowNullArray> for ArrayData {
     fn from_arrow(value: &ArrowNullArray, nullable: bool) -> Self {
         assert!(nullable);
@@ -217,7 +466,13 @@ fn nulls(nulls: Option<&NullBuffer>, nullable: bool) -> Validity {
 
 impl FromArrowArray<ArrowArrayRef> for ArrayData {
     fn from_arrow(array: ArrowArrayRef, nullable: bool) -> Self {
-        match array.data_type() {
+        Self::try_from_arrow(array, nullable).vortex_expect("Failed to convert Arrow array")
+    }
+}
+
+impl TryFromArrowArray<ArrowArrayRef> for ArrayData {
+    fn try_from_arrow(array: ArrowArrayRef, nullable: bool) -> VortexResult<Self> {
+        Ok(match array.data_type() {
             DataType::Boolean => Self::from_arrow(array.as_boolean(), nullable),
             DataType::UInt8 => Self::from_arrow(array.as_primitive::<UInt8Type>(), nullable),
             DataType::UInt16 => Self::from_arrow(array.as_primitive::<UInt16Type>(), nullable),
@@ -248,9 +503,27 @@ impl FromArrowArray<ArrowArrayRef> for ArrayData {
                     .vortex_expect("Expected Arrow StringViewArray for DataType::Utf8View"),
                 nullable,
             ),
-            DataType::Struct(_) => Self::from_arrow(array.as_struct(), nullable),
-            DataType::List(_) => Self::from_arrow(array.as_list::<i32>(), nullable),
-            DataType::LargeList(_) => Self::from_arrow(array.as_list::<i64>(), nullable),
+            DataType::Decimal128(..) => {
+                decimal128_from_arrow(array.as_primitive::<Decimal128Type>(), nullable)?
+            }
+            DataType::Decimal256(..) => {
+                decimal256_from_arrow(array.as_primitive::<Decimal256Type>(), nullable)?
+            }
+            DataType::Struct(_) => Self::try_from_arrow(array.as_struct(), nullable)?,
+            DataType::List(_) => Self::try_from_arrow(array.as_list::<i32>(), nullable)?,
+            DataType::LargeList(_) => Self::try_from_arrow(array.as_list::<i64>(), nullable)?,
+            DataType::FixedSizeList(..) => {
+                Self::try_from_arrow(array.as_fixed_size_list(), nullable)?
+            }
+            DataType::FixedSizeBinary(_) => {
+                Self::from_arrow(array.as_fixed_size_binary(), nullable)
+            }
+            DataType::Map(..) => Self::try_from_arrow(array.as_map(), nullable)?,
+            DataType::Union(..) => Self::try_from_arrow(array.as_union(), nullable)?,
+            // Dictionaries are decoded into their values.
+            DataType::Dictionary(_, values) => {
+                Self::try_from_arrow(cast(&array, values)?, nullable)?
+            }
             DataType::Null => Self::from_arrow(as_null_array(&array), nullable),
             DataType::Timestamp(u, _) => match u {
                 ArrowTimeUnit::Second => {
@@ -286,6 +559,19 @@ impl FromArrowArray<ArrowArrayRef> for ArrayData {
                 }
                 _ => unreachable!(),
             },
+            DataType::Interval(u) => match u {
+                ArrowIntervalUnit::YearMonth => {
+                    Self::from_arrow(array.as_primitive::<IntervalYearMonthType>(), nullable)
+                }
+                ArrowIntervalUnit::DayTime => interval_day_time_from_arrow(
+                    array.as_primitive::<IntervalDayTimeType>(),
+                    nullable,
+                ),
+                ArrowIntervalUnit::MonthDayNano => interval_month_day_nano_from_arrow(
+                    array.as_primitive::<IntervalMonthDayNanoType>(),
+                    nullable,
+                ),
+            },
             DataType::Duration(u) => match u {
                 ArrowTimeUnit::Second => {
                     Self::from_arrow(array.as_primitive::<DurationSecondType>(), nullable)
@@ -300,10 +586,10 @@ impl FromArrowArray<ArrowArrayRef> for ArrayData {
                     Self::from_arrow(array.as_primitive::<DurationNanosecondType>(), nullable)
                 }
             },
-            _ => vortex_panic!(
+            _ => vortex_bail!(
                 "Array encoding not implemented for Arrow data type {}",
                 array.data_type().clone()
             ),
-        }
+        })
     }
 }
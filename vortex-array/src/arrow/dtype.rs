@@ -12,15 +12,32 @@
 
 use std::sync::Arc;
 
-use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaBuilder, SchemaRef};
+use arrow_schema::{
+    DataType, Field, FieldRef, Fields, Schema, SchemaBuilder, SchemaRef, UnionFields,
+    UnionMode as ArrowUnionMode,
+};
 use itertools::Itertools;
 use vortex_datetime_dtype::arrow::{make_arrow_temporal_dtype, make_temporal_ext_dtype};
-use vortex_datetime_dtype::is_temporal_ext_type;
-use vortex_dtype::{DType, Nullability, PType, StructDType};
-use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_datetime_dtype::{is_interval_ext_type, is_temporal_ext_type};
+use vortex_dtype::{
+    fixed_size_binary_ext_dtype, fixed_size_binary_size, is_decimal_ext_type,
+    is_fixed_size_binary_ext_type, is_map_ext_type, is_union_ext_type, DType, DecimalDType,
+    FieldId, MapDType, Nullability, PType, StructDType, UnionDType, UnionMode,
+    MAX_DECIMAL128_PRECISION,
+};
+use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexExpect, VortexResult};
 
 use crate::arrow::{FromArrowType, TryFromArrowType};
 
+/// The name of the entries field of Arrow maps.
+pub(crate) const MAP_ENTRIES_FIELD: &str = "entries";
+
+/// The key of the Arrow field metadata holding the [`FieldId`] of a struct field.
+///
+/// This is the same key as used by Parquet and Iceberg, so that field IDs survive conversions
+/// through their Arrow readers and writers.
+pub const FIELD_ID_METADATA_KEY: &str = "PARQUET:field_id";
+
 impl TryFromArrowType<&DataType> for PType {
     fn try_from_arrow(value: &DataType) -> VortexResult<Self> {
         match value {
@@ -46,24 +63,68 @@ impl TryFromArrowType<&DataType> for PType {
 impl FromArrowType<SchemaRef> for DType {
     fn from_arrow(value: SchemaRef) -> Self {
         Self::Struct(
-            StructDType::new(
-                value
-                    .fields()
-                    .iter()
-                    .map(|f| f.name().as_str().into())
-                    .collect_vec()
-                    .into(),
-                value
-                    .fields()
-                    .iter()
-                    .map(|f| Self::from_arrow(f.as_ref()))
-                    .collect_vec(),
-            ),
+            struct_dtype_from_arrow(value.fields()),
             Nullability::NonNullable, // Must match From<RecordBatch> for Array
         )
     }
 }
 
+/// Convert Arrow struct fields, taking field IDs from their metadata if all fields have one.
+fn struct_dtype_from_arrow(fields: &Fields) -> StructDType {
+    let st = StructDType::new(
+        fields
+            .iter()
+            .map(|f| f.name().as_str().into())
+            .collect_vec()
+            .into(),
+        fields
+            .iter()
+            .map(|f| DType::from_arrow(f.as_ref()))
+            .collect_vec(),
+    );
+
+    let field_ids = fields
+        .iter()
+        .map(|f| {
+            f.metadata()
+                .get(FIELD_ID_METADATA_KEY)
+                .and_then(|id| id.parse::<FieldId>().ok())
+        })
+        .collect::<Option<Vec<_>>>();
+    match field_ids {
+        Some(field_ids) if !field_ids.is_empty() => st.with_field_ids(field_ids),
+        _ => st,
+    }
+}
+
+/// Convert the fields of a struct to Arrow fields, recording their IDs in the field metadata.
+fn struct_fields_to_arrow(struct_dtype: &StructDType) -> VortexResult<Vec<FieldRef>> {
+    struct_dtype
+        .names()
+        .iter()
+        .zip(struct_dtype.dtypes().iter())
+        .enumerate()
+        .map(|(idx, (field_name, field_dtype))| {
+            let field = Field::new(
+                field_name.to_string(),
+                infer_data_type(field_dtype)?,
+                field_dtype.is_nullable(),
+            );
+            Ok(FieldRef::from(match struct_dtype.field_ids() {
+                Some(field_ids) => field.with_metadata(
+                    [(
+                        FIELD_ID_METADATA_KEY.to_string(),
+                        field_ids[idx].to_string(),
+                    )]
+                    .into_iter()
+                    .collect(),
+                ),
+                None => field,
+            }))
+        })
+        .collect()
+}
+
 impl FromArrowType<&Field> for DType {
     fn from_arrow(field: &Field) -> Self {
         use vortex_dtype::DType::*;
@@ -83,22 +144,79 @@ impl FromArrowType<&Field> for DType {
             | DataType::Date64
             | DataType::Time32(_)
             | DataType::Time64(_)
-            | DataType::Timestamp(..) => Extension(Arc::new(
+            | DataType::Timestamp(..)
+            | DataType::Duration(_)
+            | DataType::Interval(_) => Extension(Arc::new(
                 make_temporal_ext_dtype(field.data_type()).with_nullability(nullability),
             )),
+            DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+                Extension(Arc::new(
+                    DecimalDType::try_new(*precision, *scale)
+                        .vortex_expect("Arrow decimal type must be valid")
+                        .ext_dtype(nullability),
+                ))
+            }
             DataType::List(e) | DataType::LargeList(e) => {
                 List(Arc::new(Self::from_arrow(e.as_ref())), nullability)
             }
-            DataType::Struct(f) => Struct(
-                StructDType::new(
-                    f.iter()
-                        .map(|f| f.name().as_str().into())
-                        .collect_vec()
-                        .into(),
-                    f.iter().map(|f| Self::from_arrow(f.as_ref())).collect_vec(),
-                ),
+            DataType::FixedSizeList(e, size) => FixedSizeList(
+                Arc::new(Self::from_arrow(e.as_ref())),
+                (*size)
+                    .try_into()
+                    .vortex_expect("Arrow fixed-size list size must not be negative"),
                 nullability,
             ),
+            DataType::FixedSizeBinary(size) => Extension(Arc::new(fixed_size_binary_ext_dtype(
+                (*size)
+                    .try_into()
+                    .vortex_expect("Arrow fixed-size binary size must not be negative"),
+                nullability,
+            ))),
+            DataType::Struct(f) => Struct(struct_dtype_from_arrow(f), nullability),
+            DataType::Map(entries, _) => {
+                let DataType::Struct(kv) = entries.data_type() else {
+                    vortex_panic!("Arrow map entries must be a struct");
+                };
+                let [key, value] = kv.iter().collect_vec()[..] else {
+                    vortex_panic!("Arrow map entries must have a key and a value field");
+                };
+                Extension(Arc::new(
+                    MapDType::try_new(
+                        Self::from_arrow(key.as_ref()).as_nonnullable(),
+                        Self::from_arrow(value.as_ref()),
+                    )
+                    .vortex_expect("Arrow map type must be valid")
+                    .ext_dtype(nullability),
+                ))
+            }
+            DataType::Union(fields, mode) => Extension(Arc::new(
+                UnionDType::try_new(
+                    StructDType::new(
+                        fields
+                            .iter()
+                            .map(|(_, f)| f.name().as_str().into())
+                            .collect_vec()
+                            .into(),
+                        fields
+                            .iter()
+                            .map(|(_, f)| Self::from_arrow(f.as_ref()))
+                            .collect_vec(),
+                    ),
+                    fields.iter().map(|(type_id, _)| type_id).collect(),
+                    match mode {
+                        ArrowUnionMode::Sparse => UnionMode::Sparse,
+                        ArrowUnionMode::Dense => UnionMode::Dense,
+                    },
+                )
+                .vortex_expect("Arrow union type must be valid")
+                .ext_dtype(nullability),
+            )),
+            // Dictionaries are decoded into their values.
+            DataType::Dictionary(_, values) => Self::from_arrow(&Field::new(
+                field.name(),
+                values.as_ref().clone(),
+                field.is_nullable(),
+            )),
             _ => unimplemented!("Arrow data type not yet supported: {:?}", field.data_type()),
         }
     }
@@ -115,16 +233,8 @@ pub fn infer_schema(dtype: &DType) -> VortexResult<Schema> {
     }
 
     let mut builder = SchemaBuilder::with_capacity(struct_dtype.names().len());
-    for (field_name, field_dtype) in struct_dtype
-        .names()
-        .iter()
-        .zip(struct_dtype.dtypes().iter())
-    {
-        builder.push(FieldRef::from(Field::new(
-            field_name.to_string(),
-            infer_data_type(field_dtype)?,
-            field_dtype.is_nullable(),
-        )));
+    for field in struct_fields_to_arrow(struct_dtype)? {
+        builder.push(field);
     }
 
     Ok(builder.finish())
@@ -151,20 +261,7 @@ pub fn infer_data_type(dtype: &DType) -> VortexResult<DataType> {
         DType::Utf8(_) => DataType::Utf8View,
         DType::Binary(_) => DataType::BinaryView,
         DType::Struct(struct_dtype, _) => {
-            let mut fields = Vec::with_capacity(struct_dtype.names().len());
-            for (field_name, field_dt) in struct_dtype
-                .names()
-                .iter()
-                .zip(struct_dtype.dtypes().iter())
-            {
-                fields.push(FieldRef::from(Field::new(
-                    field_name.to_string(),
-                    infer_data_type(field_dt)?,
-                    field_dt.is_nullable(),
-                )));
-            }
-
-            DataType::Struct(Fields::from(fields))
+            DataType::Struct(Fields::from(struct_fields_to_arrow(struct_dtype)?))
         }
         // There are four kinds of lists: List (32-bit offsets), Large List (64-bit), List View
         // (32-bit), Large List View (64-bit). We cannot both guarantee zero-copy and commit to an
@@ -173,10 +270,54 @@ pub fn infer_data_type(dtype: &DType) -> VortexResult<DataType> {
             infer_data_type(l.as_ref())?,
             (*null).into(),
         ))),
+        DType::FixedSizeList(l, size, _) => DataType::FixedSizeList(
+            FieldRef::new(Field::new_list_field(
+                infer_data_type(l.as_ref())?,
+                l.is_nullable(),
+            )),
+            (*size).try_into()?,
+        ),
         DType::Extension(ext_dtype) => {
             // Try and match against the known extension DTypes.
-            if is_temporal_ext_type(ext_dtype.id()) {
+            if is_temporal_ext_type(ext_dtype.id()) || is_interval_ext_type(ext_dtype.id()) {
                 make_arrow_temporal_dtype(ext_dtype)
+            } else if is_decimal_ext_type(ext_dtype.id()) {
+                let decimal = DecimalDType::try_from(ext_dtype.as_ref())?;
+                if decimal.precision() <= MAX_DECIMAL128_PRECISION {
+                    DataType::Decimal128(decimal.precision(), decimal.scale())
+                } else {
+                    DataType::Decimal256(decimal.precision(), decimal.scale())
+                }
+            } else if is_fixed_size_binary_ext_type(ext_dtype.id()) {
+                DataType::FixedSizeBinary(fixed_size_binary_size(ext_dtype)?.try_into()?)
+            } else if is_map_ext_type(ext_dtype.id()) {
+                let map = MapDType::try_from(ext_dtype.as_ref())?;
+                DataType::Map(
+                    FieldRef::new(Field::new(
+                        MAP_ENTRIES_FIELD,
+                        infer_data_type(&map.entries_dtype())?,
+                        false,
+                    )),
+                    false,
+                )
+            } else if is_union_ext_type(ext_dtype.id()) {
+                let union = UnionDType::try_from(ext_dtype.as_ref())?;
+                let fields = union
+                    .variants()
+                    .names()
+                    .iter()
+                    .zip(union.variants().dtypes().iter())
+                    .map(|(name, dtype)| {
+                        Ok(Field::new(name.as_ref(), infer_data_type(dtype)?, true))
+                    })
+                    .collect::<VortexResult<Vec<_>>>()?;
+                DataType::Union(
+                    UnionFields::new(union.type_ids().iter().copied(), fields),
+                    match union.mode() {
+                        UnionMode::Sparse => ArrowUnionMode::Sparse,
+                        UnionMode::Dense => ArrowUnionMode::Dense,
+                    },
+                )
             } else {
                 vortex_bail!("Unsupported extension type \"{}\"", ext_dtype.id())
             }
@@ -259,6 +400,27 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_field_id_conversion() {
+        let dtype = DType::Struct(
+            the_struct().with_field_ids(vec![3, 1, 2]),
+            Nullability::NonNullable,
+        );
+
+        let schema = infer_schema(&dtype).unwrap();
+        assert_eq!(
+            schema.field(1).metadata().get(FIELD_ID_METADATA_KEY),
+            Some(&"1".to_string())
+        );
+
+        let round_tripped = DType::from_arrow(Arc::new(schema));
+        assert_eq!(round_tripped, dtype);
+        assert_eq!(
+            round_tripped.as_struct().unwrap().field_ids(),
+            Some([3, 1, 2].as_slice())
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_schema_conversion_panics() {
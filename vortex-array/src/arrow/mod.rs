@@ -2,7 +2,8 @@
 
 use vortex_error::VortexResult;
 
-pub use crate::arrow::dtype::{infer_data_type, infer_schema};
+pub(crate) use crate::arrow::dtype::MAP_ENTRIES_FIELD;
+pub use crate::arrow::dtype::{infer_data_type, infer_schema, FIELD_ID_METADATA_KEY};
 
 mod array;
 mod datum;
@@ -15,6 +16,12 @@ pub trait FromArrowArray<A> {
     fn from_arrow(array: A, nullable: bool) -> Self;
 }
 
+/// Fallible conversion of Arrow arrays, whose values Arrow doesn't validate, e.g. decimals that
+/// don't fit into their declared precision.
+pub trait TryFromArrowArray<A>: Sized {
+    fn try_from_arrow(array: A, nullable: bool) -> VortexResult<Self>;
+}
+
 pub trait FromArrowType<T>: Sized {
     fn from_arrow(value: T) -> Self;
 }
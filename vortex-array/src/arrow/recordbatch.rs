@@ -4,7 +4,7 @@ use itertools::Itertools;
 use vortex_error::{vortex_err, VortexError, VortexResult};
 
 use crate::array::StructArray;
-use crate::arrow::FromArrowArray;
+use crate::arrow::TryFromArrowArray;
 use crate::validity::Validity;
 use crate::{ArrayData, IntoArrayData, IntoArrayVariant, IntoCanonical};
 
@@ -24,8 +24,8 @@ impl TryFrom<RecordBatch> for ArrayData {
                 .columns()
                 .iter()
                 .zip(value.schema().fields())
-                .map(|(array, field)| ArrayData::from_arrow(array.clone(), field.is_nullable()))
-                .collect(),
+                .map(|(array, field)| ArrayData::try_from_arrow(array.clone(), field.is_nullable()))
+                .collect::<VortexResult<Vec<_>>>()?,
             value.num_rows(),
             Validity::NonNullable, // Must match FromArrowType<SchemaRef> for DType
         )?
@@ -0,0 +1,100 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use vortex_dtype::{DType, Nullability};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::ListScalar;
+
+use crate::array::FixedSizeListArray;
+use crate::builders::{builder_with_capacity, ArrayBuilder, ArrayBuilderExt, BoolBuilder};
+use crate::validity::Validity;
+use crate::{ArrayData, IntoArrayData};
+
+pub struct FixedSizeListBuilder {
+    elements: Box<dyn ArrayBuilder>,
+    validity: BoolBuilder,
+    list_size: u32,
+    nullability: Nullability,
+    dtype: DType,
+}
+
+impl FixedSizeListBuilder {
+    pub fn with_capacity(
+        element_dtype: Arc<DType>,
+        list_size: u32,
+        nullability: Nullability,
+        capacity: usize,
+    ) -> Self {
+        Self {
+            elements: builder_with_capacity(&element_dtype, capacity * list_size as usize),
+            validity: BoolBuilder::with_capacity(Nullability::NonNullable, capacity),
+            list_size,
+            nullability,
+            dtype: DType::FixedSizeList(element_dtype, list_size, nullability),
+        }
+    }
+
+    pub fn append_value(&mut self, list_scalar: ListScalar) -> VortexResult<()> {
+        if !list_scalar.dtype().eq_ignore_nullability(&self.dtype) {
+            vortex_bail!(
+                "Expected list scalar with dtype {}, found {}",
+                self.dtype,
+                list_scalar.dtype()
+            )
+        }
+
+        if list_scalar.is_null() {
+            self.append_null();
+        } else {
+            for element in list_scalar.elements() {
+                self.elements.append_scalar(&element)?;
+            }
+            self.validity.append_value(true);
+        }
+
+        Ok(())
+    }
+}
+
+impl ArrayBuilder for FixedSizeListBuilder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.elements.append_zeros(n * self.list_size as usize);
+        self.validity.append_values(true, n);
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        // Null lists still occupy their elements, which may themselves be non-nullable.
+        self.elements.append_zeros(n * self.list_size as usize);
+        self.validity.append_values(false, n);
+    }
+
+    fn finish(&mut self) -> VortexResult<ArrayData> {
+        let len = self.len();
+        let validity_array = self.validity.finish()?;
+        let validity = match self.nullability {
+            Nullability::NonNullable => Validity::NonNullable,
+            Nullability::Nullable => Validity::Array(validity_array),
+        };
+
+        Ok(
+            FixedSizeListArray::try_new(self.elements.finish()?, self.list_size, len, validity)?
+                .into_array(),
+        )
+    }
+}
@@ -0,0 +1,108 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use vortex_dtype::{DType, Nullability};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::ListScalar;
+
+use crate::array::{ListArray, PrimitiveArray};
+use crate::builders::{builder_with_capacity, ArrayBuilder, ArrayBuilderExt, BoolBuilder};
+use crate::validity::Validity;
+use crate::{ArrayData, IntoArrayData};
+
+pub struct ListBuilder {
+    elements: Box<dyn ArrayBuilder>,
+    offsets: Vec<u64>,
+    validity: BoolBuilder,
+    nullability: Nullability,
+    dtype: DType,
+}
+
+impl ListBuilder {
+    pub fn with_capacity(
+        element_dtype: Arc<DType>,
+        nullability: Nullability,
+        capacity: usize,
+    ) -> Self {
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+        Self {
+            elements: builder_with_capacity(&element_dtype, capacity),
+            offsets,
+            validity: BoolBuilder::with_capacity(Nullability::NonNullable, capacity),
+            nullability,
+            dtype: DType::List(element_dtype, nullability),
+        }
+    }
+
+    pub fn append_value(&mut self, list_scalar: ListScalar) -> VortexResult<()> {
+        if !list_scalar.dtype().eq_ignore_nullability(&self.dtype) {
+            vortex_bail!(
+                "Expected list scalar with dtype {}, found {}",
+                self.dtype,
+                list_scalar.dtype()
+            )
+        }
+
+        if list_scalar.is_null() {
+            self.append_null();
+        } else {
+            for element in list_scalar.elements() {
+                self.elements.append_scalar(&element)?;
+            }
+            self.offsets.push(self.elements.len() as u64);
+            self.validity.append_value(true);
+        }
+
+        Ok(())
+    }
+
+    fn append_empty(&mut self, n: usize, valid: bool) {
+        let end = self.elements.len() as u64;
+        self.offsets.extend(std::iter::repeat(end).take(n));
+        self.validity.append_values(valid, n);
+    }
+}
+
+impl ArrayBuilder for ListBuilder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    /// The "zero" value of a list is the empty list.
+    fn append_zeros(&mut self, n: usize) {
+        self.append_empty(n, true)
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.append_empty(n, false)
+    }
+
+    fn finish(&mut self) -> VortexResult<ArrayData> {
+        let validity_array = self.validity.finish()?;
+        let validity = match self.nullability {
+            Nullability::NonNullable => Validity::NonNullable,
+            Nullability::Nullable => Validity::Array(validity_array),
+        };
+        let offsets = std::mem::replace(&mut self.offsets, vec![0]);
+
+        Ok(ListArray::try_new(
+            self.elements.finish()?,
+            PrimitiveArray::from(offsets).into_array(),
+            validity,
+        )?
+        .into_array())
+    }
+}
@@ -1,6 +1,8 @@
 mod binary;
 mod bool;
 mod extension;
+mod fixed_size_list;
+mod list;
 mod null;
 mod primitive;
 mod struct_;
@@ -11,13 +13,16 @@ use std::any::Any;
 pub use binary::*;
 pub use bool::*;
 pub use extension::*;
+pub use fixed_size_list::*;
+pub use list::*;
 pub use null::*;
 pub use primitive::*;
 pub use utf8::*;
 use vortex_dtype::{match_each_native_ptype, DType};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_scalar::{
-    BinaryScalar, BoolScalar, ExtScalar, PrimitiveScalar, Scalar, StructScalar, Utf8Scalar,
+    BinaryScalar, BoolScalar, ExtScalar, ListScalar, PrimitiveScalar, Scalar, StructScalar,
+    Utf8Scalar,
 };
 
 use crate::builders::struct_::StructBuilder;
@@ -71,9 +76,14 @@ pub fn builder_with_capacity(dtype: &DType, capacity: usize) -> Box<dyn ArrayBui
             *n,
             capacity,
         )),
-        DType::List(..) => {
-            todo!()
-        }
+        DType::List(element_dtype, n) => Box::new(ListBuilder::with_capacity(
+            element_dtype.clone(),
+            *n,
+            capacity,
+        )),
+        DType::FixedSizeList(element_dtype, size, n) => Box::new(
+            FixedSizeListBuilder::with_capacity(element_dtype.clone(), *size, *n, capacity),
+        ),
         DType::Extension(ext_dtype) => {
             Box::new(ExtensionBuilder::with_capacity(ext_dtype.clone(), capacity))
         }
@@ -127,7 +137,18 @@ pub trait ArrayBuilderExt: ArrayBuilder {
                 .downcast_mut::<StructBuilder>()
                 .ok_or_else(|| vortex_err!("Cannot append struct scalar to non-struct builder"))?
                 .append_value(StructScalar::try_from(scalar)?)?,
-            DType::List(..) => {}
+            DType::List(..) => self
+                .as_any_mut()
+                .downcast_mut::<ListBuilder>()
+                .ok_or_else(|| vortex_err!("Cannot append list scalar to non-list builder"))?
+                .append_value(ListScalar::try_from(scalar)?)?,
+            DType::FixedSizeList(..) => self
+                .as_any_mut()
+                .downcast_mut::<FixedSizeListBuilder>()
+                .ok_or_else(|| {
+                    vortex_err!("Cannot append fixed-size list scalar to non-list builder")
+                })?
+                .append_value(ListScalar::try_from(scalar)?)?,
             DType::Extension(..) => self
                 .as_any_mut()
                 .downcast_mut::<ExtensionBuilder>()
@@ -40,10 +40,10 @@ impl StructBuilder {
     }
 
     pub fn append_value(&mut self, struct_scalar: StructScalar) -> VortexResult<()> {
-        if struct_scalar.dtype() != &DType::Struct(self.struct_dtype.clone(), self.nullability) {
+        if !struct_scalar.dtype().eq_ignore_nullability(&self.dtype) {
             vortex_bail!(
-                "Expected struct scalar with dtype {:?}, found {:?}",
-                self.struct_dtype,
+                "Expected struct scalar with dtype {}, found {}",
+                self.dtype,
                 struct_scalar.dtype()
             )
         }
@@ -52,6 +52,7 @@ impl StructBuilder {
             for (builder, field) in self.builders.iter_mut().zip(fields) {
                 builder.append_scalar(&field)?;
             }
+            self.validity.append_value(true);
         } else {
             self.append_null()
         }
@@ -90,7 +91,7 @@ impl ArrayBuilder for StructBuilder {
             // We push zero values into our children when appending a null in case the children are
             // themselves non-nullable.
             .for_each(|builder| builder.append_zeros(n));
-        self.validity.append_value(false);
+        self.validity.append_values(false, n);
     }
 
     fn finish(&mut self) -> VortexResult<ArrayData> {
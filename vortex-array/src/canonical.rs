@@ -2,25 +2,36 @@
 
 use std::sync::Arc;
 
+use arrow_array::cast::AsArray;
+use arrow_array::types::{IntervalDayTime, IntervalMonthDayNano};
 use arrow_array::{
-    ArrayRef, BooleanArray as ArrowBoolArray, Date32Array, Date64Array,
-    NullArray as ArrowNullArray, PrimitiveArray as ArrowPrimitiveArray,
+    make_array, Array, ArrayRef, BooleanArray as ArrowBoolArray, Date32Array, Date64Array,
+    Decimal128Array, Decimal256Array, DurationMicrosecondArray, DurationMillisecondArray,
+    DurationNanosecondArray, DurationSecondArray, IntervalDayTimeArray, IntervalMonthDayNanoArray,
+    IntervalYearMonthArray, NullArray as ArrowNullArray, PrimitiveArray as ArrowPrimitiveArray,
     StructArray as ArrowStructArray, Time32MillisecondArray, Time32SecondArray,
     Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
     TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray,
 };
-use arrow_buffer::ScalarBuffer;
-use arrow_schema::{Field, FieldRef, Fields};
-use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata, TimeUnit};
-use vortex_dtype::{match_each_native_ptype, DType, NativePType, PType};
-use vortex_error::{vortex_bail, VortexError, VortexResult};
+use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_schema::{DataType, Field, FieldRef, Fields, UnionFields};
+use arrow_select::filter::filter;
+use vortex_datetime_dtype::{
+    is_interval_ext_type, is_temporal_ext_type, IntervalUnit, TemporalMetadata, TimeUnit,
+};
+use vortex_dtype::{
+    is_decimal_ext_type, is_fixed_size_binary_ext_type, is_map_ext_type, is_union_ext_type,
+    match_each_native_ptype, DType, NativePType, PType, UnionMode, MAX_DECIMAL128_PRECISION,
+};
+use vortex_error::{vortex_bail, VortexError, VortexExpect, VortexResult};
 
 use crate::array::{
-    varbinview_as_arrow, BoolArray, ExtensionArray, ListArray, NullArray, PrimitiveArray,
-    StructArray, TemporalArray, VarBinViewArray,
+    varbinview_as_arrow, BoolArray, DecimalArray, ExtensionArray, FixedSizeListArray,
+    IntervalArray, ListArray, MapArray, NullArray, PrimitiveArray, StructArray, TemporalArray,
+    UnionArray, VarBinViewArray,
 };
 use crate::arrow::wrappers::as_offset_buffer;
-use crate::arrow::{infer_data_type, FromArrowArray};
+use crate::arrow::{infer_data_type, FromArrowArray, MAP_ENTRIES_FIELD};
 use crate::compute::try_cast;
 use crate::encoding::Encoding;
 use crate::stats::ArrayStatistics;
@@ -59,6 +70,7 @@ pub enum Canonical {
     Struct(StructArray),
     // TODO(joe): maybe this should be a ListView, however this will be annoying in spiral
     List(ListArray),
+    FixedSizeList(FixedSizeListArray),
     VarBinView(VarBinViewArray),
     Extension(ExtensionArray),
 }
@@ -76,10 +88,21 @@ impl Canonical {
             Canonical::Primitive(a) => primitive_to_arrow(a)?,
             Canonical::Struct(a) => struct_to_arrow(a)?,
             Canonical::List(a) => list_to_arrow(a)?,
+            Canonical::FixedSizeList(a) => fixed_size_list_to_arrow(a)?,
             Canonical::VarBinView(a) => varbinview_as_arrow(&a),
             Canonical::Extension(a) => {
                 if is_temporal_ext_type(a.id()) {
                     temporal_to_arrow(TemporalArray::try_from(a.into_array())?)?
+                } else if is_interval_ext_type(a.id()) {
+                    interval_to_arrow(IntervalArray::try_from(a)?)?
+                } else if is_decimal_ext_type(a.id()) {
+                    decimal_to_arrow(DecimalArray::try_from(a)?)?
+                } else if is_fixed_size_binary_ext_type(a.id()) {
+                    fixed_size_binary_to_arrow(a)?
+                } else if is_map_ext_type(a.id()) {
+                    map_to_arrow(MapArray::try_from(a)?)?
+                } else if is_union_ext_type(a.id()) {
+                    union_to_arrow(UnionArray::try_from(a)?)?
                 } else {
                     // Convert storage array directly into arrow, losing type information
                     // that will let us round-trip.
@@ -140,6 +163,13 @@ impl Canonical {
         }
     }
 
+    pub fn into_fixed_size_list(self) -> VortexResult<FixedSizeListArray> {
+        match self {
+            Canonical::FixedSizeList(a) => Ok(a),
+            _ => vortex_bail!("Cannot unwrap FixedSizeListArray from {:?}", &self),
+        }
+    }
+
     pub fn into_varbinview(self) -> VortexResult<VarBinViewArray> {
         match self {
             Canonical::VarBinView(a) => Ok(a),
@@ -254,6 +284,126 @@ fn list_to_arrow(list: ListArray) -> VortexResult<ArrayRef> {
     })
 }
 
+fn fixed_size_list_to_arrow(list: FixedSizeListArray) -> VortexResult<ArrayRef> {
+    let field_ref = FieldRef::new(Field::new_list_field(
+        infer_data_type(list.elements().dtype())?,
+        list.elements().dtype().is_nullable(),
+    ));
+    let nulls = list.logical_validity().to_null_buffer()?;
+
+    Ok(Arc::new(arrow_array::FixedSizeListArray::try_new(
+        field_ref,
+        list.list_size().try_into()?,
+        list.elements().into_arrow()?,
+        nulls,
+    )?))
+}
+
+fn fixed_size_binary_to_arrow(array: ExtensionArray) -> VortexResult<ArrayRef> {
+    let list = array.storage().into_fixed_size_list()?;
+    let nulls = list.logical_validity().to_null_buffer()?;
+    let values = list.elements().into_primitive()?;
+
+    Ok(Arc::new(arrow_array::FixedSizeBinaryArray::try_new(
+        list.list_size().try_into()?,
+        values.buffer().clone().into_arrow(),
+        nulls,
+    )?))
+}
+
+fn map_to_arrow(map: MapArray) -> VortexResult<ArrayRef> {
+    let list = map.entries().into_list()?;
+    let offsets = try_cast(list.offsets(), PType::I32.into())?.into_primitive()?;
+    let entries = struct_to_arrow(list.elements().into_struct()?)?
+        .as_struct()
+        .clone();
+    let nulls = list.logical_validity().to_null_buffer()?;
+
+    Ok(Arc::new(arrow_array::MapArray::try_new(
+        FieldRef::new(Field::new(
+            MAP_ENTRIES_FIELD,
+            entries.data_type().clone(),
+            false,
+        )),
+        as_offset_buffer::<i32>(offsets),
+        entries,
+        nulls,
+        false,
+    )?))
+}
+
+fn union_to_arrow(union: UnionArray) -> VortexResult<ArrayRef> {
+    let union_dtype = union.union_dtype().clone();
+    let type_ids = union.type_ids()?.into_primitive()?;
+    let type_ids = type_ids.maybe_null_slice::<i8>();
+    let union_nulls = union
+        .validity()?
+        .to_logical(type_ids.len())
+        .to_null_buffer()?;
+
+    let mut fields = Vec::with_capacity(union_dtype.type_ids().len());
+    let mut children = Vec::with_capacity(union_dtype.type_ids().len());
+    for (idx, (name, type_id)) in union_dtype
+        .variants()
+        .names()
+        .iter()
+        .zip(union_dtype.type_ids())
+        .enumerate()
+    {
+        let mut child = union.variant(idx)?.into_canonical()?.into_arrow()?;
+        // Arrow unions can't be null, so null unions become nulls of their selected variant.
+        if union_nulls.is_some() && child.data_type() != &DataType::Null {
+            let nulls = NullBuffer::union(child.nulls(), union_nulls.as_ref());
+            child = make_array(child.into_data().into_builder().nulls(nulls).build()?);
+        }
+        if union_dtype.mode() == UnionMode::Dense {
+            child = filter(
+                &child,
+                &ArrowBoolArray::from_iter(type_ids.iter().map(|t| Some(t == type_id))),
+            )?;
+        }
+        fields.push(Field::new(name.as_ref(), child.data_type().clone(), true));
+        children.push(child);
+    }
+
+    let offsets = (union_dtype.mode() == UnionMode::Dense).then(|| {
+        let mut counts = vec![0i32; union_dtype.type_ids().len()];
+        type_ids
+            .iter()
+            .map(|t| {
+                // Type ids were validated when the union was created.
+                let count = &mut counts[union_dtype.variant_idx(*t).unwrap_or_default()];
+                *count += 1;
+                *count - 1
+            })
+            .collect::<ScalarBuffer<i32>>()
+    });
+
+    Ok(Arc::new(arrow_array::UnionArray::try_new(
+        UnionFields::new(union_dtype.type_ids().iter().copied(), fields),
+        ScalarBuffer::from(type_ids.to_vec()),
+        offsets,
+        children,
+    )?))
+}
+
+fn decimal_to_arrow(decimal_array: DecimalArray) -> VortexResult<ArrayRef> {
+    let decimal = decimal_array.decimal_dtype();
+    let nulls = decimal_array.logical_validity().to_null_buffer()?;
+    let values = decimal_array.unscaled_i256()?;
+    Ok(if decimal.precision() <= MAX_DECIMAL128_PRECISION {
+        Arc::new(
+            Decimal128Array::new(values.into_iter().map(|v| v.as_i128()).collect(), nulls)
+                .with_precision_and_scale(decimal.precision(), decimal.scale())?,
+        )
+    } else {
+        Arc::new(
+            Decimal256Array::new(values.into_iter().collect(), nulls)
+                .with_precision_and_scale(decimal.precision(), decimal.scale())?,
+        )
+    })
+}
+
 fn temporal_to_arrow(temporal_array: TemporalArray) -> VortexResult<ArrayRef> {
     macro_rules! extract_temporal_values {
         ($values:expr, $prim:ty) => {{
@@ -327,6 +477,66 @@ fn temporal_to_arrow(temporal_array: TemporalArray) -> VortexResult<ArrayRef> {
                 ),
             }
         }
+        TemporalMetadata::Duration(time_unit) => {
+            let (scalars, nulls) = extract_temporal_values!(&temporal_array.temporal_values(), i64);
+            match time_unit {
+                TimeUnit::Ns => Arc::new(DurationNanosecondArray::new(scalars, nulls)),
+                TimeUnit::Us => Arc::new(DurationMicrosecondArray::new(scalars, nulls)),
+                TimeUnit::Ms => Arc::new(DurationMillisecondArray::new(scalars, nulls)),
+                TimeUnit::S => Arc::new(DurationSecondArray::new(scalars, nulls)),
+                _ => vortex_bail!(
+                    "Invalid TimeUnit {time_unit} for {}",
+                    temporal_array.ext_dtype().id()
+                ),
+            }
+        }
+    })
+}
+
+fn interval_to_arrow(interval_array: IntervalArray) -> VortexResult<ArrayRef> {
+    let storage = interval_array.storage();
+    let nulls = storage.logical_validity().to_null_buffer()?;
+    if interval_array.interval_unit() == IntervalUnit::YearMonth {
+        let months = storage.into_primitive()?;
+        return Ok(Arc::new(IntervalYearMonthArray::new(
+            months.maybe_null_slice::<i32>().iter().copied().collect(),
+            nulls,
+        )));
+    }
+
+    let components = storage.into_struct()?;
+    let component = |idx: usize| {
+        components
+            .field(idx)
+            .vortex_expect("Interval storage must have all components")
+            .into_primitive()
+    };
+    Ok(match interval_array.interval_unit() {
+        IntervalUnit::YearMonth => unreachable!("Year-month intervals are primitive"),
+        IntervalUnit::DayTime => {
+            let (days, milliseconds) = (component(0)?, component(1)?);
+            Arc::new(IntervalDayTimeArray::new(
+                days.maybe_null_slice::<i32>()
+                    .iter()
+                    .zip(milliseconds.maybe_null_slice::<i32>())
+                    .map(|(d, ms)| IntervalDayTime::new(*d, *ms))
+                    .collect(),
+                nulls,
+            ))
+        }
+        IntervalUnit::MonthDayNano => {
+            let (months, days, nanoseconds) = (component(0)?, component(1)?, component(2)?);
+            Arc::new(IntervalMonthDayNanoArray::new(
+                months
+                    .maybe_null_slice::<i32>()
+                    .iter()
+                    .zip(days.maybe_null_slice::<i32>())
+                    .zip(nanoseconds.maybe_null_slice::<i64>())
+                    .map(|((m, d), ns)| IntervalMonthDayNano::new(*m, *d, *ns))
+                    .collect(),
+                nulls,
+            ))
+        }
     })
 }
 
@@ -390,6 +600,8 @@ pub trait IntoArrayVariant {
 
     fn into_list(self) -> VortexResult<ListArray>;
 
+    fn into_fixed_size_list(self) -> VortexResult<FixedSizeListArray>;
+
     fn into_varbinview(self) -> VortexResult<VarBinViewArray>;
 
     fn into_extension(self) -> VortexResult<ExtensionArray>;
@@ -419,6 +631,10 @@ where
         self.into_canonical()?.into_list()
     }
 
+    fn into_fixed_size_list(self) -> VortexResult<FixedSizeListArray> {
+        self.into_canonical()?.into_fixed_size_list()
+    }
+
     fn into_varbinview(self) -> VortexResult<VarBinViewArray> {
         self.into_canonical()?.into_varbinview()
     }
@@ -455,6 +671,7 @@ impl From<Canonical> for ArrayData {
             Canonical::Primitive(a) => a.into_array(),
             Canonical::Struct(a) => a.into_array(),
             Canonical::List(a) => a.into_array(),
+            Canonical::FixedSizeList(a) => a.into_array(),
             Canonical::VarBinView(a) => a.into_array(),
             Canonical::Extension(a) => a.into_array(),
         }
@@ -469,6 +686,7 @@ impl AsRef<ArrayData> for Canonical {
             Canonical::Primitive(a) => a.as_ref(),
             Canonical::Struct(a) => a.as_ref(),
             Canonical::List(a) => a.as_ref(),
+            Canonical::FixedSizeList(a) => a.as_ref(),
             Canonical::VarBinView(a) => a.as_ref(),
             Canonical::Extension(a) => a.as_ref(),
         }
@@ -483,6 +701,7 @@ impl IntoArrayData for Canonical {
             Canonical::Primitive(a) => a.into_array(),
             Canonical::Struct(a) => a.into_array(),
             Canonical::List(a) => a.into_array(),
+            Canonical::FixedSizeList(a) => a.into_array(),
             Canonical::VarBinView(a) => a.into_array(),
             Canonical::Extension(a) => a.into_array(),
         }
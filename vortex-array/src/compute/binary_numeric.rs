@@ -0,0 +1,358 @@
+use std::fmt::{Display, Formatter};
+
+use arrow_arith::numeric;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, Num};
+use vortex_dtype::{match_each_float_ptype, match_each_integer_ptype, DType, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::array::ConstantArray;
+use crate::arrow::{Datum, FromArrowArray};
+use crate::encoding::Encoding;
+use crate::{ArrayDType, ArrayData, IntoArrayData};
+
+/// Arithmetic operators, see [binary_numeric].
+///
+/// The `R` variants apply the operator with the operands reversed, e.g. `RSub` computes
+/// `rhs - lhs`, which allows moving constants to the right-hand side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryNumericOperator {
+    Add,
+    Sub,
+    RSub,
+    Mul,
+    Div,
+    RDiv,
+    Rem,
+    RRem,
+}
+
+impl Display for BinaryNumericOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let display = match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::RSub => "r-",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::RDiv => "r/",
+            Self::Rem => "%",
+            Self::RRem => "r%",
+        };
+        Display::fmt(display, f)
+    }
+}
+
+impl BinaryNumericOperator {
+    /// The operator that gives the same result when the operands are swapped.
+    pub fn swap(self) -> Self {
+        match self {
+            Self::Add => Self::Add,
+            Self::Sub => Self::RSub,
+            Self::RSub => Self::Sub,
+            Self::Mul => Self::Mul,
+            Self::Div => Self::RDiv,
+            Self::RDiv => Self::Div,
+            Self::Rem => Self::RRem,
+            Self::RRem => Self::Rem,
+        }
+    }
+
+    /// Apply the operator to two integers, returning `None` on overflow or division by zero.
+    pub fn apply_checked<T>(self, lhs: T, rhs: T) -> Option<T>
+    where
+        T: CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem,
+    {
+        match self {
+            Self::Add => lhs.checked_add(&rhs),
+            Self::Sub => lhs.checked_sub(&rhs),
+            Self::RSub => rhs.checked_sub(&lhs),
+            Self::Mul => lhs.checked_mul(&rhs),
+            Self::Div => lhs.checked_div(&rhs),
+            Self::RDiv => rhs.checked_div(&lhs),
+            Self::Rem => lhs.checked_rem(&rhs),
+            Self::RRem => rhs.checked_rem(&lhs),
+        }
+    }
+
+    /// Apply the operator to two floats.
+    pub fn apply_float<T: Num>(self, lhs: T, rhs: T) -> T {
+        match self {
+            Self::Add => lhs + rhs,
+            Self::Sub => lhs - rhs,
+            Self::RSub => rhs - lhs,
+            Self::Mul => lhs * rhs,
+            Self::Div => lhs / rhs,
+            Self::RDiv => rhs / lhs,
+            Self::Rem => lhs % rhs,
+            Self::RRem => rhs % lhs,
+        }
+    }
+}
+
+pub trait BinaryNumericFn<Array> {
+    /// Apply an arithmetic operator to the array and another array of the same length and dtype,
+    /// or return `None` if the encoding can't do better than the canonical implementation.
+    fn binary_numeric(
+        &self,
+        array: &Array,
+        other: &ArrayData,
+        op: BinaryNumericOperator,
+    ) -> VortexResult<Option<ArrayData>>;
+}
+
+impl<E: Encoding> BinaryNumericFn<ArrayData> for E
+where
+    E: BinaryNumericFn<E::Array>,
+    for<'a> &'a E::Array: TryFrom<&'a ArrayData, Error = VortexError>,
+{
+    fn binary_numeric(
+        &self,
+        lhs: &ArrayData,
+        rhs: &ArrayData,
+        op: BinaryNumericOperator,
+    ) -> VortexResult<Option<ArrayData>> {
+        let array_ref = <&E::Array>::try_from(lhs)?;
+        let encoding = lhs
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        BinaryNumericFn::binary_numeric(encoding, array_ref, rhs, op)
+    }
+}
+
+/// Point-wise addition of two primitive arrays.
+pub fn add(lhs: impl AsRef<ArrayData>, rhs: impl AsRef<ArrayData>) -> VortexResult<ArrayData> {
+    binary_numeric(lhs.as_ref(), rhs.as_ref(), BinaryNumericOperator::Add)
+}
+
+/// Point-wise subtraction of two primitive arrays.
+pub fn sub(lhs: impl AsRef<ArrayData>, rhs: impl AsRef<ArrayData>) -> VortexResult<ArrayData> {
+    binary_numeric(lhs.as_ref(), rhs.as_ref(), BinaryNumericOperator::Sub)
+}
+
+/// Point-wise multiplication of two primitive arrays.
+pub fn mul(lhs: impl AsRef<ArrayData>, rhs: impl AsRef<ArrayData>) -> VortexResult<ArrayData> {
+    binary_numeric(lhs.as_ref(), rhs.as_ref(), BinaryNumericOperator::Mul)
+}
+
+/// Point-wise division of two primitive arrays.
+pub fn div(lhs: impl AsRef<ArrayData>, rhs: impl AsRef<ArrayData>) -> VortexResult<ArrayData> {
+    binary_numeric(lhs.as_ref(), rhs.as_ref(), BinaryNumericOperator::Div)
+}
+
+/// Point-wise remainder of two primitive arrays.
+pub fn rem(lhs: impl AsRef<ArrayData>, rhs: impl AsRef<ArrayData>) -> VortexResult<ArrayData> {
+    binary_numeric(lhs.as_ref(), rhs.as_ref(), BinaryNumericOperator::Rem)
+}
+
+/// Apply an arithmetic operator point-wise to two primitive arrays of the same length and ptype.
+///
+/// The result is null wherever either operand is null, and nullable if either operand is.
+/// Integer overflow and integer division by zero are errors, while floats follow IEEE 754.
+pub fn binary_numeric(
+    lhs: &ArrayData,
+    rhs: &ArrayData,
+    op: BinaryNumericOperator,
+) -> VortexResult<ArrayData> {
+    if lhs.len() != rhs.len() {
+        vortex_bail!("Numeric operations aren't supported on arrays of different lengths")
+    }
+    if !matches!(lhs.dtype(), DType::Primitive(..))
+        || !lhs.dtype().eq_ignore_nullability(rhs.dtype())
+    {
+        vortex_bail!(
+            "Numeric operations are only supported on primitive arrays of the same type, found {} and {}",
+            lhs.dtype(),
+            rhs.dtype()
+        )
+    }
+
+    // Fold constant operands into a constant result.
+    if let (Some(lhs_scalar), Some(rhs_scalar)) = (lhs.as_constant(), rhs.as_constant()) {
+        return Ok(ConstantArray::new(
+            scalar_binary_numeric(&lhs_scalar, &rhs_scalar, op)?,
+            lhs.len(),
+        )
+        .into_array());
+    }
+
+    // If LHS is constant, then we make sure it's on the RHS.
+    if lhs.is_constant() && !rhs.is_constant() {
+        return binary_numeric(rhs, lhs, op.swap());
+    }
+
+    // If the RHS is constant and the LHS is Arrow, we can't do any better than arrow_numeric.
+    if lhs.is_arrow() && (rhs.is_arrow() || rhs.is_constant()) {
+        return arrow_numeric(lhs, rhs, op);
+    }
+
+    // Check if either LHS or RHS supports the operation directly.
+    if let Some(result) = lhs
+        .encoding()
+        .binary_numeric_fn()
+        .and_then(|f| f.binary_numeric(lhs, rhs, op).transpose())
+    {
+        return result;
+    }
+
+    if let Some(result) = rhs
+        .encoding()
+        .binary_numeric_fn()
+        .and_then(|f| f.binary_numeric(rhs, lhs, op.swap()).transpose())
+    {
+        return result;
+    }
+
+    log::debug!(
+        "No numeric implementation found for LHS {}, RHS {}, and operator {} (or inverse)",
+        lhs.encoding().id(),
+        rhs.encoding().id(),
+        op,
+    );
+
+    // If neither side implements the trait, then we delegate to Arrow compute.
+    arrow_numeric(lhs, rhs, op)
+}
+
+/// Implementation of `BinaryNumericFn` using the Arrow crate, whose integer kernels are checked.
+pub(crate) fn arrow_numeric(
+    lhs: &ArrayData,
+    rhs: &ArrayData,
+    op: BinaryNumericOperator,
+) -> VortexResult<ArrayData> {
+    let nullable = lhs.dtype().is_nullable() || rhs.dtype().is_nullable();
+
+    let (lhs, rhs) = match op {
+        BinaryNumericOperator::RSub | BinaryNumericOperator::RDiv | BinaryNumericOperator::RRem => {
+            (rhs, lhs)
+        }
+        _ => (lhs, rhs),
+    };
+    let lhs = Datum::try_from(lhs.clone())?;
+    let rhs = Datum::try_from(rhs.clone())?;
+
+    let array = match op {
+        BinaryNumericOperator::Add => numeric::add(&lhs, &rhs)?,
+        BinaryNumericOperator::Sub | BinaryNumericOperator::RSub => numeric::sub(&lhs, &rhs)?,
+        BinaryNumericOperator::Mul => numeric::mul(&lhs, &rhs)?,
+        BinaryNumericOperator::Div | BinaryNumericOperator::RDiv => numeric::div(&lhs, &rhs)?,
+        BinaryNumericOperator::Rem | BinaryNumericOperator::RRem => numeric::rem(&lhs, &rhs)?,
+    };
+
+    Ok(ArrayData::from_arrow(array, nullable))
+}
+
+/// Apply an arithmetic operator to two primitive scalars of the same ptype, see [binary_numeric].
+pub fn scalar_binary_numeric(
+    lhs: &Scalar,
+    rhs: &Scalar,
+    op: BinaryNumericOperator,
+) -> VortexResult<Scalar> {
+    let DType::Primitive(ptype, _) = lhs.dtype() else {
+        vortex_bail!("Expected primitive scalar, got {}", lhs.dtype())
+    };
+    if !lhs.dtype().eq_ignore_nullability(rhs.dtype()) {
+        vortex_bail!(MismatchedTypes: lhs.dtype(), rhs.dtype())
+    }
+    let nullability = Nullability::from(lhs.dtype().is_nullable() || rhs.dtype().is_nullable());
+    let null = || Scalar::null(DType::Primitive(*ptype, Nullability::Nullable));
+
+    let lhs = PrimitiveScalar::try_from(lhs)?;
+    let rhs = PrimitiveScalar::try_from(rhs)?;
+    Ok(if ptype.is_int() {
+        match_each_integer_ptype!(ptype, |$T| {
+            match (lhs.typed_value::<$T>(), rhs.typed_value::<$T>()) {
+                (Some(l), Some(r)) => Scalar::primitive(
+                    op.apply_checked(l, r).ok_or_else(|| {
+                        vortex_err!(ComputeError: "{l} {op} {r} overflows or divides by zero")
+                    })?,
+                    nullability,
+                ),
+                _ => null(),
+            }
+        })
+    } else {
+        match_each_float_ptype!(ptype, |$T| {
+            match (lhs.typed_value::<$T>(), rhs.typed_value::<$T>()) {
+                (Some(l), Some(r)) => Scalar::primitive(op.apply_float(l, r), nullability),
+                _ => null(),
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use super::*;
+    use crate::array::PrimitiveArray;
+    use crate::compute::scalar_at;
+    use crate::IntoArrayVariant;
+
+    #[test]
+    fn test_arithmetic() {
+        let lhs = PrimitiveArray::from_nullable_vec(vec![Some(7i32), None, Some(-9), Some(4)])
+            .into_array();
+        let rhs = PrimitiveArray::from(vec![2i32, 3, 4, 5]).into_array();
+
+        let result = mul(&lhs, &rhs).unwrap();
+        assert_eq!(
+            result.dtype(),
+            &DType::Primitive(PType::I32, Nullability::Nullable)
+        );
+        assert_eq!(
+            (0..4)
+                .map(|i| scalar_at(&result, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Scalar::from(Some(14i32)),
+                Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable)),
+                Scalar::from(Some(-36i32)),
+                Scalar::from(Some(20i32)),
+            ]
+        );
+
+        let result = rem(&lhs, &rhs).unwrap().into_primitive().unwrap();
+        assert_eq!(result.maybe_null_slice::<i32>()[3], 4);
+    }
+
+    #[test]
+    fn test_constant_lhs() {
+        let lhs = ConstantArray::new(10u8, 3).into_array();
+        let rhs = PrimitiveArray::from(vec![1u8, 2, 5]).into_array();
+        let result = sub(&lhs, &rhs).unwrap().into_primitive().unwrap();
+        assert_eq!(result.maybe_null_slice::<u8>(), &[9, 8, 5]);
+
+        let result = div(&lhs, &rhs).unwrap().into_primitive().unwrap();
+        assert_eq!(result.maybe_null_slice::<u8>(), &[10, 5, 2]);
+
+        let result = add(&lhs, ConstantArray::new(1u8, 3).into_array()).unwrap();
+        assert_eq!(result.as_constant(), Some(Scalar::from(11u8)));
+    }
+
+    #[test]
+    fn test_checked() {
+        let lhs = PrimitiveArray::from(vec![i64::MAX, 1]).into_array();
+        assert!(add(&lhs, ConstantArray::new(1i64, 2).into_array()).is_err());
+        assert!(div(&lhs, ConstantArray::new(0i64, 2).into_array()).is_err());
+
+        // Values under nulls are never computed.
+        let lhs = PrimitiveArray::from_nullable_vec(vec![Some(1i64), None]).into_array();
+        let rhs = PrimitiveArray::from_nullable_vec(vec![Some(2i64), Some(0)]).into_array();
+        assert!(div(&lhs, &rhs).is_ok());
+
+        let floats = PrimitiveArray::from(vec![1.0f64, -1.0]).into_array();
+        let result = div(&floats, ConstantArray::new(0.0f64, 2).into_array())
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(
+            result.maybe_null_slice::<f64>(),
+            &[f64::INFINITY, f64::NEG_INFINITY]
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use vortex_error::VortexResult;
+
+use crate::validity::ArrayValidity;
+use crate::ArrayData;
+
+/// The number of non-null values of an array.
+pub fn count(array: &ArrayData) -> VortexResult<usize> {
+    Ok(array.len() - array.logical_validity().null_count()?)
+}
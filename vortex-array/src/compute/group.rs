@@ -0,0 +1,242 @@
+use arrow_buffer::BooleanBuffer;
+use itertools::Itertools;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::aliases::hash_map::HashMap;
+use crate::array::{BoolArray, PrimitiveArray, StructArray, VarBinViewArray};
+use crate::compute::{combine_hashes, hash, scalar_at, take, TakeOptions};
+use crate::validity::ArrayValidity;
+use crate::variants::PrimitiveArrayTrait;
+use crate::{ArrayData, Canonical, IntoArrayData, IntoArrayVariant, IntoCanonical};
+
+/// The distinct rows of a set of columns, along with the number of times each occurs.
+#[derive(Debug, Clone)]
+pub struct ValueCounts {
+    /// The values of each column in the distinct rows.
+    pub values: Vec<ArrayData>,
+    /// A u64 array of the number of occurrences of each distinct row.
+    pub counts: ArrayData,
+}
+
+/// The indices of the rows of each group of equal rows of the given columns.
+///
+/// Groups are ordered by their first row. Rows are grouped by their [hash], and rows with equal
+/// hashes are compared to the first row of each group with the semantics of
+/// [Scalar][vortex_scalar::Scalar] equality, such that nulls are grouped together, and floats are
+/// grouped by their bits.
+pub fn group_indices(columns: &[ArrayData]) -> VortexResult<Vec<Vec<u64>>> {
+    let Some(len) = columns.first().map(ArrayData::len) else {
+        vortex_bail!("Grouping requires at least one column")
+    };
+    if columns.iter().any(|c| c.len() != len) {
+        vortex_bail!("Grouping requires columns of the same length")
+    }
+
+    let mut hashes = hash(&columns[0])?
+        .into_primitive()?
+        .into_maybe_null_slice::<u64>();
+    for column in &columns[1..] {
+        let column_hashes = hash(column)?.into_primitive()?;
+        for (hash, column_hash) in hashes
+            .iter_mut()
+            .zip_eq(column_hashes.maybe_null_slice::<u64>())
+        {
+            *hash = combine_hashes(*hash, *column_hash);
+        }
+    }
+
+    // Rows with equal hashes are compared on the canonical columns.
+    let columns = columns
+        .iter()
+        .map(RowEq::try_new)
+        .collect::<VortexResult<Vec<_>>>()?;
+    let rows_equal = |a: usize, b: usize| -> VortexResult<bool> {
+        for column in &columns {
+            if !column.rows_equal(a, b)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    };
+
+    let mut groups: Vec<Vec<u64>> = Vec::new();
+    let mut first_rows: Vec<usize> = Vec::new();
+    let mut groups_by_hash: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (idx, hash) in hashes.into_iter().enumerate() {
+        let candidates = groups_by_hash.entry(hash).or_default();
+        let mut group = None;
+        for candidate in candidates.iter() {
+            if rows_equal(first_rows[*candidate], idx)? {
+                group = Some(*candidate);
+                break;
+            }
+        }
+        match group {
+            Some(group) => groups[group].push(idx as u64),
+            None => {
+                candidates.push(groups.len());
+                groups.push(vec![idx as u64]);
+                first_rows.push(idx);
+            }
+        }
+    }
+    Ok(groups)
+}
+
+/// Compares the values of a canonical column by their indices, with the same semantics as
+/// [Scalar][vortex_scalar::Scalar] equality.
+enum RowEq {
+    Null,
+    Bool(BoolArray, BooleanBuffer),
+    Primitive(PrimitiveArray),
+    Bytes(VarBinViewArray),
+    Struct(StructArray, Vec<RowEq>),
+    Scalars(ArrayData),
+}
+
+impl RowEq {
+    fn try_new(column: &ArrayData) -> VortexResult<Self> {
+        Ok(match column.clone().into_canonical()? {
+            Canonical::Null(_) => Self::Null,
+            Canonical::Bool(array) => {
+                let buffer = array.boolean_buffer();
+                Self::Bool(array, buffer)
+            }
+            Canonical::Primitive(array) => Self::Primitive(array),
+            Canonical::VarBinView(array) => Self::Bytes(array),
+            Canonical::Struct(array) => {
+                let fields = array
+                    .children()
+                    .map(|field| Self::try_new(&field))
+                    .collect::<VortexResult<Vec<_>>>()?;
+                Self::Struct(array, fields)
+            }
+            Canonical::Extension(array) => Self::try_new(&array.storage())?,
+            canonical => Self::Scalars(canonical.into_array()),
+        })
+    }
+
+    fn rows_equal(&self, a: usize, b: usize) -> VortexResult<bool> {
+        // Nulls are only equal to nulls.
+        let validity = |array: &ArrayData| (array.is_valid(a), array.is_valid(b));
+        Ok(match self {
+            Self::Null => true,
+            Self::Bool(array, buffer) => match validity(array.as_ref()) {
+                (true, true) => buffer.value(a) == buffer.value(b),
+                (a_valid, b_valid) => a_valid == b_valid,
+            },
+            Self::Primitive(array) => match validity(array.as_ref()) {
+                (true, true) => {
+                    // Compare the bytes, such that floats are compared by their bits.
+                    let width = array.ptype().byte_width();
+                    let values = array.buffer().as_slice();
+                    values[a * width..(a + 1) * width] == values[b * width..(b + 1) * width]
+                }
+                (a_valid, b_valid) => a_valid == b_valid,
+            },
+            Self::Bytes(array) => match validity(array.as_ref()) {
+                (true, true) => array.bytes_at(a)? == array.bytes_at(b)?,
+                (a_valid, b_valid) => a_valid == b_valid,
+            },
+            Self::Struct(array, fields) => match validity(array.as_ref()) {
+                (true, true) => {
+                    for field in fields {
+                        if !field.rows_equal(a, b)? {
+                            return Ok(false);
+                        }
+                    }
+                    true
+                }
+                (a_valid, b_valid) => a_valid == b_valid,
+            },
+            Self::Scalars(array) => scalar_at(array, a)? == scalar_at(array, b)?,
+        })
+    }
+}
+
+/// The distinct rows of the given columns and the number of times each occurs, ordered by their
+/// first occurrence, see [group_indices].
+pub fn value_counts(columns: &[ArrayData]) -> VortexResult<ValueCounts> {
+    let groups = group_indices(columns)?;
+    let first_rows = PrimitiveArray::from(groups.iter().map(|g| g[0]).collect_vec()).into_array();
+    Ok(ValueCounts {
+        values: columns
+            .iter()
+            .map(|c| take(c, &first_rows, TakeOptions::default()))
+            .collect::<VortexResult<Vec<_>>>()?,
+        counts: PrimitiveArray::from(groups.iter().map(|g| g.len() as u64).collect_vec())
+            .into_array(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::Nullability;
+    use vortex_scalar::Scalar;
+
+    use crate::array::{PrimitiveArray, StructArray, VarBinViewArray};
+    use crate::compute::{group_indices, scalar_at, value_counts};
+    use crate::validity::Validity;
+    use crate::{IntoArrayData, IntoArrayVariant};
+
+    #[test]
+    fn group_single_column() {
+        let column =
+            PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(1), Some(3), None])
+                .into_array();
+        assert_eq!(
+            group_indices(&[column.clone()]).unwrap(),
+            vec![vec![0, 3], vec![1, 4], vec![2]]
+        );
+
+        let counts = value_counts(&[column]).unwrap();
+        assert_eq!(
+            (0..3)
+                .map(|i| scalar_at(&counts.values[0], i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Scalar::primitive(3i32, Nullability::Nullable),
+                Scalar::null_typed::<i32>(),
+                Scalar::primitive(1i32, Nullability::Nullable),
+            ]
+        );
+        assert_eq!(
+            counts
+                .counts
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[2, 2, 1]
+        );
+    }
+
+    #[test]
+    fn group_multiple_columns() {
+        let ids = PrimitiveArray::from(vec![1u8, 1, 2, 1]).into_array();
+        let names = VarBinViewArray::from_iter_str(["a", "b", "a", "a"]).into_array();
+        assert_eq!(
+            group_indices(&[ids, names]).unwrap(),
+            vec![vec![0, 3], vec![1], vec![2]]
+        );
+    }
+
+    #[test]
+    fn group_struct_and_float_columns() {
+        let points = StructArray::try_new(
+            ["x".into()].into(),
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1i64), None, Some(1), None, Some(2)])
+                    .into_array(),
+            ],
+            5,
+            Validity::from_iter([true, true, true, false, true]),
+        )
+        .unwrap()
+        .into_array();
+        let floats = PrimitiveArray::from(vec![f64::NAN, 0.0, f64::NAN, 0.0, -0.0]).into_array();
+        assert_eq!(
+            group_indices(&[points, floats]).unwrap(),
+            vec![vec![0, 2], vec![1], vec![3], vec![4]]
+        );
+    }
+}
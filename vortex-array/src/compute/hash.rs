@@ -0,0 +1,189 @@
+use std::hash::Hasher;
+
+use itertools::Itertools;
+use twox_hash::XxHash64;
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::PrimitiveArray;
+use crate::encoding::Encoding;
+use crate::validity::ArrayValidity;
+use crate::variants::PrimitiveArrayTrait;
+use crate::{
+    ArrayDType, ArrayData, ArrayLen, Canonical, IntoArrayData, IntoArrayVariant, IntoCanonical,
+};
+
+/// The seed of the [XxHash64] hashes computed by [hash].
+pub const HASH_SEED: u64 = 0;
+
+/// The hash of null values.
+pub const NULL_HASH: u64 = 0;
+
+pub trait HashFn<Array> {
+    /// Hash each value of the array, see [hash].
+    fn hash(&self, array: &Array) -> VortexResult<ArrayData>;
+}
+
+impl<E: Encoding> HashFn<ArrayData> for E
+where
+    E: HashFn<E::Array>,
+    for<'a> &'a E::Array: TryFrom<&'a ArrayData, Error = VortexError>,
+{
+    fn hash(&self, array: &ArrayData) -> VortexResult<ArrayData> {
+        let array_ref = <&E::Array>::try_from(array)?;
+        let encoding = array
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        HashFn::hash(encoding, array_ref)
+    }
+}
+
+/// Hash each value of an array into a non-nullable u64 array.
+///
+/// The hashes are stable, such that they can be persisted or compared across processes:
+///
+/// - Primitive values are hashed with [XxHash64] seeded with [HASH_SEED] over their little endian
+///   bytes, so floats hash by their bits like [Scalar][vortex_scalar::Scalar] equality.
+/// - Booleans are hashed as a single byte of 0 or 1, and strings and binaries as their bytes.
+/// - Structs combine the hashes of their fields, see [combine_hashes], and extension arrays hash
+///   their storage.
+/// - Null values hash to [NULL_HASH].
+///
+/// Values of equal dtypes that are equal hash the same regardless of how they're encoded.
+pub fn hash(array: &ArrayData) -> VortexResult<ArrayData> {
+    if let Some(f) = array.encoding().hash_fn() {
+        return f.hash(array);
+    }
+
+    // Otherwise, we canonicalize and hash the values.
+    if !array.is_canonical() {
+        log::debug!(
+            "No hash implementation found for encoding {}",
+            array.encoding().id(),
+        );
+    }
+    hash_canonical(array.clone().into_canonical()?).map(IntoArrayData::into_array)
+}
+
+/// Combine a hash with the hash of another value, e.g. of the next column of a row.
+pub fn combine_hashes(hash: u64, other: u64) -> u64 {
+    hash ^ other
+        .wrapping_add(0x9e37_79b9_7f4a_7c15)
+        .wrapping_add(hash << 6)
+        .wrapping_add(hash >> 2)
+}
+
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = XxHash64::with_seed(HASH_SEED);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+fn hash_canonical(canonical: Canonical) -> VortexResult<PrimitiveArray> {
+    let hashes = match canonical {
+        Canonical::Null(array) => vec![NULL_HASH; array.len()],
+        Canonical::Bool(array) => with_nulls(
+            &array,
+            array
+                .boolean_buffer()
+                .iter()
+                .map(|v| hash_bytes(&[u8::from(v)]))
+                .collect(),
+        )?,
+        Canonical::Primitive(array) => {
+            let hashes = match_each_native_ptype!(array.ptype(), |$T| {
+                array
+                    .maybe_null_slice::<$T>()
+                    .iter()
+                    .map(|v| hash_bytes(v.to_le_bytes().as_ref()))
+                    .collect()
+            });
+            with_nulls(&array, hashes)?
+        }
+        Canonical::VarBinView(array) => array.with_iterator(|iter| {
+            iter.map(|v| v.map_or(NULL_HASH, hash_bytes))
+                .collect::<Vec<_>>()
+        })?,
+        Canonical::Struct(array) => {
+            let mut hashes = vec![NULL_HASH; array.len()];
+            for field in array.children() {
+                let field = hash(&field)?.into_primitive()?;
+                for (hash, field) in hashes.iter_mut().zip_eq(field.maybe_null_slice::<u64>()) {
+                    *hash = combine_hashes(*hash, *field);
+                }
+            }
+            with_nulls(&array, hashes)?
+        }
+        Canonical::Extension(array) => return hash(&array.storage())?.into_primitive(),
+        Canonical::List(array) => vortex_bail!("Hashing isn't supported for {}", array.dtype()),
+        Canonical::FixedSizeList(array) => {
+            vortex_bail!("Hashing isn't supported for {}", array.dtype())
+        }
+    };
+    Ok(PrimitiveArray::from(hashes))
+}
+
+/// Replace the hashes of null values with [NULL_HASH].
+fn with_nulls(array: &impl ArrayValidity, mut hashes: Vec<u64>) -> VortexResult<Vec<u64>> {
+    if let Some(nulls) = array.logical_validity().to_null_buffer()? {
+        for (hash, valid) in hashes.iter_mut().zip_eq(nulls.iter()) {
+            if !valid {
+                *hash = NULL_HASH;
+            }
+        }
+    }
+    Ok(hashes)
+}
+
+#[cfg(test)]
+mod test {
+    use std::hash::Hasher;
+
+    use twox_hash::XxHash64;
+
+    use crate::array::{BoolArray, PrimitiveArray, StructArray, VarBinViewArray};
+    use crate::compute::{hash, HASH_SEED, NULL_HASH};
+    use crate::{IntoArrayData, IntoArrayVariant};
+
+    fn hashes(array: &crate::ArrayData) -> Vec<u64> {
+        hash(array)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .into_maybe_null_slice()
+    }
+
+    #[test]
+    fn hash_primitive() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i64), None, Some(2), Some(1)])
+            .into_array();
+        let hashes = hashes(&array);
+        assert_eq!(hashes[0], hashes[3]);
+        assert_ne!(hashes[0], hashes[2]);
+        assert_eq!(hashes[1], NULL_HASH);
+
+        let mut hasher = XxHash64::with_seed(HASH_SEED);
+        hasher.write(&1i64.to_le_bytes());
+        assert_eq!(hashes[0], hasher.finish());
+    }
+
+    #[test]
+    fn hash_struct() {
+        let array = StructArray::from_fields(&[
+            ("a", PrimitiveArray::from(vec![1u8, 1, 1]).into_array()),
+            (
+                "b",
+                VarBinViewArray::from_iter_str(["x", "y", "x"]).into_array(),
+            ),
+            ("c", BoolArray::from_iter([true, true, true]).into_array()),
+        ])
+        .unwrap()
+        .into_array();
+        let hashes = hashes(&array);
+        assert_eq!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[1]);
+    }
+}
@@ -0,0 +1,207 @@
+use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
+use vortex_error::{vortex_err, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::aliases::hash_map::HashMap;
+use crate::array::{BoolArray, ConstantArray};
+use crate::compute::{hash, scalar_at, search_sorted_many, SearchSortedSide};
+use crate::encoding::Encoding;
+use crate::stats::ArrayStatistics;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant, IntoCanonical};
+
+pub trait IsInFn<Array> {
+    /// Test the values of the array for membership in a list of values, see [is_in].
+    ///
+    /// The list values are non-null and of the dtype of the array, and the result is null where
+    /// the array is null.
+    fn is_in(&self, array: &Array, values: &[Scalar]) -> VortexResult<ArrayData>;
+}
+
+impl<E: Encoding> IsInFn<ArrayData> for E
+where
+    E: IsInFn<E::Array>,
+    for<'a> &'a E::Array: TryFrom<&'a ArrayData, Error = VortexError>,
+{
+    fn is_in(&self, array: &ArrayData, values: &[Scalar]) -> VortexResult<ArrayData> {
+        let array_ref = <&E::Array>::try_from(array)?;
+        let encoding = array
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        IsInFn::is_in(encoding, array_ref, values)
+    }
+}
+
+/// Test the values of an array for membership in a list of values, i.e. SQL `IN`.
+///
+/// Returns a boolean array that is null where the array is null. If the list contains a null,
+/// values that aren't found are null rather than false, such that their `NOT IN` isn't true.
+/// List values that can't be cast to the dtype of the array without loss are never found.
+pub fn is_in(array: &ArrayData, values: &[Scalar]) -> VortexResult<ArrayData> {
+    let list_has_null = values.iter().any(Scalar::is_null);
+    let list = values
+        .iter()
+        .filter(|v| v.is_valid())
+        .filter_map(|v| cast_exact(v, array))
+        .collect::<Vec<_>>();
+
+    let found = if array.is_empty() || list.is_empty() {
+        BoolArray::try_new(BooleanBuffer::new_unset(array.len()), validity(array))?.into_array()
+    } else if let Some(f) = array.encoding().is_in_fn() {
+        f.is_in(array, &list)?
+    } else if let Some(found) = is_in_sorted(array, &list)? {
+        found
+    } else {
+        // Otherwise, we look up the hashes of the canonical values.
+        log::debug!(
+            "No is_in implementation found for encoding {}",
+            array.encoding().id(),
+        );
+        is_in_canonical(&array.clone().into_canonical()?.into_array(), &list)?
+    };
+
+    if !list_has_null {
+        return Ok(found);
+    }
+
+    // The values that weren't found might be the null in the list.
+    let found = found.into_bool()?;
+    let buffer = found.boolean_buffer();
+    BoolArray::try_new(
+        buffer.clone(),
+        found
+            .validity()
+            .into_nullable()
+            .and(Validity::from(buffer))?,
+    )
+    .map(IntoArrayData::into_array)
+}
+
+/// The validity of the array, which keeps its nullability.
+fn validity(array: &ArrayData) -> Validity {
+    if array.dtype().is_nullable() {
+        array.logical_validity().into_validity()
+    } else {
+        Validity::NonNullable
+    }
+}
+
+fn cast_exact(value: &Scalar, array: &ArrayData) -> Option<Scalar> {
+    let cast = value.cast(array.dtype()).ok()?;
+    (cast.cast(value.dtype()).ok()? == *value).then_some(cast)
+}
+
+/// The values of sorted arrays without nulls are found by binary search.
+fn is_in_sorted(array: &ArrayData, values: &[Scalar]) -> VortexResult<Option<ArrayData>> {
+    let stats = array.statistics();
+    if array.dtype().is_nullable() && stats.compute_null_count() != Some(0) {
+        return Ok(None);
+    }
+    if stats.compute_is_sorted() != Some(true) {
+        return Ok(None);
+    }
+
+    let starts = search_sorted_many(array, values, SearchSortedSide::Left)?;
+    let ends = search_sorted_many(array, values, SearchSortedSide::Right)?;
+    let mut buffer = BooleanBufferBuilder::new(array.len());
+    buffer.append_n(array.len(), false);
+    for (start, end) in starts.into_iter().zip(ends) {
+        for idx in start.to_index()..end.to_index() {
+            buffer.set_bit(idx, true);
+        }
+    }
+    Ok(Some(
+        BoolArray::new(buffer.finish(), array.dtype().nullability()).into_array(),
+    ))
+}
+
+fn is_in_canonical(array: &ArrayData, values: &[Scalar]) -> VortexResult<ArrayData> {
+    let mut values_by_hash: HashMap<u64, Vec<&Scalar>> = HashMap::new();
+    for value in values {
+        let value_hash = hash(&ConstantArray::new(value.clone(), 1).into_array())?
+            .into_primitive()?
+            .maybe_null_slice::<u64>()[0];
+        values_by_hash.entry(value_hash).or_default().push(value);
+    }
+
+    let nulls = array.logical_validity().to_null_buffer()?;
+    let hashes = hash(array)?.into_primitive()?;
+    let mut buffer = BooleanBufferBuilder::new(array.len());
+    for (idx, value_hash) in hashes.maybe_null_slice::<u64>().iter().enumerate() {
+        let found = match values_by_hash.get(value_hash) {
+            Some(candidates) if nulls.as_ref().map_or(true, |n| n.is_valid(idx)) => {
+                let value = scalar_at(array, idx)?;
+                candidates.iter().any(|c| **c == value)
+            }
+            _ => false,
+        };
+        buffer.append(found);
+    }
+    BoolArray::try_new(buffer.finish(), validity(array)).map(IntoArrayData::into_array)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::array::{PrimitiveArray, VarBinViewArray};
+    use crate::compute::{invert, is_in};
+    use crate::validity::ArrayValidity;
+    use crate::{ArrayData, IntoArrayData, IntoArrayVariant};
+
+    fn to_vec(array: ArrayData) -> Vec<Option<bool>> {
+        let array = array.into_bool().unwrap();
+        let validity = array.logical_validity().to_null_buffer().unwrap();
+        array
+            .boolean_buffer()
+            .iter()
+            .enumerate()
+            .map(|(idx, v)| {
+                validity
+                    .as_ref()
+                    .map_or(true, |n| n.is_valid(idx))
+                    .then_some(v)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn is_in_primitive() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(1), Some(7), Some(3)])
+                .into_array();
+        assert_eq!(
+            to_vec(is_in(&array, &[3i64.into(), 7i64.into(), 1.5f64.into()]).unwrap()),
+            vec![Some(true), None, Some(false), Some(true), Some(true)]
+        );
+
+        // With a null in the list, values that aren't found are unknown.
+        let found = is_in(&array, &[3i32.into(), Scalar::null_typed::<i32>()]).unwrap();
+        assert_eq!(
+            to_vec(invert(&found).unwrap()),
+            vec![Some(false), None, None, None, Some(false)]
+        );
+    }
+
+    #[test]
+    fn is_in_sorted() {
+        let array = PrimitiveArray::from(vec![1u16, 2, 2, 4, 8, 8, 9]).into_array();
+        assert_eq!(
+            to_vec(is_in(&array, &[2u16.into(), 8u16.into(), 5u16.into()]).unwrap()),
+            [false, true, true, false, true, true, false]
+                .map(Some)
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn is_in_strings() {
+        let array = VarBinViewArray::from_iter_str(["b", "a", "c", "a"]).into_array();
+        assert_eq!(
+            to_vec(is_in(&array, &["a".into(), "z".into()]).unwrap()),
+            [false, true, false, true].map(Some).to_vec()
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::VortexResult;
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::compute::{count, sum};
+use crate::ArrayData;
+
+/// The mean of the non-null values of a primitive array.
+///
+/// Returns a nullable f64 scalar, which is null if the array has no non-null values.
+pub fn mean(array: &ArrayData) -> VortexResult<Scalar> {
+    let sum = sum(array)?;
+    let count = count(array)?;
+    Ok(match PrimitiveScalar::try_from(&sum)?.as_::<f64>()? {
+        Some(sum) if count > 0 => Scalar::primitive(sum / count as f64, Nullability::Nullable),
+        _ => Scalar::null(DType::Primitive(PType::F64, Nullability::Nullable)),
+    })
+}
@@ -0,0 +1,103 @@
+use std::cmp::Ordering;
+
+use vortex_error::{vortex_err, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::encoding::Encoding;
+use crate::stats::{ArrayStatistics, Stat};
+use crate::validity::ArrayValidity;
+use crate::{ArrayData, IntoArrayData, IntoCanonical};
+
+/// The minimum and maximum non-null values of an array.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinMaxResult {
+    pub min: Scalar,
+    pub max: Scalar,
+}
+
+impl MinMaxResult {
+    /// The minimum and maximum of the values of both results.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            min: if other.min.partial_cmp(&self.min) == Some(Ordering::Less) {
+                other.min
+            } else {
+                self.min
+            },
+            max: if other.max.partial_cmp(&self.max) == Some(Ordering::Greater) {
+                other.max
+            } else {
+                self.max
+            },
+        }
+    }
+
+    /// Merge a sequence of optional results, ignoring those that are missing.
+    pub fn merge_all(results: impl IntoIterator<Item = Option<Self>>) -> Option<Self> {
+        results.into_iter().flatten().reduce(Self::merge)
+    }
+
+    fn from_stats(min: Scalar, max: Scalar) -> Option<Self> {
+        (min.is_valid() && max.is_valid()).then_some(Self { min, max })
+    }
+}
+
+pub trait MinMaxFn<Array> {
+    /// The minimum and maximum non-null values of the array, see [min_max].
+    fn min_max(&self, array: &Array) -> VortexResult<Option<MinMaxResult>>;
+}
+
+impl<E: Encoding> MinMaxFn<ArrayData> for E
+where
+    E: MinMaxFn<E::Array>,
+    for<'a> &'a E::Array: TryFrom<&'a ArrayData, Error = VortexError>,
+{
+    fn min_max(&self, array: &ArrayData) -> VortexResult<Option<MinMaxResult>> {
+        let array_ref = <&E::Array>::try_from(array)?;
+        let encoding = array
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        MinMaxFn::min_max(encoding, array_ref)
+    }
+}
+
+/// The minimum and maximum non-null values of an array, or `None` if all values are null.
+///
+/// Statistics that were already computed for the array are reused, otherwise encodings compute
+/// them from their children where possible before falling back to the statistics of the canonical
+/// array.
+pub fn min_max(array: &ArrayData) -> VortexResult<Option<MinMaxResult>> {
+    if array.is_empty() {
+        return Ok(None);
+    }
+
+    let stats = array.statistics();
+    if let (Some(min), Some(max)) = (stats.get(Stat::Min), stats.get(Stat::Max)) {
+        return Ok(MinMaxResult::from_stats(min, max));
+    }
+
+    if let Some(f) = array.encoding().min_max_fn() {
+        return f.min_max(array);
+    }
+
+    if array.logical_validity().all_invalid() {
+        return Ok(None);
+    }
+
+    let canonical = if array.is_canonical() {
+        array.clone()
+    } else {
+        log::debug!(
+            "No min_max implementation found for encoding {}",
+            array.encoding().id(),
+        );
+        array.clone().into_canonical()?.into_array()
+    };
+    let stats = canonical.statistics();
+    match (stats.compute(Stat::Min), stats.compute(Stat::Max)) {
+        (Some(min), Some(max)) => Ok(MinMaxResult::from_stats(min, max)),
+        _ => Err(vortex_err!(NotImplemented: "min_max", array.encoding().id())),
+    }
+}
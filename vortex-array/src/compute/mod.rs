@@ -6,37 +6,64 @@
 //! Every [array variant][crate::ArrayTrait] has the ability to implement their own efficient
 //! implementations of these operators, else we will decode, and perform the equivalent operator
 //! from Arrow.
+//!
+//! The aggregates [sum] and [min_max] can likewise be computed from the children of encoded
+//! arrays, e.g. from the values and run lengths of run-end encoded arrays.
+//!
+//! Arrays can be grouped by the values of one or more columns with [group_indices], on top of the
+//! stable [hash] of their values.
 
+pub use binary_numeric::{
+    add, binary_numeric, div, mul, rem, scalar_binary_numeric, sub, BinaryNumericFn,
+    BinaryNumericOperator,
+};
 pub use boolean::{
     and, and_kleene, binary_boolean, or, or_kleene, BinaryBooleanFn, BinaryOperator,
 };
 pub use cast::{try_cast, CastFn};
 pub use compare::{compare, scalar_cmp, CompareFn, Operator};
+pub use count::count;
 pub use fill_forward::{fill_forward, FillForwardFn};
 pub use fill_null::{fill_null, FillNullFn};
 pub use filter::{filter, FilterFn, FilterIter, FilterMask};
+pub use group::{group_indices, value_counts, ValueCounts};
+pub use hash::{combine_hashes, hash, HashFn, HASH_SEED, NULL_HASH};
 pub use invert::{invert, InvertFn};
+pub use is_in::{is_in, IsInFn};
 pub use like::{like, LikeFn, LikeOptions};
+pub use mean::mean;
+pub use min_max::{min_max, MinMaxFn, MinMaxResult};
 pub use scalar_at::{scalar_at, ScalarAtFn};
 pub use scalar_subtract::{subtract_scalar, SubtractScalarFn};
 pub use search_sorted::*;
 pub use slice::{slice, SliceFn};
+pub use sort::{argsort, sort, SortFn, SortOptions};
+pub use sum::{sum, sum_ptype, SumAccumulator, SumFn};
 pub use take::{take, TakeFn, TakeOptions};
 
 use crate::ArrayData;
 
+mod binary_numeric;
 mod boolean;
 mod cast;
 mod compare;
+mod count;
 mod fill_forward;
 mod fill_null;
 mod filter;
+mod group;
+mod hash;
 mod invert;
+mod is_in;
 mod like;
+mod mean;
+mod min_max;
 mod scalar_at;
 mod scalar_subtract;
 mod search_sorted;
 mod slice;
+mod sort;
+mod sum;
 mod take;
 
 /// VTable for dispatching compute functions to Vortex encodings.
@@ -48,6 +75,13 @@ pub trait ComputeVTable {
         None
     }
 
+    /// Implementation of arithmetic operations on primitive arrays.
+    ///
+    /// See: [BinaryNumericFn].
+    fn binary_numeric_fn(&self) -> Option<&dyn BinaryNumericFn<ArrayData>> {
+        None
+    }
+
     /// Implemented for arrays that can be casted to different types.
     ///
     /// See: [CastFn].
@@ -76,6 +110,13 @@ pub trait ComputeVTable {
         None
     }
 
+    /// Hash each value of an array.
+    ///
+    /// See: [HashFn].
+    fn hash_fn(&self) -> Option<&dyn HashFn<ArrayData>> {
+        None
+    }
+
     /// Invert a boolean array. Converts true -> false, false -> true, null -> null.
     ///
     /// See [InvertFn]
@@ -83,6 +124,13 @@ pub trait ComputeVTable {
         None
     }
 
+    /// Test the values of an array for membership in a list of values.
+    ///
+    /// See: [IsInFn].
+    fn is_in_fn(&self) -> Option<&dyn IsInFn<ArrayData>> {
+        None
+    }
+
     /// Perform a SQL LIKE operation on two arrays.
     ///
     /// See: [LikeFn].
@@ -90,6 +138,13 @@ pub trait ComputeVTable {
         None
     }
 
+    /// The minimum and maximum non-null values of an array.
+    ///
+    /// See: [MinMaxFn].
+    fn min_max_fn(&self) -> Option<&dyn MinMaxFn<ArrayData>> {
+        None
+    }
+
     /// Single item indexing on Vortex arrays.
     ///
     /// See: [ScalarAtFn].
@@ -118,6 +173,13 @@ pub trait ComputeVTable {
         None
     }
 
+    /// Sort the values of an array.
+    ///
+    /// See: [SortFn].
+    fn sort_fn(&self) -> Option<&dyn SortFn<ArrayData>> {
+        None
+    }
+
     /// Broadcast subtraction of scalar from Vortex array.
     ///
     /// See: [SubtractScalarFn].
@@ -125,6 +187,13 @@ pub trait ComputeVTable {
         None
     }
 
+    /// Sum the non-null values of a primitive array.
+    ///
+    /// See: [SumFn].
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        None
+    }
+
     /// Take a set of indices from an array. This often forces allocations and decoding of
     /// the receiver.
     ///
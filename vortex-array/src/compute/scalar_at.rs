@@ -3,7 +3,7 @@ use vortex_scalar::Scalar;
 
 use crate::encoding::Encoding;
 use crate::validity::ArrayValidity;
-use crate::{ArrayDType, ArrayData};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoCanonical};
 
 /// Implementation of scalar_at for an encoding.
 ///
@@ -38,9 +38,19 @@ pub fn scalar_at(array: impl AsRef<ArrayData>, index: usize) -> VortexResult<Sca
         return Ok(Scalar::null(array.dtype().clone()));
     }
 
-    array
+    if let Some(f) = array.encoding().scalar_at_fn() {
+        return f.scalar_at(array, index);
+    }
+
+    // Otherwise, we fall back to the canonical implementations.
+    log::debug!(
+        "Falling back to canonical scalar_at for encoding {}",
+        array.encoding().id(),
+    );
+    let canonical = array.clone().into_canonical()?.into_array();
+    canonical
         .encoding()
         .scalar_at_fn()
-        .map(|f| f.scalar_at(array, index))
-        .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "scalar_at", array.encoding().id())))
+        .ok_or_else(|| vortex_err!(NotImplemented: "scalar_at", canonical.encoding().id()))?
+        .scalar_at(&canonical, index)
 }
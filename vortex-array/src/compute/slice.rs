@@ -2,7 +2,7 @@ use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 
 use crate::array::ConstantArray;
 use crate::encoding::Encoding;
-use crate::{ArrayData, IntoArrayData};
+use crate::{ArrayData, IntoArrayData, IntoCanonical};
 
 /// Limit array to start...stop range
 pub trait SliceFn<Array> {
@@ -45,16 +45,21 @@ pub fn slice(array: impl AsRef<ArrayData>, start: usize, stop: usize) -> VortexR
         return Ok(ConstantArray::new(const_scalar, stop - start).into_array());
     }
 
-    array
+    if let Some(f) = array.encoding().slice_fn() {
+        return f.slice(array, start, stop);
+    }
+
+    // Otherwise, we fall back to the canonical implementations.
+    log::debug!(
+        "Falling back to canonical slice for encoding {}",
+        array.encoding().id(),
+    );
+    let canonical = array.clone().into_canonical()?.into_array();
+    canonical
         .encoding()
         .slice_fn()
-        .map(|f| f.slice(array, start, stop))
-        .unwrap_or_else(|| {
-            Err(vortex_err!(
-                NotImplemented: "slice",
-                array.encoding().id()
-            ))
-        })
+        .ok_or_else(|| vortex_err!(NotImplemented: "slice", canonical.encoding().id()))?
+        .slice(&canonical, start, stop)
 }
 
 fn check_slice_bounds(array: &ArrayData, start: usize, stop: usize) -> VortexResult<()> {
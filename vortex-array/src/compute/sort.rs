@@ -0,0 +1,313 @@
+use std::cmp::Ordering;
+
+use arrow_buffer::NullBuffer;
+use vortex_dtype::{match_each_native_ptype, NativePType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::PrimitiveArray;
+use crate::compute::{take, TakeOptions};
+use crate::encoding::Encoding;
+use crate::stats::ArrayStatistics;
+use crate::validity::ArrayValidity;
+use crate::variants::PrimitiveArrayTrait;
+use crate::{ArrayDType, ArrayData, Canonical, IntoArrayData, IntoCanonical};
+
+/// The order to sort values in.
+///
+/// Nulls are placed first or last regardless of the direction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SortOptions {
+    pub descending: bool,
+    pub nulls_first: bool,
+}
+
+impl SortOptions {
+    fn compare<T>(
+        &self,
+        lhs: Option<T>,
+        rhs: Option<T>,
+        cmp: impl FnOnce(T, T) -> Ordering,
+    ) -> Ordering {
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) if self.descending => cmp(lhs, rhs).reverse(),
+            (Some(lhs), Some(rhs)) => cmp(lhs, rhs),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) if self.nulls_first => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) if self.nulls_first => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+        }
+    }
+}
+
+pub trait SortFn<Array> {
+    /// Sort the values of the array, see [sort].
+    fn sort(&self, array: &Array, options: SortOptions) -> VortexResult<ArrayData>;
+}
+
+impl<E: Encoding> SortFn<ArrayData> for E
+where
+    E: SortFn<E::Array>,
+    for<'a> &'a E::Array: TryFrom<&'a ArrayData, Error = VortexError>,
+{
+    fn sort(&self, array: &ArrayData, options: SortOptions) -> VortexResult<ArrayData> {
+        let array_ref = <&E::Array>::try_from(array)?;
+        let encoding = array
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        SortFn::sort(encoding, array_ref, options)
+    }
+}
+
+/// Sort the values of an array.
+///
+/// Floats are ordered by their total order, binaries and strings by their bytes, and structs
+/// lexicographically by their fields, each in the given direction. Lists can't be sorted.
+///
+/// Arrays whose statistics show they're already sorted are returned as is.
+pub fn sort(array: &ArrayData, options: SortOptions) -> VortexResult<ArrayData> {
+    if let Some(indices) = sorted_indices(array, options) {
+        return match indices {
+            SortedIndices::Identity => Ok(array.clone()),
+            SortedIndices::Reversed => take(array, reversed(array.len()), TakeOptions::default()),
+        };
+    }
+
+    if let Some(f) = array.encoding().sort_fn() {
+        return f.sort(array, options);
+    }
+
+    // Otherwise, we take the values in the order of their canonical argsort.
+    log::debug!(
+        "No sort implementation found for encoding {}",
+        array.encoding().id(),
+    );
+    take(array, argsort(array, options)?, TakeOptions::default())
+}
+
+/// The indices that sort the values of an array, see [sort].
+///
+/// Returns a non-nullable u64 array. The sort is stable, such that equal values keep their
+/// relative order.
+pub fn argsort(array: &ArrayData, options: SortOptions) -> VortexResult<ArrayData> {
+    if let Some(indices) = sorted_indices(array, options) {
+        return Ok(match indices {
+            SortedIndices::Identity => {
+                PrimitiveArray::from((0..array.len() as u64).collect::<Vec<_>>())
+            }
+            SortedIndices::Reversed => reversed(array.len()),
+        }
+        .into_array());
+    }
+
+    let cmp = comparator(array.clone().into_canonical()?, options)?;
+    let mut indices = (0..array.len()).collect::<Vec<_>>();
+    indices.sort_by(|a, b| cmp(*a, *b));
+    Ok(
+        PrimitiveArray::from(indices.into_iter().map(|i| i as u64).collect::<Vec<_>>())
+            .into_array(),
+    )
+}
+
+enum SortedIndices {
+    Identity,
+    Reversed,
+}
+
+/// Short-circuit sorting arrays that have no nulls and whose statistics show they're sorted.
+///
+/// Only strictly sorted arrays can be reversed for a descending sort, as the sort is stable.
+fn sorted_indices(array: &ArrayData, options: SortOptions) -> Option<SortedIndices> {
+    if array.len() <= 1 {
+        return Some(SortedIndices::Identity);
+    }
+    if array.dtype().is_nullable() && array.statistics().compute_null_count() != Some(0) {
+        return None;
+    }
+
+    let stats = array.statistics();
+    if !options.descending && stats.compute_is_sorted() == Some(true) {
+        Some(SortedIndices::Identity)
+    } else if options.descending && stats.compute_is_strict_sorted() == Some(true) {
+        Some(SortedIndices::Reversed)
+    } else {
+        None
+    }
+}
+
+fn reversed(len: usize) -> PrimitiveArray {
+    PrimitiveArray::from((0..len as u64).rev().collect::<Vec<_>>())
+}
+
+type Comparator = Box<dyn Fn(usize, usize) -> Ordering>;
+
+/// Compare the values at two indices of the canonical array.
+fn comparator(canonical: Canonical, options: SortOptions) -> VortexResult<Comparator> {
+    Ok(match canonical {
+        Canonical::Null(_) => Box::new(|_, _| Ordering::Equal),
+        Canonical::Bool(array) => {
+            let values = array.boolean_buffer();
+            with_nulls(
+                array.logical_validity().to_null_buffer()?,
+                options,
+                move |a, b| values.value(a).cmp(&values.value(b)),
+            )
+        }
+        Canonical::Primitive(array) => {
+            let nulls = array.logical_validity().to_null_buffer()?;
+            match_each_native_ptype!(array.ptype(), |$T| {
+                let values = array.maybe_null_slice::<$T>().to_vec();
+                with_nulls(nulls, options, move |a, b| values[a].total_compare(values[b]))
+            })
+        }
+        Canonical::VarBinView(array) => {
+            let values = array
+                .with_iterator(|iter| iter.map(|v| v.map(<[u8]>::to_vec)).collect::<Vec<_>>())?;
+            Box::new(move |a, b| options.compare(values[a].as_ref(), values[b].as_ref(), Ord::cmp))
+        }
+        Canonical::Struct(array) => {
+            let fields = array
+                .children()
+                .map(|field| comparator(field.into_canonical()?, options))
+                .collect::<VortexResult<Vec<_>>>()?;
+            // The fields already compare in the requested direction.
+            let options = SortOptions {
+                descending: false,
+                ..options
+            };
+            with_nulls(
+                array.logical_validity().to_null_buffer()?,
+                options,
+                move |a, b| {
+                    fields
+                        .iter()
+                        .map(|cmp| cmp(a, b))
+                        .find(|o| o.is_ne())
+                        .unwrap_or(Ordering::Equal)
+                },
+            )
+        }
+        Canonical::Extension(array) => comparator(array.storage().into_canonical()?, options)?,
+        Canonical::List(array) => vortex_bail!("Sorting isn't supported for {}", array.dtype()),
+        Canonical::FixedSizeList(array) => {
+            vortex_bail!("Sorting isn't supported for {}", array.dtype())
+        }
+    })
+}
+
+fn with_nulls(
+    nulls: Option<NullBuffer>,
+    options: SortOptions,
+    cmp: impl Fn(usize, usize) -> Ordering + 'static,
+) -> Comparator {
+    match nulls {
+        None => Box::new(move |a, b| options.compare(Some(a), Some(b), &cmp)),
+        Some(nulls) => Box::new(move |a, b| {
+            options.compare(
+                nulls.is_valid(a).then_some(a),
+                nulls.is_valid(b).then_some(b),
+                &cmp,
+            )
+        }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{PrimitiveArray, StructArray, VarBinViewArray};
+    use crate::compute::{argsort, scalar_at, sort, SortOptions};
+    use crate::{ArrayData, IntoArrayData, IntoArrayVariant};
+
+    fn indices(array: &ArrayData, options: SortOptions) -> Vec<u64> {
+        argsort(array, options)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .into_maybe_null_slice()
+    }
+
+    #[test]
+    fn sort_primitive() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(-1), Some(3), Some(7)])
+                .into_array();
+        assert_eq!(indices(&array, SortOptions::default()), vec![2, 0, 3, 4, 1]);
+        assert_eq!(
+            indices(
+                &array,
+                SortOptions {
+                    descending: true,
+                    nulls_first: true,
+                }
+            ),
+            vec![1, 4, 0, 3, 2]
+        );
+
+        let sorted = sort(&array, SortOptions::default()).unwrap();
+        assert_eq!(
+            (0..5)
+                .map(|i| scalar_at(&sorted, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Scalar::from(Some(-1i32)),
+                Scalar::from(Some(3i32)),
+                Scalar::from(Some(3i32)),
+                Scalar::from(Some(7i32)),
+                Scalar::null(DType::from(PType::I32).as_nullable()),
+            ]
+        );
+    }
+
+    #[test]
+    fn sort_sorted() {
+        let array = PrimitiveArray::from(vec![1u8, 2, 5]).into_array();
+        assert_eq!(indices(&array, SortOptions::default()), vec![0, 1, 2]);
+        assert_eq!(
+            indices(
+                &array,
+                SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                }
+            ),
+            vec![2, 1, 0]
+        );
+    }
+
+    #[test]
+    fn sort_struct() {
+        let array = StructArray::from_fields(&[
+            ("a", PrimitiveArray::from(vec![2u8, 1, 2, 1]).into_array()),
+            (
+                "b",
+                VarBinViewArray::from_iter_nullable_str([Some("y"), Some("z"), None, Some("x")])
+                    .into_array(),
+            ),
+        ])
+        .unwrap()
+        .into_array();
+        assert_eq!(indices(&array, SortOptions::default()), vec![3, 1, 0, 2]);
+        assert_eq!(
+            indices(
+                &array,
+                SortOptions {
+                    descending: true,
+                    nulls_first: true,
+                }
+            ),
+            vec![2, 0, 1, 3]
+        );
+
+        let sorted = sort(&array, SortOptions::default()).unwrap();
+        assert_eq!(
+            scalar_at(&sorted, 0).unwrap(),
+            scalar_at(&array, 3).unwrap()
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use num_traits::ToPrimitive;
+use vortex_dtype::{match_each_float_ptype, match_each_integer_ptype, DType, Nullability, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::array::PrimitiveArray;
+use crate::encoding::Encoding;
+use crate::validity::ArrayValidity;
+use crate::variants::PrimitiveArrayTrait;
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
+
+pub trait SumFn<Array> {
+    /// Sum the non-null values of a primitive array, see [sum].
+    fn sum(&self, array: &Array) -> VortexResult<Scalar>;
+}
+
+impl<E: Encoding> SumFn<ArrayData> for E
+where
+    E: SumFn<E::Array>,
+    for<'a> &'a E::Array: TryFrom<&'a ArrayData, Error = VortexError>,
+{
+    fn sum(&self, array: &ArrayData) -> VortexResult<Scalar> {
+        let array_ref = <&E::Array>::try_from(array)?;
+        let encoding = array
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        SumFn::sum(encoding, array_ref)
+    }
+}
+
+/// The ptype that sums of values of the given ptype are computed in: i64 for signed integers,
+/// u64 for unsigned integers and f64 for floats.
+pub fn sum_ptype(ptype: PType) -> PType {
+    if ptype.is_float() {
+        PType::F64
+    } else if ptype.is_signed_int() {
+        PType::I64
+    } else {
+        PType::U64
+    }
+}
+
+/// Sum the non-null values of a primitive array.
+///
+/// Returns a nullable scalar of the array's [`sum_ptype`], which is null if the array has no
+/// non-null values. Integer sums that overflow are an error.
+pub fn sum(array: &ArrayData) -> VortexResult<Scalar> {
+    if !matches!(array.dtype(), DType::Primitive(..)) {
+        vortex_bail!("Expected primitive array, got {}", array.dtype());
+    }
+
+    if let Some(f) = array.encoding().sum_fn() {
+        return f.sum(array);
+    }
+
+    // Otherwise, we canonicalize into a primitive array and sum.
+    log::debug!(
+        "No sum implementation found for encoding {}",
+        array.encoding().id(),
+    );
+    sum(&array.clone().into_primitive()?.into_array())
+}
+
+/// Accumulates the sum of primitive values, see [sum].
+///
+/// Integers are accumulated as i128, such that the partial sums of encodings, e.g. the reference
+/// and the offsets of a frame of reference, can't overflow unless the final sum does.
+#[derive(Debug, Clone)]
+pub struct SumAccumulator {
+    ptype: PType,
+    sum: Option<PartialSum>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum PartialSum {
+    Int(i128),
+    Float(f64),
+}
+
+impl SumAccumulator {
+    /// Create an accumulator for values of the given ptype.
+    pub fn new(ptype: PType) -> Self {
+        Self {
+            ptype: sum_ptype(ptype),
+            sum: None,
+        }
+    }
+
+    /// Add a primitive value `count` times, nulls are ignored.
+    ///
+    /// The value may be of any integer ptype when summing integers, which allows adding partial
+    /// sums.
+    pub fn add(&mut self, value: &Scalar, count: u64) -> VortexResult<()> {
+        if count == 0 {
+            return Ok(());
+        }
+
+        let value = PrimitiveScalar::try_from(value)?;
+        let partial = if self.ptype.is_float() {
+            value
+                .as_::<f64>()?
+                .map(|v| PartialSum::Float(v * count as f64))
+        } else {
+            if value.ptype().is_float() {
+                vortex_bail!(
+                    "Can't add {} values to a sum of {}",
+                    value.ptype(),
+                    self.ptype
+                )
+            }
+            if value.ptype().is_unsigned_int() {
+                value.as_::<u64>()?.map(i128::from)
+            } else {
+                value.as_::<i64>()?.map(i128::from)
+            }
+            .map(|v| {
+                v.checked_mul(i128::from(count))
+                    .map(PartialSum::Int)
+                    .ok_or_else(|| self.overflow())
+            })
+            .transpose()?
+        };
+
+        match partial {
+            Some(partial) => self.add_partial(partial),
+            None => Ok(()),
+        }
+    }
+
+    /// Add the non-null values of a primitive array.
+    pub fn add_primitive(&mut self, array: &PrimitiveArray) -> VortexResult<()> {
+        self.add_primitive_times(array, 1)
+    }
+
+    /// Add the non-null values of a primitive array, each multiplied by `factor`.
+    ///
+    /// Like [add][Self::add], the values may be of any integer ptype when summing integers.
+    pub fn add_primitive_times(&mut self, array: &PrimitiveArray, factor: u64) -> VortexResult<()> {
+        let validity = array.logical_validity();
+        if validity.all_invalid() {
+            return Ok(());
+        }
+        let nulls = validity.to_null_buffer()?;
+        let is_valid = |idx: usize| nulls.as_ref().map_or(true, |n| n.is_valid(idx));
+
+        let partial = if array.ptype().is_float() {
+            match_each_float_ptype!(array.ptype(), |$T| {
+                PartialSum::Float(
+                    array
+                        .maybe_null_slice::<$T>()
+                        .iter()
+                        .enumerate()
+                        .filter(|(idx, _)| is_valid(*idx))
+                        .map(|(_, v)| v.to_f64().unwrap_or(f64::NAN))
+                        .sum(),
+                )
+            })
+        } else {
+            match_each_integer_ptype!(array.ptype(), |$T| {
+                PartialSum::Int(
+                    array
+                        .maybe_null_slice::<$T>()
+                        .iter()
+                        .enumerate()
+                        .filter(|(idx, _)| is_valid(*idx))
+                        .map(|(_, v)| i128::from(*v))
+                        .sum(),
+                )
+            })
+        };
+        let partial = match partial {
+            PartialSum::Int(sum) => PartialSum::Int(
+                sum.checked_mul(i128::from(factor))
+                    .ok_or_else(|| self.overflow())?,
+            ),
+            PartialSum::Float(sum) => PartialSum::Float(sum * factor as f64),
+        };
+        self.add_partial(partial)
+    }
+
+    fn add_partial(&mut self, partial: PartialSum) -> VortexResult<()> {
+        self.sum = Some(match (self.sum, partial) {
+            (None, partial) => partial,
+            (Some(PartialSum::Int(a)), PartialSum::Int(b)) => {
+                PartialSum::Int(a.checked_add(b).ok_or_else(|| self.overflow())?)
+            }
+            (Some(PartialSum::Float(a)), PartialSum::Float(b)) => PartialSum::Float(a + b),
+            _ => vortex_bail!("Can't add integer and float sums"),
+        });
+        Ok(())
+    }
+
+    /// The sum of the values added so far, null if there were none.
+    pub fn finish(self) -> VortexResult<Scalar> {
+        Ok(match (self.sum, self.ptype) {
+            (None, ptype) => Scalar::null(DType::Primitive(ptype, Nullability::Nullable)),
+            (Some(PartialSum::Float(sum)), _) => Scalar::primitive(sum, Nullability::Nullable),
+            (Some(PartialSum::Int(sum)), PType::I64) => Scalar::primitive(
+                i64::try_from(sum).map_err(|_| self.overflow())?,
+                Nullability::Nullable,
+            ),
+            (Some(PartialSum::Int(sum)), _) => Scalar::primitive(
+                u64::try_from(sum).map_err(|_| self.overflow())?,
+                Nullability::Nullable,
+            ),
+        })
+    }
+
+    fn overflow(&self) -> VortexError {
+        vortex_err!(ComputeError: "Sum overflows {}", self.ptype)
+    }
+}
@@ -1,7 +1,8 @@
 use crate::aliases::hash_map::HashMap;
 use crate::array::{
-    BoolEncoding, ChunkedEncoding, ConstantEncoding, ExtensionEncoding, ListEncoding, NullEncoding,
-    PrimitiveEncoding, SparseEncoding, StructEncoding, VarBinEncoding, VarBinViewEncoding,
+    BoolEncoding, ChunkedEncoding, ConstantEncoding, ExtensionEncoding, FixedSizeListEncoding,
+    ListEncoding, NullEncoding, PrimitiveEncoding, SparseEncoding, StructEncoding, VarBinEncoding,
+    VarBinViewEncoding,
 };
 use crate::encoding::EncodingRef;
 
@@ -41,6 +42,7 @@ impl Default for Context {
                 &PrimitiveEncoding,
                 &StructEncoding,
                 &ListEncoding,
+                &FixedSizeListEncoding,
                 &VarBinEncoding,
                 &VarBinViewEncoding,
                 &ExtensionEncoding,
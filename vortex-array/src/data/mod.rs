@@ -12,8 +12,8 @@ use vortex_error::{vortex_err, VortexExpect, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::array::{
-    BoolEncoding, ExtensionEncoding, NullEncoding, PrimitiveEncoding, StructEncoding,
-    VarBinEncoding, VarBinViewEncoding,
+    BoolEncoding, ExtensionEncoding, FixedSizeListEncoding, NullEncoding, PrimitiveEncoding,
+    StructEncoding, VarBinEncoding, VarBinViewEncoding,
 };
 use crate::compute::scalar_at;
 use crate::encoding::{EncodingId, EncodingRef, EncodingVTable};
@@ -128,7 +128,7 @@ impl ArrayData {
                 DType::Utf8(_) => array.as_utf8_array().is_some(),
                 DType::Binary(_) => array.as_binary_array().is_some(),
                 DType::Struct(..) => array.as_struct_array().is_some(),
-                DType::List(..) => array.as_list_array().is_some(),
+                DType::List(..) | DType::FixedSizeList(..) => array.as_list_array().is_some(),
                 DType::Extension(..) => array.as_extension_array().is_some(),
             },
             "Encoding {} does not implement the variant trait for {}",
@@ -170,6 +170,7 @@ impl ArrayData {
             || self.is_encoding(BoolEncoding.id())
             || self.is_encoding(PrimitiveEncoding.id())
             || self.is_encoding(StructEncoding.id())
+            || self.is_encoding(FixedSizeListEncoding.id())
             || self.is_encoding(VarBinViewEncoding.id())
             || self.is_encoding(ExtensionEncoding.id())
     }
@@ -236,6 +237,40 @@ impl ArrayData {
             + if self.buffer().is_some() { 1 } else { 0 }
     }
 
+    /// Return a copy of the array with every buffer of the array and its children replaced by
+    /// `f(buffer)`, visiting the buffers in the same depth-first order as the serialized array.
+    ///
+    /// The encoding, dtype, metadata and statistics of each array are kept, and children are found
+    /// without reading any buffers, so `f` may map the buffers to bytes the encodings can't read,
+    /// e.g. compressed frames, as long as a later mapping restores them.
+    pub fn try_map_buffers(
+        &self,
+        f: &mut impl FnMut(&Buffer) -> VortexResult<Buffer>,
+    ) -> VortexResult<ArrayData> {
+        match &self.0 {
+            InnerArrayData::Owned(d) => {
+                let buffer = d.buffer().map(&mut *f).transpose()?;
+                let children = d
+                    .children()
+                    .iter()
+                    .map(|child| child.try_map_buffers(f))
+                    .collect::<VortexResult<Vec<_>>>()?;
+                Self::try_new_owned(
+                    d.encoding,
+                    d.dtype.clone(),
+                    d.len,
+                    d.metadata().clone(),
+                    buffer,
+                    children.into(),
+                    self.statistics().to_set(),
+                )
+            }
+            InnerArrayData::Viewed(v) => v
+                .try_map_buffers(f)
+                .map(|view| ArrayData(InnerArrayData::Viewed(view))),
+        }
+    }
+
     /// Return the buffer offsets and the total length of all buffers, assuming the given alignment.
     /// This includes all child buffers.
     pub fn all_buffer_offsets(&self, alignment: usize) -> Vec<u64> {
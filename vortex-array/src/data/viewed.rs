@@ -104,6 +104,38 @@ impl ViewedArrayData {
             .buffer_index()
             .map(|idx| &self.buffers[usize::try_from(idx).vortex_unwrap()])
     }
+
+    /// Replace the buffers of this array and its children, walking the flatbuffer rather than the
+    /// encodings so that no buffers are read.
+    pub fn try_map_buffers(
+        &self,
+        f: &mut impl FnMut(&Buffer) -> VortexResult<Buffer>,
+    ) -> VortexResult<Self> {
+        fn visit(
+            array: fb::Array,
+            buffers: &mut [Buffer],
+            f: &mut impl FnMut(&Buffer) -> VortexResult<Buffer>,
+        ) -> VortexResult<()> {
+            if let Some(idx) = array.buffer_index() {
+                let idx = usize::try_from(idx)?;
+                let buffer = buffers
+                    .get(idx)
+                    .ok_or_else(|| vortex_err!(OutOfBounds: idx, 0, buffers.len()))?;
+                buffers[idx] = f(buffer)?;
+            }
+            for child in array.children().iter().flatten() {
+                visit(child, buffers, f)?;
+            }
+            Ok(())
+        }
+
+        let mut buffers = self.buffers.to_vec();
+        visit(self.flatbuffer(), &mut buffers, f)?;
+        Ok(Self {
+            buffers: buffers.into(),
+            ..self.clone()
+        })
+    }
 }
 
 #[derive(Default, Debug)]
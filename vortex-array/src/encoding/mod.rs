@@ -123,10 +123,10 @@ pub mod ids {
     pub const CONSTANT: u16 = 9;
     pub const CHUNKED: u16 = 10;
     pub const LIST: u16 = 11;
+    pub const FIXED_SIZE_LIST: u16 = 12;
 
     // currently unused, saved for future built-ins
-    // e.g., FixedList, Union, Tensor, etc.
-    pub(crate) const RESERVED_12: u16 = 12;
+    // e.g., Union, Tensor, etc.
     pub(crate) const RESERVED_13: u16 = 13;
     pub(crate) const RESERVED_14: u16 = 14;
     pub(crate) const RESERVED_15: u16 = 15;
@@ -147,6 +147,8 @@ pub mod ids {
     pub const RUN_END_BOOL: u16 = 28;
     pub const ZIGZAG: u16 = 29;
     pub const ALP_RD: u16 = 30;
+    pub const ZSTD: u16 = 31;
+    pub const LZ4: u16 = 32;
 }
 
 #[cfg(test)]
@@ -170,7 +172,7 @@ mod tests {
             ids::CONSTANT,
             ids::CHUNKED,
             ids::LIST,
-            ids::RESERVED_12,
+            ids::FIXED_SIZE_LIST,
             ids::RESERVED_13,
             ids::RESERVED_14,
             ids::RESERVED_15,
@@ -188,6 +190,9 @@ mod tests {
             ids::RUN_END,
             ids::RUN_END_BOOL,
             ids::ZIGZAG,
+            ids::ALP_RD,
+            ids::ZSTD,
+            ids::LZ4,
         ];
 
         let mut ids_set = HashSet::with_capacity(all_ids.len());
@@ -180,7 +180,7 @@ impl ArrayData {
     }
 
     pub fn as_list_array(&self) -> Option<&dyn ListArrayTrait> {
-        matches!(self.dtype(), DType::List(..))
+        matches!(self.dtype(), DType::List(..) | DType::FixedSizeList(..))
             .then(|| self.encoding().as_list_array(self))
             .flatten()
     }
@@ -127,6 +127,12 @@ fn can_be_pushed_down(expr: &Expr, schema: &Schema) -> bool {
         Expr::Like(like) => {
             can_be_pushed_down(&like.expr, schema) && can_be_pushed_down(&like.pattern, schema)
         }
+        Expr::InList(in_list) => {
+            can_be_pushed_down(&in_list.expr, schema)
+                && in_list.list.iter().all(|item| {
+                    matches!(item, Expr::Literal(_)) && can_be_pushed_down(item, schema)
+                })
+        }
         Expr::Literal(lit) => supported_data_types(lit.data_type()),
         _ => {
             log::debug!("DataFusion expression can't be pushed down: {:?}", expr);
@@ -311,6 +311,33 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_in_list_pushdown() {
+        let ctx = SessionContext::new();
+
+        let df = ctx.read_mem_vortex(presidents_array()).unwrap();
+
+        let term_starts = df
+            .filter(col("president").in_list(vec![lit("Adams"), lit("Monroe")], false))
+            .unwrap()
+            .aggregate(vec![], vec![count_distinct(col("term_start"))])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *term_starts[0]
+                .column(0)
+                .as_primitive::<Int64Type>()
+                .values()
+                .first()
+                .unwrap(),
+            3i64
+        );
+    }
+
     #[test]
     fn test_can_be_pushed_down0() {
         let e = BinaryExpr {
@@ -374,4 +401,17 @@ mod test {
             ])
         ));
     }
+
+    #[test]
+    fn test_can_be_pushed_down5() {
+        let schema = Schema::new(vec![Field::new("a", DataType::UInt64, true)]);
+        assert!(can_be_pushed_down(
+            &col("a").in_list(vec![lit(1u64), lit(5u64)], false),
+            &schema
+        ));
+        assert!(!can_be_pushed_down(
+            &col("a").in_list(vec![lit(1u64), col("a")], true),
+            &schema
+        ));
+    }
 }
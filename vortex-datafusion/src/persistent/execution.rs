@@ -4,7 +4,7 @@ use std::sync::Arc;
 use datafusion::config::ConfigOptions;
 use datafusion::datasource::listing::PartitionedFile;
 use datafusion::datasource::physical_plan::{FileScanConfig, FileStream};
-use datafusion_common::{project_schema, Result as DFResult, Statistics};
+use datafusion_common::{Result as DFResult, Statistics};
 use datafusion_execution::{SendableRecordBatchStream, TaskContext};
 use datafusion_physical_expr::{EquivalenceProperties, Partitioning, PhysicalExpr};
 use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
@@ -33,12 +33,8 @@ impl VortexExec {
         predicate: Option<Arc<dyn PhysicalExpr>>,
         ctx: Arc<Context>,
     ) -> DFResult<Self> {
-        let projected_schema = project_schema(
-            &file_scan_config.file_schema,
-            file_scan_config.projection.as_ref(),
-        )?;
-
-        let (_schema, mut projected_statistics, orderings) = file_scan_config.project();
+        // The projected schema includes the partition columns.
+        let (projected_schema, mut projected_statistics, orderings) = file_scan_config.project();
 
         // We project our statistics to only the selected columns
         // We must also take care to report in-exact statistics if we have any form of filter
@@ -112,11 +108,19 @@ impl ExecutionPlan for VortexExec {
             .object_store(&self.file_scan_config.object_store_url)?;
 
         let arrow_schema = self.file_scan_config.file_schema.clone();
+        // Partition columns come after the file's columns, and are filled in by the FileStream.
+        let projection = self.file_scan_config.projection.as_ref().map(|projection| {
+            projection
+                .iter()
+                .copied()
+                .filter(|idx| *idx < arrow_schema.fields().len())
+                .collect()
+        });
 
         let opener = VortexFileOpener {
             ctx: self.ctx.clone(),
             object_store,
-            projection: self.file_scan_config.projection.clone(),
+            projection,
             predicate: self.predicate.clone(),
             arrow_schema,
         };
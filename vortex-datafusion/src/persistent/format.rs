@@ -12,14 +12,17 @@ use datafusion_common::stats::Precision;
 use datafusion_common::{
     not_impl_err, ColumnStatistics, DataFusionError, Result as DFResult, Statistics,
 };
+use datafusion_expr::dml::InsertOp;
 use datafusion_expr::Expr;
 use datafusion_physical_expr::{LexRequirement, PhysicalExpr};
+use datafusion_physical_plan::insert::DataSinkExec;
 use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
 use datafusion_physical_plan::ExecutionPlan;
 use object_store::{ObjectMeta, ObjectStore};
 use vortex_array::array::StructArray;
 use vortex_array::arrow::infer_schema;
 use vortex_array::Context;
+use vortex_file::dataset::unify_dtypes;
 use vortex_file::metadata::fetch_metadata;
 use vortex_file::{
     read_initial_bytes, LayoutContext, LayoutDeserializer, LayoutMessageCache, RelativeLayoutCache,
@@ -28,6 +31,7 @@ use vortex_file::{
 use vortex_io::{IoDispatcher, ObjectStoreReadAt};
 
 use super::execution::VortexExec;
+use super::sink::VortexSink;
 use super::statistics::{array_to_col_statistics, uncompressed_col_size};
 use crate::can_be_pushed_down;
 
@@ -72,16 +76,15 @@ impl FileFormat for VortexFormat {
         store: &Arc<dyn ObjectStore>,
         objects: &[ObjectMeta],
     ) -> DFResult<SchemaRef> {
-        let mut file_schemas = Vec::default();
+        let mut file_dtypes = Vec::default();
         for o in objects {
             let os_read_at = ObjectStoreReadAt::new(store.clone(), o.location.clone());
             let initial_read = read_initial_bytes(&os_read_at, o.size as u64).await?;
-            let lazy_dtype = initial_read.lazy_dtype();
-            let s = infer_schema(lazy_dtype.value()?)?;
-            file_schemas.push(s);
+            file_dtypes.push(initial_read.lazy_dtype().value()?.clone());
         }
 
-        let schema = Arc::new(Schema::try_merge(file_schemas)?);
+        // Files written with older schemas are adapted to the unified schema when they're read.
+        let schema = Arc::new(infer_schema(&unify_dtypes(&file_dtypes)?)?);
 
         Ok(schema)
     }
@@ -97,6 +100,7 @@ impl FileFormat for VortexFormat {
         let initial_read = read_initial_bytes(&os_read_at, object.size as u64).await?;
         let layout = initial_read.fb_layout();
         let row_count = layout.row_count();
+        let file_schema = infer_schema(initial_read.lazy_dtype().value()?)?;
 
         let layout_deserializer =
             LayoutDeserializer::new(self.context.clone(), LayoutContext::default().into());
@@ -120,22 +124,24 @@ impl FileFormat for VortexFormat {
             fetch_metadata(os_read_at, io.into(), root_layout, layout_message_cache).await?;
 
         if let Some(metadata) = metadata_table {
-            let mut column_statistics = Vec::with_capacity(table_schema.fields().len());
+            let mut column_statistics =
+                vec![ColumnStatistics::new_unknown(); table_schema.fields().len()];
             let mut total_size = 0_u64;
 
-            for col_stats in metadata.into_iter() {
-                let col_stats = match col_stats {
-                    Some(array) => {
-                        let col_metadata_array = StructArray::try_from(array)?;
-                        let col_stats = array_to_col_statistics(&col_metadata_array)?;
+            // The columns of the file are matched to those of the table by name. Statistics of
+            // columns that were widened don't have the table's type, so they stay unknown.
+            for (file_field, col_stats) in file_schema.fields().iter().zip(metadata) {
+                let Some(array) = col_stats else {
+                    continue;
+                };
+                let col_metadata_array = StructArray::try_from(array)?;
+                total_size += uncompressed_col_size(&col_metadata_array)?.unwrap_or_default();
 
-                        total_size +=
-                            uncompressed_col_size(&col_metadata_array)?.unwrap_or_default();
-                        col_stats
+                if let Some((idx, field)) = table_schema.column_with_name(file_field.name()) {
+                    if field.data_type() == file_field.data_type() {
+                        column_statistics[idx] = array_to_col_statistics(&col_metadata_array)?;
                     }
-                    None => ColumnStatistics::new_unknown(),
-                };
-                column_statistics.push(col_stats);
+                }
             }
             stats.column_statistics = column_statistics;
             stats.total_byte_size = Precision::Inexact(total_size as usize);
@@ -165,12 +171,24 @@ impl FileFormat for VortexFormat {
 
     async fn create_writer_physical_plan(
         &self,
-        _input: Arc<dyn ExecutionPlan>,
+        input: Arc<dyn ExecutionPlan>,
         _state: &SessionState,
-        _conf: FileSinkConfig,
-        _order_requirements: Option<LexRequirement>,
+        conf: FileSinkConfig,
+        order_requirements: Option<LexRequirement>,
     ) -> DFResult<Arc<dyn ExecutionPlan>> {
-        not_impl_err!("Writer not implemented for this format")
+        if conf.insert_op != InsertOp::Append {
+            return not_impl_err!("Overwrites are not implemented yet for Vortex");
+        }
+
+        let sink_schema = conf.output_schema().clone();
+        let sink = Arc::new(VortexSink::new(conf));
+
+        Ok(Arc::new(DataSinkExec::new(
+            input,
+            sink,
+            sink_schema,
+            order_requirements,
+        )) as _)
     }
 
     fn supports_filters_pushdown(
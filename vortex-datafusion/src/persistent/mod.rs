@@ -2,4 +2,5 @@ pub mod config;
 pub mod execution;
 pub mod format;
 pub mod opener;
+pub mod sink;
 pub mod statistics;
@@ -7,7 +7,9 @@ use datafusion_common::Result as DFResult;
 use datafusion_physical_expr::{split_conjunction, PhysicalExpr};
 use futures::{FutureExt as _, StreamExt, TryStreamExt};
 use object_store::ObjectStore;
+use vortex_array::arrow::FromArrowType;
 use vortex_array::Context;
+use vortex_dtype::DType;
 use vortex_expr::datafusion::convert_expr_to_vortex;
 use vortex_file::{LayoutContext, LayoutDeserializer, Projection, RowFilter, VortexReadBuilder};
 use vortex_io::{IoDispatcher, ObjectStoreReadAt};
@@ -33,7 +35,8 @@ impl FileOpener for VortexFileOpener {
             read_at,
             LayoutDeserializer::new(self.ctx.clone(), Arc::new(LayoutContext::default())),
         )
-        .with_io_dispatcher(IO_DISPATCHER.clone());
+        .with_io_dispatcher(IO_DISPATCHER.clone())
+        .with_schema_ignoring_nullability(DType::from_arrow(self.arrow_schema.clone()));
 
         // We split the predicate and filter out the conjunction members that we can't push down
         let row_filter = self
@@ -0,0 +1,354 @@
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, UInt32Array};
+use async_trait::async_trait;
+use datafusion::arrow::compute::take_record_batch;
+use datafusion::arrow::util::display::{ArrayFormatter, FormatOptions};
+use datafusion::datasource::physical_plan::FileSinkConfig;
+use datafusion_common::{exec_err, Result as DFResult};
+use datafusion_execution::{SendableRecordBatchStream, TaskContext};
+use datafusion_physical_plan::insert::DataSink;
+use datafusion_physical_plan::metrics::MetricsSet;
+use datafusion_physical_plan::{DisplayAs, DisplayFormatType};
+use futures::TryStreamExt;
+use itertools::Itertools;
+use object_store::path::Path;
+use object_store::{ObjectStore, WriteMultipart};
+use rand::distributions::{Alphanumeric, DistString};
+use vortex_array::aliases::hash_map::HashMap;
+use vortex_array::compress::CompressionStrategy;
+use vortex_array::ArrayData;
+use vortex_file::dataset::HIVE_DEFAULT_PARTITION;
+use vortex_file::{VortexFileWriter, VORTEX_FILE_EXTENSION};
+use vortex_io::{ObjectStoreWriter, VortexWrite};
+use vortex_sampling_compressor::SamplingCompressor;
+
+/// A DataFusion [`DataSink`] that writes record batches into Vortex files.
+///
+/// Every batch is converted into a Vortex struct array, compressed with the [`SamplingCompressor`]
+/// and appended to the file of the partition it belongs to. Each distinct combination of partition
+/// values gets its own hive-style `col=value/` directory below the table path.
+pub struct VortexSink {
+    config: FileSinkConfig,
+}
+
+impl VortexSink {
+    pub fn new(config: FileSinkConfig) -> Self {
+        Self { config }
+    }
+
+    /// Returns the configuration this sink writes with.
+    pub fn config(&self) -> &FileSinkConfig {
+        &self.config
+    }
+
+    /// Splits a batch by the values of the partition columns, returning the relative directory of
+    /// each partition together with the rows that belong to it.
+    ///
+    /// Partition values are written as is, because DataFusion doesn't decode escaped values when
+    /// it reads them back. Values containing a `/` can't be written.
+    fn partition_batch(&self, batch: RecordBatch) -> DFResult<Vec<(String, RecordBatch)>> {
+        let partition_cols = &self.config.table_partition_cols;
+        if partition_cols.is_empty() {
+            return Ok(vec![(String::new(), batch)]);
+        }
+
+        let schema = batch.schema();
+        let format_options = FormatOptions::default().with_null(HIVE_DEFAULT_PARTITION);
+        let formatters = partition_cols
+            .iter()
+            .map(|(name, _)| {
+                let idx = schema.index_of(name)?;
+                Ok(ArrayFormatter::try_new(
+                    batch.column(idx).as_ref(),
+                    &format_options,
+                )?)
+            })
+            .collect::<DFResult<Vec<_>>>()?;
+
+        let mut partitions: HashMap<String, Vec<u32>> = HashMap::new();
+        for row in 0..batch.num_rows() {
+            let mut dir = String::new();
+            for ((name, _), formatter) in partition_cols.iter().zip(formatters.iter()) {
+                let value = formatter.value(row).to_string();
+                if value.contains(object_store::path::DELIMITER) {
+                    return exec_err!("Partition value {value} of column {name} contains a '/'");
+                }
+                dir.push_str(&format!("{name}={value}/"));
+            }
+            partitions.entry(dir).or_default().push(row as u32);
+        }
+
+        let projection = if self.config.keep_partition_by_columns {
+            None
+        } else {
+            Some(
+                schema
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| !partition_cols.iter().any(|(name, _)| name == f.name()))
+                    .map(|(idx, _)| idx)
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        partitions
+            .into_iter()
+            .map(|(dir, rows)| {
+                let taken = take_record_batch(&batch, &UInt32Array::from(rows))?;
+                let taken = match projection.as_ref() {
+                    Some(projection) => taken.project(projection)?,
+                    None => taken,
+                };
+                Ok((dir, taken))
+            })
+            .collect()
+    }
+
+    /// Resolves the object store location a partition's data is written to.
+    fn output_path(&self, partition_dir: &str, write_id: &str) -> DFResult<Path> {
+        let Some(base) = self.config.table_paths.first() else {
+            return exec_err!("Vortex sink requires at least one table path");
+        };
+
+        // A table path that names a single file receives all data directly.
+        if !base.is_collection() && base.file_extension().is_some() {
+            if !self.config.table_partition_cols.is_empty() {
+                return exec_err!("Cannot write a partitioned Vortex table into a single file");
+            }
+            return Ok(base.prefix().clone());
+        }
+
+        let mut location = base.prefix().as_ref().to_string();
+        if !location.is_empty() && !location.ends_with(object_store::path::DELIMITER) {
+            location.push_str(object_store::path::DELIMITER);
+        }
+        location.push_str(partition_dir);
+        location.push_str(&format!("{write_id}.{VORTEX_FILE_EXTENSION}"));
+
+        Ok(Path::parse(location)?)
+    }
+}
+
+impl fmt::Debug for VortexSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VortexSink")
+            .field("table_paths", &self.config.table_paths)
+            .field("table_partition_cols", &self.config.table_partition_cols)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DisplayAs for VortexSink {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(
+                    f,
+                    "VortexSink(table_paths=[{}])",
+                    self.config
+                        .table_paths
+                        .iter()
+                        .map(|p| p.as_str())
+                        .join(", ")
+                )
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DataSink for VortexSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        None
+    }
+
+    async fn write_all(
+        &self,
+        mut data: SendableRecordBatchStream,
+        context: &Arc<TaskContext>,
+    ) -> DFResult<u64> {
+        let object_store = context
+            .runtime_env()
+            .object_store(&self.config.object_store_url)?;
+        let write_id = Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+        let compressor = SamplingCompressor::default();
+
+        // On error, the writers that haven't finished are dropped, which aborts their uploads.
+        let mut writers: HashMap<Path, VortexFileWriter<ObjectStoreWriter>> = HashMap::new();
+        let mut row_count = 0_u64;
+
+        while let Some(batch) = data.try_next().await? {
+            row_count += batch.num_rows() as u64;
+
+            for (partition_dir, batch) in self.partition_batch(batch)? {
+                if batch.num_rows() == 0 {
+                    continue;
+                }
+
+                let path = self.output_path(&partition_dir, &write_id)?;
+                let writer = match writers.remove(&path) {
+                    Some(writer) => writer,
+                    None => new_writer(&object_store, &path).await?,
+                };
+
+                let array =
+                    CompressionStrategy::compress(&compressor, &ArrayData::try_from(batch)?)?;
+                writers.insert(path, writer.write_array_columns(array).await?);
+            }
+        }
+
+        for writer in writers.into_values() {
+            writer.finalize().await?.shutdown().await?;
+        }
+
+        Ok(row_count)
+    }
+}
+
+async fn new_writer(
+    object_store: &Arc<dyn ObjectStore>,
+    path: &Path,
+) -> DFResult<VortexFileWriter<ObjectStoreWriter>> {
+    let upload = object_store.put_multipart(path).await?;
+    Ok(VortexFileWriter::new(ObjectStoreWriter::new(
+        WriteMultipart::new(upload),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_schema::{DataType, Field, Schema};
+    use datafusion::datasource::listing::{
+        ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
+    };
+    use datafusion::prelude::SessionContext;
+    use datafusion_common::cast::{as_int64_array, as_string_array};
+    use tempfile::tempdir;
+    use vortex_array::compress::CompressionStrategy;
+    use vortex_array::Context;
+    use vortex_sampling_compressor::SamplingCompressor;
+
+    use crate::persistent::format::VortexFormat;
+
+    fn register_table(
+        ctx: &SessionContext,
+        dir: &std::path::Path,
+        partition_cols: Vec<(String, DataType)>,
+    ) {
+        let context =
+            Context::default().with_encodings(SamplingCompressor::default().used_encodings());
+        let format = Arc::new(VortexFormat::new(&context));
+        let table_url = ListingTableUrl::parse(format!("{}/", dir.to_str().unwrap())).unwrap();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let config = ListingTableConfig::new(table_url)
+            .with_listing_options(
+                ListingOptions::new(format as _).with_table_partition_cols(partition_cols),
+            )
+            .with_schema(schema);
+
+        ctx.register_table(
+            "vortex_tbl",
+            Arc::new(ListingTable::try_new(config).unwrap()),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn insert_into() {
+        let dir = tempdir().unwrap();
+        let ctx = SessionContext::new();
+        register_table(&ctx, dir.path(), vec![]);
+
+        ctx.sql("INSERT INTO vortex_tbl VALUES (1, 'foo'), (2, 'bar'), (3, 'baz')")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        ctx.sql("INSERT INTO vortex_tbl VALUES (4, 'qux')")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let batches = ctx
+            .sql("SELECT sum(a) AS total FROM vortex_tbl WHERE b != 'bar'")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(as_int64_array(batches[0].column(0)).unwrap().value(0), 8);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn insert_into_partitioned() {
+        let dir = tempdir().unwrap();
+        let ctx = SessionContext::new();
+        register_table(&ctx, dir.path(), vec![("p".to_string(), DataType::Utf8)]);
+
+        ctx.sql("INSERT INTO vortex_tbl VALUES (1, 'foo', 'x'), (2, 'bar', 'y'), (3, 'baz', 'x')")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert!(dir.path().join("p=x").is_dir());
+        assert!(dir.path().join("p=y").is_dir());
+
+        // Partition values are written as is, and read back by DataFusion.
+        ctx.sql("INSERT INTO vortex_tbl VALUES (4, 'qux', 'b=c')")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert!(dir.path().join("p=b=c").is_dir());
+        let batches = ctx
+            .sql("SELECT a, p FROM vortex_tbl WHERE p = 'b=c'")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(as_int64_array(batches[0].column(0)).unwrap().values(), &[4]);
+        assert_eq!(
+            as_string_array(batches[0].column(1)).unwrap().value(0),
+            "b=c"
+        );
+
+        // Values that would span directories are rejected.
+        assert!(ctx
+            .sql("INSERT INTO vortex_tbl VALUES (5, 'quux', 'a/b')")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .is_err());
+
+        let batches = ctx
+            .sql("SELECT sum(a) AS total FROM vortex_tbl WHERE p = 'x'")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(as_int64_array(batches[0].column(0)).unwrap().value(0), 4);
+    }
+}
@@ -2,18 +2,23 @@
 
 use std::sync::Arc;
 
-use arrow_schema::{DataType, TimeUnit as ArrowTimeUnit};
-use vortex_dtype::{ExtDType, PType};
+use arrow_schema::{DataType, IntervalUnit as ArrowIntervalUnit, TimeUnit as ArrowTimeUnit};
+use vortex_dtype::{ExtDType, Nullability, PType};
 use vortex_error::{vortex_bail, vortex_panic, VortexError, VortexExpect as _, VortexResult};
 
-use crate::temporal::{TemporalMetadata, DATE_ID, TIMESTAMP_ID, TIME_ID};
+use crate::interval::{is_interval_ext_type, IntervalUnit};
+use crate::temporal::{TemporalMetadata, DATE_ID, DURATION_ID, TIMESTAMP_ID, TIME_ID};
 use crate::unit::TimeUnit;
 
 /// Construct an extension type from the provided temporal Arrow type.
 ///
-/// Supported types are Date32, Date64, Time32, Time64, Timestamp.
+/// Supported types are Date32, Date64, Time32, Time64, Timestamp, Duration and Interval.
 pub fn make_temporal_ext_dtype(data_type: &DataType) -> ExtDType {
-    assert!(data_type.is_temporal(), "Must receive a temporal DataType");
+    assert!(
+        data_type.is_temporal()
+            || matches!(data_type, DataType::Duration(_) | DataType::Interval(_)),
+        "Must receive a temporal DataType"
+    );
 
     match data_type {
         DataType::Timestamp(time_unit, time_zone) => {
@@ -52,14 +57,30 @@ pub fn make_temporal_ext_dtype(data_type: &DataType) -> ExtDType {
             Arc::new(PType::I64.into()),
             Some(TemporalMetadata::Date(TimeUnit::Ms).into()),
         ),
+        DataType::Duration(time_unit) => ExtDType::new(
+            DURATION_ID.clone(),
+            Arc::new(PType::I64.into()),
+            Some(TemporalMetadata::Duration(time_unit.into()).into()),
+        ),
+        DataType::Interval(interval_unit) => {
+            IntervalUnit::from(interval_unit).ext_dtype(Nullability::NonNullable)
+        }
         _ => unimplemented!("{data_type} conversion"),
     }
 }
 
 /// Convert temporal ExtDType to a corresponding arrow DataType
 ///
-/// panics if the ext_dtype is not a temporal dtype
+/// panics if the ext_dtype is not a temporal or interval dtype
 pub fn make_arrow_temporal_dtype(ext_dtype: &ExtDType) -> DataType {
+    if is_interval_ext_type(ext_dtype.id()) {
+        return DataType::Interval(
+            IntervalUnit::try_from(ext_dtype)
+                .vortex_expect("Interval ExtDType must have a valid unit")
+                .into(),
+        );
+    }
+
     match TemporalMetadata::try_from(ext_dtype)
         .vortex_expect("make_arrow_temporal_dtype must be called with a temporal ExtDType")
     {
@@ -88,6 +109,10 @@ pub fn make_arrow_temporal_dtype(ext_dtype: &ExtDType) -> DataType {
                 vortex_panic!(InvalidArgument: "Invalid TimeUnit {} for {}", time_unit, ext_dtype.id())
             }
         },
+        TemporalMetadata::Duration(time_unit) => DataType::Duration(
+            ArrowTimeUnit::try_from(time_unit)
+                .unwrap_or_else(|err| vortex_panic!(InvalidArgument: "{}", err)),
+        ),
     }
 }
 
@@ -108,6 +133,32 @@ impl From<ArrowTimeUnit> for TimeUnit {
     }
 }
 
+impl From<&ArrowIntervalUnit> for IntervalUnit {
+    fn from(value: &ArrowIntervalUnit) -> Self {
+        (*value).into()
+    }
+}
+
+impl From<ArrowIntervalUnit> for IntervalUnit {
+    fn from(value: ArrowIntervalUnit) -> Self {
+        match value {
+            ArrowIntervalUnit::YearMonth => Self::YearMonth,
+            ArrowIntervalUnit::DayTime => Self::DayTime,
+            ArrowIntervalUnit::MonthDayNano => Self::MonthDayNano,
+        }
+    }
+}
+
+impl From<IntervalUnit> for ArrowIntervalUnit {
+    fn from(value: IntervalUnit) -> Self {
+        match value {
+            IntervalUnit::YearMonth => Self::YearMonth,
+            IntervalUnit::DayTime => Self::DayTime,
+            IntervalUnit::MonthDayNano => Self::MonthDayNano,
+        }
+    }
+}
+
 impl TryFrom<TimeUnit> for ArrowTimeUnit {
     type Error = VortexError;
 
@@ -201,4 +252,30 @@ mod tests {
         let rt_ext_dtype = make_temporal_ext_dtype(&expected_arrow_type);
         assert_eq!(ext_dtype, rt_ext_dtype);
     }
+
+    #[test]
+    fn test_make_arrow_duration() {
+        let ext_dtype = ExtDType::new(
+            DURATION_ID.clone(),
+            Arc::new(PType::I64.into()),
+            Some(TemporalMetadata::Duration(TimeUnit::Us).into()),
+        );
+        let expected_arrow_type = DataType::Duration(ArrowTimeUnit::Microsecond);
+        let arrow_dtype = make_arrow_temporal_dtype(&ext_dtype);
+        assert_eq!(arrow_dtype, expected_arrow_type);
+
+        let rt_ext_dtype = make_temporal_ext_dtype(&expected_arrow_type);
+        assert_eq!(ext_dtype, rt_ext_dtype);
+    }
+
+    #[test]
+    fn test_make_arrow_interval() {
+        let ext_dtype = IntervalUnit::MonthDayNano.ext_dtype(Nullability::NonNullable);
+        let expected_arrow_type = DataType::Interval(ArrowIntervalUnit::MonthDayNano);
+        let arrow_dtype = make_arrow_temporal_dtype(&ext_dtype);
+        assert_eq!(arrow_dtype, expected_arrow_type);
+
+        let rt_ext_dtype = make_temporal_ext_dtype(&expected_arrow_type);
+        assert_eq!(ext_dtype, rt_ext_dtype);
+    }
 }
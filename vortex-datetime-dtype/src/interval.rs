@@ -0,0 +1,127 @@
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, LazyLock};
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use serde::{Deserialize, Serialize};
+use vortex_dtype::{
+    DType, ExtDType, ExtID, ExtMetadata, FieldNames, Nullability, PType, StructDType,
+};
+use vortex_error::{vortex_bail, vortex_err, VortexError};
+
+pub static INTERVAL_ID: LazyLock<ExtID> = LazyLock::new(|| ExtID::from("vortex.interval"));
+
+pub const INTERVAL_MONTHS_FIELD: &str = "months";
+pub const INTERVAL_DAYS_FIELD: &str = "days";
+pub const INTERVAL_MILLISECONDS_FIELD: &str = "milliseconds";
+pub const INTERVAL_NANOSECONDS_FIELD: &str = "nanoseconds";
+
+pub fn is_interval_ext_type(id: &ExtID) -> bool {
+    id == &*INTERVAL_ID
+}
+
+/// The calendar components an interval is made of, matching Arrow's `IntervalUnit`.
+///
+/// Unlike the other temporal types, intervals don't have a single time unit: months and days
+/// vary in length, so each component is stored separately.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, IntoPrimitive, TryFromPrimitive,
+)]
+#[repr(u8)]
+pub enum IntervalUnit {
+    /// An `i32` number of months.
+    YearMonth,
+    /// An `i32` number of days and an `i32` number of milliseconds.
+    DayTime,
+    /// An `i32` number of months, an `i32` number of days and an `i64` number of nanoseconds.
+    MonthDayNano,
+}
+
+impl IntervalUnit {
+    /// The dtype of the storage array of intervals with this unit.
+    ///
+    /// Year-month intervals are stored as primitive `i32` months, the other units as a struct of
+    /// their non-nullable components.
+    pub fn storage_dtype(&self, nullability: Nullability) -> DType {
+        let component = |ptype: PType| DType::Primitive(ptype, Nullability::NonNullable);
+        match self {
+            Self::YearMonth => DType::Primitive(PType::I32, nullability),
+            Self::DayTime => DType::Struct(
+                StructDType::new(
+                    FieldNames::from([
+                        INTERVAL_DAYS_FIELD.into(),
+                        INTERVAL_MILLISECONDS_FIELD.into(),
+                    ]),
+                    vec![component(PType::I32), component(PType::I32)],
+                ),
+                nullability,
+            ),
+            Self::MonthDayNano => DType::Struct(
+                StructDType::new(
+                    FieldNames::from([
+                        INTERVAL_MONTHS_FIELD.into(),
+                        INTERVAL_DAYS_FIELD.into(),
+                        INTERVAL_NANOSECONDS_FIELD.into(),
+                    ]),
+                    vec![
+                        component(PType::I32),
+                        component(PType::I32),
+                        component(PType::I64),
+                    ],
+                ),
+                nullability,
+            ),
+        }
+    }
+
+    /// The extension dtype of intervals with this unit.
+    pub fn ext_dtype(&self, nullability: Nullability) -> ExtDType {
+        ExtDType::new(
+            INTERVAL_ID.clone(),
+            Arc::new(self.storage_dtype(nullability)),
+            Some(ExtMetadata::from([u8::from(*self)].as_slice())),
+        )
+    }
+}
+
+impl Display for IntervalUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::YearMonth => write!(f, "year-month"),
+            Self::DayTime => write!(f, "day-time"),
+            Self::MonthDayNano => write!(f, "month-day-nano"),
+        }
+    }
+}
+
+impl TryFrom<&ExtDType> for IntervalUnit {
+    type Error = VortexError;
+
+    fn try_from(ext_dtype: &ExtDType) -> Result<Self, Self::Error> {
+        if !is_interval_ext_type(ext_dtype.id()) {
+            vortex_bail!("Expected {}, found {}", *INTERVAL_ID, ext_dtype.id());
+        }
+        let tag = ext_dtype
+            .metadata()
+            .and_then(|metadata| metadata.as_ref().first().copied())
+            .ok_or_else(|| vortex_err!("Interval ExtDType is missing metadata"))?;
+        Self::try_from(tag).map_err(|e| vortex_err!(ComputeError: "invalid interval unit tag: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_interval_unit() {
+        for unit in [
+            IntervalUnit::YearMonth,
+            IntervalUnit::DayTime,
+            IntervalUnit::MonthDayNano,
+        ] {
+            let ext_dtype = unit.ext_dtype(Nullability::Nullable);
+            assert!(ext_dtype.storage_dtype().is_nullable());
+            assert_eq!(IntervalUnit::try_from(&ext_dtype).unwrap(), unit);
+        }
+    }
+}
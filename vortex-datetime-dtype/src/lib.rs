@@ -1,6 +1,8 @@
 pub mod arrow;
+mod interval;
 mod temporal;
 mod unit;
 
+pub use interval::*;
 pub use temporal::*;
 pub use unit::*;
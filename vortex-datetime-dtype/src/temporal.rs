@@ -2,7 +2,7 @@ use std::fmt::Display;
 use std::sync::{Arc, LazyLock};
 
 use jiff::civil::{Date, Time};
-use jiff::{Timestamp, Zoned};
+use jiff::{Span, Timestamp, Zoned};
 use vortex_dtype::ExtID;
 
 use crate::unit::TimeUnit;
@@ -10,9 +10,10 @@ use crate::unit::TimeUnit;
 pub static TIME_ID: LazyLock<ExtID> = LazyLock::new(|| ExtID::from("vortex.time"));
 pub static DATE_ID: LazyLock<ExtID> = LazyLock::new(|| ExtID::from("vortex.date"));
 pub static TIMESTAMP_ID: LazyLock<ExtID> = LazyLock::new(|| ExtID::from("vortex.timestamp"));
+pub static DURATION_ID: LazyLock<ExtID> = LazyLock::new(|| ExtID::from("vortex.duration"));
 
 pub fn is_temporal_ext_type(id: &ExtID) -> bool {
-    [&DATE_ID as &ExtID, &TIME_ID, &TIMESTAMP_ID].contains(&id)
+    [&DATE_ID as &ExtID, &TIME_ID, &TIMESTAMP_ID, &DURATION_ID].contains(&id)
 }
 
 /// Metadata for TemporalArray.
@@ -23,6 +24,7 @@ pub enum TemporalMetadata {
     Time(TimeUnit),
     Date(TimeUnit),
     Timestamp(TimeUnit, Option<String>),
+    Duration(TimeUnit),
 }
 
 pub enum TemporalJiff {
@@ -30,6 +32,7 @@ pub enum TemporalJiff {
     Date(Date),
     Timestamp(Timestamp),
     Zoned(Zoned),
+    Span(Span),
 }
 
 impl Display for TemporalJiff {
@@ -39,6 +42,7 @@ impl Display for TemporalJiff {
             TemporalJiff::Date(d) => write!(f, "{}", d),
             TemporalJiff::Timestamp(ts) => write!(f, "{}", ts),
             TemporalJiff::Zoned(z) => write!(f, "{}", z),
+            TemporalJiff::Span(s) => write!(f, "{}", s),
         }
     }
 }
@@ -51,7 +55,8 @@ impl TemporalMetadata {
         match self {
             TemporalMetadata::Time(time_unit)
             | TemporalMetadata::Date(time_unit)
-            | TemporalMetadata::Timestamp(time_unit, _) => *time_unit,
+            | TemporalMetadata::Timestamp(time_unit, _)
+            | TemporalMetadata::Duration(time_unit) => *time_unit,
         }
     }
 
@@ -91,6 +96,10 @@ impl TemporalMetadata {
                     .checked_add(unit.to_jiff_span(v)?)?
                     .intz(tz)?,
             )),
+            TemporalMetadata::Duration(TimeUnit::D) => {
+                vortex_bail!("Invalid TimeUnit TimeUnit::D for TemporalMetadata::Duration")
+            }
+            TemporalMetadata::Duration(unit) => Ok(TemporalJiff::Span(unit.to_jiff_span(v)?)),
         }
     }
 }
@@ -111,6 +120,7 @@ macro_rules! impl_temporal_metadata_try_from {
                     x if x == TIME_ID.as_ref() => decode_time_metadata(metadata),
                     x if x == DATE_ID.as_ref() => decode_date_metadata(metadata),
                     x if x == TIMESTAMP_ID.as_ref() => decode_timestamp_metadata(metadata),
+                    x if x == DURATION_ID.as_ref() => decode_duration_metadata(metadata),
                     _ => {
                         vortex_bail!("ExtDType must be one of the known temporal types")
                     }
@@ -139,6 +149,13 @@ fn decode_time_metadata(ext_meta: &ExtMetadata) -> VortexResult<TemporalMetadata
     Ok(TemporalMetadata::Time(time_unit))
 }
 
+fn decode_duration_metadata(ext_meta: &ExtMetadata) -> VortexResult<TemporalMetadata> {
+    let tag = ext_meta.as_ref()[0];
+    let time_unit =
+        TimeUnit::try_from(tag).map_err(|e| vortex_err!(ComputeError: "invalid unit tag: {e}"))?;
+    Ok(TemporalMetadata::Duration(time_unit))
+}
+
 fn decode_timestamp_metadata(ext_meta: &ExtMetadata) -> VortexResult<TemporalMetadata> {
     let tag = ext_meta.as_ref()[0];
     let time_unit =
@@ -160,9 +177,11 @@ impl From<TemporalMetadata> for ExtMetadata {
     /// an `ExtensionArray`.
     fn from(value: TemporalMetadata) -> Self {
         match value {
-            // Time32/Time64 and Date32/Date64 only need to encode the unit in their metadata
+            // Time32/Time64, Date32/Date64 and Duration only need to encode the unit in their metadata
             // The unit also unambiguously maps to the integer width of the backing array for all.
-            TemporalMetadata::Time(time_unit) | TemporalMetadata::Date(time_unit) => {
+            TemporalMetadata::Time(time_unit)
+            | TemporalMetadata::Date(time_unit)
+            | TemporalMetadata::Duration(time_unit) => {
                 let mut meta = Vec::new();
                 let unit_tag: u8 = time_unit.into();
                 meta.push(unit_tag);
@@ -20,6 +20,7 @@ fn random_dtype(u: &mut Unstructured<'_>, depth: u8) -> Result<DType> {
         4 => DType::Struct(random_struct_dtype(u, depth - 1)?, u.arbitrary()?),
         // Null,
         // List(Arc<DType>, Nullability),
+        // FixedSizeList(Arc<DType>, u32, Nullability),
         // Extension(ExtDType, Nullability),
         _ => unreachable!("Number out of range"),
     })
@@ -0,0 +1,162 @@
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, LazyLock};
+
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::{DType, ExtDType, ExtID, ExtMetadata, FieldNames, Nullability, PType, StructDType};
+
+/// The id of the well-known decimal extension type.
+pub static DECIMAL_ID: LazyLock<ExtID> = LazyLock::new(|| ExtID::from("vortex.decimal"));
+
+/// The maximum precision of decimals whose unscaled values fit into an `i64`.
+pub const MAX_DECIMAL64_PRECISION: u8 = 18;
+/// The maximum precision of decimals whose unscaled values fit into an `i128`.
+pub const MAX_DECIMAL128_PRECISION: u8 = 38;
+/// The maximum precision of decimals whose unscaled values fit into an `i256`.
+pub const MAX_DECIMAL256_PRECISION: u8 = 76;
+
+/// Returns whether the extension type is the well-known decimal type.
+pub fn is_decimal_ext_type(id: &ExtID) -> bool {
+    id == &*DECIMAL_ID
+}
+
+/// The parameters of a fixed-point decimal type.
+///
+/// Decimals are stored as extension types over their unscaled integer values, i.e. the value
+/// `123.45` of a decimal with scale 2 is stored as `12345`. Decimals with a precision of up to 18
+/// digits are stored as `i64`. Wider decimals are stored as a struct of 64-bit words in two's
+/// complement, most significant first: the first word `w0` is an `i64`, all following words are
+/// `u64`. Both forms sort like the decimal values they hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecimalDType {
+    precision: u8,
+    scale: i8,
+}
+
+impl DecimalDType {
+    /// Create a decimal type with the given number of significant digits, of which `scale` are
+    /// after the decimal point.
+    pub fn try_new(precision: u8, scale: i8) -> VortexResult<Self> {
+        if precision == 0 || precision > MAX_DECIMAL256_PRECISION {
+            vortex_bail!(
+                "Decimal precision must be between 1 and {MAX_DECIMAL256_PRECISION}, got {precision}"
+            );
+        }
+        if scale > 0 && scale as u8 > precision {
+            vortex_bail!("Decimal scale {scale} must not exceed precision {precision}");
+        }
+        Ok(Self { precision, scale })
+    }
+
+    /// The number of significant digits.
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// The number of digits after the decimal point.
+    pub fn scale(&self) -> i8 {
+        self.scale
+    }
+
+    /// The number of 64-bit words holding each unscaled value.
+    pub fn words(&self) -> usize {
+        match self.precision {
+            p if p <= MAX_DECIMAL64_PRECISION => 1,
+            p if p <= MAX_DECIMAL128_PRECISION => 2,
+            _ => 4,
+        }
+    }
+
+    /// The dtype of the storage of the unscaled values.
+    pub fn storage_dtype(&self, nullability: Nullability) -> DType {
+        match self.words() {
+            1 => DType::Primitive(PType::I64, nullability),
+            words => DType::Struct(
+                StructDType::new(
+                    FieldNames::from(
+                        (0..words)
+                            .map(|i| Arc::from(format!("w{i}")))
+                            .collect::<Vec<_>>(),
+                    ),
+                    (0..words)
+                        .map(|i| {
+                            DType::Primitive(
+                                if i == 0 { PType::I64 } else { PType::U64 },
+                                Nullability::NonNullable,
+                            )
+                        })
+                        .collect(),
+                ),
+                nullability,
+            ),
+        }
+    }
+
+    /// The extension dtype of decimals of this type.
+    pub fn ext_dtype(&self, nullability: Nullability) -> ExtDType {
+        ExtDType::new(
+            DECIMAL_ID.clone(),
+            Arc::new(self.storage_dtype(nullability)),
+            Some(ExtMetadata::from(
+                [self.precision, self.scale as u8].as_slice(),
+            )),
+        )
+    }
+}
+
+impl Display for DecimalDType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "decimal({}, {})", self.precision, self.scale)
+    }
+}
+
+impl TryFrom<&ExtDType> for DecimalDType {
+    type Error = VortexError;
+
+    fn try_from(ext_dtype: &ExtDType) -> Result<Self, Self::Error> {
+        if !is_decimal_ext_type(ext_dtype.id()) {
+            vortex_bail!("Expected {}, found {}", *DECIMAL_ID, ext_dtype.id());
+        }
+        let metadata = ext_dtype
+            .metadata()
+            .ok_or_else(|| vortex_err!("Decimal ExtDType is missing metadata"))?;
+        let [precision, scale] = metadata.as_ref() else {
+            vortex_bail!(
+                "Invalid decimal metadata of {} bytes",
+                metadata.as_ref().len()
+            );
+        };
+        Self::try_new(*precision, *scale as i8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn storage_dtypes() {
+        let narrow = DecimalDType::try_new(10, 2).unwrap();
+        assert_eq!(
+            narrow.storage_dtype(Nullability::Nullable),
+            DType::Primitive(PType::I64, Nullability::Nullable)
+        );
+
+        let wide = DecimalDType::try_new(38, -3).unwrap();
+        let DType::Struct(st, _) = wide.storage_dtype(Nullability::NonNullable) else {
+            unreachable!("Expected struct storage")
+        };
+        assert_eq!(st.names().len(), 2);
+        assert_eq!(DecimalDType::try_new(76, 0).unwrap().words(), 4);
+
+        let ext_dtype = wide.ext_dtype(Nullability::NonNullable);
+        assert_eq!(DecimalDType::try_from(&ext_dtype).unwrap(), wide);
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert!(DecimalDType::try_new(0, 0).is_err());
+        assert!(DecimalDType::try_new(77, 0).is_err());
+        assert!(DecimalDType::try_new(5, 6).is_err());
+    }
+}
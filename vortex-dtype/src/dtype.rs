@@ -3,7 +3,7 @@ use std::hash::Hash;
 use std::sync::Arc;
 
 use itertools::Itertools;
-use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexExpect, VortexResult};
 use DType::*;
 
 use crate::field::Field;
@@ -14,6 +14,8 @@ use crate::{ExtDType, PType};
 pub type FieldName = Arc<str>;
 /// An ordered list of field names in a struct
 pub type FieldNames = Arc<[FieldName]>;
+/// A stable identifier for a field in a struct, which survives renames of the field
+pub type FieldId = u32;
 
 /// The logical types of elements in Vortex arrays.
 ///
@@ -37,6 +39,9 @@ pub enum DType {
     Struct(StructDType, Nullability),
     /// A variable-length list type, parameterized by a single element DType
     List(Arc<DType>, Nullability),
+    /// A fixed-size list type, parameterized by a single element DType and the number of elements
+    /// in every list
+    FixedSizeList(Arc<DType>, u32, Nullability),
     /// User-defined extension types
     Extension(Arc<ExtDType>),
 }
@@ -62,6 +67,7 @@ impl DType {
             Binary(n) => matches!(n, Nullable),
             Struct(_, n) => matches!(n, Nullable),
             List(_, n) => matches!(n, Nullable),
+            FixedSizeList(_, _, n) => matches!(n, Nullable),
             Extension(ext_dtype) => ext_dtype.storage_dtype().is_nullable(),
         }
     }
@@ -86,6 +92,7 @@ impl DType {
             Binary(_) => Binary(nullability),
             Struct(st, _) => Struct(st.clone(), nullability),
             List(c, _) => List(c.clone(), nullability),
+            FixedSizeList(c, size, _) => FixedSizeList(c.clone(), *size, nullability),
             Extension(ext) => Extension(Arc::new(ext.with_nullability(nullability))),
         }
     }
@@ -140,6 +147,14 @@ impl DType {
             _ => None,
         }
     }
+
+    /// Get the inner dtype and list size if `self` is a `FixedSizeList`, otherwise `None`
+    pub fn as_fixed_size_list(&self) -> Option<(&DType, u32)> {
+        match self {
+            FixedSizeList(s, size, _) => Some((s.as_ref(), *size)),
+            _ => None,
+        }
+    }
 }
 
 impl Display for DType {
@@ -161,6 +176,7 @@ impl Display for DType {
                 n
             ),
             List(edt, n) => write!(f, "list({}){}", edt, n),
+            FixedSizeList(edt, size, n) => write!(f, "fixed_size_list({}, {}){}", edt, size, n),
             Extension(ext) => write!(
                 f,
                 "ext({}, {}{}){}",
@@ -177,11 +193,51 @@ impl Display for DType {
 }
 
 /// A struct dtype is a list of names and corresponding dtypes
-#[derive(Debug, Clone, PartialOrd, PartialEq, Eq, Hash)]
+///
+/// Fields may optionally carry stable [`FieldId`]s, which allow resolving fields across versions
+/// of a schema in which they were renamed or reordered. Field IDs are an annotation, and don't
+/// take part in comparing or hashing struct dtypes, so that arrays whose dtype is rebuilt by
+/// compute functions still match the dtype they were read with.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct StructDType(Arc<StructFields>);
+
+/// The fields of a struct dtype, shared such that cloning a dtype doesn't copy them
+#[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct StructDType {
+struct StructFields {
     names: FieldNames,
     dtypes: Arc<[DType]>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    field_ids: Option<Arc<[FieldId]>>,
+}
+
+impl PartialEq for StructDType {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.names == other.0.names && self.0.dtypes == other.0.dtypes
+    }
+}
+
+impl Eq for StructDType {}
+
+impl PartialOrd for StructDType {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (&self.0.names, &self.0.dtypes).partial_cmp(&(&other.0.names, &other.0.dtypes))
+    }
+}
+
+impl Hash for StructDType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.names.hash(state);
+        self.0.dtypes.hash(state);
+    }
 }
 
 /// Information about a field in a struct dtype
@@ -204,21 +260,58 @@ impl StructDType {
                 dtypes.len()
             );
         }
-        Self {
+        Self(Arc::new(StructFields {
             names,
             dtypes: dtypes.into(),
+            field_ids: None,
+        }))
+    }
+
+    /// Assign a [`FieldId`] to each of the fields of the struct
+    pub fn with_field_ids(self, field_ids: Vec<FieldId>) -> Self {
+        self.try_with_field_ids(field_ids)
+            .vortex_expect("Failed to assign field ids")
+    }
+
+    /// Assign a [`FieldId`] to each of the fields of the struct, failing if their number doesn't
+    /// match the number of fields.
+    pub fn try_with_field_ids(self, field_ids: Vec<FieldId>) -> VortexResult<Self> {
+        if field_ids.len() != self.0.names.len() {
+            vortex_bail!(
+                "length mismatch between names ({}) and field ids ({})",
+                self.0.names.len(),
+                field_ids.len()
+            );
         }
+        Ok(Self(Arc::new(StructFields {
+            names: self.0.names.clone(),
+            dtypes: self.0.dtypes.clone(),
+            field_ids: Some(field_ids.into()),
+        })))
     }
 
     /// Get the names of the fields in the struct
     pub fn names(&self) -> &FieldNames {
-        &self.names
+        &self.0.names
+    }
+
+    /// Get the IDs of the fields in the struct, if they have been assigned
+    pub fn field_ids(&self) -> Option<&[FieldId]> {
+        self.0.field_ids.as_deref()
+    }
+
+    /// Find the index of a field by ID
+    /// Returns `None` if the field is not found or the fields don't have IDs
+    pub fn find_field_id(&self, id: FieldId) -> Option<usize> {
+        self.field_ids()?
+            .iter()
+            .position(|field_id| *field_id == id)
     }
 
     /// Find the index of a field by name
     /// Returns `None` if the field is not found
     pub fn find_name(&self, name: &str) -> Option<usize> {
-        self.names.iter().position(|n| n.as_ref() == name)
+        self.0.names.iter().position(|n| n.as_ref() == name)
     }
 
     /// Get information about the referenced field, either by name or index
@@ -229,36 +322,46 @@ impl StructDType {
                 .find_name(name)
                 .ok_or_else(|| vortex_err!("Unknown field: {}", name))?,
             Field::Index(index) => *index,
+            Field::Id(id) => self
+                .find_field_id(*id)
+                .ok_or_else(|| vortex_err!("Unknown field id: {}", id))?,
         };
-        if index >= self.names.len() {
+        if index >= self.0.names.len() {
             vortex_bail!("field index out of bounds: {}", index)
         }
         Ok(FieldInfo {
             index,
-            name: self.names[index].clone(),
-            dtype: &self.dtypes[index],
+            name: self.0.names[index].clone(),
+            dtype: &self.0.dtypes[index],
         })
     }
 
     /// Get the dtypes of the fields in the struct
     pub fn dtypes(&self) -> &Arc<[DType]> {
-        &self.dtypes
+        &self.0.dtypes
     }
 
-    /// Project a subset of fields from the struct
+    /// Project a subset of fields from the struct, keeping their IDs
     /// Returns an error if any of the referenced fields are not found
     pub fn project(&self, projection: &[Field]) -> VortexResult<Self> {
         let mut names = Vec::with_capacity(projection.len());
         let mut dtypes = Vec::with_capacity(projection.len());
+        let mut field_ids = Vec::with_capacity(projection.len());
 
         for field in projection.iter() {
-            let FieldInfo { name, dtype, .. } = self.field_info(field)?;
+            let FieldInfo { index, name, dtype } = self.field_info(field)?;
 
             names.push(name.clone());
             dtypes.push(dtype.clone());
+            field_ids.extend(self.field_ids().map(|ids| ids[index]));
         }
 
-        Ok(StructDType::new(names.into(), dtypes))
+        let projected = StructDType::new(names.into(), dtypes);
+        Ok(if self.0.field_ids.is_some() {
+            projected.with_field_ids(field_ids)
+        } else {
+            projected
+        })
     }
 }
 
@@ -272,7 +375,7 @@ mod test {
 
     #[test]
     fn size_of() {
-        assert_eq!(mem::size_of::<DType>(), 40);
+        assert_eq!(mem::size_of::<DType>(), 16);
     }
 
     #[test]
@@ -337,4 +440,30 @@ mod test {
         assert_eq!(sdt.find_name("B"), Some(1));
         assert_eq!(sdt.find_name("C"), None);
     }
+
+    #[test]
+    fn test_field_ids() {
+        let a_type = DType::Primitive(PType::I32, Nullability::Nullable);
+        let b_type = DType::Bool(Nullability::NonNullable);
+        let without_ids =
+            StructDType::new(vec!["A".into(), "B".into()].into(), vec![a_type, b_type]);
+        assert_eq!(without_ids.field_ids(), None);
+        assert_eq!(without_ids.find_field_id(7), None);
+
+        let sdt = without_ids.clone().with_field_ids(vec![7, 3]);
+        assert_eq!(sdt.field_ids(), Some([7, 3].as_slice()));
+        // Field IDs don't take part in equality.
+        assert_eq!(sdt, without_ids);
+
+        let field_info = sdt.field_info(&Field::Id(3)).unwrap();
+        assert_eq!(field_info.index, 1);
+        assert_eq!(field_info.name, "B".into());
+        assert!(sdt.field_info(&Field::Id(1)).is_err());
+
+        let proj = sdt
+            .project(&[Field::Id(3), Field::Name("A".into())])
+            .unwrap();
+        assert_eq!(proj.names().as_ref(), &["B".into(), "A".into()]);
+        assert_eq!(proj.field_ids(), Some([3, 7].as_slice()));
+    }
 }
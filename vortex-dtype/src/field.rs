@@ -1,6 +1,6 @@
 //! Selectors for fields in (possibly nested) `StructDType`s
 //!
-//! A `Field` can either be a direct child field of the top-level struct (selected by name or index),
+//! A `Field` can either be a direct child field of the top-level struct (selected by name, index or ID),
 //! or a nested field (selected by a sequence of such selectors)
 
 use core::fmt;
@@ -8,6 +8,8 @@ use std::fmt::{Display, Formatter};
 
 use itertools::Itertools;
 
+use crate::FieldId;
+
 /// A selector for a field in a struct
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -16,6 +18,8 @@ pub enum Field {
     Name(String),
     /// A field selector by index (position)
     Index(usize),
+    /// A field selector by stable field ID, see [`StructDType::field_ids`][crate::StructDType::field_ids]
+    Id(FieldId),
 }
 
 impl From<&str> for Field {
@@ -41,6 +45,7 @@ impl Display for Field {
         match self {
             Field::Name(name) => write!(f, "${name}"),
             Field::Index(idx) => write!(f, "[{idx}]"),
+            Field::Id(id) => write!(f, "#{id}"),
         }
     }
 }
@@ -0,0 +1,46 @@
+use std::sync::{Arc, LazyLock};
+
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::{DType, ExtDType, ExtID, Nullability};
+
+/// The id of the well-known fixed-size binary extension type.
+pub static FIXED_SIZE_BINARY_ID: LazyLock<ExtID> =
+    LazyLock::new(|| ExtID::from("vortex.fixed_size_binary"));
+
+/// Returns whether the extension type is the well-known fixed-size binary type.
+pub fn is_fixed_size_binary_ext_type(id: &ExtID) -> bool {
+    id == &*FIXED_SIZE_BINARY_ID
+}
+
+/// The extension dtype of binary values that are all exactly `size` bytes long, e.g. hashes or
+/// UUIDs.
+///
+/// The values are stored as a fixed-size list of non-nullable `u8`, so unlike [`DType::Binary`]
+/// they need no offsets.
+pub fn fixed_size_binary_ext_dtype(size: u32, nullability: Nullability) -> ExtDType {
+    ExtDType::new(
+        FIXED_SIZE_BINARY_ID.clone(),
+        Arc::new(DType::FixedSizeList(
+            Arc::new(DType::BYTES),
+            size,
+            nullability,
+        )),
+        None,
+    )
+}
+
+/// Returns the number of bytes of each value of a fixed-size binary extension type.
+pub fn fixed_size_binary_size(ext_dtype: &ExtDType) -> VortexResult<u32> {
+    if !is_fixed_size_binary_ext_type(ext_dtype.id()) {
+        vortex_bail!(
+            "Expected {}, found {}",
+            *FIXED_SIZE_BINARY_ID,
+            ext_dtype.id()
+        );
+    }
+    match ext_dtype.storage_dtype() {
+        DType::FixedSizeList(element, size, _) if element.as_ref() == &DType::BYTES => Ok(*size),
+        storage => vortex_bail!("Invalid fixed-size binary storage type {storage}"),
+    }
+}
@@ -6,20 +6,30 @@
 //! This crate contains the core logical type system for Vortex, including the definition of data types,
 //! and (optionally) logic for their serialization and deserialization.
 
+pub use decimal::*;
 pub use dtype::*;
 pub use extension::*;
+pub use fixed_size_binary::*;
 pub use half;
+pub use map::*;
 pub use nullability::*;
 pub use ptype::*;
+pub use union::*;
+pub use variant::*;
 
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
+mod decimal;
 mod dtype;
 mod extension;
 pub mod field;
+mod fixed_size_binary;
+mod map;
 mod nullability;
 mod ptype;
 mod serde;
+mod union;
+mod variant;
 
 #[cfg(feature = "proto")]
 pub mod proto {
@@ -0,0 +1,128 @@
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, LazyLock};
+
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+
+use crate::{DType, ExtDType, ExtID, FieldNames, Nullability, StructDType};
+
+/// The id of the well-known map extension type.
+pub static MAP_ID: LazyLock<ExtID> = LazyLock::new(|| ExtID::from("vortex.map"));
+
+/// The name of the key field of map entries.
+pub const MAP_KEY_FIELD: &str = "key";
+/// The name of the value field of map entries.
+pub const MAP_VALUE_FIELD: &str = "value";
+
+/// Returns whether the extension type is the well-known map type.
+pub fn is_map_ext_type(id: &ExtID) -> bool {
+    id == &*MAP_ID
+}
+
+/// The key and value types of a map.
+///
+/// Maps are stored as extension types over a list of entries, where each entry is a non-nullable
+/// struct of a `key` and a `value` field, i.e. `List<Struct<key, value>>`. Like in Arrow, keys
+/// must not be null, and the entries of a map are neither sorted nor deduplicated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MapDType {
+    key: Arc<DType>,
+    value: Arc<DType>,
+}
+
+impl MapDType {
+    /// Create a map type from its key and value types.
+    pub fn try_new(key: DType, value: DType) -> VortexResult<Self> {
+        if key.is_nullable() {
+            vortex_bail!("Map keys must not be nullable, found {key}");
+        }
+        Ok(Self {
+            key: Arc::new(key),
+            value: Arc::new(value),
+        })
+    }
+
+    /// The type of the keys.
+    pub fn key_dtype(&self) -> &DType {
+        &self.key
+    }
+
+    /// The type of the values.
+    pub fn value_dtype(&self) -> &DType {
+        &self.value
+    }
+
+    /// The struct dtype of a single entry.
+    pub fn entries_dtype(&self) -> DType {
+        DType::Struct(
+            StructDType::new(
+                FieldNames::from([MAP_KEY_FIELD.into(), MAP_VALUE_FIELD.into()]),
+                vec![self.key.as_ref().clone(), self.value.as_ref().clone()],
+            ),
+            Nullability::NonNullable,
+        )
+    }
+
+    /// The dtype of the storage of the entries.
+    pub fn storage_dtype(&self, nullability: Nullability) -> DType {
+        DType::List(Arc::new(self.entries_dtype()), nullability)
+    }
+
+    /// The extension dtype of maps of this type.
+    pub fn ext_dtype(&self, nullability: Nullability) -> ExtDType {
+        ExtDType::new(
+            MAP_ID.clone(),
+            Arc::new(self.storage_dtype(nullability)),
+            None,
+        )
+    }
+}
+
+impl Display for MapDType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "map({}, {})", self.key, self.value)
+    }
+}
+
+impl TryFrom<&ExtDType> for MapDType {
+    type Error = VortexError;
+
+    fn try_from(ext_dtype: &ExtDType) -> Result<Self, Self::Error> {
+        if !is_map_ext_type(ext_dtype.id()) {
+            vortex_bail!("Expected {}, found {}", *MAP_ID, ext_dtype.id());
+        }
+        let storage = ext_dtype.storage_dtype();
+        let Some(DType::Struct(entries, Nullability::NonNullable)) = storage.as_list_element()
+        else {
+            vortex_bail!("Invalid map storage type {storage}");
+        };
+        match (entries.names().as_ref(), entries.dtypes().as_ref()) {
+            ([key_name, value_name], [key, value])
+                if key_name.as_ref() == MAP_KEY_FIELD && value_name.as_ref() == MAP_VALUE_FIELD =>
+            {
+                Self::try_new(key.clone(), value.clone())
+            }
+            _ => vortex_bail!("Invalid map storage type {storage}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PType;
+
+    #[test]
+    fn map_round_trip() {
+        let map = MapDType::try_new(
+            DType::Utf8(Nullability::NonNullable),
+            DType::Primitive(PType::I64, Nullability::Nullable),
+        )
+        .unwrap();
+        let ext_dtype = map.ext_dtype(Nullability::Nullable);
+        assert!(ext_dtype.storage_dtype().is_nullable());
+        assert_eq!(MapDType::try_from(&ext_dtype).unwrap(), map);
+        assert_eq!(map.to_string(), "map(utf8, i64?)");
+
+        assert!(MapDType::try_new(DType::Utf8(Nullability::Nullable), DType::BYTES).is_err());
+    }
+}
@@ -55,6 +55,19 @@ impl TryFrom<fb::DType<'_>> for DType {
                     fb_list.nullable().into(),
                 ))
             }
+            fb::Type::FixedSizeList => {
+                let fb_list = fb.type__as_fixed_size_list().ok_or_else(|| {
+                    vortex_err!("failed to parse fixed-size list from flatbuffer")
+                })?;
+                let element_dtype = Self::try_from(fb_list.element_type().ok_or_else(|| {
+                    vortex_err!("failed to parse fixed-size list element type from flatbuffer")
+                })?)?;
+                Ok(Self::FixedSizeList(
+                    Arc::new(element_dtype),
+                    fb_list.size(),
+                    fb_list.nullable().into(),
+                ))
+            }
             fb::Type::Struct_ => {
                 let fb_struct = fb
                     .type__as_struct_()
@@ -72,8 +85,12 @@ impl TryFrom<fb::DType<'_>> for DType {
                     .iter()
                     .map(Self::try_from)
                     .collect::<VortexResult<Vec<_>>>()?;
+                let st = StructDType::new(names, dtypes);
                 Ok(Self::Struct(
-                    StructDType::new(names, dtypes),
+                    match fb_struct.field_ids() {
+                        Some(field_ids) => st.try_with_field_ids(field_ids.iter().collect())?,
+                        None => st,
+                    },
                     fb_struct.nullable().into(),
                 ))
             }
@@ -156,6 +173,7 @@ impl WriteFlatBuffer for DType {
                     .map(|dtype| dtype.write_flatbuffer(fbb))
                     .collect_vec();
                 let dtypes = Some(fbb.create_vector(&dtypes));
+                let field_ids = st.field_ids().map(|ids| fbb.create_vector(ids));
 
                 fb::Struct_::create(
                     fbb,
@@ -163,6 +181,7 @@ impl WriteFlatBuffer for DType {
                         names,
                         dtypes,
                         nullable: (*n).into(),
+                        field_ids,
                     },
                 )
                 .as_union_value()
@@ -178,6 +197,18 @@ impl WriteFlatBuffer for DType {
                 )
                 .as_union_value()
             }
+            Self::FixedSizeList(edt, size, n) => {
+                let element_type = Some(edt.as_ref().write_flatbuffer(fbb));
+                fb::FixedSizeList::create(
+                    fbb,
+                    &fb::FixedSizeListArgs {
+                        element_type,
+                        size: *size,
+                        nullable: (*n).into(),
+                    },
+                )
+                .as_union_value()
+            }
             Self::Extension(ext) => {
                 let id = Some(fbb.create_string(ext.id().as_ref()));
                 let storage_dtype = Some(ext.storage_dtype().write_flatbuffer(fbb));
@@ -202,6 +233,7 @@ impl WriteFlatBuffer for DType {
             Self::Binary(_) => fb::Type::Binary,
             Self::Struct(..) => fb::Type::Struct_,
             Self::List(..) => fb::Type::List,
+            Self::FixedSizeList(..) => fb::Type::FixedSizeList,
             Self::Extension { .. } => fb::Type::Extension,
         };
 
@@ -258,9 +290,11 @@ impl TryFrom<fb::PType> for PType {
 mod test {
     use std::sync::Arc;
 
-    use flatbuffers::root;
-    use vortex_flatbuffers::FlatBufferToBytes;
+    use flatbuffers::{root, FlatBufferBuilder};
+    use vortex_flatbuffers::{FlatBufferToBytes, WriteFlatBuffer};
 
+    use crate::field::Field;
+    use crate::flatbuffers::project_and_deserialize;
     use crate::nullability::Nullability;
     use crate::{flatbuffers as fb, DType, PType, StructDType};
 
@@ -291,6 +325,11 @@ mod test {
             Arc::new(DType::Primitive(PType::F32, Nullability::Nullable)),
             Nullability::NonNullable,
         ));
+        roundtrip_dtype(DType::FixedSizeList(
+            Arc::new(DType::Primitive(PType::F32, Nullability::NonNullable)),
+            768,
+            Nullability::Nullable,
+        ));
         roundtrip_dtype(DType::Struct(
             StructDType::new(
                 ["strings".into(), "ints".into()].into(),
@@ -302,4 +341,65 @@ mod test {
             Nullability::NonNullable,
         ))
     }
+    #[test]
+    fn roundtrip_field_ids() {
+        let dtype = DType::Struct(
+            StructDType::new(
+                ["strings".into(), "ints".into()].into(),
+                vec![
+                    DType::Utf8(Nullability::NonNullable),
+                    DType::Primitive(PType::U16, Nullability::Nullable),
+                ],
+            )
+            .with_field_ids(vec![4, 2]),
+            Nullability::NonNullable,
+        );
+        let bytes = dtype.with_flatbuffer_bytes(|bytes| bytes.to_vec());
+        let fb_dtype = root::<fb::DType>(&bytes).unwrap();
+
+        let deserialized = DType::try_from(fb_dtype).unwrap();
+        assert_eq!(
+            deserialized.as_struct().unwrap().field_ids(),
+            Some([4, 2].as_slice())
+        );
+
+        let projected = project_and_deserialize(fb_dtype, &[Field::Id(2)]).unwrap();
+        let projected = projected.as_struct().unwrap();
+        assert_eq!(projected.names().as_ref(), &["ints".into()]);
+        assert_eq!(projected.field_ids(), Some([2].as_slice()));
+    }
+
+    #[test]
+    fn mismatched_field_ids() {
+        let mut fbb = FlatBufferBuilder::new();
+        let names = ["strings", "ints"].map(|n| fbb.create_string(n));
+        let names = Some(fbb.create_vector(&names));
+        let dtypes = [
+            DType::Utf8(Nullability::NonNullable).write_flatbuffer(&mut fbb),
+            DType::Primitive(PType::U16, Nullability::Nullable).write_flatbuffer(&mut fbb),
+        ];
+        let dtypes = Some(fbb.create_vector(&dtypes));
+        let field_ids = Some(fbb.create_vector(&[4u32]));
+        let st = fb::Struct_::create(
+            &mut fbb,
+            &fb::Struct_Args {
+                names,
+                dtypes,
+                nullable: false,
+                field_ids,
+            },
+        );
+        let dtype = fb::DType::create(
+            &mut fbb,
+            &fb::DTypeArgs {
+                type_type: fb::Type::Struct_,
+                type_: Some(st.as_union_value()),
+            },
+        );
+        fbb.finish_minimal(dtype);
+        let fb_dtype = root::<fb::DType>(fbb.finished_data()).unwrap();
+
+        assert!(DType::try_from(fb_dtype).is_err());
+        assert!(project_and_deserialize(fb_dtype, &[Field::Id(4)]).is_err());
+    }
 }
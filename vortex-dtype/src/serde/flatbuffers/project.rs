@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
 use crate::field::Field;
 use crate::{flatbuffers as fb, DType, StructDType};
@@ -20,6 +20,10 @@ pub fn resolve_field<'a, 'b: 'a>(fb: fb::Struct_<'b>, field: &'a Field) -> Vorte
                 .ok_or_else(|| vortex_err!("Unknown field name {n}"))
         }
         Field::Index(i) => Ok(*i),
+        Field::Id(id) => fb
+            .field_ids()
+            .and_then(|ids| ids.iter().position(|field_id| field_id == *id))
+            .ok_or_else(|| vortex_err!("Unknown field id {id}")),
     }
 }
 
@@ -42,16 +46,33 @@ pub fn project_and_deserialize(
         .ok_or_else(|| vortex_err!("The top-level type should be a struct"))?;
     let nullability = fb_struct.nullable().into();
 
-    let (names, dtypes): (Vec<Arc<str>>, Vec<DType>) = projection
+    let indices = projection
         .iter()
         .map(|f| resolve_field(fb_struct, f))
-        .map(|idx| idx.and_then(|i| read_field(fb_struct, i)))
+        .collect::<VortexResult<Vec<_>>>()?;
+    let (names, dtypes): (Vec<Arc<str>>, Vec<DType>) = indices
+        .iter()
+        .map(|i| read_field(fb_struct, *i))
         .collect::<VortexResult<Vec<_>>>()?
         .into_iter()
         .unzip();
 
+    let st = StructDType::new(names.into(), dtypes);
     Ok(DType::Struct(
-        StructDType::new(names.into(), dtypes),
+        match fb_struct.field_ids() {
+            Some(field_ids) => {
+                let num_fields = fb_struct.names().map_or(0, |names| names.len());
+                if field_ids.len() != num_fields {
+                    vortex_bail!(
+                        "length mismatch between names ({}) and field ids ({})",
+                        num_fields,
+                        field_ids.len()
+                    );
+                }
+                st.try_with_field_ids(indices.iter().map(|i| field_ids.get(*i)).collect())?
+            }
+            None => st,
+        },
         nullability,
     ))
 }
@@ -23,16 +23,23 @@ impl TryFrom<&pb::DType> for DType {
             DtypeType::Decimal(_) => todo!("Not Implemented"),
             DtypeType::Utf8(u) => Ok(Self::Utf8(u.nullable.into())),
             DtypeType::Binary(b) => Ok(Self::Binary(b.nullable.into())),
-            DtypeType::Struct(s) => Ok(Self::Struct(
-                StructDType::new(
+            DtypeType::Struct(s) => {
+                let st = StructDType::new(
                     s.names.iter().map(|s| s.as_str().into()).collect(),
                     s.dtypes
                         .iter()
                         .map(TryInto::<Self>::try_into)
                         .collect::<VortexResult<Vec<_>>>()?,
-                ),
-                s.nullable.into(),
-            )),
+                );
+                Ok(Self::Struct(
+                    if s.field_ids.is_empty() {
+                        st
+                    } else {
+                        st.try_with_field_ids(s.field_ids.clone())?
+                    },
+                    s.nullable.into(),
+                ))
+            }
             DtypeType::List(l) => {
                 let nullable = l.nullable.into();
                 Ok(Self::List(
@@ -45,6 +52,19 @@ impl TryFrom<&pb::DType> for DType {
                     nullable,
                 ))
             }
+            DtypeType::FixedSizeList(l) => {
+                let nullable = l.nullable.into();
+                Ok(Self::FixedSizeList(
+                    l.element_type
+                        .as_ref()
+                        .ok_or_else(|| vortex_err!(InvalidSerde: "Invalid list element type"))?
+                        .as_ref()
+                        .try_into()
+                        .map(Arc::new)?,
+                    l.size,
+                    nullable,
+                ))
+            }
             DtypeType::Extension(e) => Ok(Self::Extension(
                 Arc::new(ExtDType::new(
                     ExtID::from(e.id.as_str()),
@@ -82,11 +102,19 @@ impl From<&DType> for pb::DType {
                     names: s.names().iter().map(|s| s.as_ref().to_string()).collect(),
                     dtypes: s.dtypes().iter().map(Into::into).collect(),
                     nullable: (*n).into(),
+                    field_ids: s.field_ids().map(<[u32]>::to_vec).unwrap_or_default(),
                 }),
                 DType::List(l, n) => DtypeType::List(Box::new(pb::List {
                     element_type: Some(Box::new(l.as_ref().into())),
                     nullable: (*n).into(),
                 })),
+                DType::FixedSizeList(l, size, n) => {
+                    DtypeType::FixedSizeList(Box::new(pb::FixedSizeList {
+                        element_type: Some(Box::new(l.as_ref().into())),
+                        size: *size,
+                        nullable: (*n).into(),
+                    }))
+                }
                 DType::Extension(e) => DtypeType::Extension(Box::new(pb::Extension {
                     id: e.id().as_ref().into(),
                     storage_dtype: Some(Box::new(e.storage_dtype().into())),
@@ -150,6 +178,7 @@ impl TryFrom<&pb::FieldPath> for FieldPath {
                 FieldType::Index(idx) => {
                     path.push(Field::from(usize::try_from(*idx).vortex_unwrap()))
                 }
+                FieldType::Id(id) => path.push(Field::Id(*id)),
             }
         }
         Ok(FieldPath::from(path))
@@ -0,0 +1,211 @@
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, LazyLock};
+
+use itertools::Itertools;
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::{DType, ExtDType, ExtID, ExtMetadata, FieldNames, Nullability, PType, StructDType};
+
+/// The id of the well-known union extension type.
+pub static UNION_ID: LazyLock<ExtID> = LazyLock::new(|| ExtID::from("vortex.union"));
+
+/// The name of the storage field holding the type id of each value.
+pub const UNION_TYPE_IDS_FIELD: &str = "type_ids";
+/// The name of the storage field holding the variants.
+pub const UNION_VARIANTS_FIELD: &str = "variants";
+
+/// Returns whether the extension type is the well-known union type.
+pub fn is_union_ext_type(id: &ExtID) -> bool {
+    id == &*UNION_ID
+}
+
+/// The Arrow layout a union was imported from, and is exported to.
+///
+/// Vortex always stores unions in the sparse layout, the mode only records how to reconstruct the
+/// original Arrow array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnionMode {
+    /// Every variant has the same length as the union.
+    Sparse,
+    /// Every variant only holds the values of its type, addressed by an offset per value.
+    Dense,
+}
+
+/// The variants of a tagged union.
+///
+/// Unions are stored as extension types over a struct of the `i8` type id of each value and a
+/// non-nullable struct of all variants, each of which has the same length as the union:
+/// `Struct<type_ids: i8, variants: Struct<...>>`. Variants are always nullable, and hold nulls
+/// wherever another variant is selected.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnionDType {
+    variants: StructDType,
+    type_ids: Arc<[i8]>,
+    mode: UnionMode,
+}
+
+impl UnionDType {
+    /// Create a union of the given variants, which are identified by the matching `type_ids`.
+    pub fn try_new(
+        variants: StructDType,
+        type_ids: Vec<i8>,
+        mode: UnionMode,
+    ) -> VortexResult<Self> {
+        if variants.names().len() != type_ids.len() {
+            vortex_bail!(
+                "Union has {} variants but {} type ids",
+                variants.names().len(),
+                type_ids.len()
+            );
+        }
+        if type_ids.iter().any(|id| *id < 0) {
+            vortex_bail!("Union type ids must not be negative");
+        }
+        if !type_ids.iter().all_unique() {
+            vortex_bail!("Union type ids must be unique");
+        }
+        let variants = StructDType::new(
+            variants.names().clone(),
+            variants.dtypes().iter().map(DType::as_nullable).collect(),
+        );
+        Ok(Self {
+            variants,
+            type_ids: type_ids.into(),
+            mode,
+        })
+    }
+
+    /// The names and (nullable) dtypes of the variants.
+    pub fn variants(&self) -> &StructDType {
+        &self.variants
+    }
+
+    /// The type id of each variant.
+    pub fn type_ids(&self) -> &[i8] {
+        &self.type_ids
+    }
+
+    /// The Arrow layout of the union.
+    pub fn mode(&self) -> UnionMode {
+        self.mode
+    }
+
+    /// The index of the variant with the given type id.
+    pub fn variant_idx(&self, type_id: i8) -> Option<usize> {
+        self.type_ids.iter().position(|id| *id == type_id)
+    }
+
+    /// The dtype of the storage of the type ids and variants.
+    pub fn storage_dtype(&self, nullability: Nullability) -> DType {
+        DType::Struct(
+            StructDType::new(
+                FieldNames::from([UNION_TYPE_IDS_FIELD.into(), UNION_VARIANTS_FIELD.into()]),
+                vec![
+                    DType::Primitive(PType::I8, Nullability::NonNullable),
+                    DType::Struct(self.variants.clone(), Nullability::NonNullable),
+                ],
+            ),
+            nullability,
+        )
+    }
+
+    /// The extension dtype of unions of this type.
+    pub fn ext_dtype(&self, nullability: Nullability) -> ExtDType {
+        let metadata = [match self.mode {
+            UnionMode::Sparse => 0,
+            UnionMode::Dense => 1,
+        }]
+        .into_iter()
+        .chain(self.type_ids.iter().map(|id| *id as u8))
+        .collect::<Vec<u8>>();
+        ExtDType::new(
+            UNION_ID.clone(),
+            Arc::new(self.storage_dtype(nullability)),
+            Some(ExtMetadata::from(metadata.as_slice())),
+        )
+    }
+}
+
+impl Display for UnionDType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "union({})",
+            self.variants
+                .names()
+                .iter()
+                .zip(self.variants.dtypes().iter())
+                .zip(self.type_ids.iter())
+                .map(|((name, dtype), id)| format!("{id}: {name}={dtype}"))
+                .join(", ")
+        )
+    }
+}
+
+impl TryFrom<&ExtDType> for UnionDType {
+    type Error = VortexError;
+
+    fn try_from(ext_dtype: &ExtDType) -> Result<Self, Self::Error> {
+        if !is_union_ext_type(ext_dtype.id()) {
+            vortex_bail!("Expected {}, found {}", *UNION_ID, ext_dtype.id());
+        }
+        let metadata = ext_dtype
+            .metadata()
+            .ok_or_else(|| vortex_err!("Union ExtDType is missing metadata"))?;
+        let (mode, type_ids) = metadata
+            .as_ref()
+            .split_first()
+            .ok_or_else(|| vortex_err!("Union metadata must not be empty"))?;
+        let mode = match mode {
+            0 => UnionMode::Sparse,
+            1 => UnionMode::Dense,
+            _ => vortex_bail!("Invalid union mode {mode}"),
+        };
+
+        let storage = ext_dtype.storage_dtype();
+        let variants = storage
+            .as_struct()
+            .and_then(|st| st.dtypes().get(1))
+            .and_then(DType::as_struct)
+            .ok_or_else(|| vortex_err!("Invalid union storage type {storage}"))?;
+        Self::try_new(
+            variants.clone(),
+            type_ids.iter().map(|id| *id as i8).collect(),
+            mode,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variants() -> StructDType {
+        StructDType::new(
+            FieldNames::from(["int".into(), "str".into()]),
+            vec![
+                DType::Primitive(PType::I32, Nullability::NonNullable),
+                DType::Utf8(Nullability::Nullable),
+            ],
+        )
+    }
+
+    #[test]
+    fn union_round_trip() {
+        let union = UnionDType::try_new(variants(), vec![3, 7], UnionMode::Dense).unwrap();
+        assert!(union.variants().dtypes().iter().all(DType::is_nullable));
+        assert_eq!(union.variant_idx(7), Some(1));
+        assert_eq!(union.variant_idx(0), None);
+
+        let ext_dtype = union.ext_dtype(Nullability::NonNullable);
+        assert_eq!(UnionDType::try_from(&ext_dtype).unwrap(), union);
+        assert_eq!(union.to_string(), "union(3: int=i32?, 7: str=utf8?)");
+    }
+
+    #[test]
+    fn invalid_type_ids() {
+        assert!(UnionDType::try_new(variants(), vec![1], UnionMode::Sparse).is_err());
+        assert!(UnionDType::try_new(variants(), vec![1, 1], UnionMode::Sparse).is_err());
+        assert!(UnionDType::try_new(variants(), vec![-1, 1], UnionMode::Sparse).is_err());
+    }
+}
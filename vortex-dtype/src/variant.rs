@@ -0,0 +1,142 @@
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, LazyLock};
+
+use itertools::Itertools;
+use vortex_error::{vortex_bail, vortex_err, VortexError};
+
+use crate::{DType, ExtDType, ExtID, FieldNames, Nullability, StructDType};
+
+/// The id of the well-known variant extension type.
+pub static VARIANT_ID: LazyLock<ExtID> = LazyLock::new(|| ExtID::from("vortex.variant"));
+
+/// The name of the storage field holding the values that weren't shredded.
+pub const VARIANT_RESIDUAL_FIELD: &str = "residual";
+/// The name of the storage field holding the shredded paths.
+pub const VARIANT_SHREDDED_FIELD: &str = "shredded";
+
+/// Returns whether the extension type is the well-known variant type.
+pub fn is_variant_ext_type(id: &ExtID) -> bool {
+    id == &*VARIANT_ID
+}
+
+/// A semi-structured value, e.g. a JSON document whose shape varies from row to row.
+///
+/// Variants are stored as extension types over a struct of a binary residual column and a
+/// non-nullable struct of shredded paths: `Struct<residual: binary?, shredded: Struct<...>>`.
+///
+/// Frequently occurring paths, e.g. `user.id`, are shredded into a nullable child column of a
+/// single type, which holds the values of that type at the path and null everywhere else. All
+/// other values are kept in the residual column, encoded as JSON, which is null where nothing
+/// remains of the value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VariantDType {
+    shredded: StructDType,
+}
+
+impl VariantDType {
+    /// Create a variant with the given shredded paths, whose dtypes are made nullable.
+    ///
+    /// Paths are the object keys leading to a value, joined by `.`.
+    pub fn new(shredded: StructDType) -> Self {
+        Self {
+            shredded: StructDType::new(
+                shredded.names().clone(),
+                shredded.dtypes().iter().map(DType::as_nullable).collect(),
+            ),
+        }
+    }
+
+    /// The paths and (nullable) dtypes of the shredded columns.
+    pub fn shredded(&self) -> &StructDType {
+        &self.shredded
+    }
+
+    /// The index and dtype of the column the path is shredded into, if any.
+    pub fn shredded_path(&self, path: &str) -> Option<(usize, &DType)> {
+        self.shredded
+            .names()
+            .iter()
+            .position(|name| name.as_ref() == path)
+            .map(|idx| (idx, &self.shredded.dtypes()[idx]))
+    }
+
+    /// The dtype of the storage of the residual and shredded columns.
+    pub fn storage_dtype(&self, nullability: Nullability) -> DType {
+        DType::Struct(
+            StructDType::new(
+                FieldNames::from([VARIANT_RESIDUAL_FIELD.into(), VARIANT_SHREDDED_FIELD.into()]),
+                vec![
+                    DType::Binary(Nullability::Nullable),
+                    DType::Struct(self.shredded.clone(), Nullability::NonNullable),
+                ],
+            ),
+            nullability,
+        )
+    }
+
+    /// The extension dtype of variants of this type.
+    pub fn ext_dtype(&self, nullability: Nullability) -> ExtDType {
+        ExtDType::new(
+            VARIANT_ID.clone(),
+            Arc::new(self.storage_dtype(nullability)),
+            None,
+        )
+    }
+}
+
+impl Display for VariantDType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "variant({})",
+            self.shredded
+                .names()
+                .iter()
+                .zip(self.shredded.dtypes().iter())
+                .map(|(path, dtype)| format!("{path}={dtype}"))
+                .join(", ")
+        )
+    }
+}
+
+impl TryFrom<&ExtDType> for VariantDType {
+    type Error = VortexError;
+
+    fn try_from(ext_dtype: &ExtDType) -> Result<Self, Self::Error> {
+        if !is_variant_ext_type(ext_dtype.id()) {
+            vortex_bail!("Expected {}, found {}", *VARIANT_ID, ext_dtype.id());
+        }
+        let storage = ext_dtype.storage_dtype();
+        let shredded = storage
+            .as_struct()
+            .and_then(|st| st.dtypes().get(1))
+            .and_then(DType::as_struct)
+            .ok_or_else(|| vortex_err!("Invalid variant storage type {storage}"))?;
+        Ok(Self::new(shredded.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PType;
+
+    #[test]
+    fn variant_round_trip() {
+        let variant = VariantDType::new(StructDType::new(
+            FieldNames::from(["id".into(), "user.name".into()]),
+            vec![
+                DType::Primitive(PType::I64, Nullability::NonNullable),
+                DType::Utf8(Nullability::Nullable),
+            ],
+        ));
+        assert!(variant.shredded().dtypes().iter().all(DType::is_nullable));
+        assert_eq!(variant.shredded_path("user.name").unwrap().0, 1);
+        assert!(variant.shredded_path("user").is_none());
+
+        let ext_dtype = variant.ext_dtype(Nullability::Nullable);
+        assert!(ext_dtype.storage_dtype().is_nullable());
+        assert_eq!(VariantDType::try_from(&ext_dtype).unwrap(), variant);
+        assert_eq!(variant.to_string(), "variant(id=i64?, user.name=utf8?)");
+    }
+}
@@ -3,7 +3,10 @@ use std::fmt::Display;
 use std::sync::Arc;
 
 use vortex_array::aliases::hash_set::HashSet;
-use vortex_array::compute::{and_kleene, compare, or_kleene, Operator as ArrayOperator};
+use vortex_array::compute::{
+    and_kleene, binary_numeric, compare, or_kleene, BinaryNumericOperator,
+    Operator as ArrayOperator,
+};
 use vortex_array::ArrayData;
 use vortex_dtype::field::Field;
 use vortex_error::VortexResult;
@@ -59,6 +62,11 @@ impl VortexExpr for BinaryExpr {
             Operator::Gte => compare(lhs, rhs, ArrayOperator::Gte),
             Operator::And => and_kleene(lhs, rhs),
             Operator::Or => or_kleene(lhs, rhs),
+            Operator::Add => binary_numeric(&lhs, &rhs, BinaryNumericOperator::Add),
+            Operator::Sub => binary_numeric(&lhs, &rhs, BinaryNumericOperator::Sub),
+            Operator::Mul => binary_numeric(&lhs, &rhs, BinaryNumericOperator::Mul),
+            Operator::Div => binary_numeric(&lhs, &rhs, BinaryNumericOperator::Div),
+            Operator::Rem => binary_numeric(&lhs, &rhs, BinaryNumericOperator::Rem),
         }
     }
 
@@ -76,3 +84,39 @@ impl PartialEq<dyn Any> for BinaryExpr {
             .unwrap_or(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use vortex_array::array::{PrimitiveArray, StructArray};
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+    use vortex_dtype::field::Field;
+
+    use crate::{BinaryExpr, Column, Literal, Operator};
+
+    #[test]
+    fn filter_arithmetic() {
+        let batch = StructArray::from_fields(&[
+            (
+                "price",
+                PrimitiveArray::from(vec![10i64, 25, 3]).into_array(),
+            ),
+            ("qty", PrimitiveArray::from(vec![5i64, 4, 40]).into_array()),
+        ])
+        .unwrap()
+        .into_array();
+
+        let total = BinaryExpr::new_expr(
+            Column::new_expr(Field::from("price")),
+            Operator::Mul,
+            Column::new_expr(Field::from("qty")),
+        );
+        let expensive = BinaryExpr::new_expr(total, Operator::Gt, Literal::new_expr(100i64.into()));
+        assert_eq!(expensive.to_string(), "(($price * $qty) > 100_i64)");
+
+        let result = expensive.evaluate(&batch).unwrap().into_bool().unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, false, true]
+        );
+    }
+}
@@ -5,7 +5,7 @@ use std::sync::Arc;
 use vortex_array::aliases::hash_set::HashSet;
 use vortex_array::array::StructArray;
 use vortex_array::variants::StructArrayTrait;
-use vortex_array::ArrayData;
+use vortex_array::{ArrayDType, ArrayData};
 use vortex_dtype::field::Field;
 use vortex_error::{vortex_err, VortexResult};
 
@@ -59,6 +59,11 @@ impl VortexExpr for Column {
         match &self.field {
             Field::Name(n) => s.field_by_name(n),
             Field::Index(i) => s.field(*i),
+            Field::Id(id) => s
+                .dtype()
+                .as_struct()
+                .and_then(|st| st.find_field_id(*id))
+                .and_then(|i| s.field(i)),
         }
         .ok_or_else(|| vortex_err!("Array doesn't contain child array {}", self.field))
     }
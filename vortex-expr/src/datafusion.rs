@@ -7,7 +7,7 @@ use datafusion_physical_expr::{expressions, PhysicalExpr};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_scalar::Scalar;
 
-use crate::{BinaryExpr, Column, ExprRef, Like, Literal, Operator};
+use crate::{BinaryExpr, Column, ExprRef, InList, Like, Literal, Operator};
 
 pub fn convert_expr_to_vortex(physical_expr: Arc<dyn PhysicalExpr>) -> VortexResult<ExprRef> {
     if let Some(binary_expr) = physical_expr
@@ -41,6 +41,24 @@ pub fn convert_expr_to_vortex(physical_expr: Arc<dyn PhysicalExpr>) -> VortexRes
         ));
     }
 
+    if let Some(in_list) = physical_expr
+        .as_any()
+        .downcast_ref::<expressions::InListExpr>()
+    {
+        let child = convert_expr_to_vortex(in_list.expr().clone())?;
+        let list = in_list
+            .list()
+            .iter()
+            .map(|item| {
+                item.as_any()
+                    .downcast_ref::<expressions::Literal>()
+                    .map(|lit| Scalar::from(lit.value().clone()))
+                    .ok_or_else(|| vortex_err!("IN lists can only contain literals, found {item}"))
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        return Ok(InList::new_expr(child, list, in_list.negated()));
+    }
+
     if let Some(lit) = physical_expr
         .as_any()
         .downcast_ref::<expressions::Literal>()
@@ -84,8 +102,9 @@ impl TryFrom<DFOperator> for Operator {
             | DFOperator::BitwiseShiftLeft
             | DFOperator::StringConcat
             | DFOperator::AtArrow
-            | DFOperator::ArrowAt
-            | DFOperator::Plus
+            | DFOperator::ArrowAt => Err(vortex_err!("Unsupported datafusion operator {value}")),
+            // DataFusion's arithmetic wraps around on overflow, while ours is checked.
+            DFOperator::Plus
             | DFOperator::Minus
             | DFOperator::Multiply
             | DFOperator::Divide
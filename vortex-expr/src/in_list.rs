@@ -0,0 +1,122 @@
+use std::any::Any;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use itertools::Itertools;
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::compute::{invert, is_in};
+use vortex_array::ArrayData;
+use vortex_dtype::field::Field;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::{unbox_any, ExprRef, VortexExpr};
+
+/// Tests whether the values of its child are in a list of literal values, i.e. SQL `IN`.
+#[derive(Debug)]
+pub struct InList {
+    child: ExprRef,
+    list: Vec<Scalar>,
+    negated: bool,
+}
+
+impl InList {
+    pub fn new_expr(child: ExprRef, list: Vec<Scalar>, negated: bool) -> ExprRef {
+        Arc::new(Self {
+            child,
+            list,
+            negated,
+        })
+    }
+
+    pub fn child(&self) -> &ExprRef {
+        &self.child
+    }
+
+    pub fn list(&self) -> &[Scalar] {
+        &self.list
+    }
+
+    pub fn negated(&self) -> bool {
+        self.negated
+    }
+}
+
+impl Display for InList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}IN ({})",
+            self.child,
+            if self.negated { "NOT " } else { "" },
+            self.list.iter().format(", ")
+        )
+    }
+}
+
+impl VortexExpr for InList {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn evaluate(&self, batch: &ArrayData) -> VortexResult<ArrayData> {
+        let child = self.child.evaluate(batch)?;
+        let found = is_in(&child, &self.list)?;
+        if self.negated {
+            invert(&found)
+        } else {
+            Ok(found)
+        }
+    }
+
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a Field>) {
+        self.child.collect_references(references)
+    }
+}
+
+impl PartialEq<dyn Any> for InList {
+    fn eq(&self, other: &dyn Any) -> bool {
+        unbox_any(other)
+            .downcast_ref::<Self>()
+            .map(|x| x.child.eq(&self.child) && x.list == self.list && x.negated == self.negated)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+    use vortex_scalar::Scalar;
+
+    use crate::{Identity, InList};
+
+    #[test]
+    fn in_list() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i32), Some(2), None, Some(5)])
+            .into_array();
+        let list = vec![Scalar::from(2i32), Scalar::from(5i32)];
+
+        let in_list = InList::new_expr(Identity::new_expr(), list.clone(), false);
+        assert_eq!(in_list.to_string(), "[] IN (2_i32, 5_i32)");
+        let found = in_list.evaluate(&array).unwrap().into_bool().unwrap();
+        assert_eq!(
+            found.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, false, true]
+        );
+        assert!(!found.validity().is_valid(2));
+
+        let not_in_list = InList::new_expr(Identity::new_expr(), list, true);
+        assert_eq!(
+            not_in_list
+                .evaluate(&array)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![true, false, true, false]
+        );
+    }
+}
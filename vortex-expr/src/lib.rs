@@ -8,20 +8,24 @@ mod binary;
 mod column;
 pub mod datafusion;
 mod identity;
+mod in_list;
 mod like;
 mod literal;
 mod not;
 mod operators;
 mod select;
+mod variant_get;
 
 pub use binary::*;
 pub use column::*;
 pub use identity::*;
+pub use in_list::*;
 pub use like::*;
 pub use literal::*;
 pub use not::*;
 pub use operators::*;
 pub use select::*;
+pub use variant_get::*;
 use vortex_array::ArrayData;
 use vortex_dtype::field::Field;
 use vortex_error::{VortexExpect, VortexResult};
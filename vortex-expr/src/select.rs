@@ -3,7 +3,7 @@ use std::fmt::Display;
 
 use itertools::Itertools;
 use vortex_array::aliases::hash_set::HashSet;
-use vortex_array::ArrayData;
+use vortex_array::{ArrayDType, ArrayData};
 use vortex_dtype::field::Field;
 use vortex_error::{vortex_err, VortexResult};
 
@@ -55,6 +55,13 @@ impl VortexExpr for Select {
                             .get(*i)
                             .map(|s| &**s)
                             .ok_or_else(|| vortex_err!("Column doesn't exist")),
+                        Field::Id(id) => batch
+                            .dtype()
+                            .as_struct()
+                            .and_then(|dtype| dtype.find_field_id(*id))
+                            .and_then(|i| st.names().get(i))
+                            .map(|s| &**s)
+                            .ok_or_else(|| vortex_err!("Column doesn't exist")),
                     })
                     .collect::<VortexResult<HashSet<_>>>()?;
                 let included_names = st
@@ -0,0 +1,124 @@
+use std::any::Any;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::array::VariantArray;
+use vortex_array::{ArrayData, IntoArrayVariant};
+use vortex_dtype::field::Field;
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+
+use crate::{unbox_any, ExprRef, VortexExpr};
+
+/// Extract the values of a dtype at a path of a variant, see [`VariantArray::get_path`].
+///
+/// Paths are object keys joined by `.`, e.g. `user.id`. The result is null wherever the value at
+/// the path is missing or of another type.
+#[derive(Debug, Clone)]
+pub struct VariantGet {
+    child: ExprRef,
+    path: String,
+    dtype: DType,
+}
+
+impl VariantGet {
+    pub fn new_expr(child: ExprRef, path: impl Into<String>, dtype: DType) -> ExprRef {
+        Arc::new(Self {
+            child,
+            path: path.into(),
+            dtype: dtype.as_nullable(),
+        })
+    }
+
+    pub fn child(&self) -> &ExprRef {
+        &self.child
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+}
+
+impl Display for VariantGet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}['{}']::{}", self.child, self.path, self.dtype)
+    }
+}
+
+impl VortexExpr for VariantGet {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn evaluate(&self, batch: &ArrayData) -> VortexResult<ArrayData> {
+        let child = self.child.evaluate(batch)?;
+        VariantArray::try_from(child.into_extension()?)?.get_path(&self.path, &self.dtype)
+    }
+
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a Field>) {
+        self.child.collect_references(references);
+    }
+}
+
+impl PartialEq<dyn Any> for VariantGet {
+    fn eq(&self, other: &dyn Any) -> bool {
+        unbox_any(other)
+            .downcast_ref::<Self>()
+            .map(|x| x.child.eq(&self.child) && x.path == self.path && x.dtype == self.dtype)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_array::array::{StructArray, VariantArray};
+    use vortex_array::compute::scalar_at;
+    use vortex_array::{ArrayData, IntoArrayData};
+    use vortex_dtype::field::Field;
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::{BinaryExpr, Column, Literal, Operator, VariantGet};
+
+    #[test]
+    fn filter_variant_path() {
+        let logs = VariantArray::from_json(
+            [
+                Some(r#"{"status":200}"#),
+                Some(r#"{"status":"ok"}"#),
+                Some(r#"{"status":503}"#),
+            ],
+            Nullability::NonNullable,
+            0.5,
+        )
+        .unwrap();
+        let batch = StructArray::from_fields(&[("logs", ArrayData::from(logs))])
+            .unwrap()
+            .into_array();
+
+        let status = VariantGet::new_expr(
+            Column::new_expr(Field::from("logs")),
+            "status",
+            DType::Primitive(PType::I64, Nullability::NonNullable),
+        );
+        assert_eq!(status.to_string(), "$logs['status']::i64?");
+        let errors = BinaryExpr::new_expr(status, Operator::Gte, Literal::new_expr(500i64.into()))
+            .evaluate(&batch)
+            .unwrap();
+        assert_eq!(
+            (0..3)
+                .map(|i| scalar_at(&errors, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Scalar::from(Some(false)),
+                Scalar::null(DType::Bool(Nullability::Nullable)),
+                Scalar::from(Some(true)),
+            ]
+        );
+    }
+}
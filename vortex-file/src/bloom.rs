@@ -0,0 +1,243 @@
+//! Split-block Bloom filters, used to prune chunks for equality predicates.
+//!
+//! The filter follows the split-block Bloom filter of the Parquet specification: values are
+//! hashed with 64-bit xxHash, the upper half of the hash selects a 256-bit block and the lower
+//! half sets one bit in each of the eight 32-bit words of that block.
+
+use std::hash::Hasher;
+
+use twox_hash::XxHash64;
+use vortex_array::accessor::ArrayAccessor;
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::{ArrayDType, ArrayData, IntoArrayVariant};
+use vortex_dtype::{match_each_native_ptype, DType, NativePType};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::{BinaryScalar, Scalar, Utf8Scalar};
+
+const SALT: [u32; 8] = [
+    0x47b6137b, 0x44974d91, 0x8824ad5b, 0xa2b7289d, 0x705495c7, 0x2df1424b, 0x9efc4947, 0x5c6bfb31,
+];
+
+const BYTES_PER_BLOCK: usize = 32;
+const MIN_BYTES: usize = BYTES_PER_BLOCK;
+const MAX_BYTES: usize = 128 * 1024;
+
+/// The false positive probability that filters are sized for.
+pub const DEFAULT_BLOOM_FILTER_FPP: f64 = 0.01;
+
+type Block = [u32; 8];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    blocks: Vec<Block>,
+}
+
+impl BloomFilter {
+    /// Create an empty filter sized for `ndv` distinct values at the given false positive
+    /// probability.
+    pub fn with_capacity(ndv: usize, fpp: f64) -> Self {
+        let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let num_bytes = ((num_bits / 8.0).ceil() as usize)
+            .clamp(MIN_BYTES, MAX_BYTES)
+            .next_power_of_two();
+        Self {
+            blocks: vec![[0; 8]; num_bytes / BYTES_PER_BLOCK],
+        }
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> VortexResult<Self> {
+        if bytes.is_empty() || bytes.len() % BYTES_PER_BLOCK != 0 {
+            vortex_bail!(
+                "Bloom filter size must be a positive multiple of {BYTES_PER_BLOCK}, got {}",
+                bytes.len()
+            );
+        }
+
+        let blocks = bytes
+            .chunks_exact(BYTES_PER_BLOCK)
+            .map(|block| {
+                let mut words = [0u32; 8];
+                for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+                    *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                }
+                words
+            })
+            .collect();
+        Ok(Self { blocks })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.blocks
+            .iter()
+            .flat_map(|block| block.iter().flat_map(|word| word.to_le_bytes()))
+            .collect()
+    }
+
+    pub fn insert(&mut self, value: &[u8]) {
+        self.insert_hash(hash_bytes(value))
+    }
+
+    pub fn contains(&self, value: &[u8]) -> bool {
+        self.contains_hash(hash_bytes(value))
+    }
+
+    fn insert_hash(&mut self, hash: u64) {
+        let idx = self.block_index(hash);
+        let mask = block_mask(hash as u32);
+        for (word, bit) in self.blocks[idx].iter_mut().zip(mask) {
+            *word |= bit;
+        }
+    }
+
+    fn contains_hash(&self, hash: u64) -> bool {
+        let block = &self.blocks[self.block_index(hash)];
+        block
+            .iter()
+            .zip(block_mask(hash as u32))
+            .all(|(word, bit)| word & bit != 0)
+    }
+
+    fn block_index(&self, hash: u64) -> usize {
+        // The number of blocks is at most MAX_BYTES / BYTES_PER_BLOCK, so this fits in a usize.
+        #[allow(clippy::cast_possible_truncation)]
+        let idx = (((hash >> 32) * self.blocks.len() as u64) >> 32) as usize;
+        idx
+    }
+
+    /// Build a filter of the non-null values of an array.
+    ///
+    /// Returns `None` for arrays whose values can't be hashed, i.e. anything but primitive, utf8
+    /// and binary arrays.
+    pub(crate) fn try_from_array(array: &ArrayData, fpp: f64) -> VortexResult<Option<Self>> {
+        let hashes: HashSet<u64> = match array.dtype() {
+            DType::Primitive(ptype, _) => {
+                let primitive = array.clone().into_primitive()?;
+                let validity = primitive.validity();
+                match_each_native_ptype!(ptype, |$T| {
+                    primitive
+                        .maybe_null_slice::<$T>()
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| validity.is_valid(*i))
+                        .map(|(_, v)| hash_primitive(*v))
+                        .collect()
+                })
+            }
+            DType::Utf8(_) | DType::Binary(_) => array
+                .clone()
+                .into_varbinview()?
+                .with_iterator(|iter| iter.flatten().map(hash_bytes).collect())?,
+            _ => return Ok(None),
+        };
+
+        let mut filter = Self::with_capacity(hashes.len(), fpp);
+        for hash in hashes {
+            filter.insert_hash(hash);
+        }
+        Ok(Some(filter))
+    }
+
+    /// Whether the filter may contain the given value.
+    ///
+    /// Returns `None` if the value can't be looked up, in which case it may be present.
+    pub(crate) fn might_contain(&self, value: &Scalar) -> Option<bool> {
+        if value.is_null() {
+            return None;
+        }
+
+        let hash = match value.dtype() {
+            DType::Primitive(ptype, _) => match_each_native_ptype!(ptype, |$T| {
+                hash_primitive(value.as_primitive().typed_value::<$T>()?)
+            }),
+            DType::Utf8(_) => hash_bytes(Utf8Scalar::try_from(value).ok()?.value()?.as_bytes()),
+            DType::Binary(_) => hash_bytes(BinaryScalar::try_from(value).ok()?.value()?.as_slice()),
+            _ => return None,
+        };
+        Some(self.contains_hash(hash))
+    }
+}
+
+fn block_mask(hash: u32) -> Block {
+    let mut mask = [0u32; 8];
+    for (bit, salt) in mask.iter_mut().zip(SALT) {
+        *bit = 1 << (hash.wrapping_mul(salt) >> 27);
+    }
+    mask
+}
+
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = XxHash64::with_seed(0);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+fn hash_primitive<T: NativePType>(value: T) -> u64 {
+    // Negative zero compares equal to zero, so both must hash the same.
+    let value = if value == T::zero() { T::zero() } else { value };
+    hash_bytes(value.to_le_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_array::array::{PrimitiveArray, VarBinViewArray};
+    use vortex_array::IntoArrayData;
+    use vortex_dtype::Nullability;
+    use vortex_scalar::Scalar;
+
+    use super::*;
+
+    #[test]
+    fn primitive_membership() {
+        let array = PrimitiveArray::from((0..1000i64).collect::<Vec<_>>()).into_array();
+        let filter = BloomFilter::try_from_array(&array, DEFAULT_BLOOM_FILTER_FPP)
+            .unwrap()
+            .unwrap();
+
+        for i in 0..1000i64 {
+            assert_eq!(
+                filter.might_contain(&Scalar::primitive(i, Nullability::NonNullable)),
+                Some(true)
+            );
+        }
+        let false_positives = (1000..11000i64)
+            .filter(|i| {
+                filter
+                    .might_contain(&Scalar::primitive(*i, Nullability::NonNullable))
+                    .unwrap()
+            })
+            .count();
+        assert!(false_positives < 500, "{false_positives} false positives");
+    }
+
+    #[test]
+    fn string_membership_roundtrip() {
+        let array = VarBinViewArray::from_iter_str(["foo", "bar", "baz"]).into_array();
+        let filter = BloomFilter::try_from_array(&array, DEFAULT_BLOOM_FILTER_FPP)
+            .unwrap()
+            .unwrap();
+        let filter = BloomFilter::try_from_bytes(&filter.to_bytes()).unwrap();
+
+        assert!(filter.contains(b"bar"));
+        assert_eq!(
+            filter.might_contain(&Scalar::utf8("foo", Nullability::Nullable)),
+            Some(true)
+        );
+        assert_eq!(
+            filter.might_contain(&Scalar::null(DType::Utf8(Nullability::Nullable))),
+            None
+        );
+    }
+
+    #[test]
+    fn negative_zero() {
+        let array = PrimitiveArray::from(vec![0.0f64, 1.5]).into_array();
+        let filter = BloomFilter::try_from_array(&array, DEFAULT_BLOOM_FILTER_FPP)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            filter.might_contain(&Scalar::primitive(-0.0f64, Nullability::NonNullable)),
+            Some(true)
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use std::sync::{Arc, RwLock};
+
+use flatbuffers::root;
+use itertools::Itertools;
+use vortex_array::array::StructArray;
+use vortex_array::builders::{builder_with_capacity, ArrayBuilderExt};
+use vortex_array::compute::scalar_at;
+use vortex_array::stats::{ArrayStatistics, Stat};
+use vortex_array::validity::{ArrayValidity, Validity};
+use vortex_array::variants::StructArrayTrait;
+use vortex_array::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
+use vortex_buffer::Buffer;
+use vortex_dtype::{DType, FieldName};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_flatbuffers::{dtype as fbd, FlatBufferToBytes};
+use vortex_io::{IoDispatcher, VortexReadAt, VortexWrite};
+use vortex_scalar::Scalar;
+
+use crate::dataset::unify_dtypes;
+use crate::metadata::fetch_metadata;
+use crate::pruning::stat_column_name_string;
+use crate::{
+    read_initial_bytes, LayoutDeserializer, LayoutMessageCache, RelativeLayoutCache, Scan,
+    VortexFileWriter, VortexReadBuilder,
+};
+
+/// The stats that are summarized per file in a [`DatasetManifest`].
+pub const MANIFEST_STATS: &[Stat] = &[Stat::Min, Stat::Max, Stat::NullCount];
+
+const PATH_COLUMN: &str = "path";
+const ROW_COUNT_COLUMN: &str = "row_count";
+const DTYPE_COLUMN: &str = "dtype";
+
+/// The summary of a single file of a dataset.
+#[derive(Debug, Clone)]
+pub struct FileSummary {
+    pub path: String,
+    pub row_count: u64,
+    pub dtype: DType,
+    /// File-level statistics for each column, only present if every chunk of the column had them.
+    pub stats: Vec<(FieldName, Stat, Scalar)>,
+}
+
+impl FileSummary {
+    /// Summarize a file by reading its footer and its per-chunk statistics tables.
+    pub async fn try_read<R: VortexReadAt + Unpin>(
+        path: impl Into<String>,
+        read: R,
+        layout_serde: &LayoutDeserializer,
+        dispatcher: Arc<IoDispatcher>,
+    ) -> VortexResult<Self> {
+        let initial_read = read_initial_bytes(&read, read.size().await?).await?;
+        let lazy_dtype = Arc::new(initial_read.lazy_dtype());
+        let dtype = lazy_dtype.value()?.clone();
+        let row_count = initial_read.fb_layout().row_count();
+
+        let message_cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
+        let root_layout = layout_serde.read_layout(
+            initial_read.fb_layout(),
+            Scan::empty(),
+            RelativeLayoutCache::new(message_cache.clone(), lazy_dtype),
+        )?;
+
+        let mut stats = Vec::new();
+        if let (Some(metadata), DType::Struct(st, _)) = (
+            fetch_metadata(read, dispatcher, root_layout, message_cache).await?,
+            &dtype,
+        ) {
+            for (name, column_stats) in st.names().iter().zip_eq(metadata) {
+                let Some(column_stats) = column_stats else {
+                    continue;
+                };
+                for stat in MANIFEST_STATS {
+                    if let Some(value) = summarize_stat(&column_stats, *stat)? {
+                        stats.push((name.clone(), *stat, value));
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            path: path.into(),
+            row_count,
+            dtype,
+            stats,
+        })
+    }
+}
+
+/// Reduce a column of per-chunk statistics to a single file-level value.
+fn summarize_stat(stats_table: &ArrayData, stat: Stat) -> VortexResult<Option<Scalar>> {
+    let Some(values) = stats_table
+        .as_struct_array()
+        .and_then(|st| st.field_by_name(&stat.to_string()))
+    else {
+        return Ok(None);
+    };
+
+    // A single chunk without the stat makes the file-level stat unknown.
+    if values.logical_validity().null_count()? != 0 {
+        return Ok(None);
+    }
+
+    Ok(match stat {
+        Stat::Min | Stat::Max => values.statistics().compute(stat),
+        Stat::NullCount => {
+            let mut null_count = 0u64;
+            for i in 0..values.len() {
+                null_count += u64::try_from(&scalar_at(&values, i)?)?;
+            }
+            Some(null_count.into())
+        }
+        _ => vortex_bail!("Unsupported manifest stat {stat}"),
+    })
+}
+
+/// A table of [`FileSummary`]s, one row per file of a dataset.
+///
+/// The statistics columns are named like those of the per-chunk statistics tables used for
+/// pruning, i.e. `{column}_{stat}`, which lets a
+/// [`PruningPredicate`][crate::pruning::PruningPredicate] prune whole files.
+///
+/// The manifest is itself stored as a Vortex file, so that a dataset can be opened without
+/// reading the footer of each of its files.
+#[derive(Debug, Clone)]
+pub struct DatasetManifest {
+    files: Vec<FileSummary>,
+    table: ArrayData,
+}
+
+impl DatasetManifest {
+    pub fn try_new(files: Vec<FileSummary>) -> VortexResult<Self> {
+        let dtype = unify_dtypes(files.iter().map(|f| &f.dtype))?;
+        let DType::Struct(st, _) = &dtype else {
+            vortex_bail!("Dataset dtype must be a struct, found {dtype}");
+        };
+
+        let mut names: Vec<FieldName> = vec![
+            PATH_COLUMN.into(),
+            ROW_COUNT_COLUMN.into(),
+            DTYPE_COLUMN.into(),
+        ];
+        let mut columns = vec![
+            ArrayData::from_iter(files.iter().map(|f| Some(f.path.clone()))),
+            ArrayData::from(files.iter().map(|f| f.row_count).collect_vec()),
+            ArrayData::from_iter(files.iter().map(|f| {
+                Some(Buffer::from(
+                    f.dtype.with_flatbuffer_bytes(|bytes| bytes.to_vec()),
+                ))
+            })),
+        ];
+
+        for (name, field_dtype) in st.names().iter().zip(st.dtypes().iter()) {
+            for stat in MANIFEST_STATS {
+                if !files
+                    .iter()
+                    .any(|f| f.stats.iter().any(|(n, s, _)| n == name && s == stat))
+                {
+                    continue;
+                }
+
+                let stat_dtype = stat.dtype(field_dtype).as_nullable();
+                let mut builder = builder_with_capacity(&stat_dtype, files.len());
+                for file in files.iter() {
+                    match file.stats.iter().find(|(n, s, _)| n == name && s == stat) {
+                        Some((_, _, value)) => builder.append_scalar(&value.cast(&stat_dtype)?)?,
+                        None => builder.append_null(),
+                    }
+                }
+
+                names.push(stat_column_name_string(&name.as_ref().into(), *stat).into());
+                columns.push(builder.finish()?);
+            }
+        }
+
+        let table =
+            StructArray::try_new(names.into(), columns, files.len(), Validity::NonNullable)?
+                .into_array();
+
+        Ok(Self { files, table })
+    }
+
+    /// Build a manifest by reading the footer and statistics of every file.
+    pub async fn try_build<R: VortexReadAt + Unpin>(
+        files: impl IntoIterator<Item = (String, R)>,
+        layout_serde: &LayoutDeserializer,
+        dispatcher: Arc<IoDispatcher>,
+    ) -> VortexResult<Self> {
+        let mut summaries = Vec::new();
+        for (path, read) in files {
+            summaries
+                .push(FileSummary::try_read(path, read, layout_serde, dispatcher.clone()).await?);
+        }
+        Self::try_new(summaries)
+    }
+
+    /// Read a manifest previously written with [`DatasetManifest::write`].
+    pub async fn read<R: VortexReadAt + Unpin>(read: R) -> VortexResult<Self> {
+        let table = VortexReadBuilder::new(read, LayoutDeserializer::default())
+            .build()
+            .await?
+            .read_all()
+            .await?
+            .into_struct()?;
+
+        let column = |name: &str| {
+            table
+                .field_by_name(name)
+                .ok_or_else(|| vortex_err!("Manifest is missing the {name} column"))
+        };
+        let paths = column(PATH_COLUMN)?.into_varbinview()?;
+        let row_counts = column(ROW_COUNT_COLUMN)?.into_primitive()?;
+        let dtypes = column(DTYPE_COLUMN)?.into_varbinview()?;
+
+        let mut files = Vec::with_capacity(table.len());
+        for i in 0..table.len() {
+            let dtype_bytes = dtypes.bytes_at(i)?;
+            files.push(FileSummary {
+                path: String::from_utf8(paths.bytes_at(i)?.to_vec())
+                    .map_err(|e| vortex_err!("Invalid manifest path: {e}"))?,
+                row_count: row_counts.get_as_cast::<u64>(i),
+                dtype: DType::try_from(root::<fbd::DType>(dtype_bytes.as_slice())?)?,
+                stats: Vec::new(),
+            });
+        }
+
+        let dtype = unify_dtypes(files.iter().map(|f| &f.dtype))?;
+        if let DType::Struct(st, _) = &dtype {
+            for name in st.names().iter() {
+                for stat in MANIFEST_STATS {
+                    let column_name = stat_column_name_string(&name.as_ref().into(), *stat);
+                    let Some(values) = table.field_by_name(&column_name) else {
+                        continue;
+                    };
+                    for (i, file) in files.iter_mut().enumerate() {
+                        let value = scalar_at(&values, i)?;
+                        if !value.is_null() {
+                            file.stats.push((name.clone(), *stat, value));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            files,
+            table: table.into_array(),
+        })
+    }
+
+    /// Write the manifest as a Vortex file.
+    pub async fn write<W: VortexWrite>(&self, write: W) -> VortexResult<W> {
+        VortexFileWriter::new(write)
+            .write_array_columns(self.table.clone())
+            .await?
+            .finalize()
+            .await
+    }
+
+    pub fn files(&self) -> &[FileSummary] {
+        &self.files
+    }
+
+    /// The manifest as a struct array with one row per file.
+    pub fn table(&self) -> &ArrayData {
+        &self.table
+    }
+
+    pub fn row_count(&self) -> u64 {
+        self.files.iter().map(|f| f.row_count).sum()
+    }
+}
@@ -0,0 +1,520 @@
+//! Datasets that span many Vortex files with a unified dtype.
+//!
+//! A [`VortexDataset`] reads a list of files as a single stream of arrays. Every file is
+//! summarized by a [`FileSummary`] holding its dtype, row count and file-level statistics. The
+//! summaries of all files form a [`DatasetManifest`], which is used to prune whole files before
+//! any of their data is read. The manifest can be persisted next to the data so that opening the
+//! dataset doesn't require reading the footer of every file.
+//!
+//! Datasets may be partitioned with [`HivePartitioning`], in which case the values of the
+//! partition columns are taken from `key=value` directories in the paths of the files.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::stream::LocalBoxStream;
+use futures::Stream;
+use futures_util::{stream, StreamExt, TryStreamExt};
+use itertools::Itertools;
+use vortex_array::array::{ChunkedArray, StructArray};
+use vortex_array::compute::{fill_null, filter, FilterMask};
+use vortex_array::validity::Validity;
+use vortex_array::{ArrayData, IntoArrayData};
+use vortex_dtype::field::Field;
+use vortex_dtype::{DType, FieldName, FieldNames, Nullability, StructDType};
+use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
+use vortex_expr::{ExprRef, VortexExpr};
+use vortex_io::{IoDispatcher, VortexReadAt};
+use vortex_scalar::Scalar;
+
+use crate::dataset::partition::partitioned_dtype;
+use crate::pruning::PruningPredicate;
+use crate::read::evolution::adapt_struct;
+use crate::{widen_dtype, LayoutDeserializer, Projection, RowFilter, VortexReadBuilder};
+
+mod manifest;
+mod partition;
+
+pub use manifest::*;
+pub use partition::*;
+
+/// A collection of Vortex files that is read as a single stream of arrays.
+///
+/// Each file must have a struct dtype. The dtype of the dataset is the union of the fields of all
+/// files, matched by name. Fields that are missing from some files must be nullable, and they are
+/// filled with nulls when reading those files.
+///
+/// Row filters must reference columns by name.
+pub struct VortexDataset<R> {
+    files: Vec<R>,
+    manifest: DatasetManifest,
+    dtype: DType,
+    layout_serde: LayoutDeserializer,
+    projection: Projection,
+    row_filter: Option<RowFilter>,
+    io_dispatcher: Option<Arc<IoDispatcher>>,
+    partitioning: Option<(HivePartitioning, Vec<Vec<Scalar>>)>,
+}
+
+impl<R: VortexReadAt + Unpin> VortexDataset<R> {
+    /// Open a dataset over the given files, reading the footer and statistics of every file.
+    pub async fn try_open(
+        files: impl IntoIterator<Item = (String, R)>,
+        layout_serde: LayoutDeserializer,
+    ) -> VortexResult<Self> {
+        let (paths, files): (Vec<_>, Vec<_>) = files.into_iter().unzip();
+        let manifest = DatasetManifest::try_build(
+            paths.into_iter().zip(files.iter().cloned()),
+            &layout_serde,
+            Arc::new(IoDispatcher::default()),
+        )
+        .await?;
+        Self::try_new_with_manifest(files, manifest, layout_serde)
+    }
+
+    /// Create a dataset from a previously built manifest, without reading any of the files.
+    ///
+    /// `files` must be in the same order as the files of the manifest.
+    pub fn try_new_with_manifest(
+        files: Vec<R>,
+        manifest: DatasetManifest,
+        layout_serde: LayoutDeserializer,
+    ) -> VortexResult<Self> {
+        if files.len() != manifest.files().len() {
+            vortex_bail!(
+                "Expected {} files based on the manifest, found {}",
+                manifest.files().len(),
+                files.len()
+            );
+        }
+
+        let dtype = unify_dtypes(manifest.files().iter().map(|f| &f.dtype))?;
+        Ok(Self {
+            files,
+            manifest,
+            dtype,
+            layout_serde,
+            projection: Projection::default(),
+            row_filter: None,
+            io_dispatcher: None,
+            partitioning: None,
+        })
+    }
+
+    /// Open a dataset of hive-partitioned files.
+    ///
+    /// Files whose partition values don't match the row filter are skipped before their footers
+    /// are read.
+    pub async fn try_open_partitioned(
+        files: impl IntoIterator<Item = (String, R)>,
+        partitioning: HivePartitioning,
+        row_filter: Option<RowFilter>,
+        layout_serde: LayoutDeserializer,
+    ) -> VortexResult<Self> {
+        let mut files = files.into_iter().collect_vec();
+        if let Some(row_filter) = row_filter.as_ref() {
+            let paths = files.iter().map(|(path, _)| path.as_str()).collect_vec();
+            let mut matches = partitioning.matches(&paths, row_filter)?.into_iter();
+            files.retain(|_| matches.next().unwrap_or(true));
+        }
+
+        let mut dataset = Self::try_open(files, layout_serde)
+            .await?
+            .with_partitioning(partitioning)?;
+        dataset.row_filter = row_filter;
+        Ok(dataset)
+    }
+
+    /// Materialize the values of the partition columns encoded in the paths of the files.
+    ///
+    /// The partition columns are appended to the fields of the dataset.
+    pub fn with_partitioning(mut self, partitioning: HivePartitioning) -> VortexResult<Self> {
+        let values = self
+            .manifest
+            .files()
+            .iter()
+            .map(|f| partitioning.parse_path(&f.path))
+            .collect::<VortexResult<Vec<_>>>()?;
+        self.dtype = partitioned_dtype(
+            &unify_dtypes(self.manifest.files().iter().map(|f| &f.dtype))?,
+            &partitioning,
+        )?;
+        self.partitioning = Some((partitioning, values));
+        Ok(self)
+    }
+
+    pub fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    pub fn manifest(&self) -> &DatasetManifest {
+        &self.manifest
+    }
+
+    pub fn row_count(&self) -> u64 {
+        self.manifest.row_count()
+    }
+
+    pub fn with_projection(mut self, projection: Projection) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    pub fn with_row_filter(mut self, row_filter: RowFilter) -> Self {
+        self.row_filter = Some(row_filter);
+        self
+    }
+
+    pub fn with_io_dispatcher(mut self, dispatcher: Arc<IoDispatcher>) -> Self {
+        self.io_dispatcher = Some(dispatcher);
+        self
+    }
+
+    /// Returns for each file whether the row filter proves that none of its rows match.
+    pub(crate) fn pruned_files(&self) -> VortexResult<Vec<bool>> {
+        let not_pruned = vec![false; self.files.len()];
+        let Some(row_filter) = self.row_filter.as_ref() else {
+            return Ok(not_pruned);
+        };
+
+        let mut pruned = not_pruned;
+        if let Some((partitioning, _)) = self.partitioning.as_ref() {
+            let paths = self
+                .manifest
+                .files()
+                .iter()
+                .map(|f| f.path.as_str())
+                .collect_vec();
+            for (pruned, matches) in pruned
+                .iter_mut()
+                .zip(partitioning.matches(&paths, row_filter)?)
+            {
+                *pruned |= !matches;
+            }
+        }
+
+        let filter_expr: ExprRef = Arc::new(row_filter.clone());
+        if let Some(stats_pruned) = PruningPredicate::try_new(&filter_expr)
+            .map(|p| p.evaluate(self.manifest.table()))
+            .transpose()?
+            .flatten()
+        {
+            let stats_pruned = FilterMask::try_from(fill_null(
+                stats_pruned,
+                Scalar::bool(false, Nullability::NonNullable),
+            )?)?;
+            for (pruned, stats_pruned) in pruned
+                .iter_mut()
+                .zip(stats_pruned.to_boolean_buffer()?.iter())
+            {
+                *pruned |= stats_pruned;
+            }
+        }
+
+        Ok(pruned)
+    }
+
+    pub fn build(self) -> VortexResult<VortexDatasetStream> {
+        let DType::Struct(dataset_dtype, _) = &self.dtype else {
+            vortex_bail!("Dataset dtype must be a struct, found {}", self.dtype);
+        };
+
+        let output_dtype = match &self.projection {
+            Projection::All => dataset_dtype.clone(),
+            Projection::Flat(fields) => dataset_dtype.project(fields)?,
+            Projection::Nested(_) => {
+                vortex_bail!("Nested projections are not supported for datasets")
+            }
+        };
+
+        let pruned = self.pruned_files()?;
+        let plan = Arc::new(FileScanPlan {
+            dataset_dtype: dataset_dtype.clone(),
+            output_dtype: output_dtype.clone(),
+            row_filter: self.row_filter,
+            layout_serde: self.layout_serde,
+            io_dispatcher: self.io_dispatcher.unwrap_or_default(),
+        });
+
+        let partition_values = match self.partitioning {
+            Some((partitioning, values)) => values
+                .into_iter()
+                .map(|values| {
+                    partitioning
+                        .dtype()
+                        .names()
+                        .iter()
+                        .cloned()
+                        .zip(values)
+                        .collect_vec()
+                })
+                .collect_vec(),
+            None => vec![Vec::new(); self.files.len()],
+        };
+        let files = self
+            .files
+            .into_iter()
+            .zip(self.manifest.files().iter().map(|f| f.dtype.clone()))
+            .zip(partition_values)
+            .map(|((read, dtype), constants)| (read, dtype, constants))
+            .zip(pruned)
+            .filter(|(_, pruned)| !pruned)
+            .map(|(file, _)| file)
+            .collect_vec();
+
+        let inner = stream::iter(files)
+            .then(move |(read, file_dtype, constants)| {
+                let plan = plan.clone();
+                async move { plan.open(read, &file_dtype, constants).await }
+            })
+            .try_flatten()
+            .boxed_local();
+
+        Ok(VortexDatasetStream {
+            dtype: DType::Struct(output_dtype, Nullability::NonNullable),
+            inner,
+        })
+    }
+}
+
+/// Everything needed to read a single file of a dataset.
+struct FileScanPlan {
+    dataset_dtype: StructDType,
+    output_dtype: StructDType,
+    row_filter: Option<RowFilter>,
+    layout_serde: LayoutDeserializer,
+    io_dispatcher: Arc<IoDispatcher>,
+}
+
+impl FileScanPlan {
+    async fn open<R: VortexReadAt + Unpin>(
+        &self,
+        read: R,
+        file_dtype: &DType,
+        constants: Vec<(FieldName, Scalar)>,
+    ) -> VortexResult<LocalBoxStream<'static, VortexResult<ArrayData>>> {
+        let DType::Struct(file_dtype, _) = file_dtype else {
+            vortex_bail!(
+                "Dataset files must have a struct dtype, found {}",
+                file_dtype
+            );
+        };
+        if file_dtype.names().is_empty() {
+            return self.open_without_columns(read, constants).await;
+        }
+
+        let in_file = |name: &FieldName| file_dtype.find_name(name).is_some();
+        let unchanged = |name: &FieldName| {
+            file_dtype
+                .find_name(name)
+                .map(|idx| &file_dtype.dtypes()[idx])
+                == self
+                    .dataset_dtype
+                    .find_name(name)
+                    .map(|idx| &self.dataset_dtype.dtypes()[idx])
+        };
+
+        let filter_names = self
+            .row_filter
+            .as_ref()
+            .map(|f| {
+                f.references()
+                    .into_iter()
+                    .map(|field| match field {
+                        Field::Name(n) => Ok(FieldName::from(n.as_str())),
+                        Field::Index(_) | Field::Id(_) => {
+                            vortex_bail!("Dataset row filters must reference fields by name")
+                        }
+                    })
+                    .collect::<VortexResult<Vec<_>>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        let builder = VortexReadBuilder::new(read, self.layout_serde.clone())
+            .with_io_dispatcher(self.io_dispatcher.clone());
+        let output_dtype = self.output_dtype.clone();
+
+        // If the file has all the columns the filter needs with their dataset dtypes, the filter
+        // is pushed into the file reader, otherwise the filter is evaluated after the missing
+        // columns are filled in and the others are cast.
+        if filter_names.iter().all(unchanged) {
+            let mut read_names = output_dtype
+                .names()
+                .iter()
+                .filter(|n| in_file(n))
+                .cloned()
+                .collect_vec();
+            if read_names.is_empty() {
+                // We need at least one column to know the number of rows.
+                read_names.extend(file_dtype.names().first().cloned());
+            }
+
+            let mut builder = builder.with_projection(projection_of(&read_names));
+            if let Some(row_filter) = self.row_filter.clone() {
+                builder = builder.with_row_filter(row_filter);
+            }
+
+            Ok(builder
+                .build()
+                .await?
+                .map(move |batch| adapt_struct(&batch?, &output_dtype, &constants))
+                .boxed_local())
+        } else {
+            let row_filter = self
+                .row_filter
+                .clone()
+                .vortex_expect("Row filter must be present when it references columns");
+            let read_dtype = self.dataset_dtype.project(
+                &self
+                    .dataset_dtype
+                    .names()
+                    .iter()
+                    .filter(|n| output_dtype.find_name(n).is_some() || filter_names.contains(n))
+                    .map(|n| Field::from(n.as_ref()))
+                    .collect_vec(),
+            )?;
+            let read_names = read_dtype
+                .names()
+                .iter()
+                .filter(|n| in_file(n))
+                .cloned()
+                .collect_vec();
+
+            Ok(builder
+                .with_projection(projection_of(&read_names))
+                .build()
+                .await?
+                .map(move |batch| {
+                    let batch = adapt_struct(&batch?, &read_dtype, &constants)?;
+                    let mask = fill_null(
+                        row_filter.evaluate(&batch)?,
+                        Scalar::bool(false, Nullability::NonNullable),
+                    )?;
+                    adapt_struct(
+                        &filter(&batch, FilterMask::try_from(mask)?)?,
+                        &output_dtype,
+                        &constants,
+                    )
+                })
+                .boxed_local())
+        }
+    }
+
+    /// Read a file without columns, which only has a number of rows, as nulls and constants.
+    async fn open_without_columns<R: VortexReadAt + Unpin>(
+        &self,
+        read: R,
+        constants: Vec<(FieldName, Scalar)>,
+    ) -> VortexResult<LocalBoxStream<'static, VortexResult<ArrayData>>> {
+        let row_count = VortexReadBuilder::new(read, self.layout_serde.clone())
+            .with_io_dispatcher(self.io_dispatcher.clone())
+            .build()
+            .await?
+            .row_count();
+        let empty = StructArray::try_new(
+            FieldNames::default(),
+            Vec::new(),
+            row_count as usize,
+            Validity::NonNullable,
+        )?;
+        let batch = adapt_struct(&empty.into_array(), &self.dataset_dtype, &constants)?;
+        let batch = match self.row_filter.as_ref() {
+            Some(row_filter) => {
+                let mask = fill_null(
+                    row_filter.evaluate(&batch)?,
+                    Scalar::bool(false, Nullability::NonNullable),
+                )?;
+                filter(&batch, FilterMask::try_from(mask)?)?
+            }
+            None => batch,
+        };
+        let batch = adapt_struct(&batch, &self.output_dtype, &constants);
+        Ok(stream::once(async move { batch }).boxed_local())
+    }
+}
+
+fn projection_of(names: &[FieldName]) -> Projection {
+    Projection::Flat(names.iter().map(|n| Field::from(n.as_ref())).collect())
+}
+
+/// Compute the dtype of a dataset from the dtypes of its files.
+///
+/// Fields are matched by name, and the fields of the result are ordered by first appearance.
+/// Numeric fields are widened to the narrowest dtype that can hold the values of all files, see
+/// [`widen_dtype`].
+pub fn unify_dtypes<'a>(dtypes: impl IntoIterator<Item = &'a DType>) -> VortexResult<DType> {
+    let mut names: Vec<FieldName> = Vec::new();
+    let mut field_dtypes: Vec<DType> = Vec::new();
+    let mut field_counts: Vec<usize> = Vec::new();
+    let mut file_count = 0;
+
+    for dtype in dtypes {
+        let DType::Struct(st, _) = dtype else {
+            vortex_bail!("Dataset files must have a struct dtype, found {}", dtype);
+        };
+        file_count += 1;
+
+        for (name, field_dtype) in st.names().iter().zip(st.dtypes().iter()) {
+            match names.iter().position(|n| n == name) {
+                Some(idx) => {
+                    field_dtypes[idx] =
+                        widen_dtype(&field_dtypes[idx], field_dtype).ok_or_else(|| {
+                            vortex_err!(
+                                "Field {} has incompatible dtypes {} and {}",
+                                name,
+                                field_dtypes[idx],
+                                field_dtype
+                            )
+                        })?;
+                    field_counts[idx] += 1;
+                }
+                None => {
+                    names.push(name.clone());
+                    field_dtypes.push(field_dtype.clone());
+                    field_counts.push(1);
+                }
+            }
+        }
+    }
+
+    for ((name, dtype), count) in names.iter().zip(field_dtypes.iter()).zip(field_counts) {
+        if count != file_count && !dtype.is_nullable() {
+            vortex_bail!(
+                "Field {name} is missing from some files, but its dtype {dtype} is not nullable"
+            );
+        }
+    }
+
+    Ok(DType::Struct(
+        StructDType::new(names.into(), field_dtypes),
+        Nullability::NonNullable,
+    ))
+}
+
+/// A [`Stream`] of the arrays of all the files of a [`VortexDataset`].
+pub struct VortexDatasetStream {
+    dtype: DType,
+    inner: LocalBoxStream<'static, VortexResult<ArrayData>>,
+}
+
+impl VortexDatasetStream {
+    pub fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    pub async fn read_all(self) -> VortexResult<ArrayData> {
+        let dtype = self.dtype.clone();
+        let arrays: Vec<ArrayData> = self.try_collect().await?;
+        ChunkedArray::try_new(arrays, dtype).map(|c| c.into_array())
+    }
+}
+
+impl Stream for VortexDatasetStream {
+    type Item = VortexResult<ArrayData>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
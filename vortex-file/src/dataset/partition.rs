@@ -0,0 +1,382 @@
+use std::future::Future;
+
+use itertools::Itertools;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+use vortex_array::aliases::hash_map::HashMap;
+use vortex_array::array::{PrimitiveArray, StructArray};
+use vortex_array::builders::{builder_with_capacity, ArrayBuilderExt};
+use vortex_array::compute::{scalar_at, take, FilterMask, TakeOptions};
+use vortex_array::validity::Validity;
+use vortex_array::{ArrayDType, ArrayData, IntoArrayData};
+use vortex_dtype::field::Field;
+use vortex_dtype::{match_each_native_ptype, DType, FieldName, StructDType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_expr::VortexExpr;
+use vortex_io::VortexWrite;
+use vortex_scalar::Scalar;
+
+use crate::{RowFilter, VortexFileWriter};
+
+/// The directory value used for null partition values, compatible with Hive and DataFusion.
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The characters that Hive percent-encodes in partition directories.
+const HIVE_ESCAPED: &AsciiSet = &CONTROLS
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'\'')
+    .add(b'*')
+    .add(b'/')
+    .add(b':')
+    .add(b'=')
+    .add(b'?')
+    .add(b'\\')
+    .add(b'[')
+    .add(b']')
+    .add(b'^')
+    .add(b'{');
+
+/// Escape a partition value for use in a directory name, percent-encoding the characters that
+/// Hive does, such as `/` and `=`.
+pub fn escape_partition_value(value: &str) -> String {
+    utf8_percent_encode(value, HIVE_ESCAPED).to_string()
+}
+
+/// Hive-style partitioning, where the values of the partition columns are encoded in the
+/// directories of a file's path, e.g. `year=2024/month=10/data.vortex`.
+///
+/// Partition columns are not stored in the files themselves. Only boolean, primitive and utf8
+/// partition columns are supported.
+#[derive(Debug, Clone)]
+pub struct HivePartitioning {
+    dtype: StructDType,
+}
+
+impl HivePartitioning {
+    pub fn try_new(dtype: StructDType) -> VortexResult<Self> {
+        for (name, dtype) in dtype.names().iter().zip(dtype.dtypes().iter()) {
+            if !matches!(
+                dtype,
+                DType::Bool(_) | DType::Primitive(..) | DType::Utf8(_)
+            ) {
+                vortex_bail!("Unsupported dtype {dtype} for partition column {name}");
+            }
+        }
+        Ok(Self { dtype })
+    }
+
+    /// The names and dtypes of the partition columns.
+    pub fn dtype(&self) -> &StructDType {
+        &self.dtype
+    }
+
+    /// Parse the values of the partition columns from the directories of a path.
+    ///
+    /// Segments of the path that don't name a partition column are ignored.
+    pub fn parse_path(&self, path: &str) -> VortexResult<Vec<Scalar>> {
+        let segments = path
+            .split('/')
+            .filter_map(|segment| segment.split_once('='))
+            .collect_vec();
+
+        self.dtype
+            .names()
+            .iter()
+            .zip(self.dtype.dtypes().iter())
+            .map(|(name, dtype)| {
+                let (_, value) = segments
+                    .iter()
+                    .find(|(key, _)| *key == name.as_ref())
+                    .ok_or_else(|| vortex_err!("Path {path} has no value for partition {name}"))?;
+                parse_partition_value(value, dtype)
+            })
+            .collect()
+    }
+
+    /// The relative directory of the partition with the given values, ending in a `/`.
+    pub fn partition_dir(&self, values: &[Scalar]) -> String {
+        self.dtype
+            .names()
+            .iter()
+            .zip(values)
+            .map(|(name, value)| format!("{name}={}/", format_partition_value(value)))
+            .join("")
+    }
+
+    /// Split a struct array by the values of its partition columns.
+    ///
+    /// Returns the relative directory of each partition together with its rows, without the
+    /// partition columns. Partitions are returned in order of their first row.
+    pub fn split(&self, array: &ArrayData) -> VortexResult<Vec<(String, ArrayData)>> {
+        let st = array
+            .as_struct_array()
+            .ok_or_else(|| vortex_err!("Expected a struct array, found {}", array.dtype()))?;
+
+        let partition_columns = self
+            .dtype
+            .names()
+            .iter()
+            .zip(self.dtype.dtypes().iter())
+            .map(|(name, dtype)| {
+                let column = st
+                    .field_by_name(name)
+                    .ok_or_else(|| vortex_err!("Missing partition column {name}"))?;
+                if column.dtype() != dtype {
+                    vortex_bail!(
+                        "Partition column {name} has dtype {}, expected {dtype}",
+                        column.dtype()
+                    );
+                }
+                Ok(column)
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        let data_names = st
+            .names()
+            .iter()
+            .filter(|name| self.dtype.find_name(name).is_none())
+            .map(|name| Field::from(name.as_ref()))
+            .collect_vec();
+        let data = StructArray::try_from(array.clone())?
+            .project(&data_names)?
+            .into_array();
+
+        let mut partitions: Vec<(String, Vec<u64>)> = Vec::new();
+        let mut partition_idx: HashMap<String, usize> = HashMap::new();
+        for row in 0..array.len() {
+            let values = partition_columns
+                .iter()
+                .map(|column| scalar_at(column, row))
+                .collect::<VortexResult<Vec<_>>>()?;
+            let dir = self.partition_dir(&values);
+            let idx = *partition_idx.entry(dir.clone()).or_insert_with(|| {
+                partitions.push((dir, Vec::new()));
+                partitions.len() - 1
+            });
+            partitions[idx].1.push(row as u64);
+        }
+
+        partitions
+            .into_iter()
+            .map(|(dir, rows)| {
+                let rows = take(
+                    &data,
+                    PrimitiveArray::from(rows).into_array(),
+                    TakeOptions::default(),
+                )?;
+                Ok((dir, rows))
+            })
+            .collect()
+    }
+
+    /// Returns for each path whether its partition may contain rows matching the filter.
+    ///
+    /// Only the conjuncts of the filter that reference nothing but partition columns are
+    /// considered. Since every file holds a single value of each partition column, these are
+    /// evaluated exactly.
+    pub fn matches(&self, paths: &[&str], row_filter: &RowFilter) -> VortexResult<Vec<bool>> {
+        let is_partition_field = |field: &Field| match field {
+            Field::Name(name) => self.dtype.find_name(name).is_some(),
+            Field::Index(_) | Field::Id(_) => false,
+        };
+        let conjunction = row_filter
+            .conjunction
+            .iter()
+            .filter(|conjunct| conjunct.references().into_iter().all(is_partition_field))
+            .cloned()
+            .collect_vec();
+        if conjunction.is_empty() {
+            return Ok(vec![true; paths.len()]);
+        }
+
+        let values = paths
+            .iter()
+            .map(|path| self.parse_path(path))
+            .collect::<VortexResult<Vec<_>>>()?;
+        let columns = self
+            .dtype
+            .dtypes()
+            .iter()
+            .enumerate()
+            .map(|(idx, dtype)| {
+                let mut builder = builder_with_capacity(dtype, paths.len());
+                for file_values in values.iter() {
+                    builder.append_scalar(&file_values[idx])?;
+                }
+                builder.finish()
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        let table = StructArray::try_new(
+            self.dtype.names().clone(),
+            columns,
+            paths.len(),
+            Validity::NonNullable,
+        )?
+        .into_array();
+
+        let matches = RowFilter::from_conjunction(conjunction).evaluate(&table)?;
+        Ok(FilterMask::try_from(matches)?
+            .to_boolean_buffer()?
+            .iter()
+            .collect())
+    }
+}
+
+/// Format a partition value for use as a directory name.
+fn format_partition_value(value: &Scalar) -> String {
+    if value.is_null() {
+        return HIVE_DEFAULT_PARTITION.to_string();
+    }
+
+    match value.dtype() {
+        DType::Primitive(ptype, _) => match_each_native_ptype!(ptype, |$T| {
+            value
+                .as_primitive()
+                .typed_value::<$T>()
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| HIVE_DEFAULT_PARTITION.to_string())
+        }),
+        _ => escape_partition_value(&value.to_string()),
+    }
+}
+
+/// Parse a partition value from its directory representation.
+fn parse_partition_value(value: &str, dtype: &DType) -> VortexResult<Scalar> {
+    if value == HIVE_DEFAULT_PARTITION {
+        if !dtype.is_nullable() {
+            vortex_bail!("Null value for non-nullable partition dtype {dtype}");
+        }
+        return Ok(Scalar::null(dtype.clone()));
+    }
+
+    let value = percent_decode_str(value)
+        .decode_utf8()
+        .map_err(|e| vortex_err!("Invalid partition value {value}: {e}"))?;
+    let value = value.as_ref();
+    Ok(match dtype {
+        DType::Utf8(n) => Scalar::utf8(value, *n),
+        DType::Bool(n) => Scalar::bool(
+            value
+                .parse()
+                .map_err(|e| vortex_err!("Invalid boolean partition value {value}: {e}"))?,
+            *n,
+        ),
+        DType::Primitive(ptype, n) => match_each_native_ptype!(ptype, |$T| {
+            Scalar::primitive(
+                value
+                    .parse::<$T>()
+                    .map_err(|e| vortex_err!("Invalid {ptype} partition value {value}: {e}"))?,
+                *n,
+            )
+        }),
+        _ => vortex_bail!("Unsupported partition dtype {dtype}"),
+    })
+}
+
+/// Writes struct arrays into one Vortex file per partition.
+///
+/// The partition columns are removed from the arrays before they are written. New files are
+/// created on demand by calling `create` with the relative directory of the partition.
+pub struct HivePartitionedWriter<W, F> {
+    partitioning: HivePartitioning,
+    create: F,
+    writers: HashMap<String, VortexFileWriter<W>>,
+}
+
+impl<W, F, Fut> HivePartitionedWriter<W, F>
+where
+    W: VortexWrite,
+    F: FnMut(&str) -> Fut,
+    Fut: Future<Output = VortexResult<W>>,
+{
+    pub fn new(partitioning: HivePartitioning, create: F) -> Self {
+        Self {
+            partitioning,
+            create,
+            writers: HashMap::new(),
+        }
+    }
+
+    pub async fn write_array_columns(mut self, array: ArrayData) -> VortexResult<Self> {
+        for (dir, rows) in self.partitioning.split(&array)? {
+            let writer = match self.writers.remove(&dir) {
+                Some(writer) => writer,
+                None => VortexFileWriter::new((self.create)(&dir).await?),
+            };
+            self.writers
+                .insert(dir, writer.write_array_columns(rows).await?);
+        }
+        Ok(self)
+    }
+
+    /// Finalize all files, returning them by the relative directory of their partition.
+    pub async fn finalize(self) -> VortexResult<Vec<(String, W)>> {
+        let mut written = Vec::with_capacity(self.writers.len());
+        for (dir, writer) in self.writers {
+            written.push((dir, writer.finalize().await?));
+        }
+        written.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(written)
+    }
+}
+
+/// The fields of a partitioned dataset, the fields of its files followed by the partition columns.
+pub(crate) fn partitioned_dtype(
+    file_dtype: &DType,
+    partitioning: &HivePartitioning,
+) -> VortexResult<DType> {
+    let DType::Struct(st, n) = file_dtype else {
+        vortex_bail!("Dataset dtype must be a struct, found {file_dtype}");
+    };
+
+    let mut names: Vec<FieldName> = st.names().to_vec();
+    let mut dtypes = st.dtypes().to_vec();
+    for (name, dtype) in partitioning
+        .dtype()
+        .names()
+        .iter()
+        .zip(partitioning.dtype().dtypes().iter())
+    {
+        if names.contains(name) {
+            vortex_bail!("Partition column {name} is also stored in the files");
+        }
+        names.push(name.clone());
+        dtypes.push(dtype.clone());
+    }
+
+    Ok(DType::Struct(StructDType::new(names.into(), dtypes), *n))
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::{DType, Nullability, PType, StructDType};
+    use vortex_scalar::Scalar;
+
+    use super::*;
+
+    #[test]
+    fn escaped_partition_values() {
+        let partitioning = HivePartitioning::try_new(StructDType::new(
+            ["path".into(), "id".into()].into(),
+            vec![
+                DType::Utf8(Nullability::Nullable),
+                DType::Primitive(PType::I32, Nullability::NonNullable),
+            ],
+        ))
+        .unwrap();
+        let values = vec![
+            Scalar::utf8("a/b=c%d", Nullability::Nullable),
+            Scalar::primitive(-1i32, Nullability::NonNullable),
+        ];
+
+        let dir = partitioning.partition_dir(&values);
+        assert_eq!(dir, "path=a%2Fb%3Dc%25d/id=-1/");
+        assert_eq!(
+            partitioning
+                .parse_path(&format!("table/{dir}data.vortex"))
+                .unwrap(),
+            values
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use arrow_buffer::BooleanBufferBuilder;
+use itertools::Itertools;
+use vortex_array::array::{BoolArray, PrimitiveArray, StructArray};
+use vortex_array::variants::StructArrayTrait;
+use vortex_array::{IntoArrayData, IntoArrayVariant};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_io::{VortexReadAt, VortexWrite};
+
+use crate::{LayoutDeserializer, RowMask, VortexFileWriter, VortexReadBuilder};
+
+const DELETED_COLUMN: &str = "deleted";
+
+/// The set of deleted rows of a Vortex file.
+///
+/// Deleting rows from an immutable file would require rewriting it, so deletions are instead
+/// recorded in a sidecar deletion vector, which is itself a small Vortex file holding the sorted
+/// indices of the deleted rows. Pass it to
+/// [VortexReadBuilder::with_deletion_vector][crate::VortexReadBuilder::with_deletion_vector] to
+/// skip the deleted rows when reading.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeletionVector {
+    // Sorted and deduplicated
+    deleted: Vec<u64>,
+}
+
+impl DeletionVector {
+    pub fn from_indices(indices: impl IntoIterator<Item = u64>) -> Self {
+        Self {
+            deleted: indices.into_iter().sorted_unstable().dedup().collect(),
+        }
+    }
+
+    /// The indices of the deleted rows, in ascending order.
+    pub fn indices(&self) -> &[u64] {
+        &self.deleted
+    }
+
+    pub fn deleted_count(&self) -> u64 {
+        self.deleted.len() as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.deleted.is_empty()
+    }
+
+    pub fn is_deleted(&self, row: u64) -> bool {
+        self.deleted.binary_search(&row).is_ok()
+    }
+
+    /// Mark more rows as deleted.
+    pub fn delete(&mut self, indices: impl IntoIterator<Item = u64>) {
+        self.deleted.extend(indices);
+        self.deleted.sort_unstable();
+        self.deleted.dedup();
+    }
+
+    /// The mask of rows of a file with `row_count` rows that have not been deleted.
+    pub(crate) fn live_rows(&self, row_count: u64) -> VortexResult<RowMask> {
+        let len = usize::try_from(row_count)?;
+        if let Some(last) = self.deleted.last() {
+            if *last >= row_count {
+                vortex_bail!(
+                    "Deleted row {last} is out of bounds for a file with {row_count} rows"
+                );
+            }
+        }
+
+        let mut live = BooleanBufferBuilder::new(len);
+        live.append_n(len, true);
+        for row in self.deleted.iter() {
+            live.set_bit(usize::try_from(*row)?, false);
+        }
+
+        RowMask::try_new(BoolArray::from(live.finish()).into_array(), 0, len)
+    }
+
+    /// Read a deletion vector previously written with [`DeletionVector::write`].
+    pub async fn read<R: VortexReadAt + Unpin>(read: R) -> VortexResult<Self> {
+        let table = VortexReadBuilder::new(read, LayoutDeserializer::default())
+            .build()
+            .await?
+            .read_all()
+            .await?
+            .into_struct()?;
+        let deleted = table
+            .field_by_name(DELETED_COLUMN)
+            .ok_or_else(|| vortex_err!("Deletion vector is missing the {DELETED_COLUMN} column"))?
+            .into_primitive()?;
+
+        Ok(Self::from_indices(
+            deleted.maybe_null_slice::<u64>().iter().copied(),
+        ))
+    }
+
+    /// Write the deletion vector as a Vortex file.
+    pub async fn write<W: VortexWrite>(&self, write: W) -> VortexResult<W> {
+        let table = StructArray::from_fields(&[(
+            DELETED_COLUMN,
+            PrimitiveArray::from(self.deleted.clone()).into_array(),
+        )])?;
+
+        VortexFileWriter::new(write)
+            .write_array_columns(table.into_array())
+            .await?
+            .finalize()
+            .await
+    }
+}
@@ -0,0 +1,170 @@
+//! Column-level encryption of the messages of flat layouts with AES-256-GCM.
+//!
+//! Encrypted messages are written as a little-endian u32 length, followed by a random nonce, the
+//! ciphertext of the message and the authentication tag. The id of the key is recorded in the
+//! footer on every flat layout holding encrypted messages, and authenticated along with the
+//! ciphertext.
+
+use std::fmt::{Debug, Formatter};
+
+use bytes::Bytes;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use vortex_array::aliases::hash_map::HashMap;
+use vortex_dtype::FieldName;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+/// The size of the length prefix of encrypted messages.
+const LENGTH_PREFIX_SIZE: usize = 4;
+
+/// A 256-bit key for AES-256-GCM.
+#[derive(Clone)]
+pub struct EncryptionKey([u8; 32]);
+
+impl EncryptionKey {
+    pub fn new(key: [u8; 32]) -> Self {
+        Self(key)
+    }
+}
+
+impl Debug for EncryptionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Never print the key itself.
+        f.write_str("EncryptionKey(..)")
+    }
+}
+
+/// Provides the keys to encrypt columns with when writing a file.
+pub trait KeyProvider: Debug + Send + Sync {
+    /// The id and key to encrypt the given top-level column with.
+    ///
+    /// The id is stored in the footer of the file in plain text, and is passed to the
+    /// [`KeyResolver`] of readers to resolve the key.
+    fn column_key(&self, column: &FieldName) -> VortexResult<(String, EncryptionKey)>;
+}
+
+/// Resolves the keys of encrypted columns when reading a file.
+pub trait KeyResolver: Debug + Send + Sync {
+    /// The key with the given id.
+    fn resolve_key(&self, key_id: &str) -> VortexResult<EncryptionKey>;
+}
+
+impl KeyResolver for HashMap<String, EncryptionKey> {
+    fn resolve_key(&self, key_id: &str) -> VortexResult<EncryptionKey> {
+        self.get(key_id)
+            .cloned()
+            .ok_or_else(|| vortex_err!("Unknown encryption key {key_id}"))
+    }
+}
+
+/// Encrypts and decrypts messages with a single key.
+pub(crate) struct MessageCipher {
+    key_id: String,
+    key: LessSafeKey,
+}
+
+impl Debug for MessageCipher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessageCipher")
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MessageCipher {
+    pub(crate) fn new(key_id: String, key: &EncryptionKey) -> VortexResult<Self> {
+        let key = UnboundKey::new(&AES_256_GCM, &key.0)
+            .map_err(|_| vortex_err!("Invalid AES-256-GCM key {key_id}"))?;
+        Ok(Self {
+            key_id,
+            key: LessSafeKey::new(key),
+        })
+    }
+
+    pub(crate) fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    /// Encrypt a message, returning the length prefixed nonce, ciphertext and tag.
+    pub(crate) fn encrypt(&self, message: &[u8]) -> VortexResult<Vec<u8>> {
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| vortex_err!("Failed to generate a nonce"))?;
+
+        let sealed_len = NONCE_LEN + message.len() + AES_256_GCM.tag_len();
+        let mut sealed = Vec::with_capacity(LENGTH_PREFIX_SIZE + sealed_len);
+        sealed.extend_from_slice(&u32::try_from(sealed_len)?.to_le_bytes());
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(message);
+        let tag = self
+            .key
+            .seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(self.key_id.as_bytes()),
+                &mut sealed[LENGTH_PREFIX_SIZE + NONCE_LEN..],
+            )
+            .map_err(|_| vortex_err!("Failed to encrypt message with key {}", self.key_id))?;
+        sealed.extend_from_slice(tag.as_ref());
+        Ok(sealed)
+    }
+
+    /// Decrypt a message written by [`encrypt`](Self::encrypt), ignoring any padding after it.
+    pub(crate) fn decrypt(&self, bytes: &[u8]) -> VortexResult<Bytes> {
+        let Some((prefix, rest)) = bytes.split_first_chunk::<LENGTH_PREFIX_SIZE>() else {
+            vortex_bail!("Encrypted message is missing its length prefix");
+        };
+        let sealed_len = u32::from_le_bytes(*prefix) as usize;
+        if sealed_len < NONCE_LEN + AES_256_GCM.tag_len() || sealed_len > rest.len() {
+            vortex_bail!(
+                "Invalid length {sealed_len} of encrypted message with {} bytes",
+                rest.len()
+            );
+        }
+
+        let (nonce, ciphertext) = rest[..sealed_len].split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce)
+            .map_err(|_| vortex_err!("Invalid nonce of encrypted message"))?;
+        let mut message = ciphertext.to_vec();
+        let message_len = self
+            .key
+            .open_in_place(nonce, Aad::from(self.key_id.as_bytes()), &mut message)
+            .map_err(|_| {
+                vortex_err!(
+                    "Failed to decrypt message with key {}, the key is wrong or the data is corrupted",
+                    self.key_id
+                )
+            })?
+            .len();
+        message.truncate(message_len);
+        Ok(Bytes::from(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_round_trip() {
+        let cipher = MessageCipher::new("key".to_string(), &EncryptionKey::new([7; 32])).unwrap();
+        let mut sealed = cipher.encrypt(b"hello world").unwrap();
+        assert!(!sealed.windows(11).any(|w| w == b"hello world"));
+
+        // Padding after the message is ignored.
+        sealed.extend_from_slice(&[0; 9]);
+        assert_eq!(cipher.decrypt(&sealed).unwrap().as_ref(), b"hello world");
+
+        // The key id is authenticated along with the message.
+        let other_id =
+            MessageCipher::new("other".to_string(), &EncryptionKey::new([7; 32])).unwrap();
+        assert!(other_id.decrypt(&sealed).is_err());
+        let other_key =
+            MessageCipher::new("key".to_string(), &EncryptionKey::new([8; 32])).unwrap();
+        assert!(other_key.decrypt(&sealed).is_err());
+
+        sealed[10] ^= 1;
+        assert!(cipher.decrypt(&sealed).is_err());
+        assert!(cipher.decrypt(&sealed[..3]).is_err());
+    }
+}
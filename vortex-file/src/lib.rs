@@ -136,11 +136,19 @@
 //! If you ultimately seek Arrow arrays, [VortexRecordBatchReader] converts a [VortexFileArrayStream]
 //! into a RecordBatchReader.
 
+mod bloom;
+pub mod dataset;
+mod deletion;
 mod dtype_reader;
+mod encryption;
 
+pub use bloom::{BloomFilter, DEFAULT_BLOOM_FILTER_FPP};
+pub use deletion::*;
 pub use dtype_reader::*;
+pub use encryption::{EncryptionKey, KeyProvider, KeyResolver};
 
 mod read;
+mod verify;
 mod write;
 
 mod pruning;
@@ -192,4 +200,5 @@ mod forever_constant {
 
 pub use forever_constant::*;
 pub use read::*;
+pub use verify::*;
 pub use write::*;
@@ -12,7 +12,7 @@ use vortex_array::ArrayData;
 use vortex_dtype::field::Field;
 use vortex_dtype::Nullability;
 use vortex_error::{VortexExpect as _, VortexResult};
-use vortex_expr::{BinaryExpr, Column, ExprRef, Identity, Literal, Not, Operator};
+use vortex_expr::{BinaryExpr, Column, ExprRef, Identity, InList, Literal, Not, Operator};
 use vortex_scalar::Scalar;
 
 use crate::RowFilter;
@@ -187,9 +187,12 @@ fn convert_to_pruning_expression(expr: &ExprRef) -> PruningPredicateStats {
         };
 
         if let Some(col) = bexp.rhs().as_any().downcast_ref::<Column>() {
+            let Some(op) = bexp.op().swap() else {
+                return not_prunable();
+            };
             return PruningPredicateRewriter::rewrite_binary_op(
                 FieldOrIdentity::Field(col.field().clone()),
-                bexp.op().swap(),
+                op,
                 bexp.lhs(),
             );
         }
@@ -203,14 +206,21 @@ fn convert_to_pruning_expression(expr: &ExprRef) -> PruningPredicateStats {
         };
 
         if bexp.rhs().as_any().downcast_ref::<Identity>().is_some() {
+            let Some(op) = bexp.op().swap() else {
+                return not_prunable();
+            };
             return PruningPredicateRewriter::rewrite_binary_op(
                 FieldOrIdentity::Identity,
-                bexp.op().swap(),
+                op,
                 bexp.lhs(),
             );
         };
     }
 
+    if let Some(in_list) = expr.as_any().downcast_ref::<InList>() {
+        return convert_in_list(in_list);
+    }
+
     if let Some(RowFilter { conjunction }) = expr.as_any().downcast_ref::<RowFilter>() {
         let (rewritten_conjunction, refses): (Vec<ExprRef>, Vec<Relation<FieldOrIdentity, Stat>>) =
             conjunction
@@ -247,6 +257,63 @@ fn convert_column_reference(expr: &ExprRef, invert: bool) -> PruningPredicateSta
     (expr, refs)
 }
 
+/// A chunk can't contain any of the values of an IN-list if its values are all smaller than the
+/// smallest list value, or all bigger than the biggest one.
+fn convert_in_list(in_list: &InList) -> PruningPredicateStats {
+    let column = if let Some(col) = in_list.child().as_any().downcast_ref::<Column>() {
+        FieldOrIdentity::Field(col.field().clone())
+    } else if in_list
+        .child()
+        .as_any()
+        .downcast_ref::<Identity>()
+        .is_some()
+    {
+        FieldOrIdentity::Identity
+    } else {
+        return not_prunable();
+    };
+    if in_list.negated() {
+        return not_prunable();
+    }
+
+    let mut values = in_list.list().iter().filter(|v| v.is_valid());
+    let Some(first) = values.next() else {
+        return not_prunable();
+    };
+    let (mut min, mut max) = (first, first);
+    for value in values {
+        match (value.partial_cmp(min), value.partial_cmp(max)) {
+            (Some(to_min), Some(to_max)) => {
+                if to_min.is_lt() {
+                    min = value;
+                }
+                if to_max.is_gt() {
+                    max = value;
+                }
+            }
+            _ => return not_prunable(),
+        }
+    }
+
+    let mut refs = Relation::new();
+    refs.insert(column.clone(), Stat::Min);
+    refs.insert(column.clone(), Stat::Max);
+    let expr = BinaryExpr::new_expr(
+        BinaryExpr::new_expr(
+            Column::new_expr(column.stat_column_field(Stat::Min)),
+            Operator::Gt,
+            Literal::new_expr(max.clone()),
+        ),
+        Operator::Or,
+        BinaryExpr::new_expr(
+            Literal::new_expr(min.clone()),
+            Operator::Gt,
+            Column::new_expr(column.stat_column_field(Stat::Max)),
+        ),
+    );
+    (expr, refs)
+}
+
 struct PruningPredicateRewriter<'a> {
     column: FieldOrIdentity,
     operator: Operator,
@@ -270,6 +337,15 @@ impl<'a> PruningPredicateRewriter<'a> {
             }
         };
 
+        // The stats of arithmetic results can't be derived from the stats of their operands.
+        if other_exp
+            .as_any()
+            .downcast_ref::<BinaryExpr>()
+            .is_some_and(|bexp| bexp.op().is_arithmetic())
+        {
+            return None;
+        }
+
         Some(Self {
             column,
             operator,
@@ -401,6 +477,7 @@ pub(crate) fn stat_column_name_string(field: &Field, stat: Stat) -> String {
     match field {
         Field::Name(n) => format!("{n}_{stat}"),
         Field::Index(i) => format!("{i}_{stat}"),
+        Field::Id(id) => format!("#{id}_{stat}"),
     }
 }
 
@@ -441,7 +518,8 @@ mod tests {
     use vortex_array::aliases::hash_set::HashSet;
     use vortex_array::stats::Stat;
     use vortex_dtype::field::Field;
-    use vortex_expr::{BinaryExpr, Column, Identity, Literal, Not, Operator};
+    use vortex_expr::{BinaryExpr, Column, Identity, InList, Literal, Not, Operator};
+    use vortex_scalar::Scalar;
 
     use crate::pruning::{
         convert_to_pruning_expression, stat_column_field, FieldOrIdentity, PruningPredicate,
@@ -697,6 +775,21 @@ mod tests {
         assert!(PruningPredicate::try_new(&or_expr).is_none());
     }
 
+    #[test]
+    fn arithmetic_expr_not_prunable() {
+        let a = Column::new_expr(Field::from("a"));
+        let doubled = BinaryExpr::new_expr(
+            Column::new_expr(Field::from("b")),
+            Operator::Mul,
+            Literal::new_expr(2.into()),
+        );
+        let expr = BinaryExpr::new_expr(a.clone(), Operator::Gt, doubled.clone());
+        assert!(PruningPredicate::try_new(&expr).is_none());
+
+        let expr = BinaryExpr::new_expr(doubled, Operator::Lt, a);
+        assert!(PruningPredicate::try_new(&expr).is_none());
+    }
+
     #[test]
     fn display_pruning_predicate() {
         let column = Field::from("a");
@@ -781,4 +874,40 @@ mod tests {
         );
         assert_eq!(*predicate.expr().clone(), *expected_expr.as_any(),)
     }
+
+    #[test]
+    fn pruning_in_list() {
+        let column = Field::from("a");
+        let in_list = InList::new_expr(
+            Column::new_expr(column.clone()),
+            vec![7.into(), 3.into(), Scalar::null_typed::<i32>(), 12.into()],
+            false,
+        );
+        let (converted, refs) = convert_to_pruning_expression(&in_list);
+        assert_eq!(
+            refs.into_map(),
+            HashMap::from_iter([(
+                FieldOrIdentity::Field(column.clone()),
+                HashSet::from_iter([Stat::Min, Stat::Max])
+            )])
+        );
+        let expected_expr = BinaryExpr::new_expr(
+            BinaryExpr::new_expr(
+                Column::new_expr(stat_column_field(&column, Stat::Min)),
+                Operator::Gt,
+                Literal::new_expr(12.into()),
+            ),
+            Operator::Or,
+            BinaryExpr::new_expr(
+                Literal::new_expr(3.into()),
+                Operator::Gt,
+                Column::new_expr(stat_column_field(&column, Stat::Max)),
+            ),
+        );
+        assert_eq!(*converted, *expected_expr.as_any());
+
+        let not_in_list =
+            InList::new_expr(Column::new_expr(column), vec![7.into(), 3.into()], true);
+        assert!(PruningPredicate::try_new(&not_in_list).is_none());
+    }
 }
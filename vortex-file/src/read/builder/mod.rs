@@ -2,16 +2,20 @@ use std::sync::{Arc, RwLock};
 
 use initial_read::read_initial_bytes;
 use vortex_array::{ArrayDType, ArrayData};
+use vortex_dtype::field::Field;
+use vortex_dtype::DType;
 use vortex_error::VortexResult;
 use vortex_expr::Select;
 use vortex_io::{IoDispatcher, VortexReadAt};
 
 use crate::read::cache::{LayoutMessageCache, RelativeLayoutCache};
 use crate::read::context::LayoutDeserializer;
+use crate::read::evolution::SchemaAdapter;
 use crate::read::filtering::RowFilter;
-use crate::read::projection::Projection;
+use crate::read::projection::{Projection, SelectPaths};
 use crate::read::stream::VortexFileArrayStream;
 use crate::read::{RowMask, Scan};
+use crate::{DeletionVector, KeyResolver, LazyDType};
 
 pub(crate) mod initial_read;
 
@@ -68,6 +72,8 @@ pub struct VortexReadBuilder<R> {
     file_size: Option<u64>,
     row_mask: Option<ArrayData>,
     row_filter: Option<RowFilter>,
+    deletion_vector: Option<DeletionVector>,
+    schema: Option<(DType, bool)>,
     io_dispatcher: Option<Arc<IoDispatcher>>,
 }
 
@@ -80,6 +86,8 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
             file_size: None,
             row_mask: None,
             row_filter: None,
+            deletion_vector: None,
+            schema: None,
             io_dispatcher: None,
         }
     }
@@ -109,6 +117,48 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
         self
     }
 
+    /// Skip the rows marked as deleted in the given [`DeletionVector`].
+    pub fn with_deletion_vector(mut self, deletion_vector: DeletionVector) -> Self {
+        self.deletion_vector = Some(deletion_vector);
+        self
+    }
+
+    /// Read the file as the given struct dtype instead of the dtype it was written with.
+    ///
+    /// Columns are matched by name, columns missing from the file are filled with nulls and
+    /// numeric columns may be widened, see [`can_evolve`][crate::can_evolve]. Building the stream
+    /// fails if the file can't be read as the schema.
+    ///
+    /// The projection and the row filter refer to the fields of the schema, and the row filter
+    /// must reference them by name.
+    pub fn with_schema(mut self, schema: DType) -> Self {
+        self.schema = Some((schema, false));
+        self
+    }
+
+    /// Like [`with_schema`][Self::with_schema], but the nullability of the columns of the schema
+    /// is replaced by that of the file columns of the same name.
+    ///
+    /// Useful for query engines whose table schemas don't reliably track nullability.
+    pub fn with_schema_ignoring_nullability(mut self, schema: DType) -> Self {
+        self.schema = Some((schema, true));
+        self
+    }
+
+    /// Skip verifying checksums, e.g. when reading from a trusted source.
+    pub fn with_checksum_verification(mut self, verify: bool) -> Self {
+        self.layout_serde = self.layout_serde.with_checksum_verification(verify);
+        self
+    }
+
+    /// Resolve the keys of encrypted columns with the given [`KeyResolver`].
+    ///
+    /// Only needed if the projection or the row filter include encrypted columns.
+    pub fn with_key_resolver(mut self, key_resolver: Arc<dyn KeyResolver>) -> Self {
+        self.layout_serde = self.layout_serde.with_key_resolver(key_resolver);
+        self
+    }
+
     pub fn with_io_dispatcher(mut self, dispatcher: Arc<IoDispatcher>) -> Self {
         self.io_dispatcher = Some(dispatcher);
         self
@@ -123,23 +173,45 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
         let row_count = layout.row_count();
         let lazy_dtype = Arc::new(initial_read.lazy_dtype());
 
-        let projected_dtype = match self.projection {
-            Projection::All => lazy_dtype.clone(),
-            Projection::Flat(ref fields) => lazy_dtype.project(fields)?,
+        let (adapter, projection, row_filter) = match self.schema.as_ref() {
+            Some((schema, keep_nullability)) => {
+                let (adapter, projection, row_filter) = SchemaAdapter::try_new(
+                    lazy_dtype.value()?,
+                    schema,
+                    *keep_nullability,
+                    &self.projection,
+                    self.row_filter,
+                )?;
+                (Some(adapter), projection, row_filter)
+            }
+            None => (
+                None,
+                resolve_field_ids(&lazy_dtype, self.projection)?,
+                self.row_filter,
+            ),
+        };
+
+        let projected_dtype = match adapter.as_ref() {
+            Some(adapter) => Arc::new(LazyDType::from_dtype(adapter.dtype())),
+            None => match projection {
+                Projection::All => lazy_dtype.clone(),
+                Projection::Flat(ref fields) => lazy_dtype.project(fields)?,
+                Projection::Nested(ref paths) => lazy_dtype.project_paths(paths)?,
+            },
         };
 
         let message_cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
         let layout_reader = self.layout_serde.read_layout(
             initial_read.fb_layout(),
-            Scan::new(match self.projection {
+            Scan::new(match projection {
                 Projection::All => None,
                 Projection::Flat(p) => Some(Arc::new(Select::include(p))),
+                Projection::Nested(paths) => Some(SelectPaths::new_expr(paths)),
             }),
             RelativeLayoutCache::new(message_cache.clone(), lazy_dtype.clone()),
         )?;
 
-        let filter_reader = self
-            .row_filter
+        let filter_reader = row_filter
             .map(|row_filter| {
                 self.layout_serde.read_layout(
                     initial_read.fb_layout(),
@@ -161,6 +233,20 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
             })
             .transpose()?;
 
+        let (row_mask, live_row_count) = match self.deletion_vector {
+            Some(deletion_vector) if !deletion_vector.is_empty() => {
+                let live_rows = deletion_vector.live_rows(row_count)?;
+                (
+                    Some(match row_mask {
+                        Some(row_mask) => row_mask.and_mask(&live_rows)?,
+                        None => live_rows,
+                    }),
+                    row_count - deletion_vector.deleted_count(),
+                )
+            }
+            _ => (row_mask, row_count),
+        };
+
         // Default: fallback to single-threaded tokio dispatcher.
         let io_dispatcher = self.io_dispatcher.unwrap_or_default();
 
@@ -171,7 +257,9 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
             message_cache,
             projected_dtype,
             row_count,
+            live_row_count,
             row_mask,
+            adapter,
             io_dispatcher,
         )
     }
@@ -183,3 +271,20 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
         })
     }
 }
+
+/// Replace the [`Field::Id`]s of a projection by the indices of the fields in the file dtype, since
+/// the arrays read from the file don't carry field IDs.
+fn resolve_field_ids(dtype: &LazyDType, projection: Projection) -> VortexResult<Projection> {
+    Ok(match projection {
+        Projection::Flat(fields) => Projection::Flat(
+            fields
+                .into_iter()
+                .map(|field| match field {
+                    Field::Id(_) => dtype.resolve_field(&field).map(Field::Index),
+                    _ => Ok(field),
+                })
+                .collect::<VortexResult<Vec<_>>>()?,
+        ),
+        projection => projection,
+    })
+}
@@ -3,16 +3,17 @@ use std::sync::{Arc, RwLock};
 
 use bytes::Bytes;
 use flatbuffers::root_unchecked;
+use itertools::Itertools;
 use once_cell::sync::OnceCell;
 use vortex_array::aliases::hash_map::HashMap;
-use vortex_dtype::field::Field;
+use vortex_dtype::field::{Field, FieldPath};
 use vortex_dtype::flatbuffers::{extract_field, project_and_deserialize, resolve_field};
-use vortex_dtype::{DType, FieldNames};
+use vortex_dtype::{DType, FieldNames, StructDType};
 use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexResult};
 use vortex_flatbuffers::dtype::Struct_;
 use vortex_flatbuffers::message;
 
-use crate::read::projection::Projection;
+use crate::read::projection::{project_dtype, Projection};
 use crate::read::{LayoutPartId, MessageId};
 
 #[derive(Default, Debug)]
@@ -46,44 +47,6 @@ enum SerializedDTypeField {
     Field(Field),
 }
 
-impl SerializedDTypeField {
-    pub fn project(&self, fields: &[Field]) -> VortexResult<Self> {
-        match self {
-            SerializedDTypeField::Projection(p) => {
-                Ok(SerializedDTypeField::Projection(p.project(fields)?))
-            }
-            SerializedDTypeField::Field(f) => {
-                if fields.len() > 1 && &fields[0] != f {
-                    vortex_bail!("Can't project field {f} into {fields:?}")
-                }
-                Ok(SerializedDTypeField::Field(f.clone()))
-            }
-        }
-    }
-
-    pub fn field(&self, field: &Field) -> VortexResult<Self> {
-        match self {
-            SerializedDTypeField::Projection(p) => {
-                match p {
-                    Projection::All => {}
-                    Projection::Flat(fields) => {
-                        if !fields.iter().any(|pf| pf == field) {
-                            vortex_bail!("Can't project {fields:?} into {field}")
-                        }
-                    }
-                }
-                Ok(SerializedDTypeField::Field(field.clone()))
-            }
-            SerializedDTypeField::Field(f) => {
-                if f != field {
-                    vortex_bail!("Can't extract field from field")
-                }
-                Ok(SerializedDTypeField::Field(field.clone()))
-            }
-        }
-    }
-}
-
 #[derive(Debug)]
 enum LazyDTypeState {
     DType(DType),
@@ -128,62 +91,94 @@ impl LazyDType {
     /// Restrict the underlying dtype to selected fields
     pub fn project(&self, fields: &[Field]) -> VortexResult<Arc<Self>> {
         match &self.inner {
-            LazyDTypeState::DType(dtype) => {
-                let DType::Struct(sdt, n) = dtype else {
-                    vortex_bail!("Not a struct dtype")
-                };
-                Ok(Arc::new(LazyDType::from_dtype(DType::Struct(
-                    sdt.project(fields)?,
-                    *n,
-                ))))
+            LazyDTypeState::Serialized(b, _, SerializedDTypeField::Projection(p)) => {
+                Ok(Arc::new(Self {
+                    inner: LazyDTypeState::Serialized(
+                        b.clone(),
+                        OnceCell::new(),
+                        SerializedDTypeField::Projection(p.project(fields)?),
+                    ),
+                }))
             }
-            LazyDTypeState::Serialized(b, _, current_projection) => Ok(Arc::new(Self {
-                inner: LazyDTypeState::Serialized(
-                    b.clone(),
-                    OnceCell::new(),
-                    current_projection.project(fields)?,
-                ),
-            })),
             LazyDTypeState::Unknown => vortex_bail!("Unknown dtype"),
+            _ => Ok(Arc::new(LazyDType::from_dtype(DType::Struct(
+                self.struct_dtype()?.project(fields)?,
+                self.value()?.nullability(),
+            )))),
+        }
+    }
+
+    /// Restrict the underlying dtype to the (possibly nested) fields selected by the given paths
+    pub fn project_paths(&self, paths: &[FieldPath]) -> VortexResult<Arc<Self>> {
+        match &self.inner {
+            LazyDTypeState::Serialized(b, _, SerializedDTypeField::Projection(Projection::All)) => {
+                Ok(Arc::new(Self {
+                    inner: LazyDTypeState::Serialized(
+                        b.clone(),
+                        OnceCell::new(),
+                        SerializedDTypeField::Projection(Projection::Nested(paths.to_vec())),
+                    ),
+                }))
+            }
+            LazyDTypeState::Unknown => vortex_bail!("Unknown dtype"),
+            _ => Ok(Arc::new(LazyDType::from_dtype(DType::Struct(
+                project_dtype(self.struct_dtype()?, paths)?,
+                self.value()?.nullability(),
+            )))),
         }
     }
 
     /// Extract single field out of this dtype
     pub fn field(&self, field: &Field) -> VortexResult<Arc<Self>> {
         match &self.inner {
-            LazyDTypeState::DType(dtype) => {
-                let DType::Struct(sdt, _) = dtype else {
-                    vortex_bail!("Not a struct dtype")
-                };
-                Ok(Arc::new(LazyDType::from_dtype(
-                    sdt.field_info(field)?.dtype.clone(),
-                )))
+            LazyDTypeState::Serialized(b, _, SerializedDTypeField::Projection(p)) => {
+                match p {
+                    Projection::All => {}
+                    Projection::Flat(fields) => {
+                        if !fields.iter().any(|pf| pf == field) {
+                            vortex_bail!("Can't project {fields:?} into {field}")
+                        }
+                    }
+                    Projection::Nested(_) => {
+                        vortex_bail!("Can't extract field {field} from a nested projection")
+                    }
+                }
+                Ok(Arc::new(Self {
+                    inner: LazyDTypeState::Serialized(
+                        b.clone(),
+                        OnceCell::new(),
+                        SerializedDTypeField::Field(field.clone()),
+                    ),
+                }))
             }
-            LazyDTypeState::Serialized(b, _, current_projection) => Ok(Arc::new(Self {
-                inner: LazyDTypeState::Serialized(
-                    b.clone(),
-                    OnceCell::new(),
-                    current_projection.field(field)?,
-                ),
-            })),
             LazyDTypeState::Unknown => vortex_bail!("Unknown dtype"),
+            _ => Ok(Arc::new(LazyDType::from_dtype(
+                self.struct_dtype()?.field_info(field)?.dtype.clone(),
+            ))),
         }
     }
 
     /// Extract field names from the underlying dtype if there are any
     pub fn names(&self) -> VortexResult<FieldNames> {
         match &self.inner {
-            LazyDTypeState::DType(dtype) => {
-                let DType::Struct(sdt, _) = dtype else {
-                    vortex_bail!("Not a struct dtype")
-                };
-                Ok(sdt.names().clone())
+            LazyDTypeState::Serialized(b, _, SerializedDTypeField::Projection(p)) => {
+                field_names(b, p)
             }
-            LazyDTypeState::Serialized(b, _, proj) => field_names(b, proj),
             LazyDTypeState::Unknown => vortex_bail!("Unknown dtype"),
+            _ => Ok(self.struct_dtype()?.names().clone()),
         }
     }
 
+    /// The struct dtype of this dtype, deserializing it if necessary.
+    ///
+    /// Fields of nested structs are only available in deserialized form.
+    fn struct_dtype(&self) -> VortexResult<&StructDType> {
+        let DType::Struct(sdt, _) = self.value()? else {
+            vortex_bail!("Not a struct dtype")
+        };
+        Ok(sdt)
+    }
+
     /// Get vortex dtype out of serialized bytes
     pub fn value(&self) -> VortexResult<&DType> {
         match &self.inner {
@@ -198,42 +193,39 @@ impl LazyDType {
     /// Convert all name based references to index based to create globally addressable filter
     pub(crate) fn resolve_field(&self, field: &Field) -> VortexResult<usize> {
         match &self.inner {
-            LazyDTypeState::DType(dtype) => {
-                let DType::Struct(sdt, _) = dtype else {
-                    vortex_bail!("Trying to resolve fields in non struct dtype")
-                };
-                match field {
-                    Field::Name(n) => sdt
-                        .names()
-                        .iter()
-                        .position(|name| name.as_ref() == n.as_str())
-                        .ok_or_else(|| vortex_err!("Can't find {n} in the type")),
-                    Field::Index(i) => Ok(*i),
-                }
+            LazyDTypeState::Serialized(b, _, SerializedDTypeField::Projection(_)) => {
+                resolve_field(fb_struct(b.as_ref())?, field)
             }
-            LazyDTypeState::Serialized(b, ..) => resolve_field(fb_struct(b.as_ref())?, field),
             LazyDTypeState::Unknown => vortex_bail!("Unknown dtype"),
+            _ => Ok(self.struct_dtype()?.field_info(field)?.index),
         }
     }
 }
 
-fn field_names(bytes: &[u8], dtype_field: &SerializedDTypeField) -> VortexResult<FieldNames> {
+fn field_names(bytes: &[u8], projection: &Projection) -> VortexResult<FieldNames> {
     let struct_field = fb_struct(bytes)?;
     let names = struct_field
         .names()
         .ok_or_else(|| vortex_err!("Not a struct dtype"))?;
-    match dtype_field {
-        SerializedDTypeField::Projection(projection) => match projection {
-            Projection::All => Ok(names.iter().map(Arc::from).collect()),
-            Projection::Flat(fields) => fields
-                .iter()
-                .map(|f| resolve_field(struct_field, f))
-                .map(|idx| idx.map(|i| Arc::from(names.get(i))))
-                .collect(),
-        },
-        SerializedDTypeField::Field(f) => Ok(Arc::new([Arc::from(
-            names.get(resolve_field(struct_field, f)?),
-        )])),
+    match projection {
+        Projection::All => Ok(names.iter().map(Arc::from).collect()),
+        Projection::Flat(fields) => fields
+            .iter()
+            .map(|f| resolve_field(struct_field, f))
+            .map(|idx| idx.map(|i| Arc::from(names.get(i))))
+            .collect(),
+        Projection::Nested(paths) => paths
+            .iter()
+            .filter_map(|p| p.path().first())
+            .map(|f| resolve_field(struct_field, f))
+            .collect::<VortexResult<Vec<_>>>()
+            .map(|indices| {
+                indices
+                    .into_iter()
+                    .unique()
+                    .map(|i| Arc::from(names.get(i)))
+                    .collect()
+            }),
     }
 }
 
@@ -246,6 +238,13 @@ fn project_dtype_bytes(bytes: &[u8], dtype_field: &SerializedDTypeField) -> Vort
         SerializedDTypeField::Projection(projection) => match projection {
             Projection::All => DType::try_from(fb_dtype),
             Projection::Flat(p) => project_and_deserialize(fb_dtype, p),
+            Projection::Nested(paths) => {
+                let dtype = DType::try_from(fb_dtype)?;
+                let DType::Struct(sdt, n) = &dtype else {
+                    vortex_bail!("Not a struct dtype")
+                };
+                Ok(DType::Struct(project_dtype(sdt, paths)?, *n))
+            }
         },
         SerializedDTypeField::Field(f) => extract_field(fb_dtype, f),
     }
@@ -288,6 +287,15 @@ impl RelativeLayoutCache {
         }
     }
 
+    /// The cache of the same layout, read as another dtype.
+    pub(crate) fn with_dtype(&self, dtype: Arc<LazyDType>) -> Self {
+        Self {
+            root: self.root.clone(),
+            path: self.path.clone(),
+            dtype,
+        }
+    }
+
     pub fn unknown_dtype(&self, id: LayoutPartId) -> Self {
         self.relative(id, Arc::new(LazyDType::unknown()))
     }
@@ -322,6 +330,11 @@ impl RelativeLayoutCache {
         &self.dtype
     }
 
+    /// Whether this cache belongs to the root layout of a file.
+    pub(crate) fn is_root(&self) -> bool {
+        self.path.is_empty()
+    }
+
     pub fn absolute_id(&self, path: &[LayoutPartId]) -> MessageId {
         let mut lookup_key = Vec::with_capacity(self.path.len() + path.len());
         lookup_key.clone_from(&self.path);
@@ -6,8 +6,9 @@ use vortex_array::Context;
 use vortex_error::{vortex_err, VortexResult};
 use vortex_flatbuffers::footer as fb;
 
+use crate::encryption::MessageCipher;
 use crate::layouts::{ChunkedLayout, ColumnarLayout, FlatLayout};
-use crate::{LayoutReader, RelativeLayoutCache, Scan};
+use crate::{KeyResolver, LayoutReader, RelativeLayoutCache, Scan};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct LayoutId(pub u16);
@@ -58,15 +59,41 @@ impl Default for LayoutContext {
     }
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct LayoutDeserializer {
     ctx: Arc<Context>,
     layout_ctx: Arc<LayoutContext>,
+    verify_checksums: bool,
+    key_resolver: Option<Arc<dyn KeyResolver>>,
+}
+
+impl Default for LayoutDeserializer {
+    fn default() -> Self {
+        Self::new(Default::default(), Default::default())
+    }
 }
 
 impl LayoutDeserializer {
     pub fn new(ctx: Arc<Context>, layout_ctx: Arc<LayoutContext>) -> Self {
-        Self { ctx, layout_ctx }
+        Self {
+            ctx,
+            layout_ctx,
+            verify_checksums: true,
+            key_resolver: None,
+        }
+    }
+
+    /// Whether layout readers verify the checksums of data written with checksums, which is the
+    /// default.
+    pub fn with_checksum_verification(mut self, verify: bool) -> Self {
+        self.verify_checksums = verify;
+        self
+    }
+
+    /// Resolve the keys of encrypted layouts with the given [`KeyResolver`].
+    pub fn with_key_resolver(mut self, key_resolver: Arc<dyn KeyResolver>) -> Self {
+        self.key_resolver = Some(key_resolver);
+        self
     }
 
     pub fn read_layout(
@@ -85,4 +112,17 @@ impl LayoutDeserializer {
     pub(crate) fn ctx(&self) -> Arc<Context> {
         self.ctx.clone()
     }
+
+    pub(crate) fn verify_checksums(&self) -> bool {
+        self.verify_checksums
+    }
+
+    /// The cipher to decrypt the messages of layouts encrypted with the given key.
+    pub(crate) fn cipher(&self, key_id: &str) -> VortexResult<Arc<MessageCipher>> {
+        let key_resolver = self.key_resolver.as_ref().ok_or_else(|| {
+            vortex_err!("Layout is encrypted with key {key_id}, but no key resolver was provided")
+        })?;
+        let key = key_resolver.resolve_key(key_id)?;
+        MessageCipher::new(key_id.to_string(), &key).map(Arc::new)
+    }
 }
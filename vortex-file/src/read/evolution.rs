@@ -0,0 +1,444 @@
+//! Reading files as a target dtype that differs from the dtype they were written with.
+
+use itertools::Itertools;
+use vortex_array::array::{
+    BoolArray, ConstantArray, ExtensionArray, FixedSizeListArray, ListArray, StructArray,
+    VarBinViewArray,
+};
+use vortex_array::compute::{fill_null, filter, try_cast, FilterMask};
+use vortex_array::validity::Validity;
+use vortex_array::variants::StructArrayTrait;
+use vortex_array::{ArrayDType, ArrayData, ArrayLen, Canonical, IntoArrayData, IntoCanonical};
+use vortex_dtype::field::Field;
+use vortex_dtype::{DType, FieldName, Nullability, PType, StructDType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_expr::VortexExpr;
+use vortex_scalar::Scalar;
+
+use crate::{Projection, RowFilter};
+
+/// Returns whether values of dtype `from` can be read as dtype `to`.
+///
+/// Besides equal dtypes, integers and floats can be widened, e.g. i32 to i64, u32 to i64 or f32
+/// to f64, and non-nullable values of any dtype can be read as nullable ones.
+pub fn can_evolve(from: &DType, to: &DType) -> bool {
+    if from.is_nullable() && !to.is_nullable() {
+        return false;
+    }
+    match (from, to) {
+        (DType::Primitive(from_ptype, _), DType::Primitive(to_ptype, _)) => {
+            widens(*from_ptype, *to_ptype)
+        }
+        _ => from.with_nullability(to.nullability()) == *to,
+    }
+}
+
+fn widens(from: PType, to: PType) -> bool {
+    from == to
+        || (from.bit_width() < to.bit_width()
+            && ((from.is_float() && to.is_float())
+                || (from.is_signed_int() && to.is_signed_int())
+                || (from.is_unsigned_int() && to.is_int())))
+}
+
+/// Returns the narrowest dtype that values of both dtypes can be read as, if any.
+pub fn widen_dtype(a: &DType, b: &DType) -> Option<DType> {
+    let nullability = Nullability::from(a.is_nullable() || b.is_nullable());
+    [
+        b.with_nullability(nullability),
+        a.with_nullability(nullability),
+    ]
+    .into_iter()
+    .find(|dtype| can_evolve(a, dtype) && can_evolve(b, dtype))
+}
+
+/// Adapts the struct arrays read from a file to a target struct dtype.
+///
+/// Columns are matched by [`FieldId`][vortex_dtype::FieldId] if both the file and the target
+/// dtype have field IDs, which allows reading renamed columns, and by name otherwise. Columns
+/// that are missing from the file are filled with nulls, and the others are cast to their target
+/// dtype, see [`can_evolve`].
+#[derive(Debug, Clone)]
+pub(crate) struct SchemaAdapter {
+    /// The projected target dtype, followed by the other columns needed by `row_filter`.
+    read_dtype: StructDType,
+    /// The name of the file column each field of `read_dtype` is read from, if any.
+    sources: Vec<Option<FieldName>>,
+    output_dtype: StructDType,
+    /// The part of the row filter that can only be evaluated on adapted arrays.
+    row_filter: Option<RowFilter>,
+}
+
+impl SchemaAdapter {
+    /// Plan reading a file of dtype `file_dtype` as `target`.
+    ///
+    /// If `keep_nullability` is set, the columns of the target take the nullability of the file
+    /// columns they're read from, and missing columns are nullable.
+    ///
+    /// The projection and the row filter refer to the target dtype. Returns the adapter along with
+    /// the projection and row filter to read the file with. Filter conjuncts over columns that
+    /// weren't renamed and whose dtype didn't change are pushed into the file reader, the others
+    /// are evaluated on the adapted arrays.
+    pub(crate) fn try_new(
+        file_dtype: &DType,
+        target: &DType,
+        keep_nullability: bool,
+        projection: &Projection,
+        row_filter: Option<RowFilter>,
+    ) -> VortexResult<(Self, Projection, Option<RowFilter>)> {
+        let DType::Struct(file_dtype, _) = file_dtype else {
+            vortex_bail!("Reading with a schema requires a struct dtype, found {file_dtype}");
+        };
+        let DType::Struct(target, _) = target else {
+            vortex_bail!("Schemas must be struct dtypes, found {target}");
+        };
+
+        let sources = match (target.field_ids(), file_dtype.field_ids()) {
+            (Some(target_ids), Some(_)) => target_ids
+                .iter()
+                .map(|id| file_dtype.find_field_id(*id))
+                .collect_vec(),
+            _ => target
+                .names()
+                .iter()
+                .map(|name| file_dtype.find_name(name))
+                .collect_vec(),
+        };
+
+        let target = &if keep_nullability {
+            let st = StructDType::new(
+                target.names().clone(),
+                target
+                    .dtypes()
+                    .iter()
+                    .zip(sources.iter())
+                    .map(|(dtype, source)| match source {
+                        Some(idx) => {
+                            dtype.with_nullability(file_dtype.dtypes()[*idx].nullability())
+                        }
+                        None => dtype.as_nullable(),
+                    })
+                    .collect(),
+            );
+            match target.field_ids() {
+                Some(field_ids) => st.with_field_ids(field_ids.to_vec()),
+                None => st,
+            }
+        } else {
+            target.clone()
+        };
+
+        for ((name, dtype), source) in target
+            .names()
+            .iter()
+            .zip(target.dtypes().iter())
+            .zip(sources.iter())
+        {
+            match source.map(|idx| (&file_dtype.names()[idx], &file_dtype.dtypes()[idx])) {
+                Some((file_name, file_field_dtype)) if !can_evolve(file_field_dtype, dtype) => {
+                    vortex_bail!(
+                        "Column {file_name} of dtype {file_field_dtype} can't be read as {name} of dtype {dtype}"
+                    )
+                }
+                None if !dtype.is_nullable() => vortex_bail!(
+                    "Column {name} is missing from the file, but its dtype {dtype} is not nullable"
+                ),
+                _ => {}
+            }
+        }
+        let unchanged = |idx: usize| {
+            sources[idx].is_some_and(|source| {
+                file_dtype.names()[source] == target.names()[idx]
+                    && file_dtype.dtypes()[source] == target.dtypes()[idx]
+            })
+        };
+
+        let output_indices = match projection {
+            Projection::All => (0..target.names().len()).collect_vec(),
+            Projection::Flat(fields) => fields
+                .iter()
+                .map(|field| target.field_info(field).map(|info| info.index))
+                .collect::<VortexResult<Vec<_>>>()?,
+            Projection::Nested(_) => {
+                vortex_bail!("Nested projections are not supported when reading with a schema")
+            }
+        };
+
+        let mut pushed = Vec::new();
+        let mut residual = Vec::new();
+        let mut read_indices = output_indices.clone();
+        for conjunct in row_filter.map(|f| f.conjunction).unwrap_or_default() {
+            let indices = conjunct
+                .references()
+                .into_iter()
+                .map(|field| match field {
+                    Field::Name(name) => target
+                        .find_name(name)
+                        .ok_or_else(|| vortex_err!("Row filter references unknown column {name}")),
+                    Field::Index(_) | Field::Id(_) => vortex_bail!(
+                        "Row filters must reference fields by name when reading with a schema"
+                    ),
+                })
+                .collect::<VortexResult<Vec<_>>>()?;
+
+            if indices.iter().all(|idx| unchanged(*idx)) {
+                pushed.push(conjunct);
+            } else {
+                read_indices.extend(indices);
+                residual.push(conjunct);
+            }
+        }
+        let read_indices = read_indices.into_iter().unique().collect_vec();
+
+        let read_dtype =
+            target.project(&read_indices.iter().copied().map(Field::Index).collect_vec())?;
+        let sources = read_indices
+            .iter()
+            .map(|idx| sources[*idx].map(|source| file_dtype.names()[source].clone()))
+            .collect_vec();
+
+        let mut read_fields = sources
+            .iter()
+            .flatten()
+            .unique()
+            .map(|name| Field::from(name.as_ref()))
+            .collect_vec();
+        if read_fields.is_empty() {
+            // We need at least one column to know the number of rows.
+            read_fields.extend(file_dtype.names().first().map(|n| Field::from(n.as_ref())));
+        }
+
+        Ok((
+            Self {
+                read_dtype,
+                sources,
+                output_dtype: target
+                    .project(&output_indices.into_iter().map(Field::Index).collect_vec())?,
+                row_filter: (!residual.is_empty()).then(|| RowFilter::from_conjunction(residual)),
+            },
+            Projection::Flat(read_fields),
+            (!pushed.is_empty()).then(|| RowFilter::from_conjunction(pushed)),
+        ))
+    }
+
+    /// The dtype of the adapted arrays.
+    pub(crate) fn dtype(&self) -> DType {
+        DType::Struct(self.output_dtype.clone(), Nullability::NonNullable)
+    }
+
+    pub(crate) fn adapt(&self, batch: &ArrayData) -> VortexResult<ArrayData> {
+        let st = batch
+            .as_struct_array()
+            .ok_or_else(|| vortex_err!("Expected a struct array, found {}", batch.dtype()))?;
+        let fields =
+            self.read_dtype
+                .dtypes()
+                .iter()
+                .zip(self.sources.iter())
+                .map(|(dtype, source)| {
+                    match source.as_ref().and_then(|name| st.field_by_name(name)) {
+                        Some(field) => evolve(field, dtype),
+                        None => Ok(ConstantArray::new(Scalar::null(dtype.clone()), batch.len())
+                            .into_array()),
+                    }
+                })
+                .collect::<VortexResult<Vec<_>>>()?;
+        let adapted = StructArray::try_new(
+            self.read_dtype.names().clone(),
+            fields,
+            batch.len(),
+            Validity::NonNullable,
+        )?;
+
+        let Some(row_filter) = self.row_filter.as_ref() else {
+            return Ok(adapted.into_array());
+        };
+
+        let adapted = adapted.into_array();
+        let mask = fill_null(
+            row_filter.evaluate(&adapted)?,
+            Scalar::bool(false, Nullability::NonNullable),
+        )?;
+        // The output columns come first, followed by those only needed by the filter.
+        StructArray::try_from(filter(&adapted, FilterMask::try_from(mask)?)?)?
+            .project(
+                &(0..self.output_dtype.names().len())
+                    .map(Field::Index)
+                    .collect_vec(),
+            )
+            .map(IntoArrayData::into_array)
+    }
+}
+
+/// Read an array as a dtype that its dtype can evolve to, see [`can_evolve`].
+fn evolve(array: ArrayData, dtype: &DType) -> VortexResult<ArrayData> {
+    if array.dtype() == dtype {
+        return Ok(array);
+    }
+    if matches!(dtype, DType::Primitive(..))
+        || array.dtype().with_nullability(dtype.nullability()) != *dtype
+    {
+        return try_cast(array, dtype);
+    }
+
+    // Only the nullability differs, so the values are all valid.
+    Ok(match array.into_canonical()? {
+        Canonical::Bool(a) => {
+            BoolArray::try_new(a.boolean_buffer(), Validity::AllValid)?.into_array()
+        }
+        Canonical::Struct(a) => StructArray::try_new(
+            a.names().clone(),
+            a.children().collect(),
+            a.len(),
+            Validity::AllValid,
+        )?
+        .into_array(),
+        Canonical::List(a) => {
+            ListArray::try_new(a.elements(), a.offsets(), Validity::AllValid)?.into_array()
+        }
+        Canonical::FixedSizeList(a) => {
+            FixedSizeListArray::try_new(a.elements(), a.list_size(), a.len(), Validity::AllValid)?
+                .into_array()
+        }
+        Canonical::VarBinView(a) => VarBinViewArray::try_new(
+            a.views(),
+            a.buffers().collect(),
+            dtype.clone(),
+            Validity::AllValid,
+        )?
+        .into_array(),
+        Canonical::Extension(a) => {
+            let DType::Extension(ext_dtype) = dtype else {
+                vortex_bail!("Cannot read {} as {}", a.dtype(), dtype);
+            };
+            ExtensionArray::new(
+                ext_dtype.clone(),
+                evolve(a.storage(), ext_dtype.storage_dtype())?,
+            )
+            .into_array()
+        }
+        canonical => try_cast(canonical.into_array(), dtype)?,
+    })
+}
+
+/// Adapt a struct array to the given dtype, matching fields by name and filling missing fields
+/// with the given constants, or nulls.
+pub(crate) fn adapt_struct(
+    array: &ArrayData,
+    dtype: &StructDType,
+    constants: &[(FieldName, Scalar)],
+) -> VortexResult<ArrayData> {
+    let st = array
+        .as_struct_array()
+        .ok_or_else(|| vortex_err!("Expected a struct array, found {}", array.dtype()))?;
+
+    let fields = dtype
+        .names()
+        .iter()
+        .zip(dtype.dtypes().iter())
+        .map(|(name, field_dtype)| match st.field_by_name(name) {
+            Some(field) => evolve(field, field_dtype),
+            None => {
+                let value = constants
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, value)| value.clone())
+                    .unwrap_or_else(|| Scalar::null(field_dtype.clone()));
+                Ok(ConstantArray::new(value, array.len()).into_array())
+            }
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+
+    Ok(StructArray::try_new(
+        dtype.names().clone(),
+        fields,
+        array.len(),
+        Validity::NonNullable,
+    )?
+    .into_array())
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use super::*;
+
+    #[test]
+    fn widening() {
+        let int = |ptype, n| DType::Primitive(ptype, n);
+        assert!(can_evolve(
+            &int(PType::I32, Nullability::NonNullable),
+            &int(PType::I64, Nullability::Nullable)
+        ));
+        assert!(can_evolve(
+            &int(PType::U32, Nullability::NonNullable),
+            &int(PType::I64, Nullability::NonNullable)
+        ));
+        assert!(!can_evolve(
+            &int(PType::U32, Nullability::NonNullable),
+            &int(PType::I32, Nullability::NonNullable)
+        ));
+        assert!(!can_evolve(
+            &int(PType::I64, Nullability::NonNullable),
+            &int(PType::I32, Nullability::NonNullable)
+        ));
+        assert!(!can_evolve(
+            &int(PType::I32, Nullability::Nullable),
+            &int(PType::I64, Nullability::NonNullable)
+        ));
+        assert!(!can_evolve(
+            &int(PType::I32, Nullability::NonNullable),
+            &int(PType::F64, Nullability::NonNullable)
+        ));
+
+        assert_eq!(
+            widen_dtype(
+                &int(PType::F64, Nullability::NonNullable),
+                &int(PType::F32, Nullability::Nullable)
+            ),
+            Some(int(PType::F64, Nullability::Nullable))
+        );
+        assert_eq!(
+            widen_dtype(
+                &DType::Utf8(Nullability::NonNullable),
+                &DType::Utf8(Nullability::Nullable)
+            ),
+            Some(DType::Utf8(Nullability::Nullable))
+        );
+    }
+
+    #[test]
+    fn nullable_evolution() {
+        assert!(can_evolve(
+            &DType::Utf8(Nullability::NonNullable),
+            &DType::Utf8(Nullability::Nullable)
+        ));
+        assert!(!can_evolve(
+            &DType::Utf8(Nullability::Nullable),
+            &DType::Utf8(Nullability::NonNullable)
+        ));
+
+        let array = StructArray::from_fields(&[
+            (
+                "name",
+                VarBinViewArray::from_iter_str(["a", "b"]).into_array(),
+            ),
+            ("flag", BoolArray::from_iter([true, false]).into_array()),
+        ])
+        .unwrap()
+        .into_array();
+        let dtype = StructDType::new(
+            ["name".into(), "flag".into()].into(),
+            vec![
+                DType::Utf8(Nullability::Nullable),
+                DType::Bool(Nullability::Nullable),
+            ],
+        );
+        let adapted = adapt_struct(&array, &dtype, &[]).unwrap();
+        assert_eq!(
+            adapted.dtype(),
+            &DType::Struct(dtype, Nullability::NonNullable)
+        );
+    }
+}
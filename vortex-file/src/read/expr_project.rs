@@ -2,9 +2,11 @@ use std::sync::Arc;
 
 use vortex_dtype::field::Field;
 use vortex_expr::{
-    BinaryExpr, Column, ExprRef, Identity, Like, Literal, Not, Operator, Select, VortexExpr,
+    BinaryExpr, Column, ExprRef, Identity, InList, Like, Literal, Not, Operator, Select,
+    VariantGet, VortexExpr,
 };
 
+use crate::read::projection::SelectPaths;
 use crate::RowFilter;
 
 /// Restrict expression to only the fields that appear in projection
@@ -42,6 +44,8 @@ pub fn expr_project(expr: &ExprRef, projection: &[Field]) -> Option<ExprRef> {
                 }
             }
         }
+    } else if let Some(s) = expr.as_any().downcast_ref::<SelectPaths>() {
+        s.project(projection)
     } else if let Some(c) = expr.as_any().downcast_ref::<Column>() {
         projection.contains(c.field()).then(|| {
             if projection.len() == 1 {
@@ -90,6 +94,12 @@ pub fn expr_project(expr: &ExprRef, projection: &[Field]) -> Option<ExprRef> {
             l.negated(),
             l.case_insensitive(),
         ))
+    } else if let Some(il) = expr.as_any().downcast_ref::<InList>() {
+        expr_project(il.child(), projection)
+            .map(|child| InList::new_expr(child, il.list().to_vec(), il.negated()))
+    } else if let Some(vg) = expr.as_any().downcast_ref::<VariantGet>() {
+        expr_project(vg.child(), projection)
+            .map(|child| VariantGet::new_expr(child, vg.path(), vg.dtype().clone()))
     } else {
         None
     }
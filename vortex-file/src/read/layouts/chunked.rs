@@ -3,22 +3,27 @@ use std::sync::{Arc, OnceLock, RwLock};
 
 use itertools::Itertools;
 use vortex_array::aliases::hash_map::HashMap;
-use vortex_array::array::ChunkedArray;
-use vortex_array::compute::{scalar_at, take, TakeOptions};
-use vortex_array::stats::{stats_from_bitset_bytes, ArrayStatistics as _, Stat};
-use vortex_array::{ArrayDType, ArrayData, IntoArrayData};
+use vortex_array::array::{BoolArray, ChunkedArray, VarBinViewArray};
+use vortex_array::compute::{fill_null, or, scalar_at, take, TakeOptions};
+use vortex_array::stats::{
+    as_stat_bitset_bytes, stats_from_bitset_bytes, ArrayStatistics as _, Stat,
+};
+use vortex_array::validity::ArrayValidity;
+use vortex_array::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
 use vortex_dtype::{DType, Nullability, StructDType};
 use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexExpect as _, VortexResult};
-use vortex_expr::Select;
+use vortex_expr::{BinaryExpr, ExprRef, Identity, InList, Literal, Operator, Select};
 use vortex_flatbuffers::footer as fb;
+use vortex_scalar::Scalar;
 
+use crate::bloom::BloomFilter;
 use crate::layouts::RangedLayoutReader;
 use crate::pruning::PruningPredicate;
 use crate::read::cache::RelativeLayoutCache;
 use crate::read::mask::RowMask;
 use crate::{
     BatchRead, Layout, LayoutDeserializer, LayoutId, LayoutPartId, LayoutReader, LazyDType,
-    MessageLocator, MetadataRead, PruningRead, Scan, CHUNKED_LAYOUT_ID,
+    MessageLocator, MetadataRead, PruningRead, RowFilter, Scan, CHUNKED_LAYOUT_ID,
 };
 
 #[derive(Default, Debug)]
@@ -70,7 +75,7 @@ impl ChunkedLayoutBuilder<'_> {
         self.layout
             .metadata()
             .map(|m| {
-                let set_stats = stats_from_bitset_bytes(m.bytes());
+                let metadata = ChunkedMetadata::parse(m.bytes());
                 let metadata_fb = self
                     .layout
                     .children()
@@ -79,14 +84,17 @@ impl ChunkedLayoutBuilder<'_> {
                 self.layout_builder.read_layout(
                     metadata_fb,
                     Scan::new(Some(Arc::new(Select::include(
-                        set_stats.iter().map(|s| s.to_string().into()).collect(),
+                        metadata
+                            .column_names()
+                            .into_iter()
+                            .map(|name| name.into())
+                            .collect(),
                     )))),
                     self.message_cache.relative(
                         METADATA_LAYOUT_PART_ID,
-                        Arc::new(LazyDType::from_dtype(stats_table_dtype(
-                            &set_stats,
-                            self.message_cache.dtype().value()?,
-                        ))),
+                        Arc::new(LazyDType::from_dtype(
+                            metadata.table_dtype(self.message_cache.dtype().value()?),
+                        )),
                     ),
                 )
             })
@@ -137,11 +145,90 @@ impl ChunkedLayoutBuilder<'_> {
             self.children_layouts()?,
             self.metadata_layout()?,
             self.scan.clone(),
+            self.message_cache.dtype().clone(),
         ))
     }
 }
 
-fn stats_table_dtype(stats: &[Stat], dtype: &DType) -> DType {
+/// The column of the metadata table that holds a serialized [`BloomFilter`] per chunk.
+pub(crate) const BLOOM_FILTER_COLUMN: &str = "bloom_filter";
+
+/// Set in the flags of [`ChunkedMetadata`] if the metadata table has a [`BLOOM_FILTER_COLUMN`].
+const HAS_BLOOM_FILTER_FLAG: u8 = 1;
+
+/// The metadata of a chunked layout, describing the columns of its metadata table.
+///
+/// It is serialized as the bitset of the stats in the table, optionally followed by a byte of
+/// flags for the additional columns.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ChunkedMetadata {
+    pub stats: Vec<Stat>,
+    pub has_bloom_filter: bool,
+}
+
+impl ChunkedMetadata {
+    pub fn parse(bytes: &[u8]) -> Self {
+        let (stat_bitset, flags) = bytes.split_at(stat_bitset_len().min(bytes.len()));
+        Self {
+            stats: stats_from_bitset_bytes(stat_bitset),
+            has_bloom_filter: flags
+                .first()
+                .is_some_and(|flags| flags & HAS_BLOOM_FILTER_FLAG != 0),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = as_stat_bitset_bytes(&self.stats);
+        if self.has_bloom_filter {
+            bytes.push(HAS_BLOOM_FILTER_FLAG);
+        }
+        bytes
+    }
+
+    pub fn column_names(&self) -> Vec<String> {
+        self.stats
+            .iter()
+            .map(|s| s.to_string())
+            .chain(
+                self.has_bloom_filter
+                    .then(|| BLOOM_FILTER_COLUMN.to_string()),
+            )
+            .collect()
+    }
+
+    pub fn table_dtype(&self, dtype: &DType) -> DType {
+        let DType::Struct(stats_dtype, n) = stats_table_dtype(&self.stats, dtype) else {
+            vortex_panic!("Stats table dtype must be a struct");
+        };
+        if !self.has_bloom_filter {
+            return DType::Struct(stats_dtype, n);
+        }
+
+        DType::Struct(
+            StructDType::new(
+                stats_dtype
+                    .names()
+                    .iter()
+                    .cloned()
+                    .chain([BLOOM_FILTER_COLUMN.into()])
+                    .collect(),
+                stats_dtype
+                    .dtypes()
+                    .iter()
+                    .cloned()
+                    .chain([DType::Binary(Nullability::Nullable)])
+                    .collect(),
+            ),
+            n,
+        )
+    }
+}
+
+fn stat_bitset_len() -> usize {
+    as_stat_bitset_bytes(&[]).len()
+}
+
+pub(crate) fn stats_table_dtype(stats: &[Stat], dtype: &DType) -> DType {
     let dtypes = stats.iter().map(|s| s.dtype(dtype).as_nullable()).collect();
 
     DType::Struct(
@@ -179,6 +266,7 @@ pub struct ChunkedLayoutReader {
     metadata_layout: Option<Box<dyn LayoutReader>>,
     scan: Scan,
     in_progress_ranges: InProgressLayoutRanges,
+    dtype: Arc<LazyDType>,
     cached_metadata: OnceLock<ArrayData>,
     cached_prunability: OnceLock<ArrayData>,
 }
@@ -188,11 +276,13 @@ impl ChunkedLayoutReader {
         layouts: Vec<RangedLayoutReader>,
         metadata_layout: Option<Box<dyn LayoutReader>>,
         scan: Scan,
+        dtype: Arc<LazyDType>,
     ) -> Self {
         Self {
             layouts,
             metadata_layout,
             scan,
+            dtype,
             in_progress_ranges: RwLock::new(HashMap::new()),
             cached_metadata: OnceLock::new(),
             cached_prunability: OnceLock::new(),
@@ -377,10 +467,22 @@ impl LayoutReader for ChunkedLayoutReader {
                     let Some(metadata) = batches.swap_remove(0) else {
                         vortex_bail!("chunked layout should have exactly one metadata array")
                     };
-                    let prunability = PruningPredicate::try_new(predicate_expression)
+                    let stats_prunability = PruningPredicate::try_new(predicate_expression)
                         .map(|p| p.evaluate(&metadata))
                         .transpose()?
                         .flatten();
+                    let bloom_prunability = bloom_filter_prunability(
+                        predicate_expression,
+                        &metadata,
+                        self.dtype.value()?,
+                    )?;
+                    let prunability = match (stats_prunability, bloom_prunability) {
+                        (Some(stats), Some(bloom)) => Some(or(
+                            fill_null(stats, Scalar::bool(false, Nullability::NonNullable))?,
+                            bloom,
+                        )?),
+                        (stats, bloom) => stats.or(bloom),
+                    };
 
                     match prunability {
                         Some(chunk_prunability) => {
@@ -399,6 +501,121 @@ impl LayoutReader for ChunkedLayoutReader {
     }
 }
 
+/// Evaluate which chunks the expression can be pruned for using their [`BloomFilter`]s.
+///
+/// Only equality with a literal, and conjunctions and disjunctions thereof, can be pruned. Returns
+/// `None` if the expression can't be pruned using bloom filters.
+fn bloom_filter_prunability(
+    expr: &ExprRef,
+    metadata: &ArrayData,
+    dtype: &DType,
+) -> VortexResult<Option<ArrayData>> {
+    let Some(bloom_filters) = metadata
+        .as_struct_array()
+        .and_then(|st| st.field_by_name(BLOOM_FILTER_COLUMN))
+    else {
+        return Ok(None);
+    };
+    let bloom_filters = bloom_filters.into_varbinview()?;
+
+    let pruned = bloom_filter_pruned(expr, &bloom_filters, dtype)?;
+    Ok(pruned.map(|pruned| BoolArray::from_iter(pruned).into_array()))
+}
+
+fn bloom_filter_pruned(
+    expr: &ExprRef,
+    bloom_filters: &VarBinViewArray,
+    dtype: &DType,
+) -> VortexResult<Option<Vec<bool>>> {
+    let conjunction = |exprs: &[ExprRef]| -> VortexResult<Option<Vec<bool>>> {
+        let mut pruned: Option<Vec<bool>> = None;
+        for expr in exprs {
+            if let Some(child_pruned) = bloom_filter_pruned(expr, bloom_filters, dtype)? {
+                pruned = Some(match pruned {
+                    None => child_pruned,
+                    Some(p) => p
+                        .into_iter()
+                        .zip(child_pruned)
+                        .map(|(a, b)| a || b)
+                        .collect(),
+                });
+            }
+        }
+        Ok(pruned)
+    };
+
+    // A chunk can be pruned if its bloom filter contains none of the values.
+    let pruned_without = |values: &[Scalar]| -> VortexResult<Option<Vec<bool>>> {
+        (0..bloom_filters.len())
+            .map(|i| {
+                if !bloom_filters.is_valid(i) {
+                    return Ok(false);
+                }
+                let filter = BloomFilter::try_from_bytes(bloom_filters.bytes_at(i)?.as_slice())?;
+                Ok(values
+                    .iter()
+                    .all(|value| filter.might_contain(value) == Some(false)))
+            })
+            .collect::<VortexResult<Vec<_>>>()
+            .map(Some)
+    };
+
+    if let Some(rf) = expr.as_any().downcast_ref::<RowFilter>() {
+        return conjunction(&rf.conjunction);
+    }
+
+    if let Some(in_list) = expr.as_any().downcast_ref::<InList>() {
+        if in_list.negated()
+            || in_list
+                .child()
+                .as_any()
+                .downcast_ref::<Identity>()
+                .is_none()
+        {
+            return Ok(None);
+        }
+        // Null values never match, so only the others need to be absent.
+        let Ok(values) = in_list
+            .list()
+            .iter()
+            .filter(|value| !value.is_null())
+            .map(|value| value.cast(dtype))
+            .collect::<VortexResult<Vec<_>>>()
+        else {
+            return Ok(None);
+        };
+        return pruned_without(&values);
+    }
+
+    let Some(bexp) = expr.as_any().downcast_ref::<BinaryExpr>() else {
+        return Ok(None);
+    };
+    match bexp.op() {
+        Operator::And => conjunction(&[bexp.lhs().clone(), bexp.rhs().clone()]),
+        Operator::Or => {
+            let lhs = bloom_filter_pruned(bexp.lhs(), bloom_filters, dtype)?;
+            let rhs = bloom_filter_pruned(bexp.rhs(), bloom_filters, dtype)?;
+            Ok(lhs
+                .zip(rhs)
+                .map(|(lhs, rhs)| lhs.into_iter().zip(rhs).map(|(a, b)| a && b).collect()))
+        }
+        Operator::Eq => {
+            let lhs = bexp.lhs().as_any();
+            let rhs = bexp.rhs().as_any();
+            let literal = match (lhs.downcast_ref::<Literal>(), rhs.downcast_ref::<Literal>()) {
+                (Some(lit), None) if rhs.downcast_ref::<Identity>().is_some() => lit,
+                (None, Some(lit)) if lhs.downcast_ref::<Identity>().is_some() => lit,
+                _ => return Ok(None),
+            };
+            let Ok(value) = literal.value().cast(dtype) else {
+                return Ok(None);
+            };
+            pruned_without(&[value])
+        }
+        _ => Ok(None),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::VecDeque;
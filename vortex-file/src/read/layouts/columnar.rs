@@ -3,19 +3,25 @@ use std::sync::{Arc, RwLock};
 
 use itertools::Itertools;
 use vortex_array::aliases::hash_map::HashMap;
-use vortex_array::array::StructArray;
+use vortex_array::array::{ExtensionArray, StructArray};
 use vortex_array::stats::ArrayStatistics;
 use vortex_array::validity::Validity;
 use vortex_array::{ArrayData, IntoArrayData};
 use vortex_dtype::field::Field;
-use vortex_dtype::{FieldName, FieldNames};
+use vortex_dtype::{
+    is_variant_ext_type, DType, ExtDType, FieldName, FieldNames, Nullability, VariantDType,
+    VARIANT_SHREDDED_FIELD,
+};
 use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexExpect, VortexResult};
-use vortex_expr::{Column, Select, VortexExpr};
+use vortex_expr::{
+    BinaryExpr, Column, ExprRef, Identity, Literal, Not, Select, VariantGet, VortexExpr,
+};
 use vortex_flatbuffers::footer;
 
 use crate::read::cache::{LazyDType, RelativeLayoutCache};
 use crate::read::expr_project::expr_project;
 use crate::read::mask::RowMask;
+use crate::read::projection::SelectPaths;
 use crate::{
     BatchRead, Layout, LayoutDeserializer, LayoutId, LayoutReader, MetadataRead, PruningRead,
     RowFilter, Scan, COLUMNAR_LAYOUT_ID,
@@ -36,15 +42,16 @@ impl Layout for ColumnarLayout {
         layout_serde: LayoutDeserializer,
         message_cache: RelativeLayoutCache,
     ) -> VortexResult<Box<dyn LayoutReader>> {
-        Ok(Box::new(
-            ColumnarLayoutBuilder {
-                layout,
-                scan,
-                layout_serde,
-                message_cache,
-            }
-            .build()?,
-        ))
+        let builder = ColumnarLayoutBuilder {
+            layout,
+            scan,
+            layout_serde,
+            message_cache,
+        };
+        match builder.variant_ext_dtype() {
+            Some(ext_dtype) => builder.build_variant(ext_dtype),
+            None => Ok(Box::new(builder.build()?)),
+        }
     }
 }
 
@@ -57,6 +64,12 @@ struct ColumnarLayoutBuilder<'a> {
 
 impl ColumnarLayoutBuilder<'_> {
     fn build(&self) -> VortexResult<ColumnarLayoutReader> {
+        if let Some(expr) = self.scan.expr.as_ref() {
+            if self.scan_fields().is_some_and(|fields| fields.is_empty()) {
+                return self.build_whole(expr.clone());
+            }
+        }
+
         let (refs, lazy_dtype) = self.fields_with_dtypes()?;
         let fb_children = self.layout.children().unwrap_or_default();
 
@@ -66,8 +79,8 @@ impl ColumnarLayoutBuilder<'_> {
         let mut handled_names = Vec::new();
 
         for (field, name) in refs.into_iter().zip_eq(lazy_dtype.names()?.iter()) {
-            let resolved_child = lazy_dtype.resolve_field(&field)?;
-            let child_field = lazy_dtype.field(&field)?;
+            let resolved_child = self.message_cache.dtype().resolve_field(&field)?;
+            let child_field = self.message_cache.dtype().field(&field)?;
             let child_layout = fb_children.get(resolved_child);
             let projected_expr = self
                 .scan
@@ -145,7 +158,96 @@ impl ColumnarLayoutBuilder<'_> {
             handled_children,
             top_level_expr,
             shortcircuit_siblings,
-        ))
+        )
+        .nested(!self.message_cache.is_root()))
+    }
+
+    /// Read all columns and evaluate an expression that doesn't reference any of them.
+    ///
+    /// This happens for nested columnar layouts, which are passed expressions on the whole struct,
+    /// e.g. [`Identity`][vortex_expr::Identity] when the struct is projected by its parent.
+    fn build_whole(&self, expr: ExprRef) -> VortexResult<ColumnarLayoutReader> {
+        let fb_children = self.layout.children().unwrap_or_default();
+        let lazy_dtype = self.message_cache.dtype();
+        let names = lazy_dtype.names()?;
+        let children = (0..fb_children.len())
+            .map(|idx| {
+                let field = Field::from(idx);
+                self.layout_serde.read_layout(
+                    fb_children.get(idx),
+                    Scan::empty(),
+                    self.message_cache
+                        .relative(idx as u16, lazy_dtype.field(&field)?),
+                )
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        Ok(
+            ColumnarLayoutReader::new(names, children, Some(expr), false)
+                .nested(!self.message_cache.is_root()),
+        )
+    }
+
+    /// The dtype of the column if it's a shredded variant, i.e. its storage is a nested layout.
+    fn variant_ext_dtype(&self) -> Option<Arc<ExtDType>> {
+        if self.message_cache.is_root() {
+            return None;
+        }
+        match self.message_cache.dtype().value() {
+            Ok(DType::Extension(ext_dtype)) if is_variant_ext_type(ext_dtype.id()) => {
+                Some(ext_dtype.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Read a shredded variant column.
+    ///
+    /// Filters that only extract shredded paths are evaluated on the shredded columns alone, which
+    /// lets them be pruned using the statistics of each path. Otherwise, the whole storage is read
+    /// and the expression is evaluated on the reassembled variants.
+    fn build_variant(&self, ext_dtype: Arc<ExtDType>) -> VortexResult<Box<dyn LayoutReader>> {
+        let variant = VariantDType::try_from(ext_dtype.as_ref())?;
+        if let Some(expr) = self
+            .scan
+            .expr
+            .as_ref()
+            .and_then(|e| shredded_filter(e, &variant))
+        {
+            let shredded_idx = ext_dtype
+                .storage_dtype()
+                .as_struct()
+                .and_then(|st| st.find_name(VARIANT_SHREDDED_FIELD))
+                .ok_or_else(|| vortex_err!("Variant storage is missing shredded columns"))?;
+            return self.layout_serde.read_layout(
+                self.layout.children().unwrap_or_default().get(shredded_idx),
+                Scan::new(Some(expr)),
+                self.message_cache.relative(
+                    shredded_idx as u16,
+                    Arc::new(LazyDType::from_dtype(DType::Struct(
+                        variant.shredded().clone(),
+                        Nullability::NonNullable,
+                    ))),
+                ),
+            );
+        }
+
+        let storage = ColumnarLayoutBuilder {
+            layout: self.layout,
+            scan: Scan::empty(),
+            layout_serde: self.layout_serde.clone(),
+            message_cache: self
+                .message_cache
+                .with_dtype(Arc::new(LazyDType::from_dtype(
+                    ext_dtype.storage_dtype().clone(),
+                ))),
+        }
+        .build()?;
+        Ok(Box::new(VariantLayoutReader {
+            storage,
+            ext_dtype,
+            expr: self.scan.expr.clone(),
+        }))
     }
 
     /// Get fields referenced by scan expression along with their dtype
@@ -171,6 +273,8 @@ impl ColumnarLayoutBuilder<'_> {
                     Select::Include(i) => i.clone(),
                     Select::Exclude(_) => vortex_panic!("Select::Exclude is not supported"),
                 }
+            } else if let Some(sp) = e.as_any().downcast_ref::<SelectPaths>() {
+                sp.fields()
             } else {
                 e.references().into_iter().cloned().collect::<Vec<_>>()
             }
@@ -178,6 +282,77 @@ impl ColumnarLayoutBuilder<'_> {
     }
 }
 
+/// Rewrite a row filter on a variant into a row filter on its shredded columns.
+///
+/// Returns `None` unless every conjunct only extracts shredded paths with the dtype they were
+/// shredded as, since those are the only values the shredded columns hold.
+fn shredded_filter(expr: &ExprRef, variant: &VariantDType) -> Option<ExprRef> {
+    fn rewrite(expr: &ExprRef, variant: &VariantDType) -> Option<ExprRef> {
+        if let Some(vg) = expr.as_any().downcast_ref::<VariantGet>() {
+            let (_, dtype) = variant.shredded_path(vg.path())?;
+            (vg.child().as_any().downcast_ref::<Identity>().is_some() && dtype == vg.dtype())
+                .then(|| Column::new_expr(Field::from(vg.path())))
+        } else if expr.as_any().downcast_ref::<Literal>().is_some() {
+            Some(expr.clone())
+        } else if let Some(n) = expr.as_any().downcast_ref::<Not>() {
+            rewrite(n.child(), variant).map(Not::new_expr)
+        } else if let Some(bexp) = expr.as_any().downcast_ref::<BinaryExpr>() {
+            Some(BinaryExpr::new_expr(
+                rewrite(bexp.lhs(), variant)?,
+                bexp.op(),
+                rewrite(bexp.rhs(), variant)?,
+            ))
+        } else {
+            None
+        }
+    }
+
+    let rf = expr.as_any().downcast_ref::<RowFilter>()?;
+    Some(RowFilter::from_conjunction_expr(
+        rf.conjunction
+            .iter()
+            .map(|c| rewrite(c, variant))
+            .collect::<Option<Vec<_>>>()?,
+    ))
+}
+
+/// Reads the storage of a shredded variant column and reassembles the variants.
+#[derive(Debug)]
+struct VariantLayoutReader {
+    storage: ColumnarLayoutReader,
+    ext_dtype: Arc<ExtDType>,
+    expr: Option<ExprRef>,
+}
+
+impl LayoutReader for VariantLayoutReader {
+    fn add_splits(&self, row_offset: usize, splits: &mut BTreeSet<usize>) -> VortexResult<()> {
+        self.storage.add_splits(row_offset, splits)
+    }
+
+    fn read_selection(&self, selection: &RowMask) -> VortexResult<Option<BatchRead>> {
+        match self.storage.read_selection(selection)? {
+            Some(BatchRead::Value(storage)) => {
+                let array = ExtensionArray::new(self.ext_dtype.clone(), storage).into_array();
+                self.expr
+                    .as_ref()
+                    .map(|e| e.evaluate(&array))
+                    .unwrap_or_else(|| Ok(array))
+                    .map(BatchRead::Value)
+                    .map(Some)
+            }
+            read => Ok(read),
+        }
+    }
+
+    fn read_metadata(&self) -> VortexResult<Option<MetadataRead>> {
+        Ok(None)
+    }
+
+    fn can_prune(&self, _begin: usize, _end: usize) -> VortexResult<PruningRead> {
+        Ok(PruningRead::Value(false))
+    }
+}
+
 type InProgressRanges = RwLock<HashMap<(usize, usize), Vec<Option<ArrayData>>>>;
 type InProgressPrunes = RwLock<HashMap<(usize, usize), Vec<Option<bool>>>>;
 
@@ -191,6 +366,7 @@ pub struct ColumnarLayoutReader {
     expr: Option<Arc<dyn VortexExpr>>,
     // TODO(robert): This is a hack/optimization that tells us if we're reducing results with AND or not
     shortcircuit_siblings: bool,
+    nested: bool,
     in_progress_ranges: InProgressRanges,
     in_progress_metadata: RwLock<HashMap<FieldName, Option<ArrayData>>>,
     in_progress_prunes: InProgressPrunes,
@@ -213,11 +389,18 @@ impl ColumnarLayoutReader {
             children,
             expr,
             shortcircuit_siblings,
+            nested: false,
             in_progress_ranges: RwLock::new(HashMap::new()),
             in_progress_metadata: RwLock::new(HashMap::new()),
             in_progress_prunes: RwLock::new(HashMap::new()),
         }
     }
+
+    /// Mark this reader as reading a struct column nested within another columnar layout.
+    fn nested(mut self, nested: bool) -> Self {
+        self.nested = nested;
+        self
+    }
 }
 
 impl LayoutReader for ColumnarLayoutReader {
@@ -300,6 +483,11 @@ impl LayoutReader for ColumnarLayoutReader {
     }
 
     fn read_metadata(&self) -> VortexResult<Option<MetadataRead>> {
+        // Nested columnar layouts have metadata per leaf column, but none for the struct as a whole.
+        if self.nested {
+            return Ok(None);
+        }
+
         let mut in_progress_metadata = self
             .in_progress_metadata
             .write()
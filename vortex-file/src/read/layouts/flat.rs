@@ -2,17 +2,21 @@ use std::collections::BTreeSet;
 use std::sync::Arc;
 
 use bytes::Bytes;
-use vortex_array::{ArrayData, Context};
-use vortex_error::{vortex_bail, VortexResult};
+use itertools::Itertools;
+use vortex_array::array::ChunkedArray;
+use vortex_array::{ArrayData, Context, IntoArrayData};
+use vortex_error::{vortex_bail, VortexExpect, VortexResult};
 use vortex_flatbuffers::footer;
 use vortex_ipc::messages::reader::ArrayMessageReader;
+use vortex_ipc::messages::verify_checksum;
 use vortex_ipc::stream_writer::ByteRange;
 
+use crate::encryption::MessageCipher;
 use crate::read::cache::RelativeLayoutCache;
 use crate::read::mask::RowMask;
 use crate::{
-    BatchRead, Layout, LayoutDeserializer, LayoutId, LayoutReader, MessageLocator, MetadataRead,
-    PruningRead, Scan, FLAT_LAYOUT_ID,
+    BatchRead, Layout, LayoutDeserializer, LayoutId, LayoutPartId, LayoutReader, MessageLocator,
+    MetadataRead, PruningRead, Scan, FLAT_LAYOUT_ID,
 };
 
 #[derive(Debug)]
@@ -31,23 +35,119 @@ impl Layout for FlatLayout {
         message_cache: RelativeLayoutCache,
     ) -> VortexResult<Box<dyn LayoutReader>> {
         let buffers = layout.buffers().unwrap_or_default();
-        if buffers.len() != 1 {
-            vortex_bail!("Flat layout can have exactly 1 buffer")
+        let reader = if let Some(metadata) = layout.metadata() {
+            let pages = buffers
+                .iter()
+                .map(|buf| ByteRange::new(buf.begin(), buf.end()))
+                .collect::<Vec<_>>();
+            let page_index = PageIndex::parse(metadata.bytes())?;
+            if page_index.row_offsets.len() != pages.len() + 1 {
+                vortex_bail!(
+                    "Page index has {} row offsets for {} pages",
+                    page_index.row_offsets.len(),
+                    pages.len()
+                );
+            }
+            FlatLayoutReader::new_paged(pages, page_index, scan, layout_serde.ctx(), message_cache)
+        } else {
+            if buffers.len() != 1 {
+                vortex_bail!("Flat layout can have exactly 1 buffer")
+            }
+            let buf = buffers.get(0);
+            FlatLayoutReader::new(
+                ByteRange::new(buf.begin(), buf.end()),
+                scan,
+                layout_serde.ctx(),
+                message_cache,
+            )
+        };
+
+        let reader = match layout.key_id() {
+            Some(key_id) => reader.with_cipher(layout_serde.cipher(key_id)?),
+            None => reader,
+        };
+
+        if !layout_serde.verify_checksums() {
+            return Ok(Box::new(reader.without_checksum_verification()));
+        }
+        let Some(checksums) = layout.checksums() else {
+            return Ok(Box::new(reader));
+        };
+        if checksums.len() != buffers.len() {
+            vortex_bail!(
+                "Flat layout has {} checksums for {} buffers",
+                checksums.len(),
+                buffers.len()
+            );
         }
-        let buf = buffers.get(0);
+        Ok(Box::new(reader.with_checksums(checksums.iter().collect())))
+    }
+}
 
-        Ok(Box::new(FlatLayoutReader::new(
-            ByteRange::new(buf.begin(), buf.end()),
-            scan,
-            layout_serde.ctx(),
-            message_cache,
-        )))
+/// The offset index of a flat layout that was written as multiple pages.
+///
+/// Each page is a separate array message with its own buffer in the layout, so that sparse reads
+/// only need to fetch and decode the pages holding selected rows. The index is serialized as the
+/// little-endian u64 row offsets of the pages, followed by the row count of the layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PageIndex {
+    pub row_offsets: Vec<u64>,
+}
+
+impl PageIndex {
+    pub fn from_page_row_counts(row_counts: impl IntoIterator<Item = u64>) -> Self {
+        let mut row_offsets = vec![0];
+        for row_count in row_counts {
+            row_offsets.push(row_offsets[row_offsets.len() - 1] + row_count);
+        }
+        Self { row_offsets }
+    }
+
+    pub fn parse(bytes: &[u8]) -> VortexResult<Self> {
+        if bytes.len() % size_of::<u64>() != 0 {
+            vortex_bail!("Invalid page index of {} bytes", bytes.len());
+        }
+        let row_offsets = bytes
+            .chunks_exact(size_of::<u64>())
+            .map(|offset| {
+                u64::from_le_bytes(
+                    offset
+                        .try_into()
+                        .vortex_expect("chunks_exact returns chunks of u64 size"),
+                )
+            })
+            .collect::<Vec<_>>();
+        if !row_offsets.is_sorted() {
+            vortex_bail!("Page index row offsets must be sorted");
+        }
+        Ok(Self { row_offsets })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.row_offsets
+            .iter()
+            .flat_map(|offset| offset.to_le_bytes())
+            .collect()
+    }
+
+    /// The row ranges of the pages.
+    fn page_ranges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.row_offsets
+            .iter()
+            .tuple_windows()
+            .map(|(begin, end)| (*begin as usize, *end as usize))
     }
 }
 
 #[derive(Debug)]
 pub struct FlatLayoutReader {
     range: ByteRange,
+    pages: Option<(Vec<ByteRange>, PageIndex)>,
+    /// Checksums of the messages of the layout, one per buffer.
+    checksums: Option<Vec<u64>>,
+    verify_checksums: bool,
+    /// Decrypts the messages of the layout, if they are encrypted.
+    cipher: Option<Arc<MessageCipher>>,
     scan: Scan,
     ctx: Arc<Context>,
     message_cache: RelativeLayoutCache,
@@ -62,6 +162,33 @@ impl FlatLayoutReader {
     ) -> Self {
         Self {
             range,
+            pages: None,
+            checksums: None,
+            verify_checksums: true,
+            cipher: None,
+            scan,
+            ctx,
+            message_cache,
+        }
+    }
+
+    pub(crate) fn new_paged(
+        pages: Vec<ByteRange>,
+        page_index: PageIndex,
+        scan: Scan,
+        ctx: Arc<Context>,
+        message_cache: RelativeLayoutCache,
+    ) -> Self {
+        let range = ByteRange::new(
+            pages.first().map(|page| page.begin).unwrap_or_default(),
+            pages.last().map(|page| page.end).unwrap_or_default(),
+        );
+        Self {
+            range,
+            pages: Some((pages, page_index)),
+            checksums: None,
+            verify_checksums: true,
+            cipher: None,
             scan,
             ctx,
             message_cache,
@@ -72,8 +199,92 @@ impl FlatLayoutReader {
         MessageLocator(self.message_cache.absolute_id(&[]), self.range)
     }
 
-    fn array_from_bytes(&self, mut buf: Bytes) -> VortexResult<ArrayData> {
-        let mut array_reader = ArrayMessageReader::new();
+    /// Verify the messages against checksums recorded in the layout, instead of those recorded in
+    /// the messages themselves, which don't cover the message flatbuffers.
+    pub(crate) fn with_checksums(mut self, checksums: Vec<u64>) -> Self {
+        self.checksums = Some(checksums);
+        self
+    }
+
+    pub(crate) fn with_cipher(mut self, cipher: Arc<MessageCipher>) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+
+    pub(crate) fn without_checksum_verification(mut self) -> Self {
+        self.verify_checksums = false;
+        self
+    }
+
+    fn evaluate_scan(&self, array: ArrayData) -> VortexResult<BatchRead> {
+        Ok(BatchRead::Value(
+            self.scan
+                .expr
+                .as_ref()
+                .map(|e| e.evaluate(&array))
+                .transpose()?
+                .unwrap_or(array),
+        ))
+    }
+
+    /// Read the selected rows of a paged layout, fetching only the pages that hold any of them.
+    fn read_pages(
+        &self,
+        pages: &[ByteRange],
+        page_index: &PageIndex,
+        selection: &RowMask,
+    ) -> VortexResult<Option<BatchRead>> {
+        let mut messages = Vec::new();
+        let mut page_reads = Vec::new();
+        for (idx, (begin, end)) in page_index.page_ranges().enumerate() {
+            if end <= selection.begin() || begin >= selection.end() {
+                continue;
+            }
+            let page_selection = selection.slice(begin, end)?;
+            if page_selection.is_empty() {
+                continue;
+            }
+
+            let page_id = [LayoutPartId::try_from(idx)?];
+            match self.message_cache.get(&page_id) {
+                Some(buf) => page_reads.push((idx, page_selection.shift(begin)?, buf)),
+                None => messages.push(MessageLocator(
+                    self.message_cache.absolute_id(&page_id),
+                    pages[idx],
+                )),
+            }
+        }
+        if !messages.is_empty() {
+            return Ok(Some(BatchRead::ReadMore(messages)));
+        }
+
+        let mut arrays = page_reads
+            .into_iter()
+            .map(|(idx, page_selection, buf)| {
+                page_selection.filter_array(self.array_from_bytes(idx, buf)?)
+            })
+            .filter_map(Result::transpose)
+            .collect::<VortexResult<Vec<_>>>()?;
+        let array = match arrays.len() {
+            0 => return Ok(None),
+            1 => arrays.remove(0),
+            _ => ChunkedArray::try_new(arrays, self.message_cache.dtype().value()?.clone())?
+                .into_array(),
+        };
+        self.evaluate_scan(array).map(Some)
+    }
+
+    fn array_from_bytes(&self, buffer_idx: usize, mut buf: Bytes) -> VortexResult<ArrayData> {
+        if let Some(checksums) = self.checksums.as_ref() {
+            verify_checksum(&buf, checksums[buffer_idx])?;
+        }
+        if let Some(cipher) = self.cipher.as_ref() {
+            buf = cipher.decrypt(&buf)?;
+        }
+        // Checksums recorded in the layout cover the whole message, so those recorded in the
+        // message itself don't need to be checked again.
+        let mut array_reader = ArrayMessageReader::new()
+            .with_checksum_verification(self.verify_checksums && self.checksums.is_none());
         let mut read_buf = Bytes::new();
         while let Some(u) = array_reader.read(read_buf)? {
             read_buf = buf.split_to(u);
@@ -92,20 +303,15 @@ impl LayoutReader for FlatLayoutReader {
     }
 
     fn read_selection(&self, selection: &RowMask) -> VortexResult<Option<BatchRead>> {
+        if let Some((pages, page_index)) = self.pages.as_ref() {
+            return self.read_pages(pages, page_index, selection);
+        }
+
         if let Some(buf) = self.message_cache.get(&[]) {
-            let array = self.array_from_bytes(buf)?;
+            let array = self.array_from_bytes(0, buf)?;
             selection
                 .filter_array(array)?
-                .map(|s| {
-                    Ok(BatchRead::Value(
-                        self.scan
-                            .expr
-                            .as_ref()
-                            .map(|e| e.evaluate(&s))
-                            .transpose()?
-                            .unwrap_or(s),
-                    ))
-                })
+                .map(|s| self.evaluate_scan(s))
                 .transpose()
         } else {
             Ok(Some(BatchRead::ReadMore(vec![self.own_message()])))
@@ -133,7 +339,7 @@ mod tests {
     use vortex_ipc::messages::writer::MessageWriter;
     use vortex_ipc::stream_writer::ByteRange;
 
-    use crate::layouts::flat::FlatLayoutReader;
+    use crate::layouts::flat::{FlatLayoutReader, PageIndex};
     use crate::read::cache::{LazyDType, RelativeLayoutCache};
     use crate::read::layouts::test_read::{filter_read_layout, read_layout};
     use crate::{LayoutMessageCache, RowFilter, Scan};
@@ -251,4 +457,15 @@ mod tests {
 
         assert!(arr.is_none());
     }
+
+    #[test]
+    fn page_index_round_trip() {
+        let page_index = PageIndex::from_page_row_counts([16, 16, 4]);
+        assert_eq!(page_index.row_offsets, vec![0, 16, 32, 36]);
+        assert_eq!(
+            PageIndex::parse(&page_index.to_bytes()).unwrap(),
+            page_index
+        );
+        assert!(PageIndex::parse(&[0; 12]).is_err());
+    }
 }
@@ -5,8 +5,10 @@ mod flat;
 mod test_read;
 
 pub use chunked::ChunkedLayout;
+pub(crate) use chunked::{ChunkedMetadata, BLOOM_FILTER_COLUMN};
 pub use columnar::ColumnarLayout;
 pub use flat::FlatLayout;
+pub(crate) use flat::PageIndex;
 
 use crate::LayoutReader;
 
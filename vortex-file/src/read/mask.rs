@@ -147,6 +147,24 @@ impl RowMask {
         }
     }
 
+    /// Combine two RowMasks over the same row range, keeping only rows selected by both
+    pub fn and_mask(&self, other: &RowMask) -> VortexResult<Self> {
+        if self.begin != other.begin || self.end != other.end {
+            vortex_bail!(
+                "Cannot combine RowMask [{}..{}] with RowMask [{}..{}]",
+                self.begin,
+                self.end,
+                other.begin,
+                other.end
+            );
+        }
+        Self::try_new(
+            and(self.bitmask.clone(), other.bitmask.clone())?,
+            self.begin,
+            self.end,
+        )
+    }
+
     pub fn is_empty(&self) -> bool {
         self.bitmask
             .statistics()
@@ -9,6 +9,7 @@ mod buffered;
 pub mod builder;
 mod cache;
 mod context;
+pub(crate) mod evolution;
 mod expr_project;
 mod filtering;
 pub mod layouts;
@@ -24,6 +25,7 @@ pub use builder::initial_read::*;
 pub use builder::VortexReadBuilder;
 pub use cache::*;
 pub use context::*;
+pub use evolution::{can_evolve, widen_dtype};
 pub use filtering::RowFilter;
 pub use projection::Projection;
 pub use recordbatchreader::{AsyncRuntime, VortexRecordBatchReader};
@@ -1,13 +1,27 @@
-use vortex_dtype::field::Field;
-use vortex_error::{vortex_bail, VortexResult};
+use std::any::Any;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use itertools::Itertools;
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::array::StructArray;
+use vortex_array::variants::StructArrayTrait;
+use vortex_array::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
+use vortex_dtype::field::{Field, FieldPath};
+use vortex_dtype::{DType, StructDType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_expr::{unbox_any, ExprRef, Identity, VortexExpr};
 
-// TODO(robert): Add ability to project nested columns.
-//  Until datafusion supports nested column pruning we should create a separate variant to implement it
 #[derive(Debug, Clone, Default)]
 pub enum Projection {
     #[default]
     All,
     Flat(Vec<Field>),
+    /// Select (possibly nested) fields by their path.
+    ///
+    /// The result keeps the nesting of the selected fields, e.g. selecting `a.b.c` and `d` results
+    /// in `{a: {b: {c}}, d}`. Only the selected leaf columns are read from nested columnar layouts.
+    Nested(Vec<FieldPath>),
 }
 
 impl Projection {
@@ -24,6 +38,21 @@ impl Projection {
                 }
                 Projection::Flat(fields.to_vec())
             }
+            Projection::Nested(paths) => {
+                if !fields
+                    .iter()
+                    .all(|f| paths.iter().any(|p| p.path().first() == Some(f)))
+                {
+                    vortex_bail!("Can't project {paths:?} into {fields:?}")
+                }
+                Projection::Nested(
+                    paths
+                        .iter()
+                        .filter(|p| p.path().first().is_some_and(|f| fields.contains(f)))
+                        .cloned()
+                        .collect(),
+                )
+            }
         })
     }
 }
@@ -39,3 +68,224 @@ impl From<Vec<usize>> for Projection {
         Self::Flat(indices.into_iter().map(Field::from).collect())
     }
 }
+
+impl From<Vec<FieldPath>> for Projection {
+    fn from(paths: Vec<FieldPath>) -> Self {
+        Self::Nested(paths)
+    }
+}
+
+/// The fields of a struct selected by a set of paths, in order of first appearance.
+struct SelectedField {
+    index: usize,
+    /// The paths to select within the field, or `None` if the whole field is selected.
+    nested: Option<Vec<FieldPath>>,
+}
+
+fn select_fields(dtype: &StructDType, paths: &[FieldPath]) -> VortexResult<Vec<SelectedField>> {
+    let mut selected: Vec<SelectedField> = Vec::new();
+    for path in paths {
+        let Some((head, tail)) = path.path().split_first() else {
+            vortex_bail!("Can't project an empty field path");
+        };
+        let index = dtype.field_info(head)?.index;
+        let pos = match selected.iter().position(|s| s.index == index) {
+            Some(pos) => pos,
+            None => {
+                selected.push(SelectedField {
+                    index,
+                    nested: Some(Vec::new()),
+                });
+                selected.len() - 1
+            }
+        };
+        let field = &mut selected[pos];
+        if tail.is_empty() {
+            field.nested = None;
+        } else if let Some(nested) = field.nested.as_mut() {
+            nested.push(FieldPath::from(tail.to_vec()));
+        }
+    }
+    Ok(selected)
+}
+
+/// Restrict a struct dtype to the fields selected by the given paths.
+pub(crate) fn project_dtype(dtype: &StructDType, paths: &[FieldPath]) -> VortexResult<StructDType> {
+    let mut names = Vec::new();
+    let mut dtypes = Vec::new();
+    for SelectedField { index, nested } in select_fields(dtype, paths)? {
+        names.push(dtype.names()[index].clone());
+        let field_dtype = &dtype.dtypes()[index];
+        dtypes.push(match nested {
+            None => field_dtype.clone(),
+            Some(nested) => {
+                let DType::Struct(st, n) = field_dtype else {
+                    vortex_bail!("Can't select nested fields of {field_dtype}");
+                };
+                DType::Struct(project_dtype(st, &nested)?, *n)
+            }
+        });
+    }
+    Ok(StructDType::new(names.into(), dtypes))
+}
+
+/// Restrict a struct array to the fields selected by the given paths.
+fn project_array(array: &ArrayData, paths: &[FieldPath]) -> VortexResult<ArrayData> {
+    let DType::Struct(dtype, _) = array.dtype() else {
+        vortex_bail!("Can't select fields of {}", array.dtype());
+    };
+    let st = array.clone().into_struct()?;
+    let mut names = Vec::new();
+    let mut fields = Vec::new();
+    for SelectedField { index, nested } in select_fields(dtype, paths)? {
+        names.push(st.names()[index].clone());
+        let field = st
+            .field(index)
+            .ok_or_else(|| vortex_err!(OutOfBounds: index, 0, st.nfields()))?;
+        fields.push(match nested {
+            None => field,
+            Some(nested) => project_array(&field, &nested)?,
+        });
+    }
+    Ok(StructArray::try_new(names.into(), fields, st.len(), st.validity())?.into_array())
+}
+
+/// Expression selecting (possibly nested) fields of a struct by their path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SelectPaths {
+    paths: Vec<FieldPath>,
+}
+
+impl SelectPaths {
+    pub fn new_expr(paths: Vec<FieldPath>) -> ExprRef {
+        Arc::new(Self { paths })
+    }
+
+    /// The top-level fields referenced by the paths, in order of first appearance.
+    pub fn fields(&self) -> Vec<Field> {
+        self.paths
+            .iter()
+            .filter_map(|p| p.path().first())
+            .unique()
+            .cloned()
+            .collect()
+    }
+
+    /// Restrict the selection to the given top-level fields.
+    ///
+    /// Selecting a single field results in an expression that is evaluated on that field alone.
+    pub fn project(&self, fields: &[Field]) -> Option<ExprRef> {
+        let paths = self
+            .paths
+            .iter()
+            .filter(|p| p.path().first().is_some_and(|f| fields.contains(f)))
+            .collect_vec();
+        if paths.is_empty() {
+            return None;
+        }
+        if fields.len() != 1 {
+            return Some(Self::new_expr(paths.into_iter().cloned().collect()));
+        }
+
+        let nested = paths
+            .iter()
+            .map(|p| FieldPath::from(p.path()[1..].to_vec()))
+            .collect_vec();
+        Some(if nested.iter().any(|p| p.path().is_empty()) {
+            Arc::new(Identity)
+        } else {
+            Self::new_expr(nested)
+        })
+    }
+}
+
+impl Display for SelectPaths {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SelectPaths({})", self.paths.iter().format(","))
+    }
+}
+
+impl VortexExpr for SelectPaths {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn evaluate(&self, batch: &ArrayData) -> VortexResult<ArrayData> {
+        project_array(batch, &self.paths)
+    }
+
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a Field>) {
+        references.extend(self.paths.iter().filter_map(|p| p.path().first()));
+    }
+}
+
+impl PartialEq<dyn Any> for SelectPaths {
+    fn eq(&self, other: &dyn Any) -> bool {
+        unbox_any(other)
+            .downcast_ref::<Self>()
+            .map(|x| x == self)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_array::array::PrimitiveArray;
+    use vortex_dtype::{Nullability, PType};
+
+    use super::*;
+
+    fn path(fields: &[&str]) -> FieldPath {
+        fields.iter().map(|f| Field::from(*f)).collect()
+    }
+
+    #[test]
+    fn project_nested() {
+        let inner = StructArray::from_fields(&[
+            ("b", PrimitiveArray::from(vec![1u8, 2]).into_array()),
+            ("c", PrimitiveArray::from(vec![3u16, 4]).into_array()),
+        ])
+        .unwrap();
+        let array = StructArray::from_fields(&[
+            ("a", inner.into_array()),
+            ("d", PrimitiveArray::from(vec![5u32, 6]).into_array()),
+        ])
+        .unwrap()
+        .into_array();
+
+        let projected = SelectPaths::new_expr(vec![path(&["a", "c"]), path(&["d"])])
+            .evaluate(&array)
+            .unwrap();
+        assert_eq!(
+            projected.dtype(),
+            &DType::Struct(
+                StructDType::new(
+                    ["a".into(), "d".into()].into(),
+                    vec![
+                        DType::Struct(
+                            StructDType::new(
+                                ["c".into()].into(),
+                                vec![DType::Primitive(PType::U16, Nullability::NonNullable)]
+                            ),
+                            Nullability::NonNullable
+                        ),
+                        DType::Primitive(PType::U32, Nullability::NonNullable),
+                    ]
+                ),
+                Nullability::NonNullable
+            )
+        );
+    }
+
+    #[test]
+    fn whole_field_wins() {
+        let select = SelectPaths {
+            paths: vec![path(&["a", "b"]), path(&["a"])],
+        };
+        assert_eq!(select.fields(), vec![Field::from("a")]);
+        assert_eq!(
+            *select.project(&[Field::from("a")]).unwrap(),
+            *Identity.as_any()
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use std::collections::BTreeSet;
 use std::pin::Pin;
 use std::sync::{Arc, RwLock};
-use std::task::{Context, Poll};
+use std::task::{ready, Context, Poll};
 
 use futures::Stream;
 use futures_util::{StreamExt, TryStreamExt};
@@ -13,6 +13,7 @@ use vortex_io::{IoDispatcher, VortexReadAt};
 
 use crate::read::buffered::{BufferedLayoutReader, ReadArray};
 use crate::read::cache::LayoutMessageCache;
+use crate::read::evolution::SchemaAdapter;
 use crate::read::mask::RowMask;
 use crate::read::splits::{FixedSplitIterator, ReadRowMask};
 use crate::read::LayoutReader;
@@ -34,6 +35,7 @@ pub struct VortexFileArrayStream<R> {
         ArrayData,
         ReadArray,
     >,
+    adapter: Option<SchemaAdapter>,
 }
 
 impl<R: VortexReadAt + Unpin> VortexFileArrayStream<R> {
@@ -45,7 +47,9 @@ impl<R: VortexReadAt + Unpin> VortexFileArrayStream<R> {
         messages_cache: Arc<RwLock<LayoutMessageCache>>,
         dtype: Arc<LazyDType>,
         row_count: u64,
+        live_row_count: u64,
         row_mask: Option<RowMask>,
+        adapter: Option<SchemaAdapter>,
         dispatcher: Arc<IoDispatcher>,
     ) -> VortexResult<Self> {
         let mut reader_splits = BTreeSet::new();
@@ -82,8 +86,9 @@ impl<R: VortexReadAt + Unpin> VortexFileArrayStream<R> {
 
         Ok(Self {
             dtype,
-            row_count,
+            row_count: live_row_count,
             array_reader,
+            adapter,
         })
     }
 
@@ -92,6 +97,7 @@ impl<R: VortexReadAt + Unpin> VortexFileArrayStream<R> {
         self.dtype.value().vortex_unwrap()
     }
 
+    /// The number of rows in the file, excluding deleted rows.
     pub fn row_count(&self) -> u64 {
         self.row_count
     }
@@ -101,7 +107,11 @@ impl<R: VortexReadAt + Unpin> Stream for VortexFileArrayStream<R> {
     type Item = VortexResult<ArrayData>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.array_reader.poll_next_unpin(cx)
+        let batch = ready!(self.array_reader.poll_next_unpin(cx));
+        Poll::Ready(match (self.adapter.as_ref(), batch) {
+            (Some(adapter), Some(Ok(batch))) => Some(adapter.adapt(&batch)),
+            (_, batch) => batch,
+        })
     }
 }
 
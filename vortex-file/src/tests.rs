@@ -7,22 +7,28 @@ use futures::StreamExt;
 use futures_util::TryStreamExt;
 use itertools::Itertools;
 use vortex_array::accessor::ArrayAccessor;
-use vortex_array::array::{ChunkedArray, PrimitiveArray, StructArray, VarBinArray};
+use vortex_array::aliases::hash_map::HashMap;
+use vortex_array::array::{
+    ChunkedArray, ConstantArray, FixedSizeListArray, PrimitiveArray, StructArray, VarBinArray,
+    VariantArray,
+};
 use vortex_array::compute::scalar_at;
 use vortex_array::validity::Validity;
 use vortex_array::variants::{PrimitiveArrayTrait, StructArrayTrait};
 use vortex_array::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant, ToArrayData};
 use vortex_buffer::Buffer;
-use vortex_dtype::field::Field;
-use vortex_dtype::{DType, Nullability, PType, StructDType};
-use vortex_error::vortex_panic;
-use vortex_expr::{BinaryExpr, Column, Literal, Operator};
+use vortex_dtype::field::{Field, FieldPath};
+use vortex_dtype::{DType, FieldName, Nullability, PType, StructDType};
+use vortex_error::{vortex_panic, VortexResult};
+use vortex_expr::{BinaryExpr, Column, ExprRef, InList, Literal, Operator, VariantGet};
+use vortex_scalar::Scalar;
 
 use crate::builder::initial_read::read_initial_bytes;
-use crate::write::VortexFileWriter;
+use crate::dataset::{DatasetManifest, HivePartitionedWriter, HivePartitioning, VortexDataset};
+use crate::write::{LayoutSpec, VortexFileWriter, WriteOptions};
 use crate::{
-    LayoutDeserializer, LayoutMessageCache, Projection, RelativeLayoutCache, RowFilter, Scan,
-    VortexReadBuilder, V1_FOOTER_FBS_SIZE, VERSION,
+    DeletionVector, EncryptionKey, KeyProvider, LayoutDeserializer, LayoutMessageCache, Projection,
+    RelativeLayoutCache, RowFilter, Scan, VortexReadBuilder, V1_FOOTER_FBS_SIZE, VERSION,
 };
 
 #[test]
@@ -1003,3 +1009,1466 @@ async fn test_pruning_with_or() {
         ]
     );
 }
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_append() {
+    let st = StructArray::from_fields(&[
+        (
+            "strings",
+            VarBinArray::from(vec!["ab", "foo", "bar"]).into_array(),
+        ),
+        (
+            "numbers",
+            PrimitiveArray::from(vec![1u32, 2, 3]).into_array(),
+        ),
+    ])
+    .unwrap();
+    let written = VortexFileWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    // Appending to a byte buffer appends to the end of the existing file.
+    let existing = Buffer::from(written.clone());
+    let st = StructArray::from_fields(&[
+        (
+            "strings",
+            VarBinArray::from(vec!["baz", "qux"]).into_array(),
+        ),
+        ("numbers", PrimitiveArray::from(vec![4u32, 5]).into_array()),
+    ])
+    .unwrap();
+    let written = VortexFileWriter::append(written, existing)
+        .await
+        .unwrap()
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let written = Buffer::from(written);
+    let array = VortexReadBuilder::new(written.clone(), LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+    let numbers = array
+        .as_struct_array()
+        .unwrap()
+        .field_by_name("numbers")
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(numbers.maybe_null_slice::<u32>(), &[1, 2, 3, 4, 5]);
+
+    // Stats of the existing chunks are preserved, so pruning still works on them.
+    let filtered = VortexReadBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(BinaryExpr::new_expr(
+            Column::new_expr(Field::from("numbers")),
+            Operator::Lt,
+            Literal::new_expr(3u32.into()),
+        )))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+    assert_eq!(filtered.len(), 2);
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_dataset() {
+    let first = StructArray::from_fields(&[
+        (
+            "numbers",
+            PrimitiveArray::from(vec![1u32, 2, 3]).into_array(),
+        ),
+        (
+            "tags",
+            PrimitiveArray::from_nullable_vec(vec![Some(7u8), None, Some(9)]).into_array(),
+        ),
+    ])
+    .unwrap();
+    let second = StructArray::from_fields(&[(
+        "numbers",
+        PrimitiveArray::from(vec![10u32, 11]).into_array(),
+    )])
+    .unwrap();
+
+    let mut files = Vec::new();
+    for (path, st) in [("first.vortex", first), ("second.vortex", second)] {
+        let written = VortexFileWriter::new(Vec::new())
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+        files.push((path.to_string(), Buffer::from(written)));
+    }
+
+    let dataset = VortexDataset::try_open(files.clone(), LayoutDeserializer::default())
+        .await
+        .unwrap();
+    assert_eq!(dataset.row_count(), 5);
+    assert_eq!(
+        dataset.dtype(),
+        &DType::Struct(
+            StructDType::new(
+                ["numbers".into(), "tags".into()].into(),
+                vec![
+                    DType::Primitive(PType::U32, Nullability::NonNullable),
+                    DType::Primitive(PType::U8, Nullability::Nullable),
+                ],
+            ),
+            Nullability::NonNullable,
+        )
+    );
+
+    // The manifest survives a round trip through a Vortex file.
+    let manifest_bytes = dataset.manifest().write(Vec::new()).await.unwrap();
+    let manifest = DatasetManifest::read(Buffer::from(manifest_bytes))
+        .await
+        .unwrap();
+    assert_eq!(
+        manifest
+            .files()
+            .iter()
+            .map(|f| f.path.as_str())
+            .collect_vec(),
+        vec!["first.vortex", "second.vortex"]
+    );
+
+    let array = VortexDataset::try_new_with_manifest(
+        files.into_iter().map(|(_, read)| read).collect(),
+        manifest,
+        LayoutDeserializer::default(),
+    )
+    .unwrap()
+    .build()
+    .unwrap()
+    .read_all()
+    .await
+    .unwrap()
+    .into_struct()
+    .unwrap();
+    let tags = array.field_by_name("tags").unwrap();
+    assert_eq!(
+        (0..tags.len())
+            .map(|i| scalar_at(&tags, i).unwrap().is_null())
+            .collect_vec(),
+        vec![false, true, false, true, true]
+    );
+
+    // Files whose statistics rule out the filter are never read.
+    let dataset = dataset.with_row_filter(RowFilter::new(BinaryExpr::new_expr(
+        Column::new_expr(Field::from("numbers")),
+        Operator::Gt,
+        Literal::new_expr(5u32.into()),
+    )));
+    assert_eq!(dataset.pruned_files().unwrap(), vec![true, false]);
+
+    // Filters on columns that are missing from a file are evaluated against nulls.
+    let filtered = dataset
+        .with_row_filter(RowFilter::new(BinaryExpr::new_expr(
+            Column::new_expr(Field::from("tags")),
+            Operator::Gt,
+            Literal::new_expr(Scalar::primitive(7u8, Nullability::Nullable)),
+        )))
+        .with_projection(Projection::Flat(vec![Field::from("numbers")]))
+        .build()
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    assert_eq!(
+        filtered
+            .field_by_name("numbers")
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>(),
+        &[3]
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_hive_partitioned_dataset() {
+    let st = StructArray::from_fields(&[
+        (
+            "numbers",
+            PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        ),
+        (
+            "year",
+            PrimitiveArray::from(vec![2023i32, 2024, 2023, 2024]).into_array(),
+        ),
+        (
+            "region",
+            VarBinArray::from_iter(
+                vec![Some("eu"), Some("us"), None, Some("us")],
+                DType::Utf8(Nullability::Nullable),
+            )
+            .into_array(),
+        ),
+    ])
+    .unwrap();
+    let partitioning = HivePartitioning::try_new(StructDType::new(
+        ["year".into(), "region".into()].into(),
+        vec![
+            DType::Primitive(PType::I32, Nullability::NonNullable),
+            DType::Utf8(Nullability::Nullable),
+        ],
+    ))
+    .unwrap();
+
+    let written =
+        HivePartitionedWriter::new(partitioning.clone(), |_| async { Ok(Vec::<u8>::new()) })
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+    let files = written
+        .into_iter()
+        .map(|(dir, bytes)| (format!("table/{dir}data.vortex"), Buffer::from(bytes)))
+        .collect_vec();
+    assert_eq!(
+        files.iter().map(|(path, _)| path.as_str()).collect_vec(),
+        vec![
+            "table/year=2023/region=__HIVE_DEFAULT_PARTITION__/data.vortex",
+            "table/year=2023/region=eu/data.vortex",
+            "table/year=2024/region=us/data.vortex",
+        ]
+    );
+
+    // Only the matching directory is opened.
+    let dataset = VortexDataset::try_open_partitioned(
+        files,
+        partitioning,
+        Some(RowFilter::new(BinaryExpr::new_expr(
+            Column::new_expr(Field::from("year")),
+            Operator::Eq,
+            Literal::new_expr(2024i32.into()),
+        ))),
+        LayoutDeserializer::default(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(dataset.manifest().files().len(), 1);
+    assert_eq!(dataset.row_count(), 2);
+
+    let array = dataset
+        .build()
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    assert_eq!(
+        array.names().iter().map(|n| n.as_ref()).collect_vec(),
+        vec!["numbers", "year", "region"]
+    );
+    assert_eq!(
+        array
+            .field_by_name("numbers")
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>(),
+        &[2, 4]
+    );
+    assert_eq!(
+        scalar_at(array.field_by_name("region").unwrap(), 1).unwrap(),
+        Scalar::utf8("us", Nullability::Nullable)
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_deletion_vector() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let written = Buffer::from(
+        VortexFileWriter::new(Vec::new())
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap(),
+    );
+
+    // The deletion vector survives a round trip through its sidecar file.
+    let deletion_vector = DeletionVector::from_indices([6, 1, 4, 5, 1]);
+    let sidecar = deletion_vector.write(Vec::new()).await.unwrap();
+    let deletion_vector = DeletionVector::read(Buffer::from(sidecar)).await.unwrap();
+    assert_eq!(deletion_vector.indices(), &[1, 4, 5, 6]);
+
+    let read_numbers = |builder: VortexReadBuilder<Buffer>| async move {
+        let stream = builder.build().await.unwrap();
+        let row_count = stream.row_count();
+        let array = stream.read_all().await.unwrap();
+        let numbers = array
+            .as_struct_array()
+            .unwrap()
+            .field_by_name("numbers")
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>()
+            .to_vec();
+        (row_count, numbers)
+    };
+
+    let (row_count, numbers) = read_numbers(
+        VortexReadBuilder::new(written.clone(), LayoutDeserializer::default())
+            .with_deletion_vector(deletion_vector.clone()),
+    )
+    .await;
+    assert_eq!(row_count, 4);
+    assert_eq!(numbers, vec![1, 3, 4, 8]);
+
+    // Deleted rows are removed before the row filter is applied.
+    let (_, numbers) = read_numbers(
+        VortexReadBuilder::new(written.clone(), LayoutDeserializer::default())
+            .with_deletion_vector(deletion_vector.clone())
+            .with_row_filter(RowFilter::new(BinaryExpr::new_expr(
+                Column::new_expr(Field::from("numbers")),
+                Operator::Gt,
+                Literal::new_expr(2u32.into()),
+            ))),
+    )
+    .await;
+    assert_eq!(numbers, vec![3, 4, 8]);
+
+    // Deletions are combined with explicitly selected rows.
+    let (_, numbers) = read_numbers(
+        VortexReadBuilder::new(written.clone(), LayoutDeserializer::default())
+            .with_deletion_vector(deletion_vector)
+            .with_indices(PrimitiveArray::from(vec![0u64, 1, 2, 7]).into_array()),
+    )
+    .await;
+    assert_eq!(numbers, vec![1, 3, 8]);
+
+    // Deleting rows beyond the end of the file is an error.
+    assert!(
+        VortexReadBuilder::new(written, LayoutDeserializer::default())
+            .with_deletion_vector(DeletionVector::from_indices([8]))
+            .build()
+            .await
+            .is_err()
+    );
+}
+
+/// Read the keys matching the filter from a file whose chunks all span nearly the whole key range,
+/// so that min/max stats can't prune any of them. Every chunk but the one holding `key0005` and
+/// `key0009` is zeroed out, so reading any other chunk fails.
+async fn read_bloom_filtered_keys(filter: ExprRef) -> VortexResult<ArrayData> {
+    let keys = ChunkedArray::from_iter((0..4).map(|chunk| {
+        VarBinArray::from(
+            (0..250)
+                .map(|i| format!("key{:04}", i * 4 + chunk))
+                .collect_vec(),
+        )
+        .into_array()
+    }))
+    .into_array();
+    let st = StructArray::from_fields(&[("keys", keys)])?;
+    let written = VortexFileWriter::new(Vec::new())
+        .with_bloom_filter_columns(["keys"])
+        .write_array_columns(st.into_array())
+        .await?
+        .finalize()
+        .await?;
+
+    let initial_read =
+        read_initial_bytes(&Buffer::from(written.clone()), written.len() as u64).await?;
+    let root_layout = LayoutSpec::from(initial_read.fb_layout());
+    let mut corrupted = written;
+    for (idx, chunk) in root_layout.children()[0]
+        .children()
+        .iter()
+        .skip(1)
+        .enumerate()
+    {
+        if idx != 1 {
+            let range = chunk.buffers()[0];
+            corrupted[range.begin as usize..range.end as usize].fill(0);
+        }
+    }
+
+    VortexReadBuilder::new(Buffer::from(corrupted), LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(filter))
+        .build()
+        .await?
+        .read_all()
+        .await
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_bloom_filter_pruning() {
+    let array = read_bloom_filtered_keys(BinaryExpr::new_expr(
+        Column::new_expr(Field::from("keys")),
+        Operator::Eq,
+        Literal::new_expr(Scalar::utf8("key0005", Nullability::NonNullable)),
+    ))
+    .await
+    .unwrap();
+    assert_eq!(array.len(), 1);
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_bloom_filter_in_list_pruning() {
+    let array = read_bloom_filtered_keys(InList::new_expr(
+        Column::new_expr(Field::from("keys")),
+        vec![
+            Scalar::utf8("key0005", Nullability::Nullable),
+            Scalar::utf8("key0009", Nullability::Nullable),
+            Scalar::null(DType::Utf8(Nullability::Nullable)),
+        ],
+        false,
+    ))
+    .await
+    .unwrap();
+    assert_eq!(array.len(), 2);
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_write_options_chunk_rows() {
+    let dtype = DType::Struct(
+        StructDType::new(
+            ["numbers".into()].into(),
+            vec![DType::Primitive(PType::U32, Nullability::NonNullable)],
+        ),
+        Nullability::NonNullable,
+    );
+    let batches = [(0u32, 7u32), (7, 2), (9, 40), (49, 1)]
+        .into_iter()
+        .map(|(start, len)| {
+            StructArray::from_fields(&[(
+                "numbers",
+                PrimitiveArray::from((start..start + len).collect_vec()).into_array(),
+            )])
+            .unwrap()
+            .into_array()
+        })
+        .collect_vec();
+    let written = VortexFileWriter::new(Vec::new())
+        .with_options(WriteOptions::default().with_chunk_rows(16))
+        .write_array_columns(ChunkedArray::try_new(batches, dtype).unwrap().into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let initial_read = read_initial_bytes(&Buffer::from(written.clone()), written.len() as u64)
+        .await
+        .unwrap();
+    let root_layout = LayoutSpec::from(initial_read.fb_layout());
+    assert_eq!(
+        root_layout.children()[0]
+            .children()
+            .iter()
+            .skip(1)
+            .map(|chunk| chunk.row_count())
+            .collect_vec(),
+        vec![16, 16, 16, 2]
+    );
+
+    let array = VortexReadBuilder::new(Buffer::from(written), LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    assert_eq!(
+        array
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>(),
+        (0..50).collect_vec()
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_write_options_chunk_rows_compressed() {
+    let batches = (0..2)
+        .map(|_| {
+            StructArray::from_fields(&[("numbers", ConstantArray::new(7u32, 5).into_array())])
+                .unwrap()
+                .into_array()
+        })
+        .collect_vec();
+    let dtype = batches[0].dtype().clone();
+    let written = VortexFileWriter::new(Vec::new())
+        .with_options(WriteOptions::default().with_chunk_rows(10))
+        .write_array_columns(ChunkedArray::try_new(batches, dtype).unwrap().into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    // The batches are merged into one chunk without decompressing them.
+    let chunks = VortexReadBuilder::new(Buffer::from(written), LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(chunks.len(), 1);
+    let numbers = chunks[0].as_struct_array().unwrap().field(0).unwrap();
+    assert_eq!(numbers.len(), 10);
+    assert!(!numbers.is_canonical());
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_nested_projection() {
+    let scores = |offset: i64| {
+        StructArray::from_fields(&[
+            (
+                "a",
+                PrimitiveArray::from(vec![offset, offset + 1]).into_array(),
+            ),
+            (
+                "b",
+                PrimitiveArray::from(vec![offset * 10, offset * 10 + 10]).into_array(),
+            ),
+        ])
+        .unwrap()
+        .into_array()
+    };
+    let info = ChunkedArray::from_iter([0, 2].map(|offset| {
+        StructArray::from_fields(&[
+            (
+                "name",
+                VarBinArray::from(vec![format!("name{offset}"), format!("name{}", offset + 1)])
+                    .into_array(),
+            ),
+            ("scores", scores(offset)),
+        ])
+        .unwrap()
+        .into_array()
+    }))
+    .into_array();
+    let ids = PrimitiveArray::from(vec![0u32, 1, 2, 3]).into_array();
+    let st = StructArray::from_fields(&[("id", ids), ("info", info)]).unwrap();
+
+    let written = VortexFileWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    // info is laid out as a nested columnar layout. Zero out the data of its name and scores.a
+    // columns, so that reading them fails.
+    let initial_read = read_initial_bytes(&Buffer::from(written.clone()), written.len() as u64)
+        .await
+        .unwrap();
+    let root_layout = LayoutSpec::from(initial_read.fb_layout());
+    let info_layout = &root_layout.children()[1];
+    assert_eq!(info_layout.id(), crate::COLUMNAR_LAYOUT_ID);
+    let mut corrupted = written.clone();
+    for leaf in [
+        &info_layout.children()[0],
+        &info_layout.children()[1].children()[0],
+    ] {
+        for chunk in leaf.children().iter().skip(1) {
+            let range = chunk.buffers()[0];
+            corrupted[range.begin as usize..range.end as usize].fill(0);
+        }
+    }
+
+    let path = |fields: &[&str]| {
+        fields
+            .iter()
+            .map(|f| Field::from(*f))
+            .collect::<FieldPath>()
+    };
+    let array = VortexReadBuilder::new(Buffer::from(corrupted), LayoutDeserializer::default())
+        .with_projection(Projection::Nested(vec![
+            path(&["info", "scores", "b"]),
+            path(&["id"]),
+        ]))
+        .with_row_filter(RowFilter::new(BinaryExpr::new_expr(
+            Column::new_expr(Field::from("id")),
+            Operator::Gt,
+            Literal::new_expr(Scalar::from(0u32)),
+        )))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+
+    let i64_dtype = DType::Primitive(PType::I64, Nullability::NonNullable);
+    let nested_dtype = |name: &str, dtype: DType| {
+        DType::Struct(
+            StructDType::new([name.into()].into(), vec![dtype]),
+            Nullability::NonNullable,
+        )
+    };
+    assert_eq!(
+        array.dtype(),
+        &DType::Struct(
+            StructDType::new(
+                ["info".into(), "id".into()].into(),
+                vec![
+                    nested_dtype("scores", nested_dtype("b", i64_dtype)),
+                    DType::Primitive(PType::U32, Nullability::NonNullable),
+                ]
+            ),
+            Nullability::NonNullable,
+        )
+    );
+
+    let b = array
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(b.maybe_null_slice::<i64>(), &[10, 20, 30]);
+
+    // Reading the whole struct still works.
+    let array = VortexReadBuilder::new(Buffer::from(written), LayoutDeserializer::default())
+        .with_projection(Projection::Flat(vec![Field::from("info")]))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    let names = array
+        .field_by_name("name")
+        .unwrap()
+        .into_varbinview()
+        .unwrap()
+        .with_iterator(|iter| {
+            iter.map(|name| String::from_utf8(name.unwrap().to_vec()).unwrap())
+                .collect_vec()
+        })
+        .unwrap();
+    assert_eq!(names, ["name0", "name1", "name2", "name3"]);
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_page_index_sparse_take() {
+    let st = StructArray::from_fields(&[(
+        "numbers",
+        PrimitiveArray::from((0u32..200).collect_vec()).into_array(),
+    )])
+    .unwrap();
+
+    let written = VortexFileWriter::new(Vec::new())
+        .with_options(
+            WriteOptions::default()
+                .with_chunk_rows(100)
+                .with_page_rows(16),
+        )
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    // Each chunk is split into 7 pages. Zero out all pages but the ones holding the rows we take,
+    // so that reading any other page fails.
+    let initial_read = read_initial_bytes(&Buffer::from(written.clone()), written.len() as u64)
+        .await
+        .unwrap();
+    let root_layout = LayoutSpec::from(initial_read.fb_layout());
+    let chunks = &root_layout.children()[0].children()[1..];
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks.iter().all(|chunk| chunk.buffers().len() == 7));
+    let kept_pages = [(0, 0), (0, 2), (1, 3)];
+    let mut corrupted = written.clone();
+    for (chunk_idx, chunk) in chunks.iter().enumerate() {
+        for (page_idx, range) in chunk.buffers().iter().enumerate() {
+            if !kept_pages.contains(&(chunk_idx, page_idx)) {
+                corrupted[range.begin as usize..range.end as usize].fill(0);
+            }
+        }
+    }
+
+    let taken = VortexReadBuilder::new(Buffer::from(corrupted), LayoutDeserializer::default())
+        .with_indices(ArrayData::from(vec![3u32, 40, 41, 150]))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(taken.maybe_null_slice::<u32>(), &[3, 40, 41, 150]);
+
+    // Filters spanning many pages read all of them.
+    let filtered = VortexReadBuilder::new(Buffer::from(written), LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(BinaryExpr::new_expr(
+            Column::new_expr(Field::from("numbers")),
+            Operator::Gte,
+            Literal::new_expr(Scalar::from(90u32)),
+        )))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(
+        filtered.maybe_null_slice::<u32>(),
+        (90u32..200).collect_vec()
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_verify() {
+    let st = StructArray::from_fields(&[
+        (
+            "numbers",
+            PrimitiveArray::from((0u32..100).collect_vec()).into_array(),
+        ),
+        (
+            "strings",
+            VarBinArray::from((0..100).map(|i| format!("s{i}")).collect_vec()).into_array(),
+        ),
+    ])
+    .unwrap();
+    let written = VortexFileWriter::new(Vec::new())
+        .with_bloom_filter_columns(["strings"])
+        .with_options(
+            WriteOptions::default()
+                .with_chunk_rows(40)
+                .with_page_rows(16),
+        )
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let report = crate::verify(Buffer::from(written.clone())).await.unwrap();
+    assert!(report.is_ok(), "{:?}", report.issues);
+    assert_eq!(report.row_count, Some(100));
+    // 2 columns of 3 chunks with 3, 3 and 2 pages each, and a stats table per column
+    assert_eq!(report.messages_checked, 18);
+
+    // Truncated files fail verification of the footer.
+    let report = crate::verify(Buffer::from(written[..written.len() - 3].to_vec()))
+        .await
+        .unwrap();
+    assert_eq!(report.row_count, None);
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(report.issues[0].kind, crate::VerificationIssueKind::Footer);
+
+    let initial_read = read_initial_bytes(&Buffer::from(written.clone()), written.len() as u64)
+        .await
+        .unwrap();
+    let root_layout = LayoutSpec::from(initial_read.fb_layout());
+    let numbers = &root_layout.children()[0];
+    let strings = &root_layout.children()[1];
+
+    // All corrupted messages are reported, not just the first one.
+    let mut corrupted = written.clone();
+    for chunk in [&numbers.children()[1], &strings.children()[3]] {
+        let range = chunk.buffers()[0];
+        corrupted[range.begin as usize..range.end as usize].fill(0);
+    }
+    let report = crate::verify(Buffer::from(corrupted)).await.unwrap();
+    assert_eq!(
+        report
+            .issues
+            .iter()
+            .map(|issue| (issue.kind, issue.layout_path.clone()))
+            .collect_vec(),
+        vec![
+            (crate::VerificationIssueKind::Message, vec![0, 1]),
+            (crate::VerificationIssueKind::Message, vec![1, 3]),
+        ]
+    );
+
+    // Changing the last value of the first page of numbers breaks the stored stats of its chunk.
+    let mut corrupted = written;
+    let range = numbers.children()[1].buffers()[0];
+    corrupted[range.end as usize - 4..range.end as usize].copy_from_slice(&1000u32.to_le_bytes());
+    let report = crate::verify(Buffer::from(corrupted)).await.unwrap();
+    assert!(!report.issues.is_empty());
+    assert!(report.issues.iter().all(|issue| {
+        issue.kind == crate::VerificationIssueKind::Stats && issue.layout_path == vec![0, 1]
+    }));
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_checksums() {
+    let st = StructArray::from_fields(&[(
+        "numbers",
+        PrimitiveArray::from((0u32..100).collect_vec()).into_array(),
+    )])
+    .unwrap();
+    let written = VortexFileWriter::new(Vec::new())
+        .with_options(
+            WriteOptions::default()
+                .with_chunk_rows(48)
+                .with_checksums(true),
+        )
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let initial_read = read_initial_bytes(&Buffer::from(written.clone()), written.len() as u64)
+        .await
+        .unwrap();
+    let root_layout = LayoutSpec::from(initial_read.fb_layout());
+    let chunks = root_layout.children()[0].children();
+    assert!(chunks
+        .iter()
+        .all(|chunk| chunk.checksums().map(<[u64]>::len) == Some(1)));
+
+    // Change the last value of the first chunk, whose buffer isn't padded, which leaves the
+    // message decodable.
+    let mut corrupted = written.clone();
+    let range = chunks[1].buffers()[0];
+    corrupted[range.end as usize - 4..range.end as usize].copy_from_slice(&1000u32.to_le_bytes());
+
+    async fn read_numbers(bytes: Vec<u8>, verify: bool) -> VortexResult<Vec<u32>> {
+        let array = VortexReadBuilder::new(Buffer::from(bytes), LayoutDeserializer::default())
+            .with_checksum_verification(verify)
+            .build()
+            .await?
+            .read_all()
+            .await?;
+        Ok(array
+            .into_struct()?
+            .field(0)
+            .unwrap()
+            .into_primitive()?
+            .maybe_null_slice::<u32>()
+            .to_vec())
+    }
+
+    assert_eq!(
+        read_numbers(written.clone(), true).await.unwrap(),
+        (0u32..100).collect_vec()
+    );
+    let err = read_numbers(corrupted.clone(), true).await.unwrap_err();
+    assert!(err.to_string().contains("Checksum mismatch"), "{err}");
+    // Trusted reads skip verification and see the changed data.
+    assert_eq!(
+        read_numbers(corrupted.clone(), false).await.unwrap()[47],
+        1000
+    );
+
+    assert!(crate::verify(Buffer::from(written)).await.unwrap().is_ok());
+    let report = crate::verify(Buffer::from(corrupted)).await.unwrap();
+    assert_eq!(
+        report
+            .issues
+            .iter()
+            .map(|issue| (issue.kind, issue.layout_path.clone()))
+            .collect_vec(),
+        vec![(crate::VerificationIssueKind::Checksum, vec![0, 1])]
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_encrypted_columns() {
+    #[derive(Debug)]
+    struct TestKeys;
+
+    impl KeyProvider for TestKeys {
+        fn column_key(&self, column: &FieldName) -> VortexResult<(String, EncryptionKey)> {
+            Ok((format!("key-{column}"), EncryptionKey::new([7; 32])))
+        }
+    }
+
+    let strings = (0..100).map(|i| format!("secret-{i}")).collect_vec();
+    let st = StructArray::from_fields(&[
+        (
+            "numbers",
+            PrimitiveArray::from((0u32..100).collect_vec()).into_array(),
+        ),
+        (
+            "strings",
+            VarBinArray::from(strings.iter().map(String::as_str).collect_vec()).into_array(),
+        ),
+    ])
+    .unwrap();
+    let written = VortexFileWriter::new(Vec::new())
+        .with_options(WriteOptions::default().with_chunk_rows(40))
+        .with_encrypted_columns(Arc::new(TestKeys), ["strings"])
+        .write_array_columns(st.clone().into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    // Neither the values nor their min/max stats are stored in plaintext.
+    assert!(!written
+        .windows("secret-".len())
+        .any(|window| window == b"secret-"));
+
+    let initial_read = read_initial_bytes(&Buffer::from(written.clone()), written.len() as u64)
+        .await
+        .unwrap();
+    let root_layout = LayoutSpec::from(initial_read.fb_layout());
+    assert!(root_layout.children()[0]
+        .children()
+        .iter()
+        .all(|layout| layout.key_id().is_none()));
+    assert!(root_layout.children()[1]
+        .children()
+        .iter()
+        .all(|layout| layout.key_id() == Some("key-strings")));
+
+    async fn read(
+        bytes: Vec<u8>,
+        projection: Projection,
+        keys: Option<HashMap<String, EncryptionKey>>,
+    ) -> VortexResult<StructArray> {
+        let mut builder =
+            VortexReadBuilder::new(Buffer::from(bytes), LayoutDeserializer::default())
+                .with_projection(projection);
+        if let Some(keys) = keys {
+            builder = builder.with_key_resolver(Arc::new(keys));
+        }
+        builder.build().await?.read_all().await?.into_struct()
+    }
+    let keys = |key: [u8; 32]| {
+        Some(HashMap::from_iter([(
+            "key-strings".to_string(),
+            EncryptionKey::new(key),
+        )]))
+    };
+
+    let array = read(written.clone(), Projection::All, keys([7; 32]))
+        .await
+        .unwrap();
+    let read_strings = array
+        .field(1)
+        .unwrap()
+        .into_varbinview()
+        .unwrap()
+        .with_iterator(|iter| {
+            iter.map(|s| String::from_utf8(s.unwrap().to_vec()).unwrap())
+                .collect_vec()
+        })
+        .unwrap();
+    assert_eq!(read_strings, strings);
+
+    // Projections without the encrypted column don't need any keys.
+    let numbers = read(written.clone(), Projection::new([0]), None)
+        .await
+        .unwrap();
+    assert_eq!(
+        numbers
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>(),
+        (0u32..100).collect_vec()
+    );
+
+    let err = read(written.clone(), Projection::All, None)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("no key resolver"), "{err}");
+    read(written.clone(), Projection::All, keys([8; 32]))
+        .await
+        .unwrap_err();
+
+    assert!(crate::verify(Buffer::from(written)).await.unwrap().is_ok());
+
+    // Encrypted columns must name top-level columns of a struct array.
+    let err = VortexFileWriter::new(Vec::new())
+        .with_encrypted_columns(Arc::new(TestKeys), ["strings", "missing"])
+        .write_array_columns(st.into_array())
+        .await
+        .err()
+        .unwrap();
+    assert!(
+        err.to_string()
+            .contains("Encrypted column missing is not a top-level column"),
+        "{err}"
+    );
+    let err = VortexFileWriter::new(Vec::new())
+        .with_encrypted_columns(Arc::new(TestKeys), ["strings"])
+        .write_array_columns(PrimitiveArray::from((0u32..100).collect_vec()).into_array())
+        .await
+        .err()
+        .unwrap();
+    assert!(
+        err.to_string()
+            .contains("Encrypted columns require a struct array"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_fixed_size_list() {
+    let embeddings = FixedSizeListArray::try_new(
+        PrimitiveArray::from((0..40).map(|i| i as f32).collect_vec()).into_array(),
+        4,
+        10,
+        Validity::from_iter((0..10).map(|i| i != 3)),
+    )
+    .unwrap()
+    .into_array();
+    let st = StructArray::from_fields(&[("embeddings", embeddings.clone())]).unwrap();
+
+    let writer = VortexFileWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap();
+    let written = Buffer::from(writer.finalize().await.unwrap());
+
+    let array = VortexReadBuilder::new(written, LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap();
+    assert_eq!(array.dtype(), embeddings.dtype());
+    for i in [0, 3, 9] {
+        assert_eq!(
+            scalar_at(&array, i).unwrap(),
+            scalar_at(&embeddings, i).unwrap()
+        );
+    }
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_shredded_variant_pruning() {
+    let rows = |chunk: i64| {
+        (0..100)
+            .map(|i| {
+                let status = 100 * chunk + i;
+                if i % 10 == 0 {
+                    format!(r#"{{"msg":"m{status}","retry":true,"status":{status}}}"#)
+                } else {
+                    format!(r#"{{"msg":"m{status}","status":{status}}}"#)
+                }
+            })
+            .collect_vec()
+    };
+    let logs = ChunkedArray::from_iter((0..4).map(|chunk| {
+        ArrayData::from(
+            VariantArray::from_json(
+                rows(chunk).iter().map(|row| Some(row.as_str())),
+                Nullability::NonNullable,
+                0.5,
+            )
+            .unwrap(),
+        )
+    }))
+    .into_array();
+    let st = StructArray::from_fields(&[("logs", logs)]).unwrap();
+    let written = VortexFileWriter::new(Vec::new())
+        .with_shredded_variant_columns(["logs"])
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+    assert!(crate::verify(Buffer::from(written.clone()))
+        .await
+        .unwrap()
+        .is_ok());
+
+    let read_json = |bytes: Vec<u8>, filter: Option<RowFilter>| async move {
+        let mut builder =
+            VortexReadBuilder::new(Buffer::from(bytes), LayoutDeserializer::default());
+        if let Some(filter) = filter {
+            builder = builder.with_row_filter(filter);
+        }
+        let array = builder
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .unwrap()
+            .into_struct()
+            .unwrap()
+            .field(0)
+            .unwrap();
+        VariantArray::try_from(array.into_extension().unwrap())
+            .unwrap()
+            .to_json()
+            .unwrap()
+            .into_iter()
+            .map(|row| row.unwrap())
+            .collect_vec()
+    };
+    let status = || {
+        VariantGet::new_expr(
+            Column::new_expr(Field::from("logs")),
+            "status",
+            DType::Primitive(PType::I64, Nullability::NonNullable),
+        )
+    };
+
+    assert_eq!(
+        read_json(written.clone(), None).await,
+        (0..4).flat_map(rows).collect_vec()
+    );
+
+    // Filters on paths in the residual are evaluated on the reassembled variants.
+    let retries = read_json(
+        written.clone(),
+        Some(RowFilter::new(VariantGet::new_expr(
+            Column::new_expr(Field::from("logs")),
+            "retry",
+            DType::Bool(Nullability::NonNullable),
+        ))),
+    )
+    .await;
+    assert_eq!(retries.len(), 40);
+
+    // The shredded `status` path has its own column, whose statistics let the reader skip every
+    // chunk but the one holding the matching rows.
+    let initial_read = read_initial_bytes(&Buffer::from(written.clone()), written.len() as u64)
+        .await
+        .unwrap();
+    let root_layout = LayoutSpec::from(initial_read.fb_layout());
+    let shredded_layout = &root_layout.children()[0].children()[1];
+    let mut corrupted = written;
+    for (idx, chunk) in shredded_layout.children()[1]
+        .children()
+        .iter()
+        .skip(1)
+        .enumerate()
+    {
+        if idx != 3 {
+            let range = chunk.buffers()[0];
+            corrupted[range.begin as usize..range.end as usize].fill(0);
+        }
+    }
+
+    let filtered = read_json(
+        corrupted,
+        Some(RowFilter::new(BinaryExpr::new_expr(
+            status(),
+            Operator::Gte,
+            Literal::new_expr(350i64.into()),
+        ))),
+    )
+    .await;
+    assert_eq!(filtered, rows(3)[50..].to_vec());
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_schema_evolution() {
+    let st = StructArray::from_fields(&[
+        ("id", PrimitiveArray::from(vec![1i32, 2, 3]).into_array()),
+        ("name", VarBinArray::from(vec!["a", "b", "c"]).into_array()),
+        (
+            "score",
+            PrimitiveArray::from(vec![0.5f32, 1.5, 2.5]).into_array(),
+        ),
+    ])
+    .unwrap();
+    let written = Buffer::from(
+        VortexFileWriter::new(Vec::new())
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap(),
+    );
+
+    // Reordered, widened, dropped and added columns.
+    let schema = DType::Struct(
+        StructDType::new(
+            ["score".into(), "id".into(), "added".into()].into(),
+            vec![
+                DType::Primitive(PType::F64, Nullability::NonNullable),
+                DType::Primitive(PType::I64, Nullability::Nullable),
+                DType::Utf8(Nullability::Nullable),
+            ],
+        ),
+        Nullability::NonNullable,
+    );
+    let stream = VortexReadBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_schema(schema.clone())
+        .with_row_filter(RowFilter::new(BinaryExpr::new_expr(
+            Column::new_expr(Field::from("id")),
+            Operator::Gt,
+            Literal::new_expr(Scalar::primitive(1i64, Nullability::Nullable)),
+        )))
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(stream.dtype(), &schema);
+
+    let array = stream.read_all().await.unwrap().into_struct().unwrap();
+    assert_eq!(array.dtype(), &schema);
+    assert_eq!(
+        array
+            .field_by_name("score")
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<f64>(),
+        &[1.5, 2.5]
+    );
+    assert_eq!(
+        array
+            .field_by_name("id")
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<i64>(),
+        &[2, 3]
+    );
+    let added = array.field_by_name("added").unwrap();
+    assert!((0..added.len()).all(|i| scalar_at(&added, i).unwrap().is_null()));
+
+    // Projections refer to the schema, and filters on unchanged columns are pushed down.
+    let names = VortexReadBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_schema(DType::Struct(
+            StructDType::new(
+                ["name".into(), "added".into()].into(),
+                vec![
+                    DType::Utf8(Nullability::NonNullable),
+                    DType::Utf8(Nullability::Nullable),
+                ],
+            ),
+            Nullability::NonNullable,
+        ))
+        .with_projection(Projection::Flat(vec![Field::from(1)]))
+        .with_row_filter(RowFilter::new(BinaryExpr::new_expr(
+            Column::new_expr(Field::from("name")),
+            Operator::Eq,
+            Literal::new_expr("b".into()),
+        )))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    assert_eq!(names.len(), 1);
+    assert_eq!(names.names().as_ref(), &["added".into()]);
+
+    // Narrowing and missing non-nullable columns are rejected.
+    for (name, dtype) in [
+        ("id", DType::Primitive(PType::I16, Nullability::NonNullable)),
+        ("missing", DType::Utf8(Nullability::NonNullable)),
+    ] {
+        let result = VortexReadBuilder::new(written.clone(), LayoutDeserializer::default())
+            .with_schema(DType::Struct(
+                StructDType::new([name.into()].into(), vec![dtype]),
+                Nullability::NonNullable,
+            ))
+            .build()
+            .await;
+        assert!(result.is_err());
+    }
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_field_id_evolution() {
+    let st = StructArray::from_fields(&[
+        ("id", PrimitiveArray::from(vec![1i32, 2, 3]).into_array()),
+        ("name", VarBinArray::from(vec!["a", "b", "c"]).into_array()),
+    ])
+    .unwrap()
+    .with_field_ids(vec![1, 2])
+    .unwrap();
+    let written = Buffer::from(
+        VortexFileWriter::new(Vec::new())
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap(),
+    );
+
+    // Projections may select fields by ID.
+    let names = VortexReadBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_projection(Projection::Flat(vec![Field::Id(2)]))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    assert_eq!(names.names().as_ref(), &["name".into()]);
+
+    // "name" was renamed to "label", and a column named "id" was dropped and then re-added with a
+    // new ID, so it must not be read from the file's "id" column.
+    let schema = DType::Struct(
+        StructDType::new(
+            ["label".into(), "id".into()].into(),
+            vec![
+                DType::Utf8(Nullability::NonNullable),
+                DType::Primitive(PType::I64, Nullability::Nullable),
+            ],
+        )
+        .with_field_ids(vec![2, 3]),
+        Nullability::NonNullable,
+    );
+    let array = VortexReadBuilder::new(written, LayoutDeserializer::default())
+        .with_schema(schema.clone())
+        .with_row_filter(RowFilter::new(BinaryExpr::new_expr(
+            Column::new_expr(Field::from("label")),
+            Operator::NotEq,
+            Literal::new_expr("b".into()),
+        )))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    assert_eq!(array.dtype(), &schema);
+    assert_eq!(
+        array
+            .field_by_name("label")
+            .unwrap()
+            .into_varbinview()
+            .unwrap()
+            .with_iterator(|iter| iter
+                .map(|s| s.map(|s| String::from_utf8(s.to_vec()).unwrap()))
+                .collect_vec())
+            .unwrap(),
+        vec![Some("a".to_string()), Some("c".to_string())]
+    );
+    let ids = array.field_by_name("id").unwrap();
+    assert!((0..ids.len()).all(|i| scalar_at(&ids, i).unwrap().is_null()));
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_dataset_zero_fields() {
+    let numbers = StructArray::from_fields(&[(
+        "numbers",
+        PrimitiveArray::from_nullable_vec(vec![Some(1u32), Some(2)]).into_array(),
+    )])
+    .unwrap();
+    let empty = StructArray::try_new([].into(), vec![], 3, Validity::NonNullable).unwrap();
+
+    let mut files = Vec::new();
+    for (path, st) in [("numbers.vortex", numbers), ("empty.vortex", empty)] {
+        let written = VortexFileWriter::new(Vec::new())
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+        files.push((path.to_string(), Buffer::from(written)));
+    }
+
+    let dataset = VortexDataset::try_open(files, LayoutDeserializer::default())
+        .await
+        .unwrap();
+    assert_eq!(dataset.row_count(), 5);
+
+    // The rows of the file without columns are read as nulls.
+    let array = dataset
+        .build()
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    let numbers = array.field_by_name("numbers").unwrap();
+    assert_eq!(
+        (0..numbers.len())
+            .map(|i| scalar_at(&numbers, i).unwrap().is_null())
+            .collect_vec(),
+        vec![false, false, true, true, true]
+    );
+}
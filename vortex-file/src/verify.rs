@@ -0,0 +1,667 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use bytes::{Buf, Bytes};
+use flatbuffers::root;
+use itertools::Itertools;
+use vortex_array::array::{ChunkedArray, StructArray};
+use vortex_array::compute::scalar_at;
+use vortex_array::stats::{ArrayStatistics, Stat};
+use vortex_array::variants::StructArrayTrait;
+use vortex_array::{ArrayData, Context, IntoArrayData, IntoCanonical};
+use vortex_buffer::Buffer;
+use vortex_dtype::{is_variant_ext_type, DType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_flatbuffers::message;
+use vortex_io::VortexReadAt;
+use vortex_ipc::messages::checksum;
+use vortex_ipc::messages::reader::ArrayMessageReader;
+use vortex_scalar::{BinaryScalar, Scalar};
+
+use crate::layouts::{ChunkedMetadata, PageIndex, BLOOM_FILTER_COLUMN};
+use crate::{
+    read_initial_bytes, BloomFilter, LayoutSpec, CHUNKED_LAYOUT_ID, COLUMNAR_LAYOUT_ID,
+    DEFAULT_BLOOM_FILTER_FPP, FLAT_LAYOUT_ID,
+};
+
+/// The size of the length prefix of IPC messages.
+const MESSAGE_PREFIX_LENGTH: usize = 4;
+
+/// The kind of problem found while verifying a Vortex file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VerificationIssueKind {
+    /// The end of file marker, postscript, schema or layout flatbuffers are malformed.
+    Footer,
+    /// A layout is malformed, e.g. it has an unknown id or the wrong number of buffers or children.
+    Layout,
+    /// A buffer of a layout lies outside the data section of the file.
+    ByteRange,
+    /// A message can't be read, or doesn't decode to an array of the expected dtype.
+    Message,
+    /// The row count of a layout doesn't match its children or its data.
+    RowCount,
+    /// A stored statistic or bloom filter doesn't match the one recomputed from the data.
+    Stats,
+    /// A buffer doesn't match the checksum recorded for it in its layout.
+    Checksum,
+}
+
+impl Display for VerificationIssueKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Footer => "footer",
+            Self::Layout => "layout",
+            Self::ByteRange => "byte range",
+            Self::Message => "message",
+            Self::RowCount => "row count",
+            Self::Stats => "stats",
+            Self::Checksum => "checksum",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A single problem found while verifying a Vortex file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationIssue {
+    /// The path to the layout with the problem, as child indices starting from the root layout.
+    pub layout_path: Vec<usize>,
+    pub kind: VerificationIssueKind,
+    pub message: String,
+}
+
+impl Display for VerificationIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} issue in layout [{}]: {}",
+            self.kind,
+            self.layout_path.iter().format(", "),
+            self.message
+        )
+    }
+}
+
+/// The outcome of [`verify`]ing a Vortex file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerificationReport {
+    pub file_size: u64,
+    /// The row count recorded in the footer, if the footer could be read.
+    pub row_count: Option<u64>,
+    pub layouts_checked: usize,
+    pub messages_checked: usize,
+    pub issues: Vec<VerificationIssue>,
+}
+
+impl VerificationReport {
+    /// Whether the file passed verification without any issues.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Check the integrity of a Vortex file.
+///
+/// Verification checks the end of file marker and footer, walks every layout in the footer,
+/// confirms that all buffers lie within the data section of the file, decodes every message and
+/// cross-checks the row counts and stored statistics of every chunk against those recomputed from
+/// its data. Encrypted messages are not decoded, only their byte ranges and checksums are checked.
+///
+/// Problems with the file are collected into the returned report instead of failing at the first
+/// one. Errors are only returned if the size of the file can't be determined.
+pub async fn verify<R: VortexReadAt>(read: R) -> VortexResult<VerificationReport> {
+    verify_with_context(read, Arc::new(Context::default())).await
+}
+
+/// [`verify`] a Vortex file whose arrays use encodings registered in the given context.
+pub async fn verify_with_context<R: VortexReadAt>(
+    read: R,
+    ctx: Arc<Context>,
+) -> VortexResult<VerificationReport> {
+    let file_size = read.size().await?;
+    let mut verifier = Verifier {
+        read,
+        ctx,
+        data_end: 0,
+        report: VerificationReport {
+            file_size,
+            ..Default::default()
+        },
+    };
+    verifier.verify_file().await;
+    Ok(verifier.report)
+}
+
+/// A layout that has yet to be verified.
+struct PendingLayout {
+    path: Vec<usize>,
+    layout: LayoutSpec,
+    dtype: DType,
+    row_count: u64,
+}
+
+struct Verifier<R> {
+    read: R,
+    ctx: Arc<Context>,
+    /// The end of the data section of the file, where the schema starts.
+    data_end: u64,
+    report: VerificationReport,
+}
+
+impl<R: VortexReadAt> Verifier<R> {
+    fn issue(&mut self, path: &[usize], kind: VerificationIssueKind, message: impl Into<String>) {
+        self.report.issues.push(VerificationIssue {
+            layout_path: path.to_vec(),
+            kind,
+            message: message.into(),
+        });
+    }
+
+    async fn verify_file(&mut self) {
+        let initial_read = match read_initial_bytes(&self.read, self.report.file_size).await {
+            Ok(initial_read) => initial_read,
+            Err(e) => return self.issue(&[], VerificationIssueKind::Footer, e.to_string()),
+        };
+        self.data_end = initial_read.fb_postscript().schema_offset();
+        let dtype = match initial_read.lazy_dtype().value() {
+            Ok(dtype) => dtype.clone(),
+            Err(e) => {
+                return self.issue(
+                    &[],
+                    VerificationIssueKind::Footer,
+                    format!("Invalid schema: {e}"),
+                )
+            }
+        };
+
+        let root = LayoutSpec::from(initial_read.fb_layout());
+        self.report.row_count = Some(root.row_count());
+        let mut pending = VecDeque::from([PendingLayout {
+            path: Vec::new(),
+            row_count: root.row_count(),
+            layout: root,
+            dtype,
+        }]);
+        while let Some(layout) = pending.pop_front() {
+            self.check_row_count(&layout.path, &layout.layout, layout.row_count);
+            match layout.layout.id() {
+                FLAT_LAYOUT_ID => {
+                    self.verify_flat(&layout.path, &layout.layout, &layout.dtype)
+                        .await;
+                }
+                CHUNKED_LAYOUT_ID => self.verify_chunked(layout, &mut pending).await,
+                COLUMNAR_LAYOUT_ID => self.verify_columnar(layout, &mut pending),
+                id => self.issue(
+                    &layout.path,
+                    VerificationIssueKind::Layout,
+                    format!("Unknown layout id {}", id.0),
+                ),
+            }
+        }
+    }
+
+    fn check_row_count(&mut self, path: &[usize], layout: &LayoutSpec, expected: u64) {
+        self.report.layouts_checked += 1;
+        if layout.row_count() != expected {
+            self.issue(
+                path,
+                VerificationIssueKind::RowCount,
+                format!(
+                    "Layout has {} rows, expected {expected}",
+                    layout.row_count()
+                ),
+            );
+        }
+    }
+
+    fn verify_columnar(&mut self, layout: PendingLayout, pending: &mut VecDeque<PendingLayout>) {
+        // Shredded variant columns are laid out as their storage struct.
+        let dtype = match &layout.dtype {
+            DType::Extension(ext_dtype) if is_variant_ext_type(ext_dtype.id()) => {
+                ext_dtype.storage_dtype()
+            }
+            dtype => dtype,
+        };
+        let DType::Struct(st, _) = dtype else {
+            return self.issue(
+                &layout.path,
+                VerificationIssueKind::Layout,
+                format!("Columnar layout of non-struct dtype {}", layout.dtype),
+            );
+        };
+        let children = layout.layout.children();
+        if children.len() != st.dtypes().len() {
+            return self.issue(
+                &layout.path,
+                VerificationIssueKind::Layout,
+                format!(
+                    "Columnar layout has {} children for {} fields",
+                    children.len(),
+                    st.dtypes().len()
+                ),
+            );
+        }
+
+        for (idx, (child, dtype)) in children.iter().zip(st.dtypes().iter()).enumerate() {
+            pending.push_back(PendingLayout {
+                path: child_path(&layout.path, idx),
+                layout: child.clone(),
+                dtype: dtype.clone(),
+                row_count: layout.layout.row_count(),
+            });
+        }
+    }
+
+    async fn verify_chunked(
+        &mut self,
+        layout: PendingLayout,
+        pending: &mut VecDeque<PendingLayout>,
+    ) {
+        let children = layout.layout.children();
+        let (metadata, data_chunks, first_chunk_idx) = match layout.layout.metadata() {
+            Some(metadata_bytes) => {
+                let Some((stats_layout, data_chunks)) = children.split_first() else {
+                    return self.issue(
+                        &layout.path,
+                        VerificationIssueKind::Layout,
+                        "Chunked layout with metadata must have children",
+                    );
+                };
+                let metadata = ChunkedMetadata::parse(metadata_bytes);
+                let stats_table = self
+                    .verify_stats_table(&layout, &metadata, stats_layout, data_chunks.len())
+                    .await;
+                (stats_table.map(|t| (metadata, t)), data_chunks, 1)
+            }
+            None => (None, children, 0),
+        };
+
+        let rows = data_chunks.iter().map(LayoutSpec::row_count).sum::<u64>();
+        if rows != layout.layout.row_count() {
+            self.issue(
+                &layout.path,
+                VerificationIssueKind::RowCount,
+                format!(
+                    "Chunks hold {rows} rows, but the layout has {} rows",
+                    layout.layout.row_count()
+                ),
+            );
+        }
+
+        for (chunk_idx, chunk) in data_chunks.iter().enumerate() {
+            let path = child_path(&layout.path, first_chunk_idx + chunk_idx);
+            if chunk.id() != FLAT_LAYOUT_ID {
+                pending.push_back(PendingLayout {
+                    path,
+                    layout: chunk.clone(),
+                    dtype: layout.dtype.clone(),
+                    row_count: chunk.row_count(),
+                });
+                continue;
+            }
+
+            self.check_row_count(&path, chunk, chunk.row_count());
+            let Some(array) = self.verify_flat(&path, chunk, &layout.dtype).await else {
+                continue;
+            };
+            if let Some((metadata, stats_table)) = metadata.as_ref() {
+                if let Err(e) =
+                    self.verify_chunk_stats(&path, metadata, stats_table, chunk_idx, &array)
+                {
+                    self.issue(
+                        &path,
+                        VerificationIssueKind::Stats,
+                        format!("Failed to check stats: {e}"),
+                    );
+                }
+            }
+        }
+    }
+
+    async fn verify_stats_table(
+        &mut self,
+        layout: &PendingLayout,
+        metadata: &ChunkedMetadata,
+        stats_layout: &LayoutSpec,
+        n_chunks: usize,
+    ) -> Option<StructArray> {
+        let path = child_path(&layout.path, 0);
+        self.check_row_count(&path, stats_layout, n_chunks as u64);
+        if stats_layout.id() != FLAT_LAYOUT_ID {
+            self.issue(
+                &path,
+                VerificationIssueKind::Layout,
+                "Stats table must have a flat layout",
+            );
+            return None;
+        }
+        let table = self
+            .verify_flat(&path, stats_layout, &metadata.table_dtype(&layout.dtype))
+            .await?;
+        StructArray::try_from(table).ok()
+    }
+
+    /// Compare the stats of a chunk stored in the stats table with those of its data.
+    fn verify_chunk_stats(
+        &mut self,
+        path: &[usize],
+        metadata: &ChunkedMetadata,
+        stats_table: &StructArray,
+        chunk_idx: usize,
+        chunk: &ArrayData,
+    ) -> VortexResult<()> {
+        for stat in &metadata.stats {
+            let values = stats_table
+                .field_by_name(stat.name())
+                .ok_or_else(|| vortex_err!("Stats table is missing {stat}"))?;
+            let stored = scalar_at(&values, chunk_idx)?;
+            if let Some(message) = stat_mismatch(*stat, &stored, chunk)? {
+                self.issue(
+                    path,
+                    VerificationIssueKind::Stats,
+                    format!("Stats table {message}"),
+                );
+            }
+        }
+
+        if metadata.has_bloom_filter {
+            let filters = stats_table
+                .field_by_name(BLOOM_FILTER_COLUMN)
+                .ok_or_else(|| vortex_err!("Stats table is missing {BLOOM_FILTER_COLUMN}"))?;
+            let stored = scalar_at(&filters, chunk_idx)?;
+            let stored = BinaryScalar::try_from(&stored)?.value();
+            let expected = BloomFilter::try_from_array(chunk, DEFAULT_BLOOM_FILTER_FPP)?
+                .map(|filter| filter.to_bytes());
+            if stored.as_ref().map(Buffer::as_slice) != expected.as_deref() {
+                self.issue(
+                    path,
+                    VerificationIssueKind::Stats,
+                    "Stored bloom filter doesn't match the data",
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Verify the messages of a flat layout, returning its data with freshly computed stats, unless
+    /// it is encrypted.
+    async fn verify_flat(
+        &mut self,
+        path: &[usize],
+        layout: &LayoutSpec,
+        dtype: &DType,
+    ) -> Option<ArrayData> {
+        let buffers = layout.buffers();
+        let page_rows = match layout.metadata() {
+            None if buffers.len() == 1 => vec![layout.row_count()],
+            None => {
+                self.issue(
+                    path,
+                    VerificationIssueKind::Layout,
+                    format!(
+                        "Flat layout must have exactly 1 buffer, found {}",
+                        buffers.len()
+                    ),
+                );
+                return None;
+            }
+            Some(metadata) => self.page_rows(path, layout, metadata)?,
+        };
+        let checksums = layout.checksums();
+        if let Some(checksums) = checksums {
+            if checksums.len() != buffers.len() {
+                self.issue(
+                    path,
+                    VerificationIssueKind::Layout,
+                    format!(
+                        "Flat layout has {} checksums for {} buffers",
+                        checksums.len(),
+                        buffers.len()
+                    ),
+                );
+            }
+        }
+
+        // Without the key, only the byte ranges and checksums of encrypted messages can be checked.
+        let encrypted = layout.key_id().is_some();
+        let mut pages = Vec::with_capacity(buffers.len());
+        for (page_idx, (range, rows)) in buffers.iter().zip(page_rows).enumerate() {
+            if range.begin > range.end || range.end > self.data_end {
+                self.issue(
+                    path,
+                    VerificationIssueKind::ByteRange,
+                    format!(
+                        "Buffer {page_idx} at {range} lies outside the data section ending at {}",
+                        self.data_end
+                    ),
+                );
+                continue;
+            }
+
+            self.report.messages_checked += 1;
+            let bytes = match self.read.read_byte_range(range.begin, range.len()).await {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    self.issue(
+                        path,
+                        VerificationIssueKind::Message,
+                        format!("Failed to read buffer {page_idx} at {range}: {e}"),
+                    );
+                    continue;
+                }
+            };
+            if let Some(expected) = checksums.and_then(|c| c.get(page_idx)) {
+                let actual = checksum(&bytes);
+                if actual != *expected {
+                    self.issue(
+                        path,
+                        VerificationIssueKind::Checksum,
+                        format!(
+                            "Buffer {page_idx} at {range} hashes to {actual:#018x}, expected {expected:#018x}"
+                        ),
+                    );
+                    continue;
+                }
+            }
+            if encrypted {
+                continue;
+            }
+            let page = match decode_message(bytes, self.ctx.clone(), dtype.clone()) {
+                Ok(page) => page,
+                Err(e) => {
+                    self.issue(
+                        path,
+                        VerificationIssueKind::Message,
+                        format!("Failed to decode buffer {page_idx} at {range}: {e}"),
+                    );
+                    continue;
+                }
+            };
+            if page.len() as u64 != rows {
+                self.issue(
+                    path,
+                    VerificationIssueKind::RowCount,
+                    format!(
+                        "Buffer {page_idx} holds {} rows, expected {rows}",
+                        page.len()
+                    ),
+                );
+                continue;
+            }
+            match self.verify_message_stats(path, page_idx, page) {
+                Ok(page) => pages.push(page),
+                Err(e) => self.issue(
+                    path,
+                    VerificationIssueKind::Message,
+                    format!("Failed to canonicalize buffer {page_idx}: {e}"),
+                ),
+            }
+        }
+
+        if pages.len() != buffers.len() {
+            return None;
+        }
+        if pages.len() == 1 {
+            return pages.pop();
+        }
+        match ChunkedArray::try_new(pages, dtype.clone())
+            .and_then(|chunked| chunked.into_canonical())
+        {
+            Ok(array) => Some(array.into_array()),
+            Err(e) => {
+                self.issue(
+                    path,
+                    VerificationIssueKind::Message,
+                    format!("Failed to combine pages: {e}"),
+                );
+                None
+            }
+        }
+    }
+
+    /// The row counts of the pages of a paged flat layout.
+    fn page_rows(
+        &mut self,
+        path: &[usize],
+        layout: &LayoutSpec,
+        metadata: &Bytes,
+    ) -> Option<Vec<u64>> {
+        let page_index = match PageIndex::parse(metadata) {
+            Ok(page_index) => page_index,
+            Err(e) => {
+                self.issue(
+                    path,
+                    VerificationIssueKind::Layout,
+                    format!("Invalid page index: {e}"),
+                );
+                return None;
+            }
+        };
+        if page_index.row_offsets.len() != layout.buffers().len() + 1 {
+            self.issue(
+                path,
+                VerificationIssueKind::Layout,
+                format!(
+                    "Page index has {} row offsets for {} pages",
+                    page_index.row_offsets.len(),
+                    layout.buffers().len()
+                ),
+            );
+            return None;
+        }
+        // There is at least one row offset, as checked above.
+        let first = page_index.row_offsets.first().copied().unwrap_or_default();
+        let last = page_index.row_offsets.last().copied().unwrap_or_default();
+        if first != 0 || last != layout.row_count() {
+            self.issue(
+                path,
+                VerificationIssueKind::RowCount,
+                format!(
+                    "Page index covers rows {first}..{last}, but the layout has {} rows",
+                    layout.row_count()
+                ),
+            );
+            return None;
+        }
+        Some(
+            page_index
+                .row_offsets
+                .iter()
+                .tuple_windows()
+                .map(|(begin, end)| end - begin)
+                .collect(),
+        )
+    }
+
+    /// Compare the stats stored in a message with those of its data, returning the data with
+    /// freshly computed stats.
+    fn verify_message_stats(
+        &mut self,
+        path: &[usize],
+        page_idx: usize,
+        page: ArrayData,
+    ) -> VortexResult<ArrayData> {
+        let stored = page.statistics().to_set();
+        let fresh = page.into_canonical()?.into_array();
+        for (stat, value) in stored {
+            if let Some(message) = stat_mismatch(stat, &value, &fresh)? {
+                self.issue(
+                    path,
+                    VerificationIssueKind::Stats,
+                    format!("Buffer {page_idx} {message}"),
+                );
+            }
+        }
+        Ok(fresh)
+    }
+}
+
+fn child_path(path: &[usize], idx: usize) -> Vec<usize> {
+    path.iter().copied().chain([idx]).collect()
+}
+
+/// Describe how a stored statistic differs from the one computed from the data, if it does.
+///
+/// Estimated statistics, and statistics that are unknown on either side, are not compared.
+fn stat_mismatch(stat: Stat, stored: &Scalar, data: &ArrayData) -> VortexResult<Option<String>> {
+    if stored.is_null() || matches!(stat, Stat::UncompressedSizeInBytes) {
+        return Ok(None);
+    }
+    let Some(computed) = data.statistics().compute(stat) else {
+        return Ok(None);
+    };
+    let computed = computed.cast(stored.dtype())?;
+    Ok((&computed != stored)
+        .then(|| format!("stores {stat} {stored}, but the data has {computed}")))
+}
+
+/// Decode an IPC array message, validating its framing and flatbuffer on the way.
+///
+/// Unlike the file reader, which trusts messages it was pointed to by the footer, this never
+/// interprets unvalidated flatbuffer bytes.
+fn decode_message(mut bytes: Bytes, ctx: Arc<Context>, dtype: DType) -> VortexResult<ArrayData> {
+    if bytes.len() < MESSAGE_PREFIX_LENGTH {
+        vortex_bail!(
+            "Message of {} bytes is missing its length prefix",
+            bytes.len()
+        );
+    }
+    let fb_len = bytes.get_u32_le() as usize;
+    if fb_len > bytes.len() {
+        vortex_bail!(
+            "Message flatbuffer of {fb_len} bytes doesn't fit in the remaining {} bytes",
+            bytes.len()
+        );
+    }
+    let fb_bytes = bytes.split_to(fb_len);
+
+    let batch = root::<message::Message>(&fb_bytes)?
+        .header_as_batch()
+        .ok_or_else(|| vortex_err!("Message is not a batch"))?;
+    let buffer_size = batch.buffer_size();
+    if buffer_size != bytes.len() as u64 {
+        vortex_bail!(
+            "Message buffers take {buffer_size} bytes, but {} bytes are left in the message",
+            bytes.len()
+        );
+    }
+    let buffers = batch.buffers().unwrap_or_default();
+    let next_offsets = buffers
+        .iter()
+        .map(|buffer| buffer.offset())
+        .skip(1)
+        .chain([buffer_size]);
+    for (buffer, next_offset) in buffers.iter().zip(next_offsets) {
+        if buffer.offset().saturating_add(buffer.padding() as u64) > next_offset {
+            vortex_bail!(
+                "Message buffer at offset {} overlaps the buffer at {next_offset}",
+                buffer.offset()
+            );
+        }
+    }
+
+    let mut reader = ArrayMessageReader::from_fb_bytes(Buffer::from(fb_bytes));
+    reader.read(bytes)?;
+    reader.into_array(ctx, dtype)
+}
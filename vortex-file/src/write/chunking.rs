@@ -0,0 +1,256 @@
+use std::mem;
+
+use vortex_array::array::ChunkedArray;
+use vortex_array::compute::slice;
+use vortex_array::stats::ArrayStatistics;
+use vortex_array::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
+use vortex_error::{vortex_bail, VortexResult};
+
+/// Options controlling how a [`VortexFileWriter`][crate::VortexFileWriter] lays out a file.
+///
+/// By default every array written is stored as is, so the chunks of a file follow the batches of
+/// the incoming stream. Setting a target chunk size makes the writer re-chunk the incoming arrays
+/// by concatenating small batches and slicing large ones, so that the chunks of a file, and with
+/// them its stats tables and pruning granularity, are independent of how the producer batched
+/// the data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WriteOptions {
+    chunk_rows: Option<usize>,
+    chunk_bytes: Option<usize>,
+    page_rows: Option<usize>,
+    checksums: bool,
+}
+
+impl WriteOptions {
+    /// Limit chunks to at most `rows` rows.
+    pub fn with_chunk_rows(mut self, rows: usize) -> Self {
+        self.chunk_rows = Some(rows);
+        self
+    }
+
+    /// Limit chunks to roughly `bytes` bytes of uncompressed data.
+    ///
+    /// Chunks are sized based on the average row size of the incoming arrays, so chunks of
+    /// arrays with very variable row sizes may over- or undershoot the target.
+    pub fn with_chunk_bytes(mut self, bytes: usize) -> Self {
+        self.chunk_bytes = Some(bytes);
+        self
+    }
+
+    /// Split chunks into pages of at most `rows` rows.
+    ///
+    /// Each page is written as a separate array and the chunk's flat layout records the row offsets
+    /// of its pages, so readers taking a few rows only fetch and decode the pages holding them.
+    /// Files written with pages can't be read by readers predating the page index.
+    pub fn with_page_rows(mut self, rows: usize) -> Self {
+        self.page_rows = Some(rows);
+        self
+    }
+
+    /// Record checksums of all data written to the file.
+    ///
+    /// Readers verify the checksums before decoding data, so that corrupted or truncated files fail
+    /// to read instead of decoding into garbage.
+    pub fn with_checksums(mut self, checksums: bool) -> Self {
+        self.checksums = checksums;
+        self
+    }
+
+    pub fn chunk_rows(&self) -> Option<usize> {
+        self.chunk_rows
+    }
+
+    pub fn chunk_bytes(&self) -> Option<usize> {
+        self.chunk_bytes
+    }
+
+    pub fn page_rows(&self) -> Option<usize> {
+        self.page_rows
+    }
+
+    pub fn checksums(&self) -> bool {
+        self.checksums
+    }
+
+    pub(crate) fn validate(&self) -> VortexResult<()> {
+        if self.chunk_rows == Some(0) || self.chunk_bytes == Some(0) {
+            vortex_bail!("Target chunk size must be positive, got {:?}", self);
+        }
+        if self.page_rows == Some(0) {
+            vortex_bail!("Page size must be positive, got {:?}", self);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn rechunks(&self) -> bool {
+        self.chunk_rows.is_some() || self.chunk_bytes.is_some()
+    }
+}
+
+/// Buffers the struct arrays handed to the writer and cuts them into chunks of the target size.
+pub(crate) struct Rechunker {
+    options: WriteOptions,
+    pending: Vec<ArrayData>,
+    pending_rows: usize,
+    pending_bytes: usize,
+}
+
+impl Rechunker {
+    pub fn new(options: WriteOptions) -> Self {
+        Self {
+            options,
+            pending: Vec::new(),
+            pending_rows: 0,
+            pending_bytes: 0,
+        }
+    }
+
+    /// Add an array, returning the chunks that are complete.
+    pub fn push(&mut self, mut array: ArrayData) -> VortexResult<Vec<ArrayData>> {
+        if !self.options.rechunks() {
+            return Ok(vec![array]);
+        }
+
+        let mut chunks = Vec::new();
+        while !array.is_empty() {
+            let len = array.len();
+            let bytes = uncompressed_size(&array);
+
+            let mut take = len;
+            if let Some(chunk_rows) = self.options.chunk_rows {
+                take = take.min(chunk_rows - self.pending_rows);
+            }
+            if let Some(chunk_bytes) = self.options.chunk_bytes {
+                let remaining = chunk_bytes.saturating_sub(self.pending_bytes);
+                let row_size = bytes.div_ceil(len).max(1);
+                take = take.min(remaining.div_ceil(row_size).max(1));
+            }
+
+            let (head, tail) = if take == len {
+                (array, None)
+            } else {
+                (slice(&array, 0, take)?, Some(slice(&array, take, len)?))
+            };
+            self.pending_rows += take;
+            self.pending_bytes += if take == len {
+                bytes
+            } else {
+                uncompressed_size(&head)
+            };
+            self.pending.push(head);
+
+            if self.is_full() {
+                chunks.push(self.take_pending()?);
+            }
+            match tail {
+                Some(tail) => array = tail,
+                None => break,
+            }
+        }
+        Ok(chunks)
+    }
+
+    /// Return whatever is left as a final, possibly undersized, chunk.
+    pub fn finish(&mut self) -> VortexResult<Option<ArrayData>> {
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+        self.take_pending().map(Some)
+    }
+
+    fn is_full(&self) -> bool {
+        self.options
+            .chunk_rows
+            .is_some_and(|rows| self.pending_rows >= rows)
+            || self
+                .options
+                .chunk_bytes
+                .is_some_and(|bytes| self.pending_bytes >= bytes)
+    }
+
+    fn take_pending(&mut self) -> VortexResult<ArrayData> {
+        self.pending_rows = 0;
+        self.pending_bytes = 0;
+        let pending = mem::take(&mut self.pending);
+        let dtype = pending[0].dtype().clone();
+        // The fields of merged arrays are chunked, the writer writes them as one chunk each without
+        // decompressing them.
+        match <[ArrayData; 1]>::try_from(pending) {
+            Ok([array]) => Ok(array),
+            Err(pending) => Ok(ChunkedArray::try_new(pending, dtype)?
+                .into_struct()?
+                .into_array()),
+        }
+    }
+}
+
+fn uncompressed_size(array: &ArrayData) -> usize {
+    array
+        .statistics()
+        .compute_uncompressed_size_in_bytes()
+        .unwrap_or_else(|| array.nbytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_array::array::{ChunkedArray, PrimitiveArray, StructArray};
+    use vortex_array::variants::StructArrayTrait;
+    use vortex_array::IntoArrayData;
+
+    use super::*;
+
+    fn batch(start: u32, len: u32) -> ArrayData {
+        StructArray::from_fields(&[(
+            "a",
+            PrimitiveArray::from((start..start + len).collect::<Vec<_>>()).into_array(),
+        )])
+        .unwrap()
+        .into_array()
+    }
+
+    #[test]
+    fn rechunk_rows() {
+        let mut rechunker = Rechunker::new(WriteOptions::default().with_chunk_rows(10));
+        let mut chunks = Vec::new();
+        for (start, len) in [(0, 3), (3, 4), (7, 25), (32, 1)] {
+            chunks.extend(rechunker.push(batch(start, len)).unwrap());
+        }
+        chunks.extend(rechunker.finish().unwrap());
+
+        assert_eq!(
+            chunks.iter().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![10, 10, 10, 3]
+        );
+        let values = ChunkedArray::try_new(chunks, batch(0, 0).dtype().clone())
+            .unwrap()
+            .into_struct()
+            .unwrap()
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(
+            values.maybe_null_slice::<u32>(),
+            (0..33).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rechunk_bytes() {
+        // 4 bytes per row
+        let mut rechunker = Rechunker::new(WriteOptions::default().with_chunk_bytes(100));
+        let mut chunks = rechunker.push(batch(0, 60)).unwrap();
+        chunks.extend(rechunker.finish().unwrap());
+        assert_eq!(
+            chunks.iter().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![25, 25, 10]
+        );
+    }
+
+    #[test]
+    fn no_rechunking() {
+        let mut rechunker = Rechunker::new(WriteOptions::default());
+        assert_eq!(rechunker.push(batch(0, 3)).unwrap().len(), 1);
+        assert!(rechunker.finish().unwrap().is_none());
+    }
+}
@@ -12,6 +12,8 @@ pub struct LayoutSpec {
     children: Option<Vec<LayoutSpec>>,
     row_count: u64,
     metadata: Option<Bytes>,
+    checksums: Option<Vec<u64>>,
+    key_id: Option<String>,
 }
 
 impl LayoutSpec {
@@ -22,6 +24,23 @@ impl LayoutSpec {
             children: None,
             row_count,
             metadata: None,
+            checksums: None,
+            key_id: None,
+        }
+    }
+
+    /// Create a flat layout whose array is split into pages, one buffer per page.
+    ///
+    /// The metadata holds the page index, i.e. the row offsets of the pages.
+    pub fn paged(pages: Vec<ByteRange>, page_index: Bytes, row_count: u64) -> Self {
+        Self {
+            id: FLAT_LAYOUT_ID,
+            buffers: Some(pages),
+            children: None,
+            row_count,
+            metadata: Some(page_index),
+            checksums: None,
+            key_id: None,
         }
     }
 
@@ -35,6 +54,8 @@ impl LayoutSpec {
             children: Some(children),
             row_count,
             metadata,
+            checksums: None,
+            key_id: None,
         }
     }
 
@@ -45,6 +66,70 @@ impl LayoutSpec {
             children: Some(children),
             row_count,
             metadata: None,
+            checksums: None,
+            key_id: None,
+        }
+    }
+
+    /// Record the checksums of the buffers of this layout, one per buffer.
+    pub fn with_checksums(mut self, checksums: Vec<u64>) -> Self {
+        self.checksums = Some(checksums);
+        self
+    }
+
+    /// Record the id of the key that the buffers of this layout are encrypted with.
+    pub fn with_key_id(mut self, key_id: String) -> Self {
+        self.key_id = Some(key_id);
+        self
+    }
+
+    pub fn id(&self) -> LayoutId {
+        self.id
+    }
+
+    pub fn buffers(&self) -> &[ByteRange] {
+        self.buffers.as_deref().unwrap_or_default()
+    }
+
+    pub fn children(&self) -> &[LayoutSpec] {
+        self.children.as_deref().unwrap_or_default()
+    }
+
+    pub fn row_count(&self) -> u64 {
+        self.row_count
+    }
+
+    pub fn metadata(&self) -> Option<&Bytes> {
+        self.metadata.as_ref()
+    }
+
+    pub fn checksums(&self) -> Option<&[u64]> {
+        self.checksums.as_deref()
+    }
+
+    pub fn key_id(&self) -> Option<&str> {
+        self.key_id.as_deref()
+    }
+}
+
+impl From<fb::Layout<'_>> for LayoutSpec {
+    /// Recover the spec of a layout that has previously been written to a file.
+    fn from(layout: fb::Layout<'_>) -> Self {
+        Self {
+            id: LayoutId(layout.encoding()),
+            buffers: layout.buffers().map(|buffers| {
+                buffers
+                    .iter()
+                    .map(|b| ByteRange::new(b.begin(), b.end()))
+                    .collect()
+            }),
+            children: layout
+                .children()
+                .map(|children| children.iter().map(LayoutSpec::from).collect()),
+            row_count: layout.row_count(),
+            metadata: layout.metadata().map(|m| Bytes::copy_from_slice(m.bytes())),
+            checksums: layout.checksums().map(|c| c.iter().collect()),
+            key_id: layout.key_id().map(str::to_string),
         }
     }
 }
@@ -63,6 +148,8 @@ impl WriteFlatBuffer for LayoutSpec {
         });
         let buffers = buffer_offsets.map(|bufs| fbb.create_vector(&bufs));
         let metadata = self.metadata.as_ref().map(|b| fbb.create_vector(b));
+        let checksums = self.checksums.as_ref().map(|c| fbb.create_vector(c));
+        let key_id = self.key_id.as_deref().map(|k| fbb.create_string(k));
         let child_offsets = self.children.as_ref().map(|children| {
             children
                 .iter()
@@ -78,6 +165,8 @@ impl WriteFlatBuffer for LayoutSpec {
                 children,
                 row_count: self.row_count,
                 metadata,
+                checksums,
+                key_id,
             },
         )
     }
@@ -1,6 +1,8 @@
+pub use chunking::WriteOptions;
 pub use layout::LayoutSpec;
 pub use writer::VortexFileWriter;
 
+mod chunking;
 mod layout;
 mod postscript;
 mod stats_accumulator;
@@ -3,15 +3,23 @@
 use itertools::Itertools;
 use vortex_array::array::StructArray;
 use vortex_array::builders::{builder_with_capacity, ArrayBuilder, ArrayBuilderExt};
+use vortex_array::compute::scalar_at;
 use vortex_array::stats::{ArrayStatistics as _, Stat};
 use vortex_array::validity::{ArrayValidity, Validity};
-use vortex_array::{ArrayData, IntoArrayData};
-use vortex_dtype::DType;
+use vortex_array::variants::StructArrayTrait;
+use vortex_array::{ArrayData, ArrayLen, IntoArrayData};
+use vortex_buffer::Buffer;
+use vortex_dtype::{DType, Nullability};
 use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::layouts::{ChunkedMetadata, BLOOM_FILTER_COLUMN};
+use crate::{BloomFilter, DEFAULT_BLOOM_FILTER_FPP};
 
 pub struct StatsAccumulator {
     stats: Vec<Stat>,
     builders: Vec<Box<dyn ArrayBuilder>>,
+    bloom_filters: Option<Box<dyn ArrayBuilder>>,
     length: usize,
 }
 
@@ -26,10 +34,20 @@ impl StatsAccumulator {
         Self {
             stats,
             builders,
+            bloom_filters: None,
             length: 0,
         }
     }
 
+    /// Also accumulate a [`BloomFilter`] of the values of each chunk.
+    pub fn with_bloom_filters(mut self) -> Self {
+        self.bloom_filters = Some(builder_with_capacity(
+            &DType::Binary(Nullability::Nullable),
+            1024,
+        ));
+        self
+    }
+
     pub fn push_chunk(&mut self, array: &ArrayData) -> VortexResult<()> {
         for (s, builder) in self.stats.iter().zip_eq(self.builders.iter_mut()) {
             if let Some(v) = array.statistics().compute(*s) {
@@ -38,10 +56,55 @@ impl StatsAccumulator {
                 builder.append_null();
             }
         }
+        if let Some(builder) = self.bloom_filters.as_mut() {
+            match BloomFilter::try_from_array(array, DEFAULT_BLOOM_FILTER_FPP)? {
+                Some(filter) => builder.append_scalar(&Scalar::binary(
+                    Buffer::from(filter.to_bytes()),
+                    Nullability::Nullable,
+                ))?,
+                None => builder.append_null(),
+            }
+        }
         self.length += 1;
         Ok(())
     }
 
+    /// Push the stats of previously written chunks, one row of `table` per chunk.
+    ///
+    /// Stats that are missing from the table are recorded as null.
+    pub fn push_stats_table(&mut self, table: &StructArray) -> VortexResult<()> {
+        for (s, builder) in self.stats.iter().zip_eq(self.builders.iter_mut()) {
+            match table.field_by_name(&s.to_string()) {
+                Some(values) => {
+                    for i in 0..table.len() {
+                        builder.append_scalar(&scalar_at(&values, i)?.cast(builder.dtype())?)?;
+                    }
+                }
+                None => builder.append_nulls(table.len()),
+            }
+        }
+        if let Some(builder) = self.bloom_filters.as_mut() {
+            match table.field_by_name(BLOOM_FILTER_COLUMN) {
+                Some(values) => {
+                    for i in 0..table.len() {
+                        builder.append_scalar(&scalar_at(&values, i)?)?;
+                    }
+                }
+                None => builder.append_nulls(table.len()),
+            }
+        }
+        self.length += table.len();
+        Ok(())
+    }
+
+    /// Push null stats for `n` previously written chunks whose stats are unknown.
+    pub fn push_unknown(&mut self, n: usize) {
+        for builder in self.builders.iter_mut().chain(self.bloom_filters.as_mut()) {
+            builder.append_nulls(n);
+        }
+        self.length += n;
+    }
+
     pub fn into_array(mut self) -> VortexResult<Option<StatArray>> {
         let mut names = Vec::new();
         let mut fields = Vec::new();
@@ -62,6 +125,16 @@ impl StatsAccumulator {
             fields.push(values);
         }
 
+        let mut has_bloom_filter = false;
+        if let Some(builder) = self.bloom_filters.as_mut() {
+            let values = builder.finish()?;
+            if values.logical_validity().null_count()? != values.len() {
+                has_bloom_filter = true;
+                names.push(BLOOM_FILTER_COLUMN.into());
+                fields.push(values);
+            }
+        }
+
         if names.is_empty() {
             return Ok(None);
         }
@@ -69,9 +142,12 @@ impl StatsAccumulator {
         Ok(Some(StatArray(
             StructArray::try_new(names.into(), fields, self.length, Validity::NonNullable)?
                 .into_array(),
-            stats,
+            ChunkedMetadata {
+                stats,
+                has_bloom_filter,
+            },
         )))
     }
 }
 
-pub struct StatArray(pub ArrayData, pub Vec<Stat>);
+pub struct StatArray(pub ArrayData, pub ChunkedMetadata);
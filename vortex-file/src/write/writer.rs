@@ -1,5 +1,6 @@
 #![allow(clippy::cast_possible_truncation)]
 
+use std::sync::Arc;
 use std::{io, iter, mem};
 
 use bytes::Bytes;
@@ -7,21 +8,29 @@ use flatbuffers::FlatBufferBuilder;
 use futures::TryStreamExt;
 use itertools::Itertools;
 use vortex_array::array::{ChunkedArray, StructArray};
-use vortex_array::stats::{as_stat_bitset_bytes, ArrayStatistics, Stat};
+use vortex_array::compute::slice;
+use vortex_array::stats::{ArrayStatistics, Stat};
 use vortex_array::stream::ArrayStream;
-use vortex_array::{ArrayData, ArrayLen};
+use vortex_array::{ArrayDType, ArrayData, ArrayLen, Context, IntoArrayVariant};
 use vortex_buffer::io_buf::IoBuf;
-use vortex_dtype::DType;
+use vortex_dtype::{is_variant_ext_type, DType, FieldName, Nullability};
 use vortex_error::{vortex_bail, vortex_err, VortexExpect as _, VortexResult};
 use vortex_flatbuffers::WriteFlatBuffer;
-use vortex_io::VortexWrite;
+use vortex_io::{VortexReadAt, VortexWrite};
+use vortex_ipc::messages::reader::ArrayMessageReader;
 use vortex_ipc::messages::writer::MessageWriter;
 use vortex_ipc::messages::IPCSchema;
 use vortex_ipc::stream_writer::ByteRange;
 
+use crate::encryption::MessageCipher;
+use crate::layouts::{ChunkedMetadata, PageIndex};
+use crate::write::chunking::{Rechunker, WriteOptions};
 use crate::write::postscript::Postscript;
 use crate::write::stats_accumulator::{StatArray, StatsAccumulator};
-use crate::{LayoutSpec, EOF_SIZE, MAGIC_BYTES, MAX_FOOTER_SIZE, VERSION};
+use crate::{
+    read_initial_bytes, KeyProvider, LayoutPartId, LayoutSpec, CHUNKED_LAYOUT_ID,
+    COLUMNAR_LAYOUT_ID, EOF_SIZE, MAGIC_BYTES, MAX_FOOTER_SIZE, VERSION,
+};
 
 const STATS_TO_WRITE: &[Stat] = &[
     Stat::Min,
@@ -41,6 +50,12 @@ pub struct VortexFileWriter<W> {
     row_count: u64,
     dtype: Option<DType>,
     column_writers: Vec<ColumnWriter>,
+    bloom_filter_columns: Vec<FieldName>,
+    shredded_variant_columns: Vec<FieldName>,
+    key_provider: Option<Arc<dyn KeyProvider>>,
+    encrypted_columns: Vec<FieldName>,
+    options: WriteOptions,
+    rechunker: Rechunker,
 }
 
 impl<W: VortexWrite> VortexFileWriter<W> {
@@ -50,9 +65,132 @@ impl<W: VortexWrite> VortexFileWriter<W> {
             dtype: None,
             column_writers: Vec::new(),
             row_count: 0,
+            bloom_filter_columns: Vec::new(),
+            shredded_variant_columns: Vec::new(),
+            key_provider: None,
+            encrypted_columns: Vec::new(),
+            options: WriteOptions::default(),
+            rechunker: Rechunker::new(WriteOptions::default()),
         }
     }
 
+    /// Set the [`WriteOptions`] of the file, e.g. to write chunks of a fixed number of rows.
+    ///
+    /// Must be called before any arrays are written.
+    pub fn with_options(mut self, options: WriteOptions) -> Self {
+        self.options = options;
+        self.rechunker = Rechunker::new(options);
+        self.msgs = self.msgs.with_checksums(options.checksums());
+        self
+    }
+
+    /// Write a [`BloomFilter`][crate::BloomFilter] per chunk of the given top-level columns.
+    ///
+    /// Bloom filters let readers prune chunks for equality predicates on high-cardinality columns,
+    /// where min/max statistics rarely help.
+    pub fn with_bloom_filter_columns(
+        mut self,
+        columns: impl IntoIterator<Item = impl Into<FieldName>>,
+    ) -> Self {
+        self.bloom_filter_columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Write the shredded paths of the given top-level [variant][vortex_dtype::VariantDType] columns
+    /// as columns of their own.
+    ///
+    /// Each shredded path then has its own statistics, which lets readers prune chunks for
+    /// predicates on the path. Only non-nullable variant columns can be shredded, others are
+    /// written as a single column.
+    pub fn with_shredded_variant_columns(
+        mut self,
+        columns: impl IntoIterator<Item = impl Into<FieldName>>,
+    ) -> Self {
+        self.shredded_variant_columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Encrypt the given top-level columns with AES-256-GCM, using keys from the [`KeyProvider`].
+    ///
+    /// All messages of the columns are encrypted, including their statistics tables, and the ids of
+    /// their keys are recorded in the footer. Readers only need the keys to read the encrypted
+    /// columns themselves. Writing fails unless every name is a top-level column of a struct array.
+    pub fn with_encrypted_columns(
+        mut self,
+        key_provider: Arc<dyn KeyProvider>,
+        columns: impl IntoIterator<Item = impl Into<FieldName>>,
+    ) -> Self {
+        self.key_provider = Some(key_provider);
+        self.encrypted_columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Reopen a finished Vortex file in order to append more chunks to it.
+    ///
+    /// `read` provides access to the existing file and `write` must append to its end, e.g. a file
+    /// opened in append mode. New chunks are written after the existing footer, and [`finalize`]
+    /// then writes a new footer that references both the existing and the new chunks. Since readers
+    /// always locate the footer from the end of the file, they see either the previous or the new
+    /// footer, but never a partially written one.
+    ///
+    /// Appended arrays must have exactly the same dtype as the existing file, and files with
+    /// encrypted columns can't be appended to.
+    ///
+    /// [`finalize`]: VortexFileWriter::finalize
+    pub async fn append<R: VortexReadAt>(write: W, read: R) -> VortexResult<Self> {
+        let file_size = read.size().await?;
+        let initial_read = read_initial_bytes(&read, file_size).await?;
+        let dtype = initial_read.lazy_dtype().value()?.clone();
+        let root_layout = LayoutSpec::from(initial_read.fb_layout());
+
+        if root_layout.id() != COLUMNAR_LAYOUT_ID {
+            vortex_bail!(
+                "Can only append to files with a columnar root layout, found {:?}",
+                root_layout.id()
+            );
+        }
+        let DType::Struct(ref struct_dtype, _) = dtype else {
+            vortex_bail!(
+                "Can only append to files with a struct dtype, found {}",
+                dtype
+            );
+        };
+        if struct_dtype.dtypes().len() != root_layout.children().len() {
+            vortex_bail!(
+                "Expected a column layout per field, found {} fields and {} columns",
+                struct_dtype.dtypes().len(),
+                root_layout.children().len()
+            );
+        }
+
+        let ctx = Arc::new(Context::default());
+        let mut column_writers = Vec::with_capacity(root_layout.children().len());
+        for (column_layout, column_dtype) in root_layout
+            .children()
+            .iter()
+            .zip(struct_dtype.dtypes().iter())
+        {
+            column_writers
+                .push(ColumnWriter::resume(column_dtype, column_layout, &read, ctx.clone()).await?);
+        }
+
+        let mut msgs = MessageWriter::new_at(write, file_size);
+        msgs.align().await?;
+
+        Ok(VortexFileWriter {
+            msgs,
+            dtype: Some(dtype),
+            column_writers,
+            row_count: root_layout.row_count(),
+            bloom_filter_columns: Vec::new(),
+            shredded_variant_columns: Vec::new(),
+            key_provider: None,
+            encrypted_columns: Vec::new(),
+            options: WriteOptions::default(),
+            rechunker: Rechunker::new(WriteOptions::default()),
+        })
+    }
+
     pub async fn write_array_columns(self, array: ArrayData) -> VortexResult<Self> {
         if let Ok(chunked) = ChunkedArray::try_from(array.clone()) {
             self.write_array_columns_stream(chunked.array_stream())
@@ -67,6 +205,7 @@ impl<W: VortexWrite> VortexFileWriter<W> {
         mut self,
         mut array_stream: S,
     ) -> VortexResult<Self> {
+        self.options.validate()?;
         match self.dtype {
             None => self.dtype = Some(array_stream.dtype().clone()),
             Some(ref sd) => {
@@ -79,31 +218,68 @@ impl<W: VortexWrite> VortexFileWriter<W> {
                 }
             }
         }
+        if !self.encrypted_columns.is_empty() {
+            let Some(DType::Struct(st, _)) = self.dtype.as_ref() else {
+                vortex_bail!(
+                    "Encrypted columns require a struct array, found {}",
+                    array_stream.dtype()
+                )
+            };
+            if let Some(name) = self
+                .encrypted_columns
+                .iter()
+                .find(|name| !st.names().contains(name))
+            {
+                vortex_bail!("Encrypted column {name} is not a top-level column")
+            }
+        }
 
         while let Some(columns) = array_stream.try_next().await? {
-            let st = StructArray::try_from(columns)?;
-            self.row_count += st.len() as u64;
-            for (i, field) in st.children().enumerate() {
-                if let Ok(chunked_array) = ChunkedArray::try_from(field.clone()) {
-                    self.write_column_chunks(chunked_array.array_stream(), i)
-                        .await?
-                } else {
-                    self.write_column_chunks(field.into_array_stream(), i)
-                        .await?
-                }
+            for chunk in self.rechunker.push(columns)? {
+                self.write_columns(chunk).await?;
             }
         }
 
         Ok(self)
     }
 
-    async fn write_column_chunks<S>(&mut self, stream: S, column_idx: usize) -> VortexResult<()>
-    where
-        S: ArrayStream + Unpin,
-    {
+    async fn write_columns(&mut self, columns: ArrayData) -> VortexResult<()> {
+        let st = StructArray::try_from(columns)?;
+        self.row_count += st.len() as u64;
+        for (i, field) in st.children().enumerate() {
+            self.write_column_chunks(field, i).await?
+        }
+        Ok(())
+    }
+
+    async fn write_column_chunks(
+        &mut self,
+        column: ArrayData,
+        column_idx: usize,
+    ) -> VortexResult<()> {
         let column_writer = match self.column_writers.get_mut(column_idx) {
             None => {
-                self.column_writers.push(ColumnWriter::new(stream.dtype()));
+                let name = match self.dtype.as_ref() {
+                    Some(DType::Struct(st, _)) => st.names().get(column_idx),
+                    _ => None,
+                };
+                let bloom_filter =
+                    name.is_some_and(|name| self.bloom_filter_columns.contains(name));
+                let shred_variant =
+                    name.is_some_and(|name| self.shredded_variant_columns.contains(name));
+                let cipher = match (name, self.key_provider.as_ref()) {
+                    (Some(name), Some(key_provider)) if self.encrypted_columns.contains(name) => {
+                        let (key_id, key) = key_provider.column_key(name)?;
+                        Some(Arc::new(MessageCipher::new(key_id, &key)?))
+                    }
+                    _ => None,
+                };
+                self.column_writers.push(ColumnWriter::new(
+                    column.dtype(),
+                    bloom_filter,
+                    shred_variant,
+                    cipher,
+                ));
 
                 assert_eq!(
                     self.column_writers.len(),
@@ -120,7 +296,14 @@ impl<W: VortexWrite> VortexFileWriter<W> {
             Some(x) => x,
         };
 
-        column_writer.write_chunks(stream, &mut self.msgs).await
+        column_writer
+            .write_chunks(
+                column,
+                self.options.rechunks(),
+                self.options.page_rows(),
+                &mut self.msgs,
+            )
+            .await
     }
 
     async fn write_metadata_arrays(&mut self) -> VortexResult<LayoutSpec> {
@@ -137,6 +320,9 @@ impl<W: VortexWrite> VortexFileWriter<W> {
     }
 
     pub async fn finalize(mut self) -> VortexResult<W> {
+        if let Some(chunk) = self.rechunker.finish()? {
+            self.write_columns(chunk).await?;
+        }
         let top_level_layout = self.write_metadata_arrays().await?;
         let schema_offset = self.msgs.tell();
 
@@ -181,6 +367,22 @@ impl<W: VortexWrite> VortexFileWriter<W> {
     }
 }
 
+/// Read and decode the array stored in a single flat layout message.
+async fn read_flat_array<R: VortexReadAt>(
+    read: &R,
+    range: ByteRange,
+    ctx: Arc<Context>,
+    dtype: DType,
+) -> VortexResult<ArrayData> {
+    let mut buf = read.read_byte_range(range.begin, range.len()).await?;
+    let mut array_reader = ArrayMessageReader::new();
+    let mut read_buf = Bytes::new();
+    while let Some(u) = array_reader.read(read_buf)? {
+        read_buf = buf.split_to(u);
+    }
+    array_reader.into_array(ctx, dtype)
+}
+
 /// Write a flatbuffer to a writer and return the number of bytes written.
 async fn write_fb_raw<W: VortexWrite, F: WriteFlatBuffer>(
     writer: &mut W,
@@ -198,56 +400,347 @@ async fn write_fb_raw<W: VortexWrite, F: WriteFlatBuffer>(
     Ok((buffer_end - buffer_begin) as u64)
 }
 
+/// Writes the chunks of a single column.
+///
+/// Non-nullable struct columns are written as a nested columnar layout with a column per field,
+/// so that readers can fetch individual nested fields. The same goes for the storage of shredded
+/// variant columns. All other columns, i.e. the leaves of the nested columnar layout, are written
+/// as a chunked layout.
 struct ColumnWriter {
-    metadata: StatsAccumulator,
-    batch_byte_offsets: Vec<Vec<u64>>,
-    batch_row_offsets: Vec<Vec<u64>>,
+    shape: ColumnShape,
+    leaves: Vec<ChunkedColumnWriter>,
+}
+
+enum ColumnShape {
+    /// A column written by the leaf writer with the given index.
+    Leaf(usize),
+    Struct(Vec<ColumnShape>),
+    /// A variant column, written as the nested columnar layout of its storage.
+    Variant(Box<ColumnShape>),
 }
 
 impl ColumnWriter {
-    fn new(dtype: &DType) -> Self {
+    fn new(
+        dtype: &DType,
+        bloom_filter: bool,
+        shred_variant: bool,
+        cipher: Option<Arc<MessageCipher>>,
+    ) -> Self {
+        let mut leaf_dtypes = Vec::new();
+        let shape = match dtype {
+            DType::Extension(ext_dtype)
+                if shred_variant
+                    && is_variant_ext_type(ext_dtype.id())
+                    && !ext_dtype.storage_dtype().is_nullable() =>
+            {
+                ColumnShape::Variant(Box::new(ColumnShape::new(
+                    ext_dtype.storage_dtype(),
+                    &mut leaf_dtypes,
+                )))
+            }
+            _ => ColumnShape::new(dtype, &mut leaf_dtypes),
+        };
+        // Bloom filters are only written for top-level columns that aren't nested layouts.
+        let bloom_filter = bloom_filter && matches!(shape, ColumnShape::Leaf(_));
         Self {
-            metadata: StatsAccumulator::new(dtype, STATS_TO_WRITE.to_vec()),
-            batch_byte_offsets: Vec::new(),
-            batch_row_offsets: Vec::new(),
+            shape,
+            leaves: leaf_dtypes
+                .into_iter()
+                .map(|dtype| ChunkedColumnWriter::new(dtype, bloom_filter, cipher.clone()))
+                .collect(),
+        }
+    }
+
+    /// Recover the state of a column writer from the layout of a previously written column.
+    async fn resume<R: VortexReadAt>(
+        dtype: &DType,
+        layout: &LayoutSpec,
+        read: &R,
+        ctx: Arc<Context>,
+    ) -> VortexResult<Self> {
+        let mut leaf_layouts = Vec::new();
+        let shape = ColumnShape::from_layout(dtype, layout, &mut leaf_layouts)?;
+        let mut leaves = Vec::with_capacity(leaf_layouts.len());
+        for (dtype, layout) in leaf_layouts {
+            leaves.push(ChunkedColumnWriter::resume(dtype, layout, read, ctx.clone()).await?);
         }
+        Ok(Self { shape, leaves })
     }
 
-    async fn write_chunks<W: VortexWrite, S: ArrayStream + Unpin>(
+    /// Write a column, as one chunk if it was cut by the rechunker and otherwise as one chunk per
+    /// chunk of the column.
+    async fn write_chunks<W: VortexWrite>(
         &mut self,
-        mut stream: S,
+        column: ArrayData,
+        rechunked: bool,
+        page_rows: Option<usize>,
         msgs: &mut MessageWriter<W>,
     ) -> VortexResult<()> {
-        let mut offsets = Vec::with_capacity(stream.size_hint().0 + 1);
-        offsets.push(msgs.tell());
-        let mut row_offsets = Vec::with_capacity(stream.size_hint().0 + 1);
-        row_offsets.push(
-            self.batch_row_offsets
-                .last()
-                .and_then(|bro| bro.last())
-                .copied()
-                .unwrap_or(0),
-        );
+        let mut leaf_chunks = vec![Vec::new(); self.leaves.len()];
+        if rechunked {
+            self.shape.split_whole(column, &mut leaf_chunks)?;
+        } else {
+            self.shape.split(column, &mut leaf_chunks)?;
+        }
+        for (leaf, chunks) in self.leaves.iter_mut().zip(leaf_chunks) {
+            leaf.write_chunks(chunks, page_rows, msgs).await?;
+        }
+        Ok(())
+    }
+
+    async fn write_metadata<W: VortexWrite>(
+        self,
+        row_count: u64,
+        msgs: &mut MessageWriter<W>,
+    ) -> VortexResult<LayoutSpec> {
+        let mut leaf_layouts = Vec::with_capacity(self.leaves.len());
+        for leaf in self.leaves {
+            leaf_layouts.push(leaf.write_metadata(row_count, msgs).await?);
+        }
+        self.shape
+            .into_layout(&mut leaf_layouts.into_iter(), row_count)
+    }
+}
+
+impl ColumnShape {
+    /// The shape of a column of the given dtype, pushing the dtypes of its leaves in order.
+    fn new<'a>(dtype: &'a DType, leaf_dtypes: &mut Vec<&'a DType>) -> Self {
+        match dtype {
+            DType::Struct(st, Nullability::NonNullable) if !st.dtypes().is_empty() => Self::Struct(
+                st.dtypes()
+                    .iter()
+                    .map(|field_dtype| Self::new(field_dtype, leaf_dtypes))
+                    .collect(),
+            ),
+            _ => {
+                leaf_dtypes.push(dtype);
+                Self::Leaf(leaf_dtypes.len() - 1)
+            }
+        }
+    }
+
+    /// The shape of a previously written column, pushing the dtypes and layouts of its leaves in
+    /// order.
+    fn from_layout<'a>(
+        dtype: &'a DType,
+        layout: &'a LayoutSpec,
+        leaves: &mut Vec<(&'a DType, &'a LayoutSpec)>,
+    ) -> VortexResult<Self> {
+        if layout.id() != COLUMNAR_LAYOUT_ID {
+            leaves.push((dtype, layout));
+            return Ok(Self::Leaf(leaves.len() - 1));
+        }
+
+        let st = match dtype {
+            DType::Struct(st, _) => st,
+            DType::Extension(ext_dtype) if is_variant_ext_type(ext_dtype.id()) => {
+                return Ok(Self::Variant(Box::new(Self::from_layout(
+                    ext_dtype.storage_dtype(),
+                    layout,
+                    leaves,
+                )?)));
+            }
+            _ => vortex_bail!("Columnar layout for a column of non-struct dtype {dtype}"),
+        };
+        if st.dtypes().len() != layout.children().len() {
+            vortex_bail!(
+                "Expected a column layout per field, found {} fields and {} columns",
+                st.dtypes().len(),
+                layout.children().len()
+            );
+        }
+        Ok(Self::Struct(
+            st.dtypes()
+                .iter()
+                .zip(layout.children())
+                .map(|(field_dtype, field_layout)| {
+                    Self::from_layout(field_dtype, field_layout, leaves)
+                })
+                .collect::<VortexResult<_>>()?,
+        ))
+    }
+
+    /// Split a column into the chunks of its leaves.
+    fn split(&self, column: ArrayData, leaf_chunks: &mut [Vec<ArrayData>]) -> VortexResult<()> {
+        match self {
+            Self::Leaf(idx) => leaf_chunks[*idx].extend(column_chunks(column)),
+            Self::Struct(fields) => {
+                for chunk in column_chunks(column) {
+                    for (field_shape, field) in
+                        fields.iter().zip_eq(chunk.into_struct()?.children())
+                    {
+                        field_shape.split(field, leaf_chunks)?;
+                    }
+                }
+            }
+            Self::Variant(storage) => {
+                for chunk in column_chunks(column) {
+                    storage.split(chunk.into_extension()?.storage(), leaf_chunks)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Split a column into its leaves, keeping it in one chunk even if it's a chunked array.
+    fn split_whole(
+        &self,
+        column: ArrayData,
+        leaf_chunks: &mut [Vec<ArrayData>],
+    ) -> VortexResult<()> {
+        match self {
+            Self::Leaf(idx) => leaf_chunks[*idx].push(column),
+            Self::Struct(fields) => {
+                let st = column.as_struct_array().ok_or_else(|| {
+                    vortex_err!("Expected a struct array, found {}", column.dtype())
+                })?;
+                for (idx, field_shape) in fields.iter().enumerate() {
+                    let field = st
+                        .field(idx)
+                        .ok_or_else(|| vortex_err!("Missing field {idx} of {}", column.dtype()))?;
+                    field_shape.split_whole(field, leaf_chunks)?;
+                }
+            }
+            Self::Variant(storage) => {
+                let ext = column.as_extension_array().ok_or_else(|| {
+                    vortex_err!("Expected an extension array, found {}", column.dtype())
+                })?;
+                storage.split_whole(ext.storage_data(), leaf_chunks)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn into_layout(
+        self,
+        leaf_layouts: &mut impl Iterator<Item = LayoutSpec>,
+        row_count: u64,
+    ) -> VortexResult<LayoutSpec> {
+        match self {
+            Self::Leaf(_) => leaf_layouts
+                .next()
+                .ok_or_else(|| vortex_err!("Missing layout of leaf column")),
+            Self::Struct(fields) => Ok(LayoutSpec::column(
+                fields
+                    .into_iter()
+                    .map(|field| field.into_layout(leaf_layouts, row_count))
+                    .collect::<VortexResult<_>>()?,
+                row_count,
+            )),
+            Self::Variant(storage) => storage.into_layout(leaf_layouts, row_count),
+        }
+    }
+}
+
+/// The chunks of a column, written as one chunk each.
+fn column_chunks(column: ArrayData) -> Vec<ArrayData> {
+    match ChunkedArray::try_from(column.clone()) {
+        Ok(chunked) => chunked.chunks().collect(),
+        Err(_) => vec![column],
+    }
+}
 
-        let mut rows_written = row_offsets[0];
+struct ChunkedColumnWriter {
+    metadata: StatsAccumulator,
+    /// Layouts of the data chunks, including those that were already written to the file before it
+    /// was reopened for appending.
+    chunks: Vec<LayoutSpec>,
+    /// Encrypts the messages of the column, if it is encrypted.
+    cipher: Option<Arc<MessageCipher>>,
+}
+
+impl ChunkedColumnWriter {
+    fn new(dtype: &DType, bloom_filter: bool, cipher: Option<Arc<MessageCipher>>) -> Self {
+        let mut metadata = StatsAccumulator::new(dtype, STATS_TO_WRITE.to_vec());
+        if bloom_filter {
+            metadata = metadata.with_bloom_filters();
+        }
+        Self {
+            metadata,
+            chunks: Vec::new(),
+            cipher,
+        }
+    }
+
+    /// Recover the state of a column writer from the chunked layout of a previously written column.
+    async fn resume<R: VortexReadAt>(
+        dtype: &DType,
+        layout: &LayoutSpec,
+        read: &R,
+        ctx: Arc<Context>,
+    ) -> VortexResult<Self> {
+        if layout.id() != CHUNKED_LAYOUT_ID {
+            vortex_bail!(
+                "Can only append to columns with a chunked layout, found {:?}",
+                layout.id()
+            );
+        }
+        if layout
+            .children()
+            .iter()
+            .any(|child| child.key_id().is_some())
+        {
+            vortex_bail!("Can't append to encrypted columns");
+        }
 
-        while let Some(chunk) = stream.try_next().await? {
-            rows_written += chunk.len() as u64;
+        match layout.metadata() {
+            Some(metadata_bytes) => {
+                // Keep writing bloom filters for columns that already have them.
+                let metadata = ChunkedMetadata::parse(metadata_bytes);
+                let mut writer = Self::new(dtype, metadata.has_bloom_filter, None);
+                let (stats_layout, data_chunks) =
+                    layout.children().split_first().ok_or_else(|| {
+                        vortex_err!("Chunked layout with metadata must have children")
+                    })?;
+                let [range] = stats_layout.buffers() else {
+                    vortex_bail!("Flat layout can have exactly 1 buffer");
+                };
+
+                let stats_table =
+                    read_flat_array(read, *range, ctx, metadata.table_dtype(dtype)).await?;
+                writer
+                    .metadata
+                    .push_stats_table(&StructArray::try_from(stats_table)?)?;
+                writer.chunks = data_chunks.to_vec();
+                Ok(writer)
+            }
+            None => {
+                let mut writer = Self::new(dtype, false, None);
+                writer.metadata.push_unknown(layout.children().len());
+                writer.chunks = layout.children().to_vec();
+                Ok(writer)
+            }
+        }
+    }
 
+    async fn write_chunks<W: VortexWrite>(
+        &mut self,
+        chunks: Vec<ArrayData>,
+        page_rows: Option<usize>,
+        msgs: &mut MessageWriter<W>,
+    ) -> VortexResult<()> {
+        for chunk in chunks {
             // accumulate the stats for the stats table
             self.metadata.push_chunk(&chunk)?;
 
-            // clear the stats that we don't want to serialize into the file
-            chunk.statistics().retain_only(STATS_TO_WRITE);
+            let row_count = chunk.len();
+            let layout = match page_rows {
+                Some(page_rows) if row_count > page_rows => {
+                    write_pages(chunk, page_rows, self.cipher.as_deref(), msgs).await?
+                }
+                _ => {
+                    // clear the stats that we don't want to serialize into the file
+                    chunk.statistics().retain_only(STATS_TO_WRITE);
 
-            msgs.write_batch(chunk).await?;
-            offsets.push(msgs.tell());
-            row_offsets.push(rows_written);
+                    let (range, checksum) =
+                        write_array_message(chunk, self.cipher.as_deref(), msgs).await?;
+                    with_message_checksums(LayoutSpec::flat(range, row_count as u64), [checksum])
+                }
+            };
+            self.chunks
+                .push(with_key_id(layout, self.cipher.as_deref()));
         }
 
-        self.batch_byte_offsets.push(offsets);
-        self.batch_row_offsets.push(row_offsets);
-
         Ok(())
     }
 
@@ -256,39 +749,22 @@ impl ColumnWriter {
         row_count: u64,
         msgs: &mut MessageWriter<W>,
     ) -> VortexResult<LayoutSpec> {
-        let data_chunks = self
-            .batch_byte_offsets
-            .into_iter()
-            .zip(self.batch_row_offsets.into_iter())
-            .flat_map(|(byte_offsets, row_offsets)| {
-                byte_offsets
-                    .into_iter()
-                    .tuple_windows::<(_, _)>()
-                    .map(|(begin, end)| ByteRange::new(begin, end))
-                    .zip(
-                        row_offsets
-                            .into_iter()
-                            .tuple_windows::<(_, _)>()
-                            .map(|(begin, end)| end - begin),
-                    )
-                    .map(|(range, len)| LayoutSpec::flat(range, len))
-            });
+        let data_chunks = self.chunks.into_iter();
 
-        if let Some(StatArray(metadata_array, present_stats)) = self.metadata.into_array()? {
+        if let Some(StatArray(metadata_array, metadata)) = self.metadata.into_array()? {
             let expected_n_data_chunks = metadata_array.len();
 
-            let stat_bitset = as_stat_bitset_bytes(&present_stats);
-
-            let metadata_array_begin = msgs.tell();
-            msgs.write_batch(metadata_array).await?;
-            let metadata_array_end = msgs.tell();
+            // The stats table of encrypted columns is encrypted too, since it holds their values.
+            let (range, checksum) =
+                write_array_message(metadata_array, self.cipher.as_deref(), msgs).await?;
+            let stats_layout = with_message_checksums(
+                LayoutSpec::flat(range, expected_n_data_chunks as u64),
+                [checksum],
+            );
 
-            let layouts = iter::once(LayoutSpec::flat(
-                ByteRange::new(metadata_array_begin, metadata_array_end),
-                expected_n_data_chunks as u64,
-            ))
-            .chain(data_chunks)
-            .collect::<Vec<_>>();
+            let layouts = iter::once(with_key_id(stats_layout, self.cipher.as_deref()))
+                .chain(data_chunks)
+                .collect::<Vec<_>>();
 
             if layouts.len() != expected_n_data_chunks + 1 {
                 vortex_bail!(
@@ -301,7 +777,7 @@ impl ColumnWriter {
             Ok(LayoutSpec::chunked(
                 layouts,
                 row_count,
-                Some(Bytes::from(stat_bitset)),
+                Some(Bytes::from(metadata.to_bytes())),
             ))
         } else {
             Ok(LayoutSpec::chunked(data_chunks.collect(), row_count, None))
@@ -309,6 +785,86 @@ impl ColumnWriter {
     }
 }
 
+/// Write a chunk as consecutive pages of at most `page_rows` rows, returning its paged flat layout.
+async fn write_pages<W: VortexWrite>(
+    chunk: ArrayData,
+    page_rows: usize,
+    cipher: Option<&MessageCipher>,
+    msgs: &mut MessageWriter<W>,
+) -> VortexResult<LayoutSpec> {
+    let row_count = chunk.len();
+    let n_pages = row_count.div_ceil(page_rows);
+    if n_pages > LayoutPartId::MAX as usize {
+        vortex_bail!(
+            "Chunk of {row_count} rows has more than {} pages of {page_rows} rows",
+            LayoutPartId::MAX
+        );
+    }
+
+    let mut pages = Vec::with_capacity(n_pages);
+    let mut checksums = Vec::with_capacity(n_pages);
+    for begin in (0..row_count).step_by(page_rows) {
+        let page = slice(&chunk, begin, (begin + page_rows).min(row_count))?;
+        page.statistics().retain_only(STATS_TO_WRITE);
+
+        let (range, checksum) = write_array_message(page, cipher, msgs).await?;
+        pages.push(range);
+        checksums.push(checksum);
+    }
+
+    let page_index = PageIndex::from_page_row_counts(
+        (0..row_count)
+            .step_by(page_rows)
+            .map(|begin| (page_rows.min(row_count - begin)) as u64),
+    );
+    Ok(with_message_checksums(
+        LayoutSpec::paged(pages, Bytes::from(page_index.to_bytes()), row_count as u64),
+        checksums,
+    ))
+}
+
+/// Write an array as a single message, encrypted if a cipher is given, returning its byte range and
+/// its checksum if writing checksums.
+async fn write_array_message<W: VortexWrite>(
+    array: ArrayData,
+    cipher: Option<&MessageCipher>,
+    msgs: &mut MessageWriter<W>,
+) -> VortexResult<(ByteRange, Option<u64>)> {
+    let begin = msgs.tell();
+    match cipher {
+        None => msgs.write_batch(array).await?,
+        Some(cipher) => {
+            let mut message = MessageWriter::new(Vec::new());
+            message.write_batch(array).await?;
+            msgs.write_raw_message(cipher.encrypt(&message.into_inner())?)
+                .await?;
+        }
+    }
+    Ok((
+        ByteRange::new(begin, msgs.tell()),
+        msgs.last_message_checksum(),
+    ))
+}
+
+/// Record the id of the key of an encrypted flat layout.
+fn with_key_id(layout: LayoutSpec, cipher: Option<&MessageCipher>) -> LayoutSpec {
+    match cipher {
+        Some(cipher) => layout.with_key_id(cipher.key_id().to_string()),
+        None => layout,
+    }
+}
+
+/// Record the checksums of the messages of a flat layout, if they were written with checksums.
+fn with_message_checksums(
+    layout: LayoutSpec,
+    checksums: impl IntoIterator<Item = Option<u64>>,
+) -> LayoutSpec {
+    match checksums.into_iter().collect::<Option<Vec<_>>>() {
+        Some(checksums) => layout.with_checksums(checksums),
+        None => layout,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use flatbuffers::FlatBufferBuilder;
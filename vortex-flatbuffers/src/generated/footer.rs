@@ -152,6 +152,12 @@ pub enum LayoutOffset {}
 /// `encoding` to embed additional information that may be useful for the reader. For example, the `ChunkedLayout`
 /// uses the first byte of the `metadata` array as a boolean to indicate whether the first child Layout represents
 /// the statistics table for the other chunks. 
+///
+/// The optional `checksums` hold the xxHash64 of the bytes of each of the `buffers`, allowing readers to detect
+/// corrupted or truncated data before decoding it.
+///
+/// The optional `key_id` identifies the key that the `buffers` are encrypted with using AES-256-GCM. Readers
+/// must resolve the key from its id in order to decrypt the buffers.
 pub struct Layout<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
@@ -170,6 +176,8 @@ impl<'a> Layout<'a> {
   pub const VT_CHILDREN: flatbuffers::VOffsetT = 8;
   pub const VT_ROW_COUNT: flatbuffers::VOffsetT = 10;
   pub const VT_METADATA: flatbuffers::VOffsetT = 12;
+  pub const VT_CHECKSUMS: flatbuffers::VOffsetT = 14;
+  pub const VT_KEY_ID: flatbuffers::VOffsetT = 16;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -182,6 +190,8 @@ impl<'a> Layout<'a> {
   ) -> flatbuffers::WIPOffset<Layout<'bldr>> {
     let mut builder = LayoutBuilder::new(_fbb);
     builder.add_row_count(args.row_count);
+    if let Some(x) = args.key_id { builder.add_key_id(x); }
+    if let Some(x) = args.checksums { builder.add_checksums(x); }
     if let Some(x) = args.metadata { builder.add_metadata(x); }
     if let Some(x) = args.children { builder.add_children(x); }
     if let Some(x) = args.buffers { builder.add_buffers(x); }
@@ -225,6 +235,20 @@ impl<'a> Layout<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Layout::VT_METADATA, None)}
   }
+  #[inline]
+  pub fn checksums(&self) -> Option<flatbuffers::Vector<'a, u64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u64>>>(Layout::VT_CHECKSUMS, None)}
+  }
+  #[inline]
+  pub fn key_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Layout::VT_KEY_ID, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for Layout<'_> {
@@ -239,6 +263,8 @@ impl flatbuffers::Verifiable for Layout<'_> {
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Layout>>>>("children", Self::VT_CHILDREN, false)?
      .visit_field::<u64>("row_count", Self::VT_ROW_COUNT, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("metadata", Self::VT_METADATA, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u64>>>("checksums", Self::VT_CHECKSUMS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("key_id", Self::VT_KEY_ID, false)?
      .finish();
     Ok(())
   }
@@ -249,6 +275,8 @@ pub struct LayoutArgs<'a> {
     pub children: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Layout<'a>>>>>,
     pub row_count: u64,
     pub metadata: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub checksums: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u64>>>,
+    pub key_id: Option<flatbuffers::WIPOffset<&'a str>>,
 }
 impl<'a> Default for LayoutArgs<'a> {
   #[inline]
@@ -259,6 +287,8 @@ impl<'a> Default for LayoutArgs<'a> {
       children: None,
       row_count: 0,
       metadata: None,
+      checksums: None,
+      key_id: None,
     }
   }
 }
@@ -289,6 +319,14 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LayoutBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Layout::VT_METADATA, metadata);
   }
   #[inline]
+  pub fn add_checksums(&mut self, checksums: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Layout::VT_CHECKSUMS, checksums);
+  }
+  #[inline]
+  pub fn add_key_id(&mut self, key_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Layout::VT_KEY_ID, key_id);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LayoutBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     LayoutBuilder {
@@ -311,6 +349,8 @@ impl core::fmt::Debug for Layout<'_> {
       ds.field("children", &self.children());
       ds.field("row_count", &self.row_count());
       ds.field("metadata", &self.metadata());
+      ds.field("checksums", &self.checksums());
+      ds.field("key_id", &self.key_id());
       ds.finish()
   }
 }
@@ -542,6 +542,7 @@ impl<'a> Batch<'a> {
   pub const VT_LENGTH: flatbuffers::VOffsetT = 6;
   pub const VT_BUFFERS: flatbuffers::VOffsetT = 8;
   pub const VT_BUFFER_SIZE: flatbuffers::VOffsetT = 10;
+  pub const VT_CHECKSUM: flatbuffers::VOffsetT = 12;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -553,6 +554,7 @@ impl<'a> Batch<'a> {
     args: &'args BatchArgs<'args>
   ) -> flatbuffers::WIPOffset<Batch<'bldr>> {
     let mut builder = BatchBuilder::new(_fbb);
+    if let Some(x) = args.checksum { builder.add_checksum(x); }
     builder.add_buffer_size(args.buffer_size);
     builder.add_length(args.length);
     if let Some(x) = args.buffers { builder.add_buffers(x); }
@@ -589,6 +591,14 @@ impl<'a> Batch<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<u64>(Batch::VT_BUFFER_SIZE, Some(0)).unwrap()}
   }
+  /// The xxHash64 of the `buffer_size` bytes of buffers following the message, if written with checksums.
+  #[inline]
+  pub fn checksum(&self) -> Option<u64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Batch::VT_CHECKSUM, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for Batch<'_> {
@@ -602,6 +612,7 @@ impl flatbuffers::Verifiable for Batch<'_> {
      .visit_field::<u64>("length", Self::VT_LENGTH, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Buffer>>>("buffers", Self::VT_BUFFERS, false)?
      .visit_field::<u64>("buffer_size", Self::VT_BUFFER_SIZE, false)?
+     .visit_field::<u64>("checksum", Self::VT_CHECKSUM, false)?
      .finish();
     Ok(())
   }
@@ -611,6 +622,7 @@ pub struct BatchArgs<'a> {
     pub length: u64,
     pub buffers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Buffer>>>,
     pub buffer_size: u64,
+    pub checksum: Option<u64>,
 }
 impl<'a> Default for BatchArgs<'a> {
   #[inline]
@@ -620,6 +632,7 @@ impl<'a> Default for BatchArgs<'a> {
       length: 0,
       buffers: None,
       buffer_size: 0,
+      checksum: None,
     }
   }
 }
@@ -646,6 +659,10 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BatchBuilder<'a, 'b, A> {
     self.fbb_.push_slot::<u64>(Batch::VT_BUFFER_SIZE, buffer_size, 0);
   }
   #[inline]
+  pub fn add_checksum(&mut self, checksum: u64) {
+    self.fbb_.push_slot_always::<u64>(Batch::VT_CHECKSUM, checksum);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> BatchBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     BatchBuilder {
@@ -667,6 +684,7 @@ impl core::fmt::Debug for Batch<'_> {
       ds.field("length", &self.length());
       ds.field("buffers", &self.buffers());
       ds.field("buffer_size", &self.buffer_size());
+      ds.field("checksum", &self.checksum());
       ds.finish()
   }
 }
@@ -688,6 +706,7 @@ impl<'a> flatbuffers::Follow<'a> for Page<'a> {
 impl<'a> Page<'a> {
   pub const VT_BUFFER_SIZE: flatbuffers::VOffsetT = 4;
   pub const VT_PADDING: flatbuffers::VOffsetT = 6;
+  pub const VT_CHECKSUM: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -699,6 +718,7 @@ impl<'a> Page<'a> {
     args: &'args PageArgs
   ) -> flatbuffers::WIPOffset<Page<'bldr>> {
     let mut builder = PageBuilder::new(_fbb);
+    if let Some(x) = args.checksum { builder.add_checksum(x); }
     builder.add_buffer_size(args.buffer_size);
     builder.add_padding(args.padding);
     builder.finish()
@@ -719,6 +739,14 @@ impl<'a> Page<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<u16>(Page::VT_PADDING, Some(0)).unwrap()}
   }
+  /// The xxHash64 of the page buffer, excluding padding, if written with checksums.
+  #[inline]
+  pub fn checksum(&self) -> Option<u64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Page::VT_CHECKSUM, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for Page<'_> {
@@ -730,6 +758,7 @@ impl flatbuffers::Verifiable for Page<'_> {
     v.visit_table(pos)?
      .visit_field::<u32>("buffer_size", Self::VT_BUFFER_SIZE, false)?
      .visit_field::<u16>("padding", Self::VT_PADDING, false)?
+     .visit_field::<u64>("checksum", Self::VT_CHECKSUM, false)?
      .finish();
     Ok(())
   }
@@ -737,6 +766,7 @@ impl flatbuffers::Verifiable for Page<'_> {
 pub struct PageArgs {
     pub buffer_size: u32,
     pub padding: u16,
+    pub checksum: Option<u64>,
 }
 impl<'a> Default for PageArgs {
   #[inline]
@@ -744,6 +774,7 @@ impl<'a> Default for PageArgs {
     PageArgs {
       buffer_size: 0,
       padding: 0,
+      checksum: None,
     }
   }
 }
@@ -762,6 +793,10 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PageBuilder<'a, 'b, A> {
     self.fbb_.push_slot::<u16>(Page::VT_PADDING, padding, 0);
   }
   #[inline]
+  pub fn add_checksum(&mut self, checksum: u64) {
+    self.fbb_.push_slot_always::<u64>(Page::VT_CHECKSUM, checksum);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PageBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     PageBuilder {
@@ -781,6 +816,7 @@ impl core::fmt::Debug for Page<'_> {
     let mut ds = f.debug_struct("Page");
       ds.field("buffer_size", &self.buffer_size());
       ds.field("padding", &self.padding());
+      ds.field("checksum", &self.checksum());
       ds.finish()
   }
 }
@@ -169,3 +169,16 @@ impl VortexWrite for ObjectStoreWriter {
         Ok(())
     }
 }
+
+impl Drop for ObjectStoreWriter {
+    /// Aborts the upload if it wasn't finished, e.g. because writing failed or was cancelled, such
+    /// that its parts don't linger in the object store.
+    fn drop(&mut self) {
+        if let Some(mp) = self.multipart.take() {
+            if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                // Aborting is best-effort, the upload is abandoned either way.
+                drop(handle.spawn(mp.abort()));
+            }
+        }
+    }
+}
@@ -28,13 +28,20 @@ mod test {
     use futures_executor::block_on;
     use futures_util::{pin_mut, StreamExt, TryStreamExt};
     use itertools::Itertools;
-    use vortex_array::array::{ChunkedArray, PrimitiveArray, PrimitiveEncoding};
-    use vortex_array::encoding::EncodingVTable;
+    use vortex_array::accessor::ArrayAccessor;
+    use vortex_array::array::{
+        ChunkedArray, PrimitiveArray, PrimitiveEncoding, StructArray, VarBinViewArray,
+    };
+    use vortex_array::encoding::{EncodingRef, EncodingVTable};
     use vortex_array::stream::ArrayStreamExt;
-    use vortex_array::{ArrayDType, Context, IntoArrayData, IntoArrayVariant};
+    use vortex_array::validity::Validity;
+    use vortex_array::variants::StructArrayTrait;
+    use vortex_array::{ArrayDType, ArrayData, Context, IntoArrayData, IntoArrayVariant};
     use vortex_buffer::Buffer;
     use vortex_error::VortexResult;
     use vortex_io::VortexBufReader;
+    use vortex_lz4::{Lz4Array, Lz4Encoding};
+    use vortex_zstd::{ZstdArray, ZstdEncoding};
 
     use crate::stream_reader::StreamArrayReader;
     use crate::stream_writer::StreamArrayWriter;
@@ -119,4 +126,69 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_block_compressed_round_trip() -> VortexResult<()> {
+        let strings = (0..1000).map(|i| format!("value-{}", i % 10)).collect_vec();
+        // The chunked field can only find its chunks by reading its chunk offsets, which are
+        // compressed too.
+        let numbers = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from((0..500i64).collect_vec()).into_array(),
+                PrimitiveArray::from((500..1000i64).collect_vec()).into_array(),
+            ],
+            PrimitiveArray::from(vec![0i64]).dtype().clone(),
+        )?;
+        let st = StructArray::try_new(
+            ["numbers".into(), "strings".into()].into(),
+            vec![
+                numbers.into_array(),
+                VarBinViewArray::from_iter_str(strings.iter().map(String::as_str)).into_array(),
+            ],
+            1000,
+            Validity::NonNullable,
+        )?
+        .into_array();
+
+        let ctx = Arc::new(
+            Context::default()
+                .with_encodings([&ZstdEncoding as EncodingRef, &Lz4Encoding as EncodingRef]),
+        );
+        for buffer in [
+            write_ipc(ZstdArray::encode(&st, 3)?),
+            write_ipc(Lz4Array::encode(&st)?),
+        ] {
+            let buffer = Buffer::from(buffer);
+            let read: ArrayData =
+                StreamArrayReader::try_new(VortexBufReader::new(buffer), ctx.clone())
+                    .await?
+                    .load_dtype()
+                    .await?
+                    .into_array_stream()
+                    .collect_chunked()
+                    .await?
+                    .into_array();
+            let read = read.into_struct()?;
+            assert_eq!(
+                read.field(0)
+                    .unwrap()
+                    .into_primitive()?
+                    .maybe_null_slice::<i64>(),
+                (0..1000i64).collect_vec()
+            );
+            assert_eq!(
+                read.field(1)
+                    .unwrap()
+                    .into_varbinview()?
+                    .with_iterator(|iter| {
+                        iter.map(|s| String::from_utf8(s.unwrap().to_vec()).unwrap())
+                            .collect_vec()
+                    })?,
+                strings
+            );
+        }
+
+        Ok(())
+    }
 }
@@ -1,10 +1,13 @@
+use std::hash::Hasher;
+
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
 use itertools::Itertools;
+use twox_hash::XxHash64;
 use vortex_array::stats::ArrayStatistics;
 use vortex_array::{flatbuffers as fba, ArrayData};
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
-use vortex_error::{VortexExpect as _, VortexUnwrap};
+use vortex_error::{vortex_bail, VortexExpect as _, VortexResult, VortexUnwrap};
 use vortex_flatbuffers::message::Compression;
 use vortex_flatbuffers::{message as fb, FlatBufferRoot, WriteFlatBuffer};
 
@@ -20,9 +23,34 @@ pub enum IPCMessage<'a> {
 }
 
 pub struct IPCSchema<'a>(pub &'a DType);
-pub struct IPCBatch<'a>(pub &'a ArrayData);
+/// An array message, with the optional checksum of its buffers.
+pub struct IPCBatch<'a>(pub &'a ArrayData, pub Option<u64>);
 pub struct IPCArray<'a>(pub &'a ArrayData, usize);
-pub struct IPCPage<'a>(pub &'a Buffer);
+/// A page message, with the optional checksum of its buffer.
+pub struct IPCPage<'a>(pub &'a Buffer, pub Option<u64>);
+
+/// Create the hasher used for message checksums, i.e. xxHash64 with a zero seed.
+pub fn checksum_hasher() -> XxHash64 {
+    XxHash64::with_seed(0)
+}
+
+/// The checksum of the given bytes, as recorded in messages and file layouts.
+pub fn checksum(bytes: &[u8]) -> u64 {
+    let mut hasher = checksum_hasher();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// Check that the given bytes match a recorded checksum.
+pub fn verify_checksum(bytes: &[u8], expected: u64) -> VortexResult<()> {
+    let actual = checksum(bytes);
+    if actual != expected {
+        vortex_bail!(
+            "Checksum mismatch, expected {expected:#018x} but the data hashes to {actual:#018x}"
+        );
+    }
+    Ok(())
+}
 
 impl FlatBufferRoot for IPCMessage<'_> {}
 
@@ -99,6 +127,7 @@ impl WriteFlatBuffer for IPCBatch<'_> {
                 length,
                 buffers,
                 buffer_size: offset as u64,
+                checksum: self.1,
             },
         )
     }
@@ -169,6 +198,7 @@ impl WriteFlatBuffer for IPCPage<'_> {
             &fb::PageArgs {
                 buffer_size: buffer_size.try_into().vortex_unwrap(),
                 padding: padding_size.try_into().vortex_unwrap(),
+                checksum: self.1,
             },
         )
     }
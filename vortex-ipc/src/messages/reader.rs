@@ -12,6 +12,8 @@ use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult, VortexUn
 use vortex_flatbuffers::message as fb;
 use vortex_io::{VortexBufReader, VortexReadAt};
 
+use crate::messages::verify_checksum;
+
 pub const MESSAGE_PREFIX_LENGTH: usize = 4;
 
 /// A stateful reader of [`Message`s][fb::Message] from a stream.
@@ -20,6 +22,7 @@ pub struct MessageReader<R> {
     message: Option<Bytes>,
     prev_message: Option<Bytes>,
     finished: bool,
+    verify_checksums: bool,
 }
 
 impl<R: VortexReadAt> MessageReader<R> {
@@ -29,11 +32,20 @@ impl<R: VortexReadAt> MessageReader<R> {
             message: None,
             prev_message: None,
             finished: false,
+            verify_checksums: true,
         };
         reader.load_next_message().await?;
         Ok(reader)
     }
 
+    /// Whether to verify the checksums of messages that have them, which is the default.
+    ///
+    /// Skipping verification saves hashing every buffer when reading from trusted sources.
+    pub fn with_checksum_verification(mut self, verify: bool) -> Self {
+        self.verify_checksums = verify;
+        self
+    }
+
     async fn load_next_message(&mut self) -> VortexResult<bool> {
         let mut buffer = match self.read.read_bytes(MESSAGE_PREFIX_LENGTH as u64).await {
             Ok(b) => b,
@@ -124,7 +136,8 @@ impl<R: VortexReadAt> MessageReader<R> {
 
         let mut array_reader = ArrayMessageReader::from_fb_bytes(Buffer::from(
             self.message.clone().vortex_expect("MessageReader: message"),
-        ));
+        ))
+        .with_checksum_verification(self.verify_checksums);
 
         // Issue a single read to grab all buffers
         let all_buffers = self.read.read_bytes(all_buffers_size as u64).await?;
@@ -200,13 +213,15 @@ impl<R: VortexReadAt> MessageReader<R> {
 
         let buffer_len = page_msg.buffer_size() as u64;
         let total_len = buffer_len + (page_msg.padding() as u64);
+        let checksum = page_msg.checksum().filter(|_| self.verify_checksums);
 
         let buffer = self.read.read_bytes(total_len).await?;
-        let page_buffer = Ok(Some(Buffer::from(
-            buffer.slice(..usize::try_from(buffer_len)?),
-        )));
+        let page_buffer = buffer.slice(..usize::try_from(buffer_len)?);
+        if let Some(checksum) = checksum {
+            verify_checksum(&page_buffer, checksum)?;
+        }
         let _ = self.next().await?;
-        page_buffer
+        Ok(Some(Buffer::from(page_buffer)))
     }
 
     pub fn into_inner(self) -> VortexBufReader<R> {
@@ -226,6 +241,7 @@ pub struct ArrayMessageReader {
     state: ReadState,
     fb_msg: Option<Buffer>,
     buffers: Vec<Buffer>,
+    verify_checksums: bool,
 }
 
 impl Default for ArrayMessageReader {
@@ -240,6 +256,7 @@ impl ArrayMessageReader {
             state: ReadState::Init,
             fb_msg: None,
             buffers: Vec::new(),
+            verify_checksums: true,
         }
     }
 
@@ -248,9 +265,16 @@ impl ArrayMessageReader {
             state: ReadState::ReadingBuffers,
             fb_msg: Some(fb_bytes),
             buffers: Vec::new(),
+            verify_checksums: true,
         }
     }
 
+    /// Whether to verify the checksum of the message buffers, if it has one, which is the default.
+    pub fn with_checksum_verification(mut self, verify: bool) -> Self {
+        self.verify_checksums = verify;
+        self
+    }
+
     pub fn read(&mut self, mut bytes: Bytes) -> VortexResult<Option<usize>> {
         match self.state {
             ReadState::Init => {
@@ -279,6 +303,9 @@ impl ArrayMessageReader {
                 // Initialize the column's buffers for a vectored read.
                 // To start with, we include the padding and then truncate the buffers after.
                 let batch_msg = self.fb_bytes_as_batch()?;
+                if let Some(checksum) = batch_msg.checksum().filter(|_| self.verify_checksums) {
+                    verify_checksum(&bytes, checksum)?;
+                }
                 let all_buffers_size = batch_msg.buffer_size();
                 let ipc_buffers = batch_msg.buffers().unwrap_or_default();
                 let buffers = ipc_buffers
@@ -347,9 +374,14 @@ impl ArrayMessageReader {
 
 #[cfg(test)]
 mod test {
+    use std::sync::Arc;
+
     use bytes::Bytes;
     use futures_executor::block_on;
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::{ArrayDType, ArrayData, Context, IntoArrayData, IntoArrayVariant};
     use vortex_buffer::Buffer;
+    use vortex_error::VortexResult;
     use vortex_io::VortexBufReader;
 
     use crate::messages::reader::MessageReader;
@@ -374,4 +406,68 @@ mod test {
             .unwrap();
         assert_eq!(read_page, Buffer::from(Bytes::from("somevalue")));
     }
+
+    fn write_checksummed_batch() -> (Vec<u8>, PrimitiveArray) {
+        let array = PrimitiveArray::from((0u32..16).collect::<Vec<_>>());
+        let mut writer = MessageWriter::new(Vec::new()).with_checksums(true);
+        block_on(async { writer.write_batch(array.clone().into_array()).await }).unwrap();
+        assert!(writer.last_message_checksum().is_some());
+        (writer.into_inner(), array)
+    }
+
+    fn read_batch(
+        written: Vec<u8>,
+        array: &PrimitiveArray,
+        verify: bool,
+    ) -> VortexResult<ArrayData> {
+        block_on(async {
+            MessageReader::try_new(VortexBufReader::new(Buffer::from(written)))
+                .await?
+                .with_checksum_verification(verify)
+                .maybe_read_chunk(Arc::new(Context::default()), array.dtype().clone())
+                .await
+        })
+        .map(|chunk| chunk.unwrap())
+    }
+
+    #[test]
+    fn read_checksummed_batch() {
+        let (written, array) = write_checksummed_batch();
+        let read = read_batch(written, &array, true).unwrap();
+        assert_eq!(
+            read.into_primitive().unwrap().maybe_null_slice::<u32>(),
+            array.maybe_null_slice::<u32>()
+        );
+    }
+
+    #[test]
+    fn detect_corrupted_batch() {
+        let (mut written, array) = write_checksummed_batch();
+        let last = written.len() - 1;
+        written[last] ^= 1;
+
+        assert!(read_batch(written.clone(), &array, true).is_err());
+        // Verification can be skipped.
+        assert!(read_batch(written, &array, false).is_ok());
+    }
+
+    #[test]
+    fn detect_corrupted_page() {
+        let mut writer = MessageWriter::new(Vec::new()).with_checksums(true);
+        block_on(async {
+            writer
+                .write_page(Buffer::from(Bytes::from("somevalue")))
+                .await
+        })
+        .unwrap();
+        let mut written = writer.into_inner();
+        let value_pos = written.windows(9).position(|w| w == b"somevalue").unwrap();
+        written[value_pos] = b'S';
+
+        let mut reader = block_on(async {
+            MessageReader::try_new(VortexBufReader::new(Buffer::from(written))).await
+        })
+        .unwrap();
+        assert!(block_on(async { reader.maybe_read_page().await }).is_err());
+    }
 }
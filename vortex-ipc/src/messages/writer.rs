@@ -1,9 +1,11 @@
 #![allow(clippy::assertions_on_constants)]
+use std::hash::Hasher;
 use std::io;
 
 use bytes::Bytes;
 use flatbuffers::FlatBufferBuilder;
 use itertools::Itertools;
+use twox_hash::XxHash64;
 use vortex_array::ArrayData;
 use vortex_buffer::io_buf::IoBuf;
 use vortex_buffer::Buffer;
@@ -12,7 +14,7 @@ use vortex_error::VortexUnwrap;
 use vortex_flatbuffers::WriteFlatBuffer;
 use vortex_io::VortexWrite;
 
-use crate::messages::{IPCBatch, IPCMessage, IPCPage, IPCSchema};
+use crate::messages::{checksum, checksum_hasher, IPCBatch, IPCMessage, IPCPage, IPCSchema};
 use crate::ALIGNMENT;
 
 static ZEROS: [u8; 512] = [0; 512];
@@ -24,6 +26,11 @@ pub struct MessageWriter<W> {
     alignment: usize,
 
     scratch: Option<Vec<u8>>,
+
+    checksums: bool,
+    /// Hashes all bytes of the message being written, if writing checksums.
+    message_hasher: Option<XxHash64>,
+    last_message_checksum: Option<u64>,
 }
 
 impl<W: VortexWrite> MessageWriter<W> {
@@ -34,6 +41,31 @@ impl<W: VortexWrite> MessageWriter<W> {
             pos: 0,
             alignment: ALIGNMENT,
             scratch: Some(Vec::new()),
+            checksums: false,
+            message_hasher: None,
+            last_message_checksum: None,
+        }
+    }
+
+    /// Record checksums of the buffers of batch and page messages, which readers verify.
+    pub fn with_checksums(mut self, checksums: bool) -> Self {
+        self.checksums = checksums;
+        self
+    }
+
+    /// The checksum of all bytes of the last batch or page message, if writing checksums.
+    ///
+    /// Unlike the checksum recorded in the message, which only covers its buffers, this covers
+    /// the message flatbuffer as well, so it can be stored elsewhere to verify the whole message.
+    pub fn last_message_checksum(&self) -> Option<u64> {
+        self.last_message_checksum
+    }
+
+    /// Create a writer that continues a stream into which `pos` bytes have already been written.
+    pub fn new_at(write: W, pos: u64) -> Self {
+        Self {
+            pos,
+            ..Self::new(write)
         }
     }
 
@@ -46,6 +78,17 @@ impl<W: VortexWrite> MessageWriter<W> {
         self.pos
     }
 
+    /// Write zero padding until the current position is a multiple of the alignment.
+    pub async fn align(&mut self) -> io::Result<()> {
+        let padding: usize = (self.pos.next_multiple_of(self.alignment as u64) - self.pos)
+            .try_into()
+            .vortex_unwrap();
+        if padding > 0 {
+            self.write_all(Bytes::from(&ZEROS[..padding])).await?;
+        }
+        Ok(())
+    }
+
     pub async fn write_dtype_raw(&mut self, dtype: &DType) -> io::Result<()> {
         let fb = IPCSchema(dtype);
         let mut fbb = FlatBufferBuilder::new();
@@ -75,30 +118,44 @@ impl<W: VortexWrite> MessageWriter<W> {
     pub async fn write_batch(&mut self, chunk: ArrayData) -> io::Result<()> {
         let buffer_offsets = chunk.all_buffer_offsets(self.alignment);
 
-        // Serialize the Chunk message.
-        self.write_message(IPCMessage::Batch(IPCBatch(&chunk)))
-            .await?;
-
-        // Keep track of the offset to add padding after each buffer.
-        let mut current_offset = 0;
-        for (buffer, buffer_end) in chunk
+        // Each buffer is followed by padding up to the offset of the next one.
+        let buffers = chunk
             .depth_first_traversal()
             .flat_map(|data| data.into_buffer().into_iter())
-            .zip_eq(buffer_offsets.into_iter().skip(1))
-        {
-            let buffer_len = buffer.len();
-            let buffer_end: usize = buffer_end.try_into().vortex_unwrap();
+            .zip_eq(buffer_offsets.into_iter().tuple_windows())
+            .map(|(buffer, (begin, end))| {
+                let padding: usize = (end - begin).try_into().vortex_unwrap();
+                let padding = padding - buffer.len();
+                (buffer, padding)
+            })
+            .collect::<Vec<_>>();
+        let buffers_checksum = self.checksums.then(|| {
+            let mut hasher = checksum_hasher();
+            for (buffer, padding) in &buffers {
+                hasher.write(buffer.as_slice());
+                hasher.write(&ZEROS[..*padding]);
+            }
+            hasher.finish()
+        });
+
+        self.begin_message();
+        // Serialize the Chunk message.
+        self.write_message(IPCMessage::Batch(IPCBatch(&chunk, buffers_checksum)))
+            .await?;
+        for (buffer, padding) in buffers {
             self.write_all(buffer).await?;
-            let padding = buffer_end - current_offset - buffer_len;
             self.write_all(Bytes::from(&ZEROS[..padding])).await?;
-            current_offset = buffer_end;
         }
+        self.finish_message();
 
         Ok(())
     }
 
     pub async fn write_page(&mut self, buffer: Buffer) -> io::Result<()> {
-        self.write_message(IPCMessage::Page(IPCPage(&buffer)))
+        let buffer_checksum = self.checksums.then(|| checksum(buffer.as_slice()));
+
+        self.begin_message();
+        self.write_message(IPCMessage::Page(IPCPage(&buffer, buffer_checksum)))
             .await?;
         let buffer_len = buffer.len();
         self.write_all(buffer).await?;
@@ -106,10 +163,33 @@ impl<W: VortexWrite> MessageWriter<W> {
         let aligned_size = buffer_len.next_multiple_of(self.alignment);
         let padding = aligned_size - buffer_len;
         self.write_all(Bytes::from(&ZEROS[..padding])).await?;
+        self.finish_message();
+
+        Ok(())
+    }
 
+    /// Write the bytes of an opaque message, e.g. an encrypted one, followed by padding up to the
+    /// alignment.
+    ///
+    /// Readers must know how to interpret the message, since it doesn't carry a flatbuffer header.
+    pub async fn write_raw_message(&mut self, message: Vec<u8>) -> io::Result<()> {
+        self.begin_message();
+        let len = message.len();
+        self.write_all(message).await?;
+        let padding = len.next_multiple_of(self.alignment) - len;
+        self.write_all(Bytes::from(&ZEROS[..padding])).await?;
+        self.finish_message();
         Ok(())
     }
 
+    fn begin_message(&mut self) {
+        self.message_hasher = self.checksums.then(checksum_hasher);
+    }
+
+    fn finish_message(&mut self) {
+        self.last_message_checksum = self.message_hasher.take().map(|hasher| hasher.finish());
+    }
+
     pub async fn write_message<F: WriteFlatBuffer>(&mut self, flatbuffer: F) -> io::Result<()> {
         // We reuse the scratch buffer each time and then replace it at the end.
         // The scratch buffer may be missing if a previous write failed. We could use scopeguard
@@ -155,6 +235,9 @@ impl<W: VortexWrite> MessageWriter<W> {
     }
 
     async fn write_all<B: IoBuf>(&mut self, buf: B) -> io::Result<B> {
+        if let Some(hasher) = self.message_hasher.as_mut() {
+            hasher.write(buf.as_slice());
+        }
         let buf = self.write.write_all(buf).await?;
         self.pos += buf.bytes_init() as u64;
         Ok(buf)
@@ -27,6 +27,12 @@ impl<R: VortexReadAt> StreamArrayReader<R> {
         })
     }
 
+    /// Whether to verify the checksums of buffers that have them, which is the default.
+    pub fn with_checksum_verification(mut self, verify: bool) -> Self {
+        self.msgs = self.msgs.with_checksum_verification(verify);
+        self
+    }
+
     pub fn with_dtype(mut self, dtype: Arc<DType>) -> Self {
         assert!(self.dtype.is_none(), "DType already set");
         self.dtype = Some(dtype);
@@ -31,6 +31,12 @@ impl<W: VortexWrite> StreamArrayWriter<W> {
         }
     }
 
+    /// Record checksums of the written buffers, which readers verify.
+    pub fn with_checksums(mut self, checksums: bool) -> Self {
+        self.msgs = self.msgs.with_checksums(checksums);
+        self
+    }
+
     pub fn array_layouts(&self) -> &[ArrayLayout] {
         &self.array_layouts
     }
@@ -40,6 +40,8 @@ pub struct Struct {
     pub dtypes: ::prost::alloc::vec::Vec<DType>,
     #[prost(bool, tag = "3")]
     pub nullable: bool,
+    #[prost(uint32, repeated, tag = "4")]
+    pub field_ids: ::prost::alloc::vec::Vec<u32>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct List {
@@ -49,6 +51,15 @@ pub struct List {
     pub nullable: bool,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FixedSizeList {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub element_type: ::core::option::Option<::prost::alloc::boxed::Box<DType>>,
+    #[prost(uint32, tag = "2")]
+    pub size: u32,
+    #[prost(bool, tag = "3")]
+    pub nullable: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Extension {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
@@ -59,7 +70,7 @@ pub struct Extension {
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DType {
-    #[prost(oneof = "d_type::DtypeType", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9")]
+    #[prost(oneof = "d_type::DtypeType", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10")]
     pub dtype_type: ::core::option::Option<d_type::DtypeType>,
 }
 /// Nested message and enum types in `DType`.
@@ -84,11 +95,13 @@ pub mod d_type {
         List(::prost::alloc::boxed::Box<super::List>),
         #[prost(message, tag = "9")]
         Extension(::prost::alloc::boxed::Box<super::Extension>),
+        #[prost(message, tag = "10")]
+        FixedSizeList(::prost::alloc::boxed::Box<super::FixedSizeList>),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Field {
-    #[prost(oneof = "field::FieldType", tags = "1, 2")]
+    #[prost(oneof = "field::FieldType", tags = "1, 2, 3")]
     pub field_type: ::core::option::Option<field::FieldType>,
 }
 /// Nested message and enum types in `Field`.
@@ -99,6 +112,8 @@ pub mod field {
         Name(::prost::alloc::string::String),
         #[prost(uint64, tag = "2")]
         Index(u64),
+        #[prost(uint32, tag = "3")]
+        Id(u32),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -0,0 +1,52 @@
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::array::{DecimalArray, ExtensionArray, ExtensionEncoding};
+use vortex_array::encoding::EncodingRef;
+use vortex_array::{ArrayData, IntoArrayData};
+use vortex_dtype::DECIMAL_ID;
+use vortex_error::VortexResult;
+
+use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+use crate::{constants, SamplingCompressor};
+
+/// Compresses the unscaled integer values of decimals, e.g. with frame-of-reference and
+/// bit-packing.
+#[derive(Debug)]
+pub struct DecimalCompressor;
+
+impl EncodingCompressor for DecimalCompressor {
+    fn id(&self) -> &str {
+        DECIMAL_ID.as_ref()
+    }
+
+    fn cost(&self) -> u8 {
+        constants::DECIMAL_COST
+    }
+
+    fn can_compress(&self, array: &ArrayData) -> Option<&dyn EncodingCompressor> {
+        DecimalArray::try_from(array.clone())
+            .ok()
+            .map(|_| self as _)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &ArrayData,
+        like: Option<CompressionTree<'a>>,
+        ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let decimal_array = DecimalArray::try_from(array.clone())?;
+        let unscaled = ctx.named("unscaled").compress(
+            &decimal_array.unscaled_values(),
+            like.as_ref().and_then(|l| l.child(0)),
+        )?;
+        Ok(CompressedArray::compressed(
+            ExtensionArray::new(decimal_array.ext_dtype(), unscaled.array).into_array(),
+            Some(CompressionTree::new(self, vec![unscaled.path])),
+            array,
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([&ExtensionEncoding as EncodingRef])
+    }
+}
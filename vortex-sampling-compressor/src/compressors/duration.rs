@@ -0,0 +1,54 @@
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::array::{ExtensionArray, ExtensionEncoding, TemporalArray};
+use vortex_array::encoding::EncodingRef;
+use vortex_array::{ArrayData, IntoArrayData};
+use vortex_datetime_dtype::{TemporalMetadata, DURATION_ID};
+use vortex_error::VortexResult;
+
+use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+use crate::{constants, SamplingCompressor};
+
+/// Compresses the integer values of durations, e.g. with frame-of-reference and bit-packing.
+#[derive(Debug)]
+pub struct DurationCompressor;
+
+impl EncodingCompressor for DurationCompressor {
+    fn id(&self) -> &str {
+        DURATION_ID.as_ref()
+    }
+
+    fn cost(&self) -> u8 {
+        constants::DURATION_COST
+    }
+
+    fn can_compress(&self, array: &ArrayData) -> Option<&dyn EncodingCompressor> {
+        TemporalArray::try_from(array.clone())
+            .ok()
+            .filter(|temporal| {
+                matches!(temporal.temporal_metadata(), TemporalMetadata::Duration(_))
+            })
+            .map(|_| self as _)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &ArrayData,
+        like: Option<CompressionTree<'a>>,
+        ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let temporal_array = TemporalArray::try_from(array.clone())?;
+        let values = ctx.named("values").compress(
+            &temporal_array.temporal_values(),
+            like.as_ref().and_then(|l| l.child(0)),
+        )?;
+        Ok(CompressedArray::compressed(
+            ExtensionArray::new(temporal_array.ext_dtype(), values.array).into_array(),
+            Some(CompressionTree::new(self, vec![values.path])),
+            array,
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([&ExtensionEncoding as EncodingRef])
+    }
+}
@@ -0,0 +1,56 @@
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::array::{FixedSizeListArray, FixedSizeListEncoding};
+use vortex_array::encoding::{Encoding, EncodingRef};
+use vortex_array::{ArrayData, ArrayLen, IntoArrayData};
+use vortex_error::VortexResult;
+
+use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+use crate::{constants, SamplingCompressor};
+
+#[derive(Debug)]
+pub struct FixedSizeListCompressor;
+
+impl EncodingCompressor for FixedSizeListCompressor {
+    fn id(&self) -> &str {
+        FixedSizeListEncoding::ID.as_ref()
+    }
+
+    fn cost(&self) -> u8 {
+        constants::FIXED_SIZE_LIST_COST
+    }
+
+    fn can_compress(&self, array: &ArrayData) -> Option<&dyn EncodingCompressor> {
+        array.is_encoding(FixedSizeListEncoding::ID).then_some(self)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &ArrayData,
+        like: Option<CompressionTree<'a>>,
+        ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let list_array = FixedSizeListArray::try_from(array.clone())?;
+        let compressed_elements = ctx.named("elements").compress(
+            &list_array.elements(),
+            like.as_ref().and_then(|l| l.child(0)),
+        )?;
+        Ok(CompressedArray::compressed(
+            FixedSizeListArray::try_new(
+                compressed_elements.array,
+                list_array.list_size(),
+                list_array.len(),
+                list_array.validity(),
+            )?
+            .into_array(),
+            Some(CompressionTree::new(
+                self,
+                vec![compressed_elements.path, None],
+            )),
+            array,
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([&FixedSizeListEncoding as EncodingRef])
+    }
+}
@@ -0,0 +1,47 @@
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::encoding::{Encoding, EncodingRef};
+use vortex_array::{ArrayData, IntoArrayData};
+use vortex_error::VortexResult;
+use vortex_lz4::{Lz4Array, Lz4Encoding};
+
+use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+use crate::{constants, SamplingCompressor};
+
+#[derive(Debug)]
+pub struct Lz4Compressor;
+
+impl EncodingCompressor for Lz4Compressor {
+    fn id(&self) -> &str {
+        Lz4Encoding::ID.as_ref()
+    }
+
+    fn cost(&self) -> u8 {
+        constants::LZ4_COST
+    }
+
+    fn is_heavyweight(&self) -> bool {
+        true
+    }
+
+    fn can_compress(&self, _array: &ArrayData) -> Option<&dyn EncodingCompressor> {
+        // Any array can be compressed, since only its buffers are compressed.
+        Some(self)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &ArrayData,
+        _like: Option<CompressionTree<'a>>,
+        _ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        Ok(CompressedArray::compressed(
+            Lz4Array::encode(array)?.into_array(),
+            Some(CompressionTree::flat(self)),
+            array,
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([&Lz4Encoding as EncodingRef])
+    }
+}
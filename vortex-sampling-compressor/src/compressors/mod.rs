@@ -19,11 +19,15 @@ pub mod bitpacked;
 pub mod chunked;
 pub mod constant;
 pub mod date_time_parts;
+pub mod decimal;
 pub mod delta;
 pub mod dict;
+pub mod duration;
+pub mod fixed_size_list;
 pub mod r#for;
 pub mod fsst;
 pub mod list;
+pub mod lz4;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod roaring_bool;
 #[cfg(not(target_arch = "wasm32"))]
@@ -34,12 +38,19 @@ pub mod sparse;
 pub mod struct_;
 pub mod varbin;
 pub mod zigzag;
+pub mod zstd;
 
 pub trait EncodingCompressor: Sync + Send + Debug {
     fn id(&self) -> &str;
 
     fn cost(&self) -> u8;
 
+    /// Whether the encoding trades decode speed for size, like general-purpose block compression
+    /// codecs. These are only considered when the [`Objective`](crate::Objective) allows it.
+    fn is_heavyweight(&self) -> bool {
+        false
+    }
+
     fn can_compress(&self, array: &ArrayData) -> Option<&dyn EncodingCompressor>;
 
     fn compress<'a>(
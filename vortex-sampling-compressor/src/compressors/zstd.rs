@@ -0,0 +1,57 @@
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::encoding::{Encoding, EncodingRef};
+use vortex_array::{ArrayData, IntoArrayData};
+use vortex_error::VortexResult;
+use vortex_zstd::{ZstdArray, ZstdEncoding};
+
+use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+use crate::{constants, SamplingCompressor};
+
+pub const DEFAULT_ZSTD_COMPRESSOR: ZstdCompressor = ZstdCompressor { level: 3 };
+
+#[derive(Debug, Clone, Copy)]
+pub struct ZstdCompressor {
+    level: i32,
+}
+
+impl ZstdCompressor {
+    pub const fn new(level: i32) -> Self {
+        Self { level }
+    }
+}
+
+impl EncodingCompressor for ZstdCompressor {
+    fn id(&self) -> &str {
+        ZstdEncoding::ID.as_ref()
+    }
+
+    fn cost(&self) -> u8 {
+        constants::ZSTD_COST
+    }
+
+    fn is_heavyweight(&self) -> bool {
+        true
+    }
+
+    fn can_compress(&self, _array: &ArrayData) -> Option<&dyn EncodingCompressor> {
+        // Any array can be compressed, since only its buffers are compressed.
+        Some(self)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &ArrayData,
+        _like: Option<CompressionTree<'a>>,
+        _ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        Ok(CompressedArray::compressed(
+            ZstdArray::encode(array, self.level)?.into_array(),
+            Some(CompressionTree::flat(self)),
+            array,
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([&ZstdEncoding as EncodingRef])
+    }
+}
@@ -4,9 +4,12 @@ pub const DEFAULT_MAX_COST: u8 = 3;
 
 // structural pass-throughs have no cost
 pub const CHUNKED_COST: u8 = 0;
+pub const DECIMAL_COST: u8 = 0;
+pub const DURATION_COST: u8 = 0;
 pub const SPARSE_COST: u8 = 0;
 pub const STRUCT_COST: u8 = 0;
 pub const LIST_COST: u8 = 0;
+pub const FIXED_SIZE_LIST_COST: u8 = 0;
 pub const VARBIN_COST: u8 = 0;
 
 // so fast that we can ignore the cost
@@ -29,3 +32,5 @@ pub const RUN_END_COST: u8 = 1;
 
 // "expensive" encodings
 pub const DELTA_COST: u8 = 2;
+pub const LZ4_COST: u8 = 2;
+pub const ZSTD_COST: u8 = 2;
@@ -5,12 +5,14 @@ use compressors::chunked::DEFAULT_CHUNKED_COMPRESSOR;
 use compressors::constant::ConstantCompressor;
 use compressors::delta::DeltaCompressor;
 use compressors::fsst::FSSTCompressor;
+use compressors::lz4::Lz4Compressor;
 #[cfg(not(target_arch = "wasm32"))]
 use compressors::roaring_bool::RoaringBoolCompressor;
 #[cfg(not(target_arch = "wasm32"))]
 use compressors::roaring_int::RoaringIntCompressor;
 use compressors::struct_::StructCompressor;
 use compressors::varbin::VarBinCompressor;
+use compressors::zstd::DEFAULT_ZSTD_COMPRESSOR;
 use compressors::{CompressedArray, CompressorRef};
 use vortex_alp::{ALPEncoding, ALPRDEncoding};
 use vortex_array::array::{
@@ -23,15 +25,19 @@ use vortex_datetime_parts::DateTimePartsEncoding;
 use vortex_dict::DictEncoding;
 use vortex_fastlanes::{BitPackedEncoding, DeltaEncoding, FoREncoding};
 use vortex_fsst::FSSTEncoding;
+use vortex_lz4::Lz4Encoding;
 #[cfg(not(target_arch = "wasm32"))]
 use vortex_roaring::{RoaringBoolEncoding, RoaringIntEncoding};
 use vortex_runend::RunEndEncoding;
 use vortex_runend_bool::RunEndBoolEncoding;
 use vortex_zigzag::ZigZagEncoding;
+use vortex_zstd::ZstdEncoding;
 
 use crate::compressors::alp::ALPCompressor;
 use crate::compressors::date_time_parts::DateTimePartsCompressor;
+use crate::compressors::decimal::DecimalCompressor;
 use crate::compressors::dict::DictCompressor;
+use crate::compressors::duration::DurationCompressor;
 use crate::compressors::r#for::FoRCompressor;
 use crate::compressors::runend::DEFAULT_RUN_END_COMPRESSOR;
 use crate::compressors::runend_bool::RunEndBoolCompressor;
@@ -48,18 +54,22 @@ mod sampling_compressor;
 
 pub use sampling_compressor::*;
 
+use crate::compressors::fixed_size_list::FixedSizeListCompressor;
 use crate::compressors::list::ListCompressor;
 
-pub const DEFAULT_COMPRESSORS: [CompressorRef; 15] = [
+pub const DEFAULT_COMPRESSORS: [CompressorRef; 20] = [
     &ALPCompressor as CompressorRef,
     &BITPACK_WITH_PATCHES,
     &DEFAULT_CHUNKED_COMPRESSOR,
     &ConstantCompressor,
     &DateTimePartsCompressor,
+    &DecimalCompressor,
+    &DurationCompressor,
     // &DeltaCompressor,
     &DictCompressor,
     &FoRCompressor,
     &FSSTCompressor,
+    &Lz4Compressor,
     //&RoaringBoolCompressor,
     //&RoaringIntCompressor,
     &RunEndBoolCompressor,
@@ -67,21 +77,26 @@ pub const DEFAULT_COMPRESSORS: [CompressorRef; 15] = [
     &SparseCompressor,
     &StructCompressor,
     &ListCompressor,
+    &FixedSizeListCompressor,
     &VarBinCompressor,
     &ZigZagCompressor,
+    &DEFAULT_ZSTD_COMPRESSOR,
 ];
 
 #[cfg(not(target_arch = "wasm32"))]
-pub const ALL_COMPRESSORS: [CompressorRef; 17] = [
+pub const ALL_COMPRESSORS: [CompressorRef; 21] = [
     &ALPCompressor as CompressorRef,
     &BITPACK_WITH_PATCHES,
     &DEFAULT_CHUNKED_COMPRESSOR,
     &ConstantCompressor,
     &DateTimePartsCompressor,
+    &DecimalCompressor,
+    &DurationCompressor,
     &DeltaCompressor,
     &DictCompressor,
     &FoRCompressor,
     &FSSTCompressor,
+    &Lz4Compressor,
     &RoaringBoolCompressor,
     &RoaringIntCompressor,
     &RunEndBoolCompressor,
@@ -90,19 +105,23 @@ pub const ALL_COMPRESSORS: [CompressorRef; 17] = [
     &StructCompressor,
     &VarBinCompressor,
     &ZigZagCompressor,
+    &DEFAULT_ZSTD_COMPRESSOR,
 ];
 
 #[cfg(target_arch = "wasm32")]
-pub const ALL_COMPRESSORS: [CompressorRef; 15] = [
+pub const ALL_COMPRESSORS: [CompressorRef; 19] = [
     &ALPCompressor as CompressorRef,
     &BITPACK_WITH_PATCHES,
     &DEFAULT_CHUNKED_COMPRESSOR,
     &ConstantCompressor,
     &DateTimePartsCompressor,
+    &DecimalCompressor,
+    &DurationCompressor,
     &DeltaCompressor,
     &DictCompressor,
     &FoRCompressor,
     &FSSTCompressor,
+    &Lz4Compressor,
     // vortex-roaring depends on croaring which does not build for wasm32
     // &RoaringBoolCompressor,
     // &RoaringIntCompressor,
@@ -112,6 +131,7 @@ pub const ALL_COMPRESSORS: [CompressorRef; 15] = [
     &StructCompressor,
     &VarBinCompressor,
     &ZigZagCompressor,
+    &DEFAULT_ZSTD_COMPRESSOR,
 ];
 
 pub static ALL_ENCODINGS_CONTEXT: LazyLock<Arc<Context>> = LazyLock::new(|| {
@@ -125,6 +145,7 @@ pub static ALL_ENCODINGS_CONTEXT: LazyLock<Arc<Context>> = LazyLock::new(|| {
         &DeltaEncoding,
         &FoREncoding,
         &FSSTEncoding,
+        &Lz4Encoding,
         &PrimitiveEncoding,
         // vortex-roaring depends on croaring which does not build for wasm32
         #[cfg(not(target_arch = "wasm32"))]
@@ -138,12 +159,17 @@ pub static ALL_ENCODINGS_CONTEXT: LazyLock<Arc<Context>> = LazyLock::new(|| {
         &VarBinEncoding,
         &VarBinViewEncoding,
         &ZigZagEncoding,
+        &ZstdEncoding,
     ]))
 });
 
 #[derive(Debug, Clone)]
 pub enum Objective {
+    /// Minimize size using lightweight encodings that remain fast to decode.
     MinSize,
+    /// Minimize size, also considering heavyweight block compression codecs such as zstd and LZ4,
+    /// which are much slower to decode. Suited to cold, archival data.
+    MinSizeArchival,
 }
 
 impl Objective {
@@ -151,13 +177,20 @@ impl Objective {
         1.0
     }
 
+    /// Whether compressors that trade decode speed for size may be used.
+    pub fn allows_heavyweight(&self) -> bool {
+        matches!(self, Objective::MinSizeArchival)
+    }
+
     pub fn evaluate(
         array: &CompressedArray,
         base_size_bytes: usize,
         config: &CompressConfig,
     ) -> f64 {
         match &config.objective {
-            Objective::MinSize => (array.nbytes() as f64) / (base_size_bytes as f64),
+            Objective::MinSize | Objective::MinSizeArchival => {
+                (array.nbytes() as f64) / (base_size_bytes as f64)
+            }
         }
     }
 }
@@ -198,3 +231,14 @@ impl Default for CompressConfig {
         }
     }
 }
+
+impl CompressConfig {
+    pub fn with_objective(mut self, objective: Objective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    pub fn objective(&self) -> &Objective {
+        &self.objective
+    }
+}
@@ -192,6 +192,9 @@ impl<'a> SamplingCompressor<'a> {
             .compressors
             .iter()
             .filter(|&encoding| !self.disabled_compressors.contains(encoding))
+            .filter(|&encoding| {
+                !encoding.is_heavyweight() || self.options.objective.allows_heavyweight()
+            })
             .filter(|&encoding| encoding.can_compress(array).is_some())
             .partition::<Vec<&dyn EncodingCompressor>, _>(|&encoding| {
                 self.depth + encoding.cost() <= self.options.max_cost
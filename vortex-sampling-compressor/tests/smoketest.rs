@@ -5,23 +5,28 @@ use vortex_array::aliases::hash_set::HashSet;
 use vortex_array::array::builder::VarBinBuilder;
 use vortex_array::array::{BoolArray, PrimitiveArray, StructArray, TemporalArray};
 use vortex_array::validity::Validity;
-use vortex_array::{ArrayDType, ArrayData, IntoArrayData};
+use vortex_array::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
 use vortex_dtype::{DType, FieldName, FieldNames, Nullability};
-use vortex_sampling_compressor::{CompressConfig, SamplingCompressor};
+use vortex_sampling_compressor::{CompressConfig, Objective, SamplingCompressor};
 
 #[cfg(test)]
 mod tests {
-    use vortex_array::array::{BoolEncoding, BooleanBuffer, ChunkedArray, VarBinEncoding};
+    use vortex_array::array::{
+        BoolEncoding, BooleanBuffer, ChunkedArray, DecimalArray, FixedSizeListArray, VarBinEncoding,
+    };
     use vortex_array::encoding::Encoding;
     use vortex_array::stats::{ArrayStatistics, Stat};
     use vortex_array::variants::StructArrayTrait;
     use vortex_datetime_dtype::TimeUnit;
     use vortex_datetime_parts::DateTimePartsEncoding;
     use vortex_dict::DictEncoding;
+    use vortex_dtype::DecimalDType;
     use vortex_fastlanes::FoREncoding;
     use vortex_fsst::FSSTEncoding;
+    use vortex_lz4::Lz4Encoding;
     use vortex_sampling_compressor::ALL_COMPRESSORS;
     use vortex_scalar::Scalar;
+    use vortex_zstd::ZstdEncoding;
 
     use super::*;
 
@@ -185,6 +190,141 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)] // zstd uses an unsupported FFI
+    pub fn smoketest_archival_objective() {
+        let text = b"the quick brown fox jumps over the lazy dog. ";
+        let bytes = PrimitiveArray::from_vec(
+            text.iter()
+                .copied()
+                .cycle()
+                .take(65536)
+                .collect::<Vec<u8>>(),
+            Validity::NonNullable,
+        )
+        .into_array();
+
+        // Block compression codecs are only considered when the objective allows them.
+        let compressed = SamplingCompressor::default()
+            .compress(&bytes, None)
+            .unwrap()
+            .into_array();
+        assert_ne!(compressed.encoding().id(), ZstdEncoding::ID);
+        assert_ne!(compressed.encoding().id(), Lz4Encoding::ID);
+
+        let compressed = SamplingCompressor::new_with_options(
+            HashSet::from_iter(ALL_COMPRESSORS),
+            CompressConfig::default().with_objective(Objective::MinSizeArchival),
+        )
+        .compress(&bytes, None)
+        .unwrap()
+        .into_array();
+        println!("compressed: {}", compressed.tree_display());
+        assert!(
+            compressed.encoding().id() == ZstdEncoding::ID
+                || compressed.encoding().id() == Lz4Encoding::ID
+        );
+        assert!(compressed.nbytes() < bytes.nbytes() / 10);
+        assert_eq!(
+            compressed
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u8>(),
+            bytes.into_primitive().unwrap().maybe_null_slice::<u8>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn smoketest_decimals() {
+        for precision in [12, 30] {
+            let decimal = DecimalDType::try_new(precision, 2).unwrap();
+            let values = (0..65536)
+                .map(|i| 1_000_000 + i % 1000)
+                .collect::<Vec<i128>>();
+            let array = ArrayData::from(
+                DecimalArray::from_i128(values.clone(), decimal, Validity::NonNullable).unwrap(),
+            );
+
+            let compressed = SamplingCompressor::default()
+                .compress(&array, None)
+                .unwrap()
+                .into_array();
+            println!("compressed: {}", compressed.tree_display());
+            // The unscaled values fit into a few bits once their reference is subtracted.
+            assert!(compressed.nbytes() < array.nbytes() / 4);
+            assert_eq!(compressed.dtype(), array.dtype());
+            assert_eq!(
+                DecimalArray::try_from(compressed)
+                    .unwrap()
+                    .unscaled_i256()
+                    .unwrap()
+                    .iter()
+                    .map(|v| v.as_i128())
+                    .collect::<Vec<_>>(),
+                values
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn smoketest_durations() {
+        let values = (0..65536).map(|i| 60_000 + i % 1000).collect::<Vec<i64>>();
+        let array = ArrayData::from(TemporalArray::new_duration(
+            PrimitiveArray::from(values.clone()).into_array(),
+            TimeUnit::Ms,
+        ));
+
+        let compressed = SamplingCompressor::default()
+            .compress(&array, None)
+            .unwrap()
+            .into_array();
+        println!("compressed: {}", compressed.tree_display());
+        assert!(compressed.nbytes() < array.nbytes() / 4);
+        assert_eq!(compressed.dtype(), array.dtype());
+        assert_eq!(
+            TemporalArray::try_from(compressed)
+                .unwrap()
+                .temporal_values()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i64>(),
+            values.as_slice()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn smoketest_fixed_size_list() {
+        let values = (0..65536u32).map(|i| i % 100).collect::<Vec<_>>();
+        let array = FixedSizeListArray::try_new(
+            PrimitiveArray::from(values.clone()).into_array(),
+            16,
+            values.len() / 16,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let compressed = SamplingCompressor::default()
+            .compress(&array, None)
+            .unwrap()
+            .into_array();
+        println!("compressed: {}", compressed.tree_display());
+        assert!(compressed.nbytes() < array.nbytes() / 2);
+        assert_eq!(compressed.dtype(), array.dtype());
+        assert_eq!(
+            FixedSizeListArray::try_from(compressed)
+                .unwrap()
+                .elements()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>(),
+            values.as_slice()
+        );
+    }
+
     fn make_primitive_column(count: usize) -> ArrayData {
         PrimitiveArray::from_vec(
             (0..count).map(|i| i as i64).collect::<Vec<i64>>(),
@@ -40,6 +40,12 @@ fn random_scalar_value(u: &mut Unstructured, dtype: &DType) -> Result<ScalarValu
             .collect::<Result<Vec<_>>>()?
             .into(),
         ))),
+        DType::FixedSizeList(edt, size, _) => Ok(ScalarValue(InnerScalarValue::List(
+            (0..*size)
+                .map(|_| random_scalar_value(u, edt).map(|x| x.0))
+                .collect::<Result<Vec<_>>>()?
+                .into(),
+        ))),
         DType::Extension(..) => {
             unreachable!("Can't yet generate arbitrary scalars for ext dtype")
         }
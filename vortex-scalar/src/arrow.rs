@@ -1,9 +1,15 @@
 use std::sync::Arc;
 
+use arrow_array::types::{IntervalDayTime, IntervalMonthDayNano};
 use arrow_array::*;
-use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata, TimeUnit};
-use vortex_dtype::{DType, PType};
-use vortex_error::{vortex_bail, vortex_err, VortexError};
+use vortex_datetime_dtype::{
+    is_interval_ext_type, is_temporal_ext_type, IntervalUnit, TemporalMetadata, TimeUnit,
+};
+use vortex_dtype::{
+    fixed_size_binary_size, is_decimal_ext_type, is_fixed_size_binary_ext_type, DType, PType,
+    MAX_DECIMAL128_PRECISION,
+};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 
 use crate::Scalar;
 
@@ -81,7 +87,7 @@ impl TryFrom<&Scalar> for Arc<dyn Datum> {
             DType::Struct(..) => {
                 todo!("struct scalar conversion")
             }
-            DType::List(..) => {
+            DType::List(..) | DType::FixedSizeList(..) => {
                 todo!("list scalar conversion")
             }
             DType::Extension(ext) => {
@@ -143,9 +149,94 @@ impl TryFrom<&Scalar> for Arc<dyn Datum> {
                                 vortex_bail!("Unsupported TimeUnit {u} for {}", ext.id())
                             }
                         },
+                        TemporalMetadata::Duration(u) => match u {
+                            TimeUnit::Ns => value_to_arrow_scalar!(
+                                primitive.as_::<i64>()?,
+                                DurationNanosecondArray
+                            ),
+                            TimeUnit::Us => value_to_arrow_scalar!(
+                                primitive.as_::<i64>()?,
+                                DurationMicrosecondArray
+                            ),
+                            TimeUnit::Ms => value_to_arrow_scalar!(
+                                primitive.as_::<i64>()?,
+                                DurationMillisecondArray
+                            ),
+                            TimeUnit::S => {
+                                value_to_arrow_scalar!(primitive.as_::<i64>()?, DurationSecondArray)
+                            }
+                            TimeUnit::D => {
+                                vortex_bail!("Unsupported TimeUnit {u} for {}", ext.id())
+                            }
+                        },
                     };
                 }
 
+                if is_interval_ext_type(ext.id()) {
+                    let storage = value.as_extension().storage();
+                    return match IntervalUnit::try_from(ext.as_ref())? {
+                        IntervalUnit::YearMonth => value_to_arrow_scalar!(
+                            storage.as_primitive().as_::<i32>()?,
+                            IntervalYearMonthArray
+                        ),
+                        IntervalUnit::DayTime => value_to_arrow_scalar!(
+                            storage
+                                .as_struct()
+                                .fields()
+                                .map(|f| {
+                                    VortexResult::Ok(IntervalDayTime::new(
+                                        i32::try_from(&f[0])?,
+                                        i32::try_from(&f[1])?,
+                                    ))
+                                })
+                                .transpose()?,
+                            IntervalDayTimeArray
+                        ),
+                        IntervalUnit::MonthDayNano => value_to_arrow_scalar!(
+                            storage
+                                .as_struct()
+                                .fields()
+                                .map(|f| {
+                                    VortexResult::Ok(IntervalMonthDayNano::new(
+                                        i32::try_from(&f[0])?,
+                                        i32::try_from(&f[1])?,
+                                        i64::try_from(&f[2])?,
+                                    ))
+                                })
+                                .transpose()?,
+                            IntervalMonthDayNanoArray
+                        ),
+                    };
+                }
+
+                if is_decimal_ext_type(ext.id()) {
+                    let decimal = value.as_decimal();
+                    let dtype = decimal.decimal_dtype();
+                    let unscaled = decimal.unscaled();
+                    return Ok(if dtype.precision() <= MAX_DECIMAL128_PRECISION {
+                        Arc::new(arrow_array::Scalar::new(
+                            Decimal128Array::from(vec![unscaled.map(|v| v.as_i128())])
+                                .with_precision_and_scale(dtype.precision(), dtype.scale())?,
+                        ))
+                    } else {
+                        Arc::new(arrow_array::Scalar::new(
+                            Decimal256Array::from(vec![unscaled])
+                                .with_precision_and_scale(dtype.precision(), dtype.scale())?,
+                        ))
+                    });
+                }
+
+                if is_fixed_size_binary_ext_type(ext.id()) {
+                    let size = fixed_size_binary_size(ext)?;
+                    let bytes = value.as_extension().storage().as_list().bytes()?;
+                    return Ok(Arc::new(arrow_array::Scalar::new(
+                        FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                            std::iter::once(bytes),
+                            size.try_into()?,
+                        )?,
+                    )));
+                }
+
                 todo!("Non temporal extension scalar conversion")
             }
         }
@@ -1,13 +1,18 @@
 #![cfg(feature = "datafusion")]
 use std::sync::Arc;
 
+use arrow_array::types::{IntervalDayTime, IntervalMonthDayNano};
 use datafusion_common::ScalarValue;
 use vortex_buffer::Buffer;
 use vortex_datetime_dtype::arrow::make_temporal_ext_dtype;
-use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata, TimeUnit};
+use vortex_datetime_dtype::{
+    is_interval_ext_type, is_temporal_ext_type, IntervalUnit, TemporalMetadata, TimeUnit,
+};
 use vortex_dtype::half::f16;
-use vortex_dtype::{DType, Nullability, PType};
-use vortex_error::VortexError;
+use vortex_dtype::{
+    fixed_size_binary_size, is_fixed_size_binary_ext_type, DType, Nullability, PType,
+};
+use vortex_error::{VortexError, VortexResult};
 
 use crate::{InnerScalarValue, PValue, Scalar};
 
@@ -46,7 +51,7 @@ impl TryFrom<Scalar> for ScalarValue {
             DType::Struct(..) => {
                 todo!("struct scalar conversion")
             }
-            DType::List(..) => {
+            DType::List(..) | DType::FixedSizeList(..) => {
                 todo!("list scalar conversion")
             }
             DType::Extension(ext) => {
@@ -92,7 +97,52 @@ impl TryFrom<Scalar> for ScalarValue {
                                 unreachable!("Unsupported TimeUnit {u} for {}", ext.id())
                             }
                         },
+                        TemporalMetadata::Duration(u) => match u {
+                            TimeUnit::Ns => ScalarValue::DurationNanosecond(pv.as_::<i64>()?),
+                            TimeUnit::Us => ScalarValue::DurationMicrosecond(pv.as_::<i64>()?),
+                            TimeUnit::Ms => ScalarValue::DurationMillisecond(pv.as_::<i64>()?),
+                            TimeUnit::S => ScalarValue::DurationSecond(pv.as_::<i64>()?),
+                            TimeUnit::D => {
+                                unreachable!("Unsupported TimeUnit {u} for {}", ext.id())
+                            }
+                        },
                     });
+                } else if is_interval_ext_type(ext.id()) {
+                    match IntervalUnit::try_from(ext.as_ref())? {
+                        IntervalUnit::YearMonth => ScalarValue::IntervalYearMonth(
+                            storage_scalar.as_primitive().as_::<i32>()?,
+                        ),
+                        IntervalUnit::DayTime => ScalarValue::IntervalDayTime(
+                            storage_scalar
+                                .as_struct()
+                                .fields()
+                                .map(|f| {
+                                    VortexResult::Ok(IntervalDayTime::new(
+                                        i32::try_from(&f[0])?,
+                                        i32::try_from(&f[1])?,
+                                    ))
+                                })
+                                .transpose()?,
+                        ),
+                        IntervalUnit::MonthDayNano => ScalarValue::IntervalMonthDayNano(
+                            storage_scalar
+                                .as_struct()
+                                .fields()
+                                .map(|f| {
+                                    VortexResult::Ok(IntervalMonthDayNano::new(
+                                        i32::try_from(&f[0])?,
+                                        i32::try_from(&f[1])?,
+                                        i64::try_from(&f[2])?,
+                                    ))
+                                })
+                                .transpose()?,
+                        ),
+                    }
+                } else if is_fixed_size_binary_ext_type(ext.id()) {
+                    ScalarValue::FixedSizeBinary(
+                        fixed_size_binary_size(ext)?.try_into()?,
+                        storage_scalar.as_list().bytes()?,
+                    )
                 } else {
                     // Unknown extension type: perform scalar conversion using the canonical
                     // scalar DType.
@@ -144,13 +194,43 @@ impl From<ScalarValue> for Scalar {
             | ScalarValue::TimestampSecond(v, _)
             | ScalarValue::TimestampMillisecond(v, _)
             | ScalarValue::TimestampMicrosecond(v, _)
-            | ScalarValue::TimestampNanosecond(v, _) => v.map(|i| {
+            | ScalarValue::TimestampNanosecond(v, _)
+            | ScalarValue::DurationSecond(v)
+            | ScalarValue::DurationMillisecond(v)
+            | ScalarValue::DurationMicrosecond(v)
+            | ScalarValue::DurationNanosecond(v) => v.map(|i| {
                 let ext_dtype = make_temporal_ext_dtype(&value.data_type());
                 Scalar::new(
                     DType::Extension(Arc::new(ext_dtype.with_nullability(Nullability::Nullable))),
                     crate::ScalarValue(InnerScalarValue::Primitive(PValue::I64(i))),
                 )
             }),
+            ScalarValue::IntervalYearMonth(v) => v.map(|i| {
+                Scalar::extension(
+                    Arc::new(IntervalUnit::YearMonth.ext_dtype(Nullability::Nullable)),
+                    Scalar::from(i),
+                )
+            }),
+            ScalarValue::IntervalDayTime(v) => v.map(|i| {
+                let ext_dtype = IntervalUnit::DayTime.ext_dtype(Nullability::Nullable);
+                let storage = Scalar::struct_(
+                    ext_dtype.storage_dtype().clone(),
+                    vec![Scalar::from(i.days), Scalar::from(i.milliseconds)],
+                );
+                Scalar::extension(Arc::new(ext_dtype), storage)
+            }),
+            ScalarValue::IntervalMonthDayNano(v) => v.map(|i| {
+                let ext_dtype = IntervalUnit::MonthDayNano.ext_dtype(Nullability::Nullable);
+                let storage = Scalar::struct_(
+                    ext_dtype.storage_dtype().clone(),
+                    vec![
+                        Scalar::from(i.months),
+                        Scalar::from(i.days),
+                        Scalar::from(i.nanoseconds),
+                    ],
+                );
+                Scalar::extension(Arc::new(ext_dtype), storage)
+            }),
             _ => unimplemented!("Can't convert {value:?} value to a Vortex scalar"),
         }
         .unwrap_or_else(|| Scalar::null(DType::Null))
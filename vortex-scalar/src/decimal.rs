@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use arrow_array::types::{Decimal256Type, DecimalType};
+use arrow_buffer::i256;
+use num_traits::ToPrimitive;
+use vortex_dtype::{DType, DecimalDType, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexExpect, VortexResult};
+
+use crate::value::ScalarValue;
+use crate::{InnerScalarValue, PValue, Scalar};
+
+/// A scalar of the well-known decimal extension type, see [`DecimalDType`].
+#[derive(Debug, Clone)]
+pub struct DecimalScalar<'a> {
+    dtype: &'a DType,
+    decimal: DecimalDType,
+    value: Option<i256>,
+}
+
+impl<'a> DecimalScalar<'a> {
+    pub fn try_new(dtype: &'a DType, value: &ScalarValue) -> VortexResult<Self> {
+        let DType::Extension(ext_dtype) = dtype else {
+            vortex_bail!("Expected decimal scalar, found {}", dtype)
+        };
+        let decimal = DecimalDType::try_from(ext_dtype.as_ref())?;
+
+        let value = match &value.0 {
+            InnerScalarValue::Null => None,
+            InnerScalarValue::Primitive(pvalue) => {
+                Some(i256::from_i128(i64::try_from(*pvalue)?.into()))
+            }
+            InnerScalarValue::List(words) if words.len() == decimal.words() => {
+                let words = words
+                    .iter()
+                    .map(|word| match word {
+                        // The words are stored in two's complement, reinterpret them as unsigned.
+                        InnerScalarValue::Primitive(pvalue) => i64::try_from(*pvalue)
+                            .map(|word| word as u64)
+                            .or_else(|_| u64::try_from(*pvalue)),
+                        _ => vortex_bail!("Expected primitive decimal word, found {word}"),
+                    })
+                    .collect::<VortexResult<Vec<_>>>()?;
+                Some(join_decimal_words(&words))
+            }
+            _ => vortex_bail!("Invalid scalar value {} for {decimal}", value),
+        };
+
+        Ok(Self {
+            dtype,
+            decimal,
+            value,
+        })
+    }
+
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    #[inline]
+    pub fn decimal_dtype(&self) -> DecimalDType {
+        self.decimal
+    }
+
+    /// The unscaled integer value, i.e. the decimal value times `10^scale`.
+    #[inline]
+    pub fn unscaled(&self) -> Option<i256> {
+        self.value
+    }
+
+    /// Cast to another decimal type, rounding half away from zero when reducing the scale, or to
+    /// a primitive type.
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        let Some(value) = self.value else {
+            return Ok(Scalar::null(dtype.clone()));
+        };
+        match dtype {
+            DType::Extension(ext_dtype) => {
+                let target = DecimalDType::try_from(ext_dtype.as_ref())?;
+                let rescaled = rescale(value, self.decimal.scale(), target.scale())
+                    .ok_or_else(|| vortex_err!("Can't cast {value} to {target}"))?;
+                Scalar::decimal(rescaled, target, dtype.nullability())
+            }
+            DType::Primitive(ptype, nullability) if ptype.is_float() => Scalar::primitive(
+                value
+                    .to_f64()
+                    .ok_or_else(|| vortex_err!("Can't cast decimal {value} to f64"))?
+                    / 10f64.powi(self.decimal.scale().into()),
+                *nullability,
+            )
+            .cast(dtype),
+            DType::Primitive(..) => {
+                let integer = rescale(value, self.decimal.scale(), 0)
+                    .and_then(|v| v.to_i64())
+                    .ok_or_else(|| vortex_err!("Can't cast decimal {value} to {dtype}"))?;
+                Scalar::primitive(integer, dtype.nullability()).cast(dtype)
+            }
+            _ => vortex_bail!("Can't cast {} scalar to {dtype}", self.decimal),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for DecimalScalar<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
+        Self::try_new(value.dtype(), &value.value)
+    }
+}
+
+impl Scalar {
+    /// Create a decimal scalar from its unscaled integer value.
+    pub fn decimal(
+        unscaled: i256,
+        decimal: DecimalDType,
+        nullability: Nullability,
+    ) -> VortexResult<Self> {
+        if !Decimal256Type::is_valid_decimal_precision(unscaled, decimal.precision()) {
+            vortex_bail!("Value {unscaled} doesn't fit into {decimal}");
+        }
+        let value = match decimal.words() {
+            1 => InnerScalarValue::Primitive(PValue::I64(
+                unscaled
+                    .to_i64()
+                    .vortex_expect("Decimal of precision <= 18 must fit into i64"),
+            )),
+            n => InnerScalarValue::List(
+                split_decimal_words(unscaled, n)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, word)| {
+                        InnerScalarValue::Primitive(if i == 0 {
+                            PValue::I64(word as i64)
+                        } else {
+                            PValue::U64(word)
+                        })
+                    })
+                    .collect::<Arc<[_]>>(),
+            ),
+        };
+        Ok(Self {
+            dtype: DType::Extension(Arc::new(decimal.ext_dtype(nullability))),
+            value: ScalarValue(value),
+        })
+    }
+
+    pub fn as_decimal(&self) -> DecimalScalar {
+        DecimalScalar::try_from(self).vortex_expect("Failed to convert scalar to decimal")
+    }
+
+    pub fn as_decimal_opt(&self) -> Option<DecimalScalar> {
+        DecimalScalar::try_from(self).ok()
+    }
+}
+
+/// Convert an integer scalar to a decimal with the given parameters.
+pub(crate) fn int_to_decimal(
+    value: i64,
+    decimal: DecimalDType,
+    nullability: Nullability,
+) -> VortexResult<Scalar> {
+    let unscaled = rescale(i256::from_i128(value.into()), 0, decimal.scale())
+        .ok_or_else(|| vortex_err!("Can't cast {value} to {decimal}"))?;
+    Scalar::decimal(unscaled, decimal, nullability)
+}
+
+/// Change the scale of an unscaled decimal value, rounding half away from zero when reducing it.
+pub fn rescale(value: i256, from_scale: i8, to_scale: i8) -> Option<i256> {
+    let ten = i256::from_i128(10);
+    match to_scale.cmp(&from_scale) {
+        std::cmp::Ordering::Equal => Some(value),
+        std::cmp::Ordering::Greater => {
+            value.checked_mul(ten.checked_pow((to_scale as i32 - from_scale as i32) as u32)?)
+        }
+        std::cmp::Ordering::Less => {
+            let divisor = ten.checked_pow((from_scale as i32 - to_scale as i32) as u32)?;
+            let quotient = value.checked_div(divisor)?;
+            let remainder = value.checked_rem(divisor)?;
+            let twice = remainder.checked_abs()?.checked_mul(i256::from_i128(2))?;
+            if twice >= divisor {
+                quotient.checked_add(value.signum())
+            } else {
+                Some(quotient)
+            }
+        }
+    }
+}
+
+/// Split an unscaled decimal value into `n` 64-bit words in two's complement, most significant
+/// first, as stored by decimals that don't fit into an `i64`.
+pub fn split_decimal_words(value: i256, n: usize) -> Vec<u64> {
+    let bytes = value.to_le_bytes();
+    (0..n)
+        .rev()
+        .map(|i| {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&bytes[i * 8..(i + 1) * 8]);
+            u64::from_le_bytes(word)
+        })
+        .collect()
+}
+
+/// Reassemble an unscaled decimal value from its words, most significant first.
+pub fn join_decimal_words(words: &[u64]) -> i256 {
+    let negative = words.first().is_some_and(|&w| (w as i64) < 0);
+    let mut bytes = [if negative { 0xFF } else { 0 }; 32];
+    for (i, word) in words.iter().rev().enumerate() {
+        bytes[i * 8..(i + 1) * 8].copy_from_slice(&word.to_le_bytes());
+    }
+    i256::from_le_bytes(bytes)
+}
+
+/// Format an unscaled decimal value with its scale, e.g. `12345` with scale 2 as `123.45`.
+pub(crate) fn format_decimal(value: i256, decimal: DecimalDType) -> String {
+    Decimal256Type::format_decimal(value, decimal.precision(), decimal.scale())
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::PType;
+
+    use super::*;
+
+    #[test]
+    fn words_round_trip() {
+        for value in [0i128, 1, -1, i64::MAX as i128 + 1, i128::MIN, i128::MAX] {
+            let value = i256::from_i128(value);
+            assert_eq!(join_decimal_words(&split_decimal_words(value, 2)), value);
+            assert_eq!(join_decimal_words(&split_decimal_words(value, 4)), value);
+        }
+        assert_eq!(
+            join_decimal_words(&split_decimal_words(i256::MIN, 4)),
+            i256::MIN
+        );
+    }
+
+    #[test]
+    fn decimal_scalars() {
+        let decimal = DecimalDType::try_new(30, 2).unwrap();
+        let scalar =
+            Scalar::decimal(i256::from_i128(-12345), decimal, Nullability::NonNullable).unwrap();
+        assert_eq!(
+            scalar.as_decimal().unscaled(),
+            Some(i256::from_i128(-12345))
+        );
+        assert_eq!(scalar.to_string(), "-123.45");
+
+        let bigger =
+            Scalar::decimal(i256::from_i128(100), decimal, Nullability::NonNullable).unwrap();
+        assert!(scalar < bigger);
+
+        let narrow = DecimalDType::try_new(10, 1).unwrap();
+        let rescaled = scalar
+            .cast(&DType::Extension(Arc::new(
+                narrow.ext_dtype(Nullability::NonNullable),
+            )))
+            .unwrap();
+        assert_eq!(
+            rescaled.as_decimal().unscaled(),
+            Some(i256::from_i128(-1235))
+        );
+        assert_eq!(
+            scalar
+                .cast(&DType::Primitive(PType::F64, Nullability::NonNullable))
+                .unwrap(),
+            Scalar::from(-123.45f64)
+        );
+
+        assert!(Scalar::decimal(
+            i256::from_i128(1000),
+            DecimalDType::try_new(3, 0).unwrap(),
+            Nullability::NonNullable
+        )
+        .is_err());
+    }
+}
@@ -2,11 +2,15 @@ use std::fmt::{Display, Formatter};
 
 use itertools::Itertools;
 use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata};
-use vortex_dtype::DType;
+use vortex_dtype::{
+    is_decimal_ext_type, is_fixed_size_binary_ext_type, is_map_ext_type, is_union_ext_type, DType,
+};
 use vortex_error::vortex_panic;
 
 use crate::binary::BinaryScalar;
+use crate::decimal::format_decimal;
 use crate::extension::ExtScalar;
+use crate::list::ListScalar;
 use crate::struct_::StructScalar;
 use crate::utf8::Utf8Scalar;
 use crate::Scalar;
@@ -59,7 +63,14 @@ impl Display for Scalar {
                     write!(f, "}}")
                 }
             }
-            DType::List(..) => todo!(),
+            DType::List(..) | DType::FixedSizeList(..) => {
+                let list = ListScalar::try_from(self).map_err(|_| std::fmt::Error)?;
+                if list.is_null() {
+                    write!(f, "null")
+                } else {
+                    write!(f, "[{}]", list.elements().format(","))
+                }
+            }
             // Specialized handling for date/time/timestamp builtin extension types.
             DType::Extension(dtype) if is_temporal_ext_type(dtype.id()) => {
                 let metadata =
@@ -88,6 +99,41 @@ impl Display for Scalar {
                     }
                 }
             }
+            DType::Extension(dtype) if is_decimal_ext_type(dtype.id()) => {
+                let decimal = self.as_decimal();
+                match decimal.unscaled() {
+                    None => write!(f, "null"),
+                    Some(v) => write!(f, "{}", format_decimal(v, decimal.decimal_dtype())),
+                }
+            }
+            DType::Extension(dtype) if is_fixed_size_binary_ext_type(dtype.id()) => {
+                let storage = self.as_extension().storage();
+                match storage.as_list().bytes().map_err(|_| std::fmt::Error)? {
+                    None => write!(f, "null"),
+                    Some(bytes) => {
+                        write!(f, "{}", bytes.iter().map(|b| format!("{b:x}")).format(","))
+                    }
+                }
+            }
+            DType::Extension(dtype) if is_map_ext_type(dtype.id()) => {
+                match self.as_map().entries().map_err(|_| std::fmt::Error)? {
+                    None => write!(f, "null"),
+                    Some(entries) => write!(
+                        f,
+                        "{{{}}}",
+                        entries
+                            .iter()
+                            .map(|(k, v)| format!("{k}: {v}"))
+                            .format(", ")
+                    ),
+                }
+            }
+            DType::Extension(dtype) if is_union_ext_type(dtype.id()) => {
+                match self.as_union().value() {
+                    None => write!(f, "null"),
+                    Some(value) => write!(f, "{}", value),
+                }
+            }
             // Generic handling of unknown extension types.
             // TODO(aduffy): Allow extension authors plugin their own Scalar display.
             DType::Extension(..) => {
@@ -105,7 +151,9 @@ mod tests {
     use std::sync::Arc;
 
     use vortex_buffer::Buffer;
-    use vortex_datetime_dtype::{TemporalMetadata, TimeUnit, DATE_ID, TIMESTAMP_ID, TIME_ID};
+    use vortex_datetime_dtype::{
+        TemporalMetadata, TimeUnit, DATE_ID, DURATION_ID, TIMESTAMP_ID, TIME_ID,
+    };
     use vortex_dtype::Nullability::{NonNullable, Nullable};
     use vortex_dtype::{DType, ExtDType, ExtMetadata, PType, StructDType};
 
@@ -262,6 +310,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_duration() {
+        fn dtype() -> DType {
+            DType::Extension(Arc::new(ExtDType::new(
+                DURATION_ID.clone(),
+                Arc::new(DType::Primitive(PType::I64, Nullable)),
+                Some(ExtMetadata::from(TemporalMetadata::Duration(TimeUnit::S))),
+            )))
+        }
+
+        assert_eq!(format!("{}", Scalar::null(dtype())), "null");
+
+        assert_eq!(
+            format!(
+                "{}",
+                Scalar::new(
+                    dtype(),
+                    ScalarValue(InnerScalarValue::Primitive(PValue::I64(
+                        (3 * MINUTES + 25).into()
+                    )))
+                )
+            ),
+            "PT205s"
+        );
+    }
+
     #[test]
     fn display_date() {
         fn dtype() -> DType {
@@ -5,16 +5,18 @@ use std::sync::Arc;
 pub use scalar_type::ScalarType;
 use vortex_buffer::{Buffer, BufferString};
 use vortex_dtype::half::f16;
-use vortex_dtype::{DType, Nullability};
+use vortex_dtype::{is_decimal_ext_type, DType, DecimalDType, Nullability};
 #[cfg(feature = "arbitrary")]
 pub mod arbitrary;
 mod arrow;
 mod binary;
 mod bool;
 mod datafusion;
+mod decimal;
 mod display;
 mod extension;
 mod list;
+mod map;
 mod null;
 mod primitive;
 mod pvalue;
@@ -22,19 +24,23 @@ mod scalar_type;
 #[cfg(feature = "serde")]
 mod serde;
 mod struct_;
+mod union;
 mod utf8;
 mod value;
 
 pub use binary::*;
 pub use bool::*;
+pub use decimal::*;
 pub use extension::*;
 pub use list::*;
+pub use map::*;
 pub use primitive::*;
 pub use pvalue::*;
 pub use struct_::*;
+pub use union::*;
 pub use utf8::*;
 pub use value::*;
-use vortex_error::{vortex_bail, VortexExpect, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
 
 /// A single logical item, composed of both a [`ScalarValue`] and a logical [`DType`].
 ///
@@ -111,6 +117,12 @@ impl Scalar {
             });
         }
 
+        if let DType::Extension(ext_dtype) = self.dtype() {
+            if is_decimal_ext_type(ext_dtype.id()) {
+                return DecimalScalar::try_from(self).and_then(|s| s.cast(dtype));
+            }
+        }
+
         match dtype {
             DType::Null => vortex_bail!("Can't cast non-null to null"),
             DType::Bool(_) => BoolScalar::try_from(self).and_then(|s| s.cast(dtype)),
@@ -118,7 +130,21 @@ impl Scalar {
             DType::Utf8(_) => Utf8Scalar::try_from(self).and_then(|s| s.cast(dtype)),
             DType::Binary(_) => BinaryScalar::try_from(self).and_then(|s| s.cast(dtype)),
             DType::Struct(..) => StructScalar::try_from(self).and_then(|s| s.cast(dtype)),
-            DType::List(..) => ListScalar::try_from(self).and_then(|s| s.cast(dtype)),
+            DType::List(..) | DType::FixedSizeList(..) => {
+                ListScalar::try_from(self).and_then(|s| s.cast(dtype))
+            }
+            DType::Extension(ext_dtype) if is_decimal_ext_type(ext_dtype.id()) => {
+                match self.dtype() {
+                    DType::Primitive(ptype, _) if ptype.is_int() => int_to_decimal(
+                        self.as_primitive()
+                            .as_::<i64>()?
+                            .ok_or_else(|| vortex_err!("Can't cast null scalar to {dtype}"))?,
+                        DecimalDType::try_from(ext_dtype.as_ref())?,
+                        dtype.nullability(),
+                    ),
+                    _ => vortex_bail!("Can't cast {} scalar to {dtype}", self.dtype()),
+                }
+            }
             DType::Extension(ext_dtype) => {
                 if !self.value().is_instance_of(ext_dtype.storage_dtype()) {
                     vortex_bail!(
@@ -189,7 +215,7 @@ impl Scalar {
     }
 
     pub fn as_list_opt(&self) -> Option<ListScalar> {
-        matches!(self.dtype, DType::List(..)).then(|| self.as_list())
+        matches!(self.dtype, DType::List(..) | DType::FixedSizeList(..)).then(|| self.as_list())
     }
 
     pub fn as_extension(&self) -> ExtScalar {
@@ -1,9 +1,11 @@
 use std::ops::Deref;
 use std::sync::Arc;
 
-use vortex_dtype::DType;
 use vortex_dtype::Nullability::NonNullable;
-use vortex_error::{vortex_bail, vortex_panic, VortexError, VortexResult};
+use vortex_dtype::{DType, Nullability};
+use vortex_error::{
+    vortex_bail, vortex_err, vortex_panic, VortexError, VortexExpect, VortexResult,
+};
 
 use crate::value::ScalarValue;
 use crate::{InnerScalarValue, Scalar};
@@ -38,7 +40,8 @@ impl<'a> ListScalar<'a> {
     }
 
     pub fn element_dtype(&self) -> DType {
-        let DType::List(element_type, _) = self.dtype() else {
+        let (DType::List(element_type, _) | DType::FixedSizeList(element_type, ..)) = self.dtype()
+        else {
             unreachable!();
         };
         (*element_type).deref().clone()
@@ -66,6 +69,20 @@ impl<'a> ListScalar<'a> {
             })
     }
 
+    /// The elements of a list of `u8`, e.g. the storage of a fixed-size binary scalar.
+    pub(crate) fn bytes(&self) -> VortexResult<Option<Vec<u8>>> {
+        self.elements
+            .as_ref()
+            .map(|elements| {
+                elements
+                    .iter()
+                    .map(|e| e.as_pvalue()?.map(u8::try_from).transpose())
+                    .map(|b| b?.ok_or_else(|| vortex_err!("Expected non-null byte")))
+                    .collect::<VortexResult<Vec<_>>>()
+            })
+            .transpose()
+    }
+
     pub fn cast(&self, _dtype: &DType) -> VortexResult<Scalar> {
         todo!()
     }
@@ -94,11 +111,41 @@ impl Scalar {
     }
 }
 
+impl Scalar {
+    /// Create a fixed-size list scalar, whose size is the number of `children`.
+    pub fn fixed_size_list(
+        element_dtype: Arc<DType>,
+        children: Vec<Scalar>,
+        nullability: Nullability,
+    ) -> Self {
+        for child in &children {
+            if child.dtype() != &*element_dtype {
+                vortex_panic!(
+                    "tried to create fixed-size list of {} with values of type {}",
+                    element_dtype,
+                    child.dtype()
+                );
+            }
+        }
+        let size = u32::try_from(children.len())
+            .vortex_expect("Fixed-size list must have at most u32::MAX elements");
+        Self {
+            dtype: DType::FixedSizeList(element_dtype, size, nullability),
+            value: ScalarValue(InnerScalarValue::List(
+                children
+                    .into_iter()
+                    .map(|x| x.value.0)
+                    .collect::<Arc<[_]>>(),
+            )),
+        }
+    }
+}
+
 impl<'a> TryFrom<&'a Scalar> for ListScalar<'a> {
     type Error = VortexError;
 
     fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
-        if !matches!(value.dtype(), DType::List(..)) {
+        if !matches!(value.dtype(), DType::List(..) | DType::FixedSizeList(..)) {
             vortex_bail!("Expected list scalar, found {}", value.dtype())
         }
 
@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use vortex_dtype::{DType, MapDType, Nullability};
+use vortex_error::{vortex_bail, VortexError, VortexExpect, VortexResult};
+
+use crate::value::ScalarValue;
+use crate::{InnerScalarValue, Scalar};
+
+/// A scalar of the well-known map extension type, see [`MapDType`].
+#[derive(Debug, Clone)]
+pub struct MapScalar<'a> {
+    dtype: &'a DType,
+    map: MapDType,
+    entries: Option<&'a Arc<[InnerScalarValue]>>,
+}
+
+impl<'a> MapScalar<'a> {
+    pub fn try_new(dtype: &'a DType, value: &'a ScalarValue) -> VortexResult<Self> {
+        let DType::Extension(ext_dtype) = dtype else {
+            vortex_bail!("Expected map scalar, found {}", dtype)
+        };
+        Ok(Self {
+            dtype,
+            map: MapDType::try_from(ext_dtype.as_ref())?,
+            entries: value.as_list()?,
+        })
+    }
+
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    #[inline]
+    pub fn map_dtype(&self) -> &MapDType {
+        &self.map
+    }
+
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        self.entries.is_none()
+    }
+
+    /// The number of entries, which is zero for null maps.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.map_or(0, |e| e.len())
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The key-value pairs of the map, in storage order.
+    pub fn entries(&self) -> VortexResult<Option<Vec<(Scalar, Scalar)>>> {
+        self.entries
+            .map(|entries| {
+                entries
+                    .iter()
+                    .map(|entry| match entry {
+                        InnerScalarValue::List(kv) if kv.len() == 2 => Ok((
+                            Scalar::new(self.map.key_dtype().clone(), ScalarValue(kv[0].clone())),
+                            Scalar::new(self.map.value_dtype().clone(), ScalarValue(kv[1].clone())),
+                        )),
+                        _ => vortex_bail!("Invalid map entry {entry}"),
+                    })
+                    .collect()
+            })
+            .transpose()
+    }
+
+    /// The value of the first entry with the given key, if any.
+    pub fn get(&self, key: &Scalar) -> VortexResult<Option<Scalar>> {
+        Ok(self
+            .entries()?
+            .and_then(|entries| entries.into_iter().find(|(k, _)| k == key))
+            .map(|(_, v)| v))
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for MapScalar<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
+        Self::try_new(value.dtype(), &value.value)
+    }
+}
+
+impl Scalar {
+    /// Create a map scalar from its key-value pairs, whose dtypes must match the map type.
+    pub fn map(
+        map: MapDType,
+        entries: Vec<(Scalar, Scalar)>,
+        nullability: Nullability,
+    ) -> VortexResult<Self> {
+        let entries = entries
+            .into_iter()
+            .map(|(key, value)| {
+                if key.dtype() != map.key_dtype() || value.dtype() != map.value_dtype() {
+                    vortex_bail!(
+                        "Expected map entry of {map}, found ({}, {})",
+                        key.dtype(),
+                        value.dtype()
+                    );
+                }
+                Ok(InnerScalarValue::List(
+                    [key.value.0, value.value.0].into_iter().collect(),
+                ))
+            })
+            .collect::<VortexResult<Arc<[_]>>>()?;
+        Ok(Self {
+            dtype: DType::Extension(Arc::new(map.ext_dtype(nullability))),
+            value: ScalarValue(InnerScalarValue::List(entries)),
+        })
+    }
+
+    pub fn as_map(&self) -> MapScalar {
+        MapScalar::try_from(self).vortex_expect("Failed to convert scalar to map")
+    }
+
+    pub fn as_map_opt(&self) -> Option<MapScalar> {
+        MapScalar::try_from(self).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::{PType, MAP_ID};
+
+    use super::*;
+
+    #[test]
+    fn map_scalar() {
+        let map = MapDType::try_new(
+            DType::Utf8(Nullability::NonNullable),
+            DType::Primitive(PType::I32, Nullability::Nullable),
+        )
+        .unwrap();
+        let scalar = Scalar::map(
+            map.clone(),
+            vec![
+                ("a".into(), Scalar::from(Some(1i32))),
+                ("b".into(), Scalar::null(map.value_dtype().clone())),
+            ],
+            Nullability::Nullable,
+        )
+        .unwrap();
+        assert!(matches!(scalar.dtype(), DType::Extension(ext) if ext.id() == &*MAP_ID));
+
+        let map_scalar = scalar.as_map();
+        assert_eq!(map_scalar.len(), 2);
+        assert_eq!(
+            map_scalar.get(&"a".into()).unwrap(),
+            Some(Scalar::from(Some(1i32)))
+        );
+        assert!(map_scalar.get(&"b".into()).unwrap().unwrap().is_null());
+        assert_eq!(map_scalar.get(&"c".into()).unwrap(), None);
+        assert_eq!(scalar.to_string(), "{a: 1_i32, b: null}");
+
+        let null = Scalar::null(scalar.dtype().clone());
+        assert!(null.as_map().is_null());
+        assert_eq!(null.to_string(), "null");
+
+        Scalar::map(
+            map,
+            vec![("a".into(), Scalar::from(1i64))],
+            Nullability::Nullable,
+        )
+        .unwrap_err();
+    }
+}
@@ -117,6 +117,10 @@ fn deserialize_scalar_value(dtype: &DType, value: &pb::ScalarValue) -> VortexRes
         .as_ref()
         .ok_or_else(|| vortex_err!(InvalidSerde: "ScalarValue missing kind"))?;
 
+    if let DType::Extension(ext_dtype) = dtype {
+        return deserialize_scalar_value(ext_dtype.storage_dtype(), value);
+    }
+
     match kind {
         Kind::NullValue(_) => Ok(ScalarValue(InnerScalarValue::Null)),
         Kind::BoolValue(v) => Ok(ScalarValue(InnerScalarValue::Bool(*v))),
@@ -157,7 +161,7 @@ fn deserialize_scalar_value(dtype: &DType, value: &pb::ScalarValue) -> VortexRes
                         values.push(deserialize_scalar_value(dtype, elem)?);
                     }
                 }
-                DType::List(elementdt, _) => {
+                DType::List(elementdt, _) | DType::FixedSizeList(elementdt, ..) => {
                     for elem in v.values.iter() {
                         values.push(deserialize_scalar_value(elementdt, elem)?);
                     }
@@ -246,6 +250,15 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_fixed_size_list() {
+        round_trip(Scalar::fixed_size_list(
+            Arc::new(DType::Primitive(I32, Nullability::NonNullable)),
+            vec![Scalar::from(1i32), Scalar::from(2i32)],
+            Nullability::Nullable,
+        ));
+    }
+
     #[test]
     fn test_f16() {
         round_trip(Scalar::new(
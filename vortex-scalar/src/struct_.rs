@@ -128,11 +128,11 @@ impl<'a> StructScalar<'a> {
             ScalarValue(InnerScalarValue::List(
                 projection
                     .iter()
-                    .map(|p| match p {
-                        Field::Name(n) => struct_dtype
-                            .find_name(n)
-                            .vortex_expect("DType has been successfully projected already"),
-                        Field::Index(i) => *i,
+                    .map(|p| {
+                        struct_dtype
+                            .field_info(p)
+                            .vortex_expect("DType has been successfully projected already")
+                            .index
                     })
                     .map(|i| fs[i].clone())
                     .collect(),
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use vortex_dtype::{DType, Nullability, UnionDType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexExpect, VortexResult};
+
+use crate::value::ScalarValue;
+use crate::{InnerScalarValue, PValue, Scalar};
+
+/// A scalar of the well-known union extension type, see [`UnionDType`].
+#[derive(Debug, Clone)]
+pub struct UnionScalar<'a> {
+    dtype: &'a DType,
+    union: UnionDType,
+    /// The type id and the value of the selected variant, unless the union itself is null.
+    value: Option<(i8, Scalar)>,
+}
+
+impl<'a> UnionScalar<'a> {
+    pub fn try_new(dtype: &'a DType, value: &ScalarValue) -> VortexResult<Self> {
+        let DType::Extension(ext_dtype) = dtype else {
+            vortex_bail!("Expected union scalar, found {}", dtype)
+        };
+        let union = UnionDType::try_from(ext_dtype.as_ref())?;
+
+        let value = match value.as_list()? {
+            None => None,
+            Some(fields) => {
+                let [InnerScalarValue::Primitive(type_id), InnerScalarValue::List(variants)] =
+                    fields.as_ref()
+                else {
+                    vortex_bail!("Invalid scalar value {} for {union}", value);
+                };
+                let type_id = i8::try_from(*type_id)?;
+                let idx = union
+                    .variant_idx(type_id)
+                    .ok_or_else(|| vortex_err!("Invalid type id {type_id} for {union}"))?;
+                let variant = variants
+                    .get(idx)
+                    .ok_or_else(|| vortex_err!("Invalid scalar value {} for {union}", value))?;
+                Some((
+                    type_id,
+                    Scalar::new(
+                        union.variants().dtypes()[idx].clone(),
+                        ScalarValue(variant.clone()),
+                    ),
+                ))
+            }
+        };
+
+        Ok(Self {
+            dtype,
+            union,
+            value,
+        })
+    }
+
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    #[inline]
+    pub fn union_dtype(&self) -> &UnionDType {
+        &self.union
+    }
+
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        self.value.is_none()
+    }
+
+    /// The type id of the selected variant.
+    #[inline]
+    pub fn type_id(&self) -> Option<i8> {
+        self.value.as_ref().map(|(type_id, _)| *type_id)
+    }
+
+    /// The value of the selected variant, which may itself be null.
+    #[inline]
+    pub fn value(&self) -> Option<&Scalar> {
+        self.value.as_ref().map(|(_, value)| value)
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for UnionScalar<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
+        Self::try_new(value.dtype(), &value.value)
+    }
+}
+
+impl Scalar {
+    /// Create a union scalar holding `value` in the variant with the given type id.
+    pub fn union(
+        union: UnionDType,
+        type_id: i8,
+        value: Scalar,
+        nullability: Nullability,
+    ) -> VortexResult<Self> {
+        let idx = union
+            .variant_idx(type_id)
+            .ok_or_else(|| vortex_err!("Invalid type id {type_id} for {union}"))?;
+        let value = value.cast(&union.variants().dtypes()[idx])?;
+        let variants = (0..union.variants().names().len())
+            .map(|i| {
+                if i == idx {
+                    value.value.0.clone()
+                } else {
+                    InnerScalarValue::Null
+                }
+            })
+            .collect::<Arc<[_]>>();
+        Ok(Self {
+            dtype: DType::Extension(Arc::new(union.ext_dtype(nullability))),
+            value: ScalarValue(InnerScalarValue::List(
+                [
+                    InnerScalarValue::Primitive(PValue::I8(type_id)),
+                    InnerScalarValue::List(variants),
+                ]
+                .into_iter()
+                .collect(),
+            )),
+        })
+    }
+
+    pub fn as_union(&self) -> UnionScalar {
+        UnionScalar::try_from(self).vortex_expect("Failed to convert scalar to union")
+    }
+
+    pub fn as_union_opt(&self) -> Option<UnionScalar> {
+        UnionScalar::try_from(self).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::{FieldNames, PType, StructDType, UnionMode};
+
+    use super::*;
+
+    #[test]
+    fn union_scalar() {
+        let union = UnionDType::try_new(
+            StructDType::new(
+                FieldNames::from(["int".into(), "str".into()]),
+                vec![
+                    DType::Primitive(PType::I32, Nullability::NonNullable),
+                    DType::Utf8(Nullability::NonNullable),
+                ],
+            ),
+            vec![0, 5],
+            UnionMode::Sparse,
+        )
+        .unwrap();
+
+        let scalar =
+            Scalar::union(union.clone(), 5, "hello".into(), Nullability::NonNullable).unwrap();
+        let union_scalar = scalar.as_union();
+        assert_eq!(union_scalar.type_id(), Some(5));
+        assert_eq!(
+            union_scalar
+                .value()
+                .unwrap()
+                .as_utf8()
+                .value()
+                .unwrap()
+                .as_str(),
+            "hello"
+        );
+        assert_eq!(scalar.to_string(), "hello");
+
+        let scalar = Scalar::union(union.clone(), 0, 42i32.into(), Nullability::Nullable).unwrap();
+        assert_eq!(
+            scalar.as_union().value().unwrap(),
+            &Scalar::from(Some(42i32))
+        );
+        assert!(Scalar::null(scalar.dtype().clone()).as_union().is_null());
+
+        Scalar::union(union, 1, 42i32.into(), Nullability::Nullable).unwrap_err();
+    }
+}
@@ -133,6 +133,9 @@ impl InnerScalarValue {
             (InnerScalarValue::List(values), DType::List(dtype, _)) => {
                 values.iter().all(|v| v.is_instance_of(dtype))
             }
+            (InnerScalarValue::List(values), DType::FixedSizeList(dtype, size, _)) => {
+                values.len() == *size as usize && values.iter().all(|v| v.is_instance_of(dtype))
+            }
             (InnerScalarValue::List(values), DType::Struct(structdt, _)) => values
                 .iter()
                 .zip(structdt.dtypes().to_vec())